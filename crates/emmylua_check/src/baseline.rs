@@ -0,0 +1,184 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use emmylua_code_analysis::{DbIndex, FileId};
+use lsp_types::{Diagnostic, NumberOrString};
+use serde::{Deserialize, Serialize};
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    entries: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+struct BaselineKey {
+    file: String,
+    code: String,
+    fingerprint: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineEntry {
+    #[serde(flatten)]
+    key: BaselineKey,
+    count: usize,
+}
+
+/// The known diagnostics of a workspace, only the diagnostics which are not in it are reported
+#[derive(Debug)]
+pub struct Baseline {
+    path: PathBuf,
+    workspace: PathBuf,
+    // rewrite the baseline with the current diagnostics instead of filtering them
+    update: bool,
+    entries: HashMap<BaselineKey, usize>,
+}
+
+impl Baseline {
+    /// Load the baseline, it's recorded from this run when the file doesn't exist yet
+    pub fn new(path: PathBuf, workspace: PathBuf, update: bool) -> Result<Self, String> {
+        let mut baseline = Baseline {
+            path,
+            workspace,
+            update,
+            entries: HashMap::new(),
+        };
+        if update || !baseline.path.exists() {
+            baseline.update = true;
+            return Ok(baseline);
+        }
+
+        let content = std::fs::read_to_string(&baseline.path)
+            .map_err(|e| format!("Failed to read baseline {:?}: {}", baseline.path, e))?;
+        let baseline_file: BaselineFile = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse baseline {:?}: {}", baseline.path, e))?;
+        if baseline_file.version != BASELINE_VERSION {
+            return Err(format!(
+                "Unsupported baseline version {} in {:?}, rerun with --update-baseline",
+                baseline_file.version, baseline.path
+            ));
+        }
+
+        for entry in baseline_file.entries {
+            *baseline.entries.entry(entry.key).or_default() += entry.count;
+        }
+        Ok(baseline)
+    }
+
+    /// Remove the diagnostics recorded in the baseline, when updating all of them are recorded
+    /// and none is reported
+    pub fn filter_diagnostics(
+        &mut self,
+        db: &DbIndex,
+        file_id: FileId,
+        diagnostics: Vec<Diagnostic>,
+    ) -> Vec<Diagnostic> {
+        let mut new_diagnostics = Vec::new();
+        for diagnostic in diagnostics {
+            let Some(key) = self.get_key(db, file_id, &diagnostic) else {
+                new_diagnostics.push(diagnostic);
+                continue;
+            };
+
+            if self.update {
+                *self.entries.entry(key).or_default() += 1;
+                continue;
+            }
+
+            match self.entries.get_mut(&key) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => new_diagnostics.push(diagnostic),
+            }
+        }
+
+        new_diagnostics
+    }
+
+    /// Write the baseline when it's updated
+    pub fn finish(self) -> Result<(), String> {
+        if !self.update {
+            return Ok(());
+        }
+
+        let diagnostic_count = self.entries.values().sum::<usize>();
+        let mut entries = self
+            .entries
+            .into_iter()
+            .map(|(key, count)| BaselineEntry { key, count })
+            .collect::<Vec<_>>();
+        // a stable order keeps the baseline diff readable
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        let baseline_file = BaselineFile {
+            version: BASELINE_VERSION,
+            entries,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
+                std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+        }
+        let content = serde_json::to_string_pretty(&baseline_file).map_err(|e| e.to_string())?;
+        std::fs::write(&self.path, content)
+            .map_err(|e| format!("Failed to write baseline {:?}: {}", self.path, e))?;
+        eprintln!(
+            "Baseline {:?} updated with {} diagnostics",
+            self.path, diagnostic_count
+        );
+        Ok(())
+    }
+
+    fn get_key(
+        &self,
+        db: &DbIndex,
+        file_id: FileId,
+        diagnostic: &Diagnostic,
+    ) -> Option<BaselineKey> {
+        let document = db.get_vfs().get_document(&file_id)?;
+        let file_path = get_relative_path(document.get_file_path(), &self.workspace);
+        let code = match &diagnostic.code {
+            Some(NumberOrString::String(code)) => code.clone(),
+            Some(NumberOrString::Number(code)) => code.to_string(),
+            None => String::new(),
+        };
+
+        // the line and the diagnosed code identify the diagnostic when the code around it moves
+        let text = document.get_text();
+        let (start, end) = document.get_range_span(diagnostic.range)?;
+        let line_range = document.get_line_range(diagnostic.range.start.line as usize)?;
+        let line_text = document.get_text_slice(line_range);
+        let mut content = normalize_whitespace(line_text);
+        content.push('\n');
+        content.push_str(&normalize_whitespace(text.get(start..end)?));
+
+        Some(BaselineKey {
+            file: file_path,
+            code,
+            fingerprint: format!("{:016x}", fnv1a_hash(content.as_bytes())),
+        })
+    }
+}
+
+fn get_relative_path(file_path: &Path, workspace: &Path) -> String {
+    let file_path = file_path.strip_prefix(workspace).unwrap_or(file_path);
+    file_path.to_string_lossy().replace('\\', "/")
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// the std hasher isn't guaranteed to be stable between releases, the baseline is kept in git
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
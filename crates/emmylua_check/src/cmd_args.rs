@@ -20,7 +20,7 @@ pub struct CmdArgs {
 
     #[structopt(
         long,
-        help = "Specify output format (json or text)",
+        help = "Specify output format (json, text, sarif or junit)",
         default_value = "text",
         possible_values = &OutputFormat::variants(),
         case_insensitive = true
@@ -29,7 +29,7 @@ pub struct CmdArgs {
 
     #[structopt(
         long,
-        help = "Specify output destination (stdout or a file path, only used when output_format is json)",
+        help = "Specify output destination (stdout or a file path, only used when output_format is not text)",
         default_value = "stdout",
         parse(try_from_str)
     )]
@@ -37,12 +37,41 @@ pub struct CmdArgs {
 
     #[structopt(long, help = "Treat warnings as errors")]
     pub warnings_as_errors: bool,
+
+    #[structopt(
+        long,
+        parse(from_os_str),
+        help = "Only report the diagnostics which are not recorded in the baseline file, the file is created when it doesn't exist"
+    )]
+    pub baseline: Option<std::path::PathBuf>,
+
+    #[structopt(
+        long,
+        requires = "baseline",
+        help = "Rewrite the baseline file with the current diagnostics"
+    )]
+    pub update_baseline: bool,
+
+    #[structopt(
+        long,
+        help = "Apply the machine-applicable fixes and write the files back"
+    )]
+    pub fix: bool,
+
+    #[structopt(
+        long,
+        conflicts_with = "fix",
+        help = "Print the machine-applicable fixes as a unified diff to stderr without writing the files"
+    )]
+    pub fix_dry_run: bool,
 }
 
 #[derive(Debug, Clone)]
 pub enum OutputFormat {
     Json,
     Text,
+    Sarif,
+    Junit,
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -51,14 +80,16 @@ impl std::str::FromStr for OutputFormat {
         match s.to_lowercase().as_str() {
             "json" => Ok(OutputFormat::Json),
             "text" => Ok(OutputFormat::Text),
+            "sarif" => Ok(OutputFormat::Sarif),
+            "junit" => Ok(OutputFormat::Junit),
             _ => Err(format!("Invalid output format: {}", s)),
         }
     }
 }
 
 impl OutputFormat {
-    pub fn variants() -> [&'static str; 2] {
-        ["json", "text"]
+    pub fn variants() -> [&'static str; 4] {
+        ["json", "text", "sarif", "junit"]
     }
 }
 
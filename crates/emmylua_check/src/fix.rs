@@ -0,0 +1,167 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use emmylua_code_analysis::{get_diagnostic_fix, EmmyLuaAnalysis, FileId};
+use rowan::TextRange;
+use tokio_util::sync::CancellationToken;
+
+// fixes which overlap each other are applied in the following rounds
+const MAX_FIX_ROUNDS: usize = 10;
+
+/// Apply the machine-applicable fixes of the files and write them back, or print the changes
+/// as a unified diff to stderr when `dry_run` is set, stdout is left to the report
+pub fn fix_files(
+    analysis: &mut EmmyLuaAnalysis,
+    file_ids: &[FileId],
+    workspace: &PathBuf,
+    dry_run: bool,
+) -> Result<(), String> {
+    let mut original_texts: HashMap<PathBuf, String> = HashMap::new();
+    let mut fix_count = 0;
+    for _ in 0..MAX_FIX_ROUNDS {
+        let mut fixed_files = Vec::new();
+        for file_id in file_ids {
+            let Some((file_path, text, count)) = fix_file(analysis, *file_id) else {
+                continue;
+            };
+
+            if !original_texts.contains_key(&file_path) {
+                let document = analysis
+                    .compilation
+                    .get_db()
+                    .get_vfs()
+                    .get_document(file_id);
+                if let Some(document) = document {
+                    original_texts.insert(file_path.clone(), document.get_text().to_string());
+                }
+            }
+            fix_count += count;
+            fixed_files.push((file_path, Some(text)));
+        }
+
+        if fixed_files.is_empty() {
+            break;
+        }
+        analysis.update_files_by_path(fixed_files);
+    }
+
+    let mut fixed_texts = Vec::new();
+    for (file_path, original_text) in &original_texts {
+        let Some(text) = get_file_text(analysis, file_path) else {
+            continue;
+        };
+        fixed_texts.push((file_path.clone(), original_text.clone(), text));
+    }
+    fixed_texts.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if dry_run {
+        for (file_path, original_text, text) in &fixed_texts {
+            let relative_path = file_path
+                .strip_prefix(workspace)
+                .unwrap_or(file_path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let patch = diffy::DiffOptions::new()
+                .set_original_filename(format!("a/{}", relative_path))
+                .set_modified_filename(format!("b/{}", relative_path))
+                .create_patch(original_text, text);
+            eprint!("{}", patch);
+        }
+
+        // the check reports the diagnostics of the files on disk
+        analysis.update_files_by_path(
+            original_texts
+                .into_iter()
+                .map(|(file_path, text)| (file_path, Some(text)))
+                .collect(),
+        );
+        eprintln!(
+            "{} problems can be fixed in {} files",
+            fix_count,
+            fixed_texts.len()
+        );
+        return Ok(());
+    }
+
+    for (file_path, _, text) in &fixed_texts {
+        std::fs::write(file_path, text)
+            .map_err(|e| format!("Failed to write {:?}: {}", file_path, e))?;
+    }
+    eprintln!(
+        "Fixed {} problems in {} files",
+        fix_count,
+        fixed_texts.len()
+    );
+    Ok(())
+}
+
+// apply the fixes which don't overlap, returns the new text and the number of applied fixes
+fn fix_file(analysis: &EmmyLuaAnalysis, file_id: FileId) -> Option<(PathBuf, String, usize)> {
+    let diagnostics = analysis.diagnose_file(file_id, CancellationToken::new())?;
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let document = semantic_model.get_document();
+
+    let mut applied_ranges: Vec<TextRange> = Vec::new();
+    let mut edits = Vec::new();
+    let mut count = 0;
+    for diagnostic in &diagnostics {
+        let Some(fix) = get_diagnostic_fix(&semantic_model, diagnostic) else {
+            continue;
+        };
+        if !fix.is_machine_applicable {
+            continue;
+        }
+
+        let Some(ranges) = fix
+            .edits
+            .iter()
+            .map(|edit| document.to_rowan_range(edit.range))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        let is_overlapped = ranges.iter().any(|range| {
+            applied_ranges
+                .iter()
+                .any(|applied| is_overlapped(*range, *applied))
+        });
+        if is_overlapped {
+            continue;
+        }
+
+        applied_ranges.extend(ranges.iter().copied());
+        edits.extend(
+            ranges
+                .into_iter()
+                .zip(fix.edits.into_iter().map(|edit| edit.new_text)),
+        );
+        count += 1;
+    }
+
+    if edits.is_empty() {
+        return None;
+    }
+
+    // apply from the end so the offsets of the earlier edits stay valid
+    edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start()));
+    let mut text = document.get_text().to_string();
+    for (range, new_text) in edits {
+        text.replace_range(
+            usize::from(range.start())..usize::from(range.end()),
+            &new_text,
+        );
+    }
+
+    Some((document.get_file_path().clone(), text, count))
+}
+
+fn is_overlapped(a: TextRange, b: TextRange) -> bool {
+    // two insertions at the same offset would be applied in an unknown order
+    a.start() < b.end() && b.start() < a.end() || a.start() == b.start()
+}
+
+fn get_file_text(analysis: &EmmyLuaAnalysis, file_path: &PathBuf) -> Option<String> {
+    let vfs = analysis.compilation.get_db().get_vfs();
+    let uri = emmylua_code_analysis::file_path_to_uri(file_path)?;
+    let file_id = vfs.get_file_id(&uri)?;
+    Some(vfs.get_document(&file_id)?.get_text().to_string())
+}
@@ -1,6 +1,9 @@
+mod baseline;
 mod cmd_args;
+mod fix;
 mod init;
 mod output;
+mod test;
 
 use cmd_args::CmdArgs;
 use emmylua_code_analysis::{DbIndex, FileId};
@@ -17,17 +20,35 @@ async fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
         workspace = std::env::current_dir()?.join(workspace);
     }
 
-    let analysis = match init::load_workspace(workspace.clone(), cmd_args.config, cmd_args.ignore) {
-        Some(analysis) => analysis,
-        None => {
-            eprintln!("Failed to load workspace");
-            return Err("Failed to load workspace".into());
-        }
+    let mut analysis =
+        match init::load_workspace(workspace.clone(), cmd_args.config, cmd_args.ignore) {
+            Some(analysis) => analysis,
+            None => {
+                eprintln!("Failed to load workspace");
+                return Err("Failed to load workspace".into());
+            }
+        };
+
+    let baseline = match cmd_args.baseline {
+        Some(path) => Some(baseline::Baseline::new(
+            path,
+            workspace.clone(),
+            cmd_args.update_baseline,
+        )?),
+        None => None,
     };
 
     let files = analysis.compilation.get_db().get_vfs().get_all_file_ids();
     let db = analysis.compilation.get_db();
     let need_check_files = get_need_check_ids(db, files, &workspace);
+    if cmd_args.fix || cmd_args.fix_dry_run {
+        fix::fix_files(
+            &mut analysis,
+            &need_check_files,
+            &workspace,
+            cmd_args.fix_dry_run,
+        )?;
+    }
 
     let (sender, receiver) = tokio::sync::mpsc::channel(100);
     let analysis = Arc::new(analysis);
@@ -50,6 +71,7 @@ async fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
         cmd_args.output_format,
         cmd_args.output,
         cmd_args.warnings_as_errors,
+        baseline,
     )
     .await;
 
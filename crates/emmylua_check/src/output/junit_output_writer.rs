@@ -0,0 +1,129 @@
+use std::{fs::File, path::PathBuf};
+
+use emmylua_code_analysis::{DbIndex, FileId};
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+
+use crate::cmd_args::OutputDestination;
+
+use super::{create_output_file, write_output, OutputWriter};
+
+/// Every checked file is a test suite, and every diagnostic is a failed test case
+#[derive(Debug)]
+pub struct JunitOutputWriter {
+    workspace: PathBuf,
+    output: Option<File>,
+    test_suites: Vec<String>,
+    test_count: usize,
+    failure_count: usize,
+}
+
+impl JunitOutputWriter {
+    pub fn new(workspace: PathBuf, output: OutputDestination) -> Self {
+        JunitOutputWriter {
+            workspace,
+            output: create_output_file(output),
+            test_suites: Vec::new(),
+            test_count: 0,
+            failure_count: 0,
+        }
+    }
+
+    pub(crate) fn get_report(&self) -> String {
+        let mut content = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        content.push_str(&format!(
+            "<testsuites name=\"emmylua_check\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+            self.test_count, self.failure_count
+        ));
+        for test_suite in &self.test_suites {
+            content.push_str(test_suite);
+        }
+        content.push_str("</testsuites>");
+        content
+    }
+}
+
+impl OutputWriter for JunitOutputWriter {
+    fn write(&mut self, db: &DbIndex, file_id: FileId, diagnostics: Vec<Diagnostic>) {
+        let file_path = db.get_vfs().get_file_path(&file_id).unwrap();
+        let file_path = file_path.strip_prefix(&self.workspace).unwrap_or(file_path);
+        let file_path = escape_xml(&file_path.to_string_lossy().replace('\\', "/"));
+
+        let mut test_cases = String::new();
+        if diagnostics.is_empty() {
+            // a passed test case, so that clean files are counted too
+            test_cases.push_str(&format!(
+                "    <testcase name=\"emmylua_check\" classname=\"{}\"/>\n",
+                file_path
+            ));
+        }
+
+        for diagnostic in &diagnostics {
+            let code = match &diagnostic.code {
+                Some(NumberOrString::String(code)) => code.clone(),
+                Some(NumberOrString::Number(code)) => code.to_string(),
+                None => "emmylua_check".to_string(),
+            };
+            let severity = get_severity_name(diagnostic.severity);
+            let start = diagnostic.range.start;
+            let end = diagnostic.range.end;
+            test_cases.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\">\n",
+                escape_xml(&code),
+                file_path
+            ));
+            test_cases.push_str(&format!(
+                "      <failure message=\"{}\" type=\"{}\">{}:{}:{}-{}:{}: {}: {}</failure>\n",
+                escape_xml(&diagnostic.message),
+                severity,
+                file_path,
+                start.line + 1,
+                start.character + 1,
+                end.line + 1,
+                end.character + 1,
+                severity,
+                escape_xml(&diagnostic.message),
+            ));
+            test_cases.push_str("    </testcase>\n");
+        }
+
+        let tests = diagnostics.len().max(1);
+        self.test_count += tests;
+        self.failure_count += diagnostics.len();
+        self.test_suites.push(format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n{}  </testsuite>\n",
+            file_path,
+            tests,
+            diagnostics.len(),
+            test_cases
+        ));
+    }
+
+    fn finish(&mut self) {
+        let content = self.get_report();
+        write_output(self.output.as_mut(), &content);
+    }
+}
+
+fn get_severity_name(severity: Option<DiagnosticSeverity>) -> &'static str {
+    match severity {
+        Some(DiagnosticSeverity::ERROR) | None => "error",
+        Some(DiagnosticSeverity::WARNING) => "warning",
+        Some(DiagnosticSeverity::INFORMATION) => "information",
+        _ => "hint",
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
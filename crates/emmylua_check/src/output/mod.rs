@@ -1,13 +1,18 @@
 mod json_output_writer;
+pub(crate) mod junit_output_writer;
+pub(crate) mod sarif_output_writer;
 mod text_output_writer;
 
-use std::path::PathBuf;
+use std::{fs::File, io::Write, path::PathBuf};
 
 use emmylua_code_analysis::{DbIndex, FileId};
 use lsp_types::Diagnostic;
 use tokio::sync::mpsc::Receiver;
 
-use crate::cmd_args::{OutputDestination, OutputFormat};
+use crate::{
+    baseline::Baseline,
+    cmd_args::{OutputDestination, OutputFormat},
+};
 
 pub async fn output_result(
     total_count: usize,
@@ -17,17 +22,28 @@ pub async fn output_result(
     output_format: OutputFormat,
     output: OutputDestination,
     warnings_as_errors: bool,
+    mut baseline: Option<Baseline>,
 ) -> i32 {
     let mut writer: Box<dyn OutputWriter> = match output_format {
         OutputFormat::Json => Box::new(json_output_writer::JsonOutputWriter::new(output)),
         OutputFormat::Text => Box::new(text_output_writer::TextOutputWriter::new(workspace)),
+        OutputFormat::Sarif => Box::new(sarif_output_writer::SarifOutputWriter::new(
+            workspace, output,
+        )),
+        OutputFormat::Junit => Box::new(junit_output_writer::JunitOutputWriter::new(
+            workspace, output,
+        )),
     };
 
     let mut has_error = false;
     let mut count = 0;
     while let Some((file_id, diagnostics)) = receiver.recv().await {
         count += 1;
-        if let Some(diagnostics) = diagnostics {
+        if let Some(mut diagnostics) = diagnostics {
+            if let Some(baseline) = baseline.as_mut() {
+                diagnostics = baseline.filter_diagnostics(db, file_id, diagnostics);
+            }
+
             for diagnostic in &diagnostics {
                 if diagnostic.severity == Some(lsp_types::DiagnosticSeverity::ERROR) {
                     has_error = true;
@@ -49,6 +65,13 @@ pub async fn output_result(
 
     writer.finish();
 
+    if let Some(baseline) = baseline {
+        if let Err(e) = baseline.finish() {
+            eprintln!("{}", e);
+            return 1;
+        }
+    }
+
     if has_error {
         1
     } else {
@@ -56,8 +79,31 @@ pub async fn output_result(
     }
 }
 
-trait OutputWriter {
+pub(crate) trait OutputWriter {
     fn write(&mut self, db: &DbIndex, file_id: FileId, diagnostics: Vec<Diagnostic>);
 
     fn finish(&mut self);
 }
+
+fn create_output_file(output: OutputDestination) -> Option<File> {
+    match output {
+        OutputDestination::Stdout => None,
+        OutputDestination::File(path) => {
+            if let Some(parent) = path.parent() {
+                if !parent.exists() {
+                    std::fs::create_dir_all(parent).unwrap();
+                }
+            }
+
+            Some(std::fs::File::create(path).unwrap())
+        }
+    }
+}
+
+// the whole report is written at once, by the formats which can't be streamed
+fn write_output(output: Option<&mut File>, content: &str) {
+    match output {
+        Some(output) => output.write_all(content.as_bytes()).unwrap(),
+        None => println!("{}", content),
+    }
+}
@@ -0,0 +1,135 @@
+use std::{fs::File, path::PathBuf, str::FromStr};
+
+use emmylua_code_analysis::{
+    file_path_to_uri, get_default_severity, DbIndex, DiagnosticCode, FileId,
+};
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+use serde_json::{json, Value};
+
+use crate::cmd_args::OutputDestination;
+
+use super::{create_output_file, write_output, OutputWriter};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Debug)]
+pub struct SarifOutputWriter {
+    workspace: PathBuf,
+    output: Option<File>,
+    rules: Vec<DiagnosticCode>,
+    results: Vec<Value>,
+}
+
+impl SarifOutputWriter {
+    pub fn new(workspace: PathBuf, output: OutputDestination) -> Self {
+        SarifOutputWriter {
+            workspace,
+            output: create_output_file(output),
+            rules: DiagnosticCode::all()
+                .into_iter()
+                .filter(|code| *code != DiagnosticCode::None)
+                .collect(),
+            results: Vec::new(),
+        }
+    }
+
+    fn get_relative_path(&self, db: &DbIndex, file_id: FileId) -> String {
+        let file_path = db.get_vfs().get_file_path(&file_id).unwrap();
+        let file_path = file_path.strip_prefix(&self.workspace).unwrap_or(file_path);
+        file_path.to_string_lossy().replace('\\', "/")
+    }
+
+    pub(crate) fn get_report(&mut self) -> Value {
+        let rules = self
+            .rules
+            .iter()
+            .map(|code| {
+                let severity = get_default_severity(*code);
+                json!({
+                    "id": code.get_name(),
+                    "shortDescription": { "text": code.get_description() },
+                    "defaultConfiguration": { "level": get_level(Some(severity)) },
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut workspace_uri = file_path_to_uri(&self.workspace)
+            .map(|uri| uri.as_str().to_string())
+            .unwrap_or_default();
+        if !workspace_uri.ends_with('/') {
+            workspace_uri.push('/');
+        }
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "emmylua_check",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": rules,
+                    }
+                },
+                "originalUriBaseIds": {
+                    "%SRCROOT%": { "uri": workspace_uri },
+                },
+                // the columns of the diagnostics count chars, not utf-16 code units
+                "columnKind": "unicodeCodePoints",
+                "results": std::mem::take(&mut self.results),
+            }],
+        })
+    }
+}
+
+impl OutputWriter for SarifOutputWriter {
+    fn write(&mut self, db: &DbIndex, file_id: FileId, diagnostics: Vec<Diagnostic>) {
+        let file_path = self.get_relative_path(db, file_id);
+        for diagnostic in diagnostics {
+            let mut result = json!({
+                "level": get_level(diagnostic.severity),
+                "message": { "text": diagnostic.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": file_path,
+                            "uriBaseId": "%SRCROOT%",
+                        },
+                        // sarif lines and columns are 1-based
+                        "region": {
+                            "startLine": diagnostic.range.start.line + 1,
+                            "startColumn": diagnostic.range.start.character + 1,
+                            "endLine": diagnostic.range.end.line + 1,
+                            "endColumn": diagnostic.range.end.character + 1,
+                        }
+                    }
+                }],
+            });
+
+            if let Some(NumberOrString::String(code)) = &diagnostic.code {
+                result["ruleId"] = json!(code);
+                if let Ok(code) = DiagnosticCode::from_str(code) {
+                    if let Some(index) = self.rules.iter().position(|rule| *rule == code) {
+                        result["ruleIndex"] = json!(index);
+                    }
+                }
+            }
+
+            self.results.push(result);
+        }
+    }
+
+    fn finish(&mut self) {
+        let content = serde_json::to_string_pretty(&self.get_report()).unwrap();
+        write_output(self.output.as_mut(), &content);
+    }
+}
+
+fn get_level(severity: Option<DiagnosticSeverity>) -> &'static str {
+    match severity {
+        Some(DiagnosticSeverity::ERROR) | None => "error",
+        Some(DiagnosticSeverity::WARNING) => "warning",
+        _ => "note",
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::baseline::Baseline;
+
+    #[test]
+    fn test_baseline_suppression() {
+        let mut ws = VirtualWorkspace::new();
+        let workspace = ws.virtual_url_generator.base.clone();
+        let baseline_path = std::env::temp_dir().join(format!(
+            "emmylua_check_baseline_{}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&baseline_path);
+        let file_id = ws.def_file("baseline.lua", "return name\n");
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .expect("diagnostics");
+        assert_eq!(diagnostics.len(), 1);
+
+        // the first run records every diagnostic and reports none of them
+        let mut baseline =
+            Baseline::new(baseline_path.clone(), workspace.clone(), false).expect("new baseline");
+        let db = ws.analysis.compilation.get_db();
+        assert!(baseline
+            .filter_diagnostics(db, file_id, diagnostics)
+            .is_empty());
+        baseline.finish().expect("write baseline");
+
+        let content = std::fs::read_to_string(&baseline_path).expect("read baseline");
+        let expected = r#"{
+  "version": 1,
+  "entries": [
+    {
+      "file": "baseline.lua",
+      "code": "undefined-global",
+      "fingerprint": "eb532cefd9f222c7",
+      "count": 1
+    }
+  ]
+}"#;
+        assert_eq!(content, expected);
+
+        // the recorded diagnostic moved to another line, only the new one is reported
+        ws.def_file("baseline.lua", "Value = other\nreturn name\n");
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .expect("diagnostics");
+        assert_eq!(diagnostics.len(), 2);
+        let mut baseline =
+            Baseline::new(baseline_path.clone(), workspace, false).expect("load baseline");
+        let db = ws.analysis.compilation.get_db();
+        let reported = baseline.filter_diagnostics(db, file_id, diagnostics);
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].message, "undefined global variable: other");
+        baseline.finish().expect("keep baseline");
+        assert_eq!(
+            std::fs::read_to_string(&baseline_path).expect("read baseline"),
+            content
+        );
+
+        let _ = std::fs::remove_file(&baseline_path);
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::EmmyLuaAnalysis;
+
+    use crate::fix::fix_files;
+
+    #[test]
+    fn test_fix_writes_machine_applicable_edits() {
+        let workspace =
+            std::env::temp_dir().join(format!("emmylua_check_fix_{}", std::process::id()));
+        std::fs::create_dir_all(&workspace).expect("create workspace");
+        let file_path = workspace.join("fix.lua");
+        let text = r#"---@param a integer
+---@param b integer
+local function f(a)
+    local unused = a
+    do return a end
+    print(a)
+end
+
+---@param value integer
+local function g(value, count) end
+
+return f, g
+"#;
+        std::fs::write(&file_path, text).expect("write file");
+
+        let mut analysis = EmmyLuaAnalysis::new();
+        analysis.add_main_workspace(workspace.clone());
+        let file_id = analysis
+            .update_file_by_path(&file_path, Some(text.to_string()))
+            .expect("file id");
+        fix_files(&mut analysis, &[file_id], &workspace, false).expect("fix files");
+
+        // the unreachable code and the documented parameter are left to the user
+        let expected = r#"---@param a integer
+local function f(a)
+    local _unused = a
+    do return a end
+    print(a)
+end
+
+---@param value integer
+local function g(value, _count) end
+
+return f, g
+"#;
+        let fixed = std::fs::read_to_string(&file_path).expect("read file");
+        let _ = std::fs::remove_dir_all(&workspace);
+        assert_eq!(fixed, expected);
+    }
+}
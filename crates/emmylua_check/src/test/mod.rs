@@ -0,0 +1,3 @@
+mod baseline_test;
+mod fix_test;
+mod output_test;
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::VirtualWorkspace;
+    use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+    use serde_json::json;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{
+        cmd_args::OutputDestination,
+        output::{
+            junit_output_writer::JunitOutputWriter, sarif_output_writer::SarifOutputWriter,
+            OutputWriter,
+        },
+    };
+
+    #[test]
+    fn test_sarif_output() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def_file("sarif.lua", "return \"😀\" .. name\n");
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .expect("diagnostics");
+        let workspace = ws.virtual_url_generator.base.clone();
+        let mut writer = SarifOutputWriter::new(workspace, OutputDestination::Stdout);
+        writer.write(ws.analysis.compilation.get_db(), file_id, diagnostics);
+
+        let report = writer.get_report();
+        let run = &report["runs"][0];
+        // the emoji is one column, it would be two utf-16 code units
+        assert_eq!(run["columnKind"], json!("unicodeCodePoints"));
+        assert_eq!(
+            run["results"],
+            json!([{
+                "level": "error",
+                "message": { "text": "undefined global variable: name" },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": "sarif.lua",
+                            "uriBaseId": "%SRCROOT%",
+                        },
+                        "region": {
+                            "startLine": 1,
+                            "startColumn": 15,
+                            "endLine": 1,
+                            "endColumn": 19,
+                        }
+                    }
+                }],
+                "ruleId": "undefined-global",
+                "ruleIndex": 10,
+            }])
+        );
+        let rules = run["tool"]["driver"]["rules"]
+            .as_array()
+            .expect("rules array");
+        assert_eq!(rules[10]["id"], json!("undefined-global"));
+    }
+
+    #[test]
+    fn test_junit_output() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def_file("junit.lua", "return 1 .. name\n");
+        let clean_file_id = ws.def_file("clean.lua", "return 1\n");
+        let workspace = ws.virtual_url_generator.base.clone();
+        let mut writer = JunitOutputWriter::new(workspace, OutputDestination::Stdout);
+
+        let mut diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .expect("diagnostics");
+        diagnostics.push(Diagnostic {
+            range: Range::new(Position::new(0, 0), Position::new(0, 6)),
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::String("<code>".to_string())),
+            message: "expected `A<T>` & \"B\"".to_string(),
+            ..Default::default()
+        });
+        let db = ws.analysis.compilation.get_db();
+        writer.write(db, file_id, diagnostics);
+        writer.write(db, clean_file_id, Vec::new());
+
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="emmylua_check" tests="3" failures="2" errors="0">
+  <testsuite name="junit.lua" tests="2" failures="2" errors="0">
+    <testcase name="undefined-global" classname="junit.lua">
+      <failure message="undefined global variable: name" type="error">junit.lua:1:13-1:17: error: undefined global variable: name</failure>
+    </testcase>
+    <testcase name="&lt;code&gt;" classname="junit.lua">
+      <failure message="expected `A&lt;T&gt;` &amp; &quot;B&quot;" type="warning">junit.lua:1:1-1:7: warning: expected `A&lt;T&gt;` &amp; &quot;B&quot;</failure>
+    </testcase>
+  </testsuite>
+  <testsuite name="clean.lua" tests="1" failures="0" errors="0">
+    <testcase name="emmylua_check" classname="clean.lua"/>
+  </testsuite>
+</testsuites>"#;
+        assert_eq!(writer.get_report(), expected);
+    }
+}
@@ -137,6 +137,7 @@ function io.write(...) end
 
 --- File object
 ---@class file
+---@operator close
 local file = {}
 
 --- @version > 5.2
@@ -122,7 +122,7 @@ function os.execute(command) end
 --- exiting.
 ---@param code integer
 ---@param close? boolean
----@return integer
+---@return never
 function os.exit(code, close) end
 
 --- @version 5.1
@@ -130,7 +130,7 @@ function os.exit(code, close) end
 --- Calls the C function exit, with an optional `code`, to terminate the host
 --- program. The default value for `code` is the success code.
 ---@param code integer
----@return integer
+---@return never
 function os.exit(code) end
 
 ---
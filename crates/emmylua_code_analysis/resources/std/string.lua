@@ -116,6 +116,7 @@ function string.find(s, pattern, init, plain) end
 ---@param ... any
 ---@return string
 ---@nodiscard
+---@format_string fmt
 function string.format(fmt, ...) end
 
 ---
@@ -66,6 +66,16 @@ function table.move(a1, f, e, t, a2) end
 ---@nodiscard
 function table.maxn(table) end
 
+---@version > 5.5
+---
+--- Creates a new empty table, preallocating memory for `nseq` elements in its
+--- array part and `nrec` elements in its hash part.
+---@param nseq integer
+---@param nrec? integer
+---@return table
+---@nodiscard
+function table.create(nseq, nrec) end
+
 ---
 --- Removes from `list` the element at position `pos`, returning the value of
 --- the removed element. When `pos` is an integer between 1 and `#list`, it
@@ -161,7 +161,7 @@ pub fn analyze_doc_tag_meta(analyzer: &mut DeclAnalyzer, tag: LuaDocTagMeta) ->
     Some(())
 }
 
-fn add_type_decl(
+pub(super) fn add_type_decl(
     analyzer: &mut DeclAnalyzer,
     name: &str,
     range: TextRange,
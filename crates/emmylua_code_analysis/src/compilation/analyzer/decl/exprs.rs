@@ -250,7 +250,12 @@ pub fn analyze_literal_expr(analyzer: &mut DeclAnalyzer, expr: LuaLiteralExpr) -
 
     match literal {
         LuaLiteralToken::String(string_token) => {
-            if !analyzer.db.get_emmyrc().references.short_string_search {
+            if !analyzer
+                .db
+                .get_file_emmyrc(file_id)
+                .references
+                .short_string_search
+            {
                 return Some(());
             }
 
@@ -51,6 +51,10 @@ fn walk_node_enter(analyzer: &mut DeclAnalyzer, node: LuaAst) {
             analyzer.create_scope(stat.get_range(), LuaScopeKind::LocalOrAssignStat);
             stats::analyze_assign_stat(analyzer, stat);
         }
+        LuaAst::LuaGlobalStat(stat) => {
+            analyzer.create_scope(stat.get_range(), LuaScopeKind::LocalOrAssignStat);
+            stats::analyze_global_stat(analyzer, stat);
+        }
         LuaAst::LuaForStat(stat) => {
             analyzer.create_scope(stat.get_range(), LuaScopeKind::Normal);
             stats::analyze_for_stat(analyzer, stat);
@@ -71,6 +75,9 @@ fn walk_node_enter(analyzer: &mut DeclAnalyzer, node: LuaAst) {
             analyzer.create_scope(stat.get_range(), LuaScopeKind::FuncStat);
             stats::analyze_local_func_stat(analyzer, stat);
         }
+        LuaAst::LuaTypeAliasStat(stat) => {
+            stats::analyze_type_alias_stat(analyzer, stat);
+        }
         LuaAst::LuaRepeatStat(stat) => {
             analyzer.create_scope(stat.get_range(), LuaScopeKind::Repeat);
         }
@@ -132,7 +139,8 @@ fn is_scope_owner(node: &LuaAst) -> bool {
         | LuaSyntaxKind::LocalStat
         | LuaSyntaxKind::FuncStat
         | LuaSyntaxKind::LocalFuncStat
-        | LuaSyntaxKind::AssignStat => true,
+        | LuaSyntaxKind::AssignStat
+        | LuaSyntaxKind::GlobalStat => true,
         _ => false,
     }
 }
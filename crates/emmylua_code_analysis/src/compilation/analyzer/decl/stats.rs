@@ -1,16 +1,18 @@
 use emmylua_parser::{
     LuaAssignStat, LuaAstNode, LuaAstToken, LuaExpr, LuaForRangeStat, LuaForStat, LuaFuncStat,
-    LuaIndexExpr, LuaIndexKey, LuaLocalFuncStat, LuaLocalStat, LuaSyntaxId, LuaSyntaxKind,
-    LuaVarExpr,
+    LuaGlobalStat, LuaIndexExpr, LuaIndexKey, LuaLocalFuncStat, LuaLocalStat, LuaSyntaxId,
+    LuaSyntaxKind, LuaTypeAliasStat, LuaVarExpr,
 };
 
 use crate::{
     compilation::analyzer::bind_type::bind_type,
-    db_index::{LocalAttribute, LuaDecl, LuaMember, LuaMemberKey},
+    db_index::{
+        LocalAttribute, LuaDecl, LuaDeclTypeKind, LuaMember, LuaMemberKey, LuaTypeAttribute,
+    },
     LuaDeclExtra, LuaMemberFeature, LuaMemberId, LuaSemanticDeclId, LuaSignatureId, LuaType,
 };
 
-use super::{members::find_index_owner, DeclAnalyzer};
+use super::{docs::add_type_decl, members::find_index_owner, DeclAnalyzer};
 
 pub fn analyze_local_stat(analyzer: &mut DeclAnalyzer, stat: LuaLocalStat) -> Option<()> {
     let local_name_list = stat.get_local_name_list().collect::<Vec<_>>();
@@ -128,6 +130,30 @@ pub fn analyze_assign_stat(analyzer: &mut DeclAnalyzer, stat: LuaAssignStat) ->
     Some(())
 }
 
+pub fn analyze_global_stat(analyzer: &mut DeclAnalyzer, stat: LuaGlobalStat) -> Option<()> {
+    let (vars, value_exprs) = stat.get_var_and_expr_list();
+    for (idx, var) in vars.iter().enumerate() {
+        let Some(name_token) = var.get_name_token() else {
+            continue;
+        };
+        let value_expr_id = value_exprs.get(idx).map(|expr| expr.get_syntax_id());
+        // `global x` always declares a global, even if a local `x` is visible
+        let decl = LuaDecl::new(
+            name_token.get_name_text(),
+            analyzer.get_file_id(),
+            name_token.get_range(),
+            LuaDeclExtra::Global {
+                kind: LuaSyntaxKind::NameExpr.into(),
+            },
+            value_expr_id,
+        );
+
+        analyzer.add_decl(decl);
+    }
+
+    Some(())
+}
+
 fn analyze_maybe_global_index_expr(
     analyzer: &mut DeclAnalyzer,
     index_expr: &LuaIndexExpr,
@@ -318,3 +344,19 @@ pub fn analyze_local_func_stat(analyzer: &mut DeclAnalyzer, stat: LuaLocalFuncSt
 
     Some(())
 }
+
+// luau `type Name = ...` is declared like a `---@alias`
+pub fn analyze_type_alias_stat(analyzer: &mut DeclAnalyzer, stat: LuaTypeAliasStat) -> Option<()> {
+    let name_token = stat.get_name_token()?;
+    let name = name_token.get_name_text().to_string();
+    let range = name_token.syntax().text_range();
+
+    add_type_decl(
+        analyzer,
+        &name,
+        range,
+        LuaDeclTypeKind::Alias,
+        LuaTypeAttribute::None.into(),
+    );
+    Some(())
+}
@@ -31,7 +31,7 @@ fn analyze_diagnostic_disable(
     analyzer: &mut DocAnalyzer,
     diagnostic: LuaDocTagDiagnostic,
 ) -> Option<()> {
-    let comment = analyzer.comment.clone();
+    let comment = analyzer.comment.clone()?;
     let owner_block = comment.ancestors::<LuaBlock>().next()?;
     let owner_block_range = owner_block.get_range();
     let is_file_disable = if let Some(_) = owner_block.get_parent::<LuaChunk>() {
@@ -76,7 +76,7 @@ fn analyze_diagnostic_disable_next_line(
     analyzer: &mut DocAnalyzer,
     diagnostic: LuaDocTagDiagnostic,
 ) -> Option<()> {
-    let comment = analyzer.comment.clone();
+    let comment = analyzer.comment.clone()?;
     let comment_range = comment.get_range();
     let document = analyzer.db.get_vfs().get_document(&analyzer.file_id)?;
     let comment_end_line = document.get_line(comment_range.end().into())?;
@@ -112,7 +112,7 @@ fn analyze_diagnostic_disable_line(
     analyzer: &mut DocAnalyzer,
     diagnostic: LuaDocTagDiagnostic,
 ) -> Option<()> {
-    let comment = analyzer.comment.clone();
+    let comment = analyzer.comment.clone()?;
     let comment_range = comment.get_range();
     let document = analyzer.db.get_vfs().get_document(&analyzer.file_id)?;
     let comment_end_line = document.get_line(comment_range.end().into())?;
@@ -150,12 +150,17 @@ pub fn analyze_operator(analyzer: &mut DocAnalyzer, tag: LuaDocTagOperator) -> O
     let current_type_id = analyzer.current_type_id.clone()?;
     let name_token = tag.get_name_token()?;
     let op_kind = LuaOperatorMetaMethod::from_operator_name(name_token.get_name_text())?;
+    // the parameter list is optional for the operators without operands, such as `pairs`
     let mut operands: Vec<(String, Option<LuaType>)> = tag
-        .get_param_list()?
-        .get_types()
-        .enumerate()
-        .map(|(i, doc_type)| (format!("arg{}", i), Some(infer_type(analyzer, doc_type))))
-        .collect();
+        .get_param_list()
+        .map(|param_list| {
+            param_list
+                .get_types()
+                .enumerate()
+                .map(|(i, doc_type)| (format!("arg{}", i), Some(infer_type(analyzer, doc_type))))
+                .collect()
+        })
+        .unwrap_or_default();
 
     operands.insert(
         0,
@@ -111,6 +111,21 @@ impl FileGenericIndex {
         None
     }
 
+    /// The id of a new type param declared at the position, the type params of the enclosing
+    /// scopes are numbered before it
+    pub fn get_next_type_param_id(&self, position: TextSize) -> usize {
+        let Some(params_ids) = self.find_generic_params(position) else {
+            return 0;
+        };
+
+        params_ids
+            .iter()
+            .filter_map(|params_id| self.generic_params.get(*params_id))
+            .filter(|params| !params.is_func)
+            .map(|params| params.params.len())
+            .sum()
+    }
+
     fn find_generic_params(&self, position: TextSize) -> Option<Vec<usize>> {
         for effect_id in self.root_node_ids.iter() {
             if self
@@ -1,10 +1,10 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaDocBinaryType, LuaDocFuncType, LuaDocGenericType,
-    LuaDocMultiLineUnionType, LuaDocObjectFieldKey, LuaDocObjectType, LuaDocStrTplType, LuaDocType,
-    LuaDocUnaryType, LuaDocVariadicType, LuaLiteralToken, LuaSyntaxKind, LuaTypeBinaryOperator,
-    LuaTypeUnaryOperator, LuaVarExpr,
+    LuaAst, LuaAstNode, LuaDocBinaryType, LuaDocConditionalType, LuaDocFuncType, LuaDocGenericType,
+    LuaDocIndexAccessType, LuaDocMappedType, LuaDocMultiLineUnionType, LuaDocObjectFieldKey,
+    LuaDocObjectType, LuaDocStrTplType, LuaDocType, LuaDocUnaryType, LuaDocVariadicType,
+    LuaLiteralToken, LuaSyntaxKind, LuaTypeBinaryOperator, LuaTypeUnaryOperator, LuaVarExpr,
 };
 use rowan::TextRange;
 use smol_str::SmolStr;
@@ -14,8 +14,8 @@ use crate::{
         AnalyzeError, LuaAliasCallType, LuaFunctionType, LuaGenericType, LuaIndexAccessKey,
         LuaIntersectionType, LuaObjectType, LuaStringTplType, LuaTupleType, LuaType, LuaUnionType,
     },
-    DiagnosticCode, GenericTpl, InFiled, LuaAliasCallKind, LuaMultiLineUnion, LuaTypeDeclId,
-    TypeOps, VariadicType,
+    DiagnosticCode, GenericTpl, GenericTplId, InFiled, LuaAliasCallKind, LuaConditionalType,
+    LuaMappedType, LuaMultiLineUnion, LuaTypeDeclId, TypeOps, VariadicType,
 };
 
 use super::{preprocess_description, DocAnalyzer};
@@ -108,7 +108,15 @@ pub fn infer_type(analyzer: &mut DocAnalyzer, node: LuaDocType) -> LuaType {
         LuaDocType::MultiLineUnion(multi_union) => {
             return infer_multi_line_union_type(analyzer, multi_union);
         }
-        _ => {} // LuaDocType::Conditional(lua_doc_conditional_type) => todo!(),
+        LuaDocType::Conditional(conditional_type) => {
+            return infer_conditional_type(analyzer, conditional_type);
+        }
+        LuaDocType::IndexAccess(index_access_type) => {
+            return infer_index_access_type(analyzer, index_access_type);
+        }
+        LuaDocType::Mapped(mapped_type) => {
+            return infer_mapped_type(analyzer, mapped_type).unwrap_or(LuaType::Unknown);
+        }
     }
     LuaType::Unknown
 }
@@ -133,6 +141,7 @@ fn infer_buildin_or_ref_type(
         "io" => LuaType::Io,
         "self" => LuaType::SelfInfer,
         "global" => LuaType::Global,
+        "never" => LuaType::Never,
         "function" => LuaType::Function,
         "table" => {
             if let Some(inst) = infer_special_table_type(analyzer, node) {
@@ -394,11 +403,14 @@ fn infer_unary_type(analyzer: &mut DocAnalyzer, unary_type: &LuaDocUnaryType) ->
 
 fn infer_func_type(analyzer: &mut DocAnalyzer, func: &LuaDocFuncType) -> LuaType {
     let mut params_result = Vec::new();
-    for param in func.get_params() {
+    for (idx, param) in func.get_params().enumerate() {
         let name = if let Some(param) = param.get_name_token() {
             param.get_name_text().to_string()
         } else if param.is_dots() {
             "...".to_string()
+        } else if param.get_type().is_some() {
+            // luau allows function types with unnamed params: `(number, string) -> ()`
+            format!("arg{}", idx + 1)
         } else {
             continue;
         };
@@ -464,7 +476,7 @@ fn infer_func_type(analyzer: &mut DocAnalyzer, func: &LuaDocFuncType) -> LuaType
 }
 
 fn get_colon_define(analyzer: &mut DocAnalyzer) -> Option<bool> {
-    let owner = analyzer.comment.get_owner()?;
+    let owner = analyzer.comment.as_ref()?.get_owner()?;
     match owner {
         LuaAst::LuaFuncStat(func_stat) => {
             let func_name = func_stat.get_func_name()?;
@@ -578,3 +590,73 @@ fn infer_multi_line_union_type(
 
     LuaType::MultiLineUnion(LuaMultiLineUnion::new(union_members).into())
 }
+
+fn infer_conditional_type(
+    analyzer: &mut DocAnalyzer,
+    conditional_type: &LuaDocConditionalType,
+) -> LuaType {
+    if let Some((condition, true_type, false_type)) = conditional_type.get_types() {
+        let condition = infer_type(analyzer, condition);
+        let true_type = infer_type(analyzer, true_type);
+        let false_type = infer_type(analyzer, false_type);
+        if condition.is_unknown() {
+            return LuaType::Unknown;
+        }
+
+        return LuaType::Conditional(
+            LuaConditionalType::new(condition, true_type, false_type).into(),
+        );
+    }
+
+    LuaType::Unknown
+}
+
+fn infer_index_access_type(
+    analyzer: &mut DocAnalyzer,
+    index_access_type: &LuaDocIndexAccessType,
+) -> LuaType {
+    if let Some((base_type, key_type)) = index_access_type.get_types() {
+        let base = infer_type(analyzer, base_type);
+        let key = infer_type(analyzer, key_type);
+        if base.is_unknown() || key.is_unknown() {
+            return LuaType::Unknown;
+        }
+
+        return LuaType::Call(
+            LuaAliasCallType::new(LuaAliasCallKind::Index, vec![base, key]).into(),
+        );
+    }
+
+    LuaType::Unknown
+}
+
+fn infer_mapped_type(
+    analyzer: &mut DocAnalyzer,
+    mapped_type: &LuaDocMappedType,
+) -> Option<LuaType> {
+    let mapped_keys = mapped_type.get_mapped_keys()?;
+    let param_name = mapped_keys.get_param_name_type()?.get_name_text()?;
+    let keys = infer_type(analyzer, mapped_keys.get_constraint_type()?);
+    if keys.is_unknown() {
+        return None;
+    }
+
+    // the param is only visible in the mapped type
+    let param_id = analyzer
+        .generic_index
+        .get_next_type_param_id(mapped_type.get_position());
+    analyzer.generic_index.add_generic_scope(
+        vec![mapped_type.get_range()],
+        HashMap::from([(param_name.clone(), param_id)]),
+        false,
+    );
+    let param = GenericTpl::new(
+        GenericTplId::Type(param_id as u32),
+        SmolStr::new(param_name).into(),
+    );
+
+    let value = infer_type(analyzer, mapped_type.get_value_type()?);
+    Some(LuaType::Mapped(
+        LuaMappedType::new(param, keys, value, mapped_keys.is_nullable()).into(),
+    ))
+}
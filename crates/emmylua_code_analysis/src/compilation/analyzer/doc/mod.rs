@@ -4,6 +4,7 @@ mod file_generic_index;
 mod infer_type;
 mod property_tags;
 mod tags;
+mod type_annotations;
 mod type_def_tags;
 mod type_ref_tags;
 
@@ -11,7 +12,7 @@ use super::AnalyzeContext;
 use crate::{
     db_index::{DbIndex, LuaTypeDeclId},
     profile::Profile,
-    FileId,
+    EmmyrcLuaVersion, FileId,
 };
 use emmylua_parser::{LuaAstNode, LuaComment, LuaDocDescriptionOwner, LuaSyntaxNode};
 use file_generic_index::FileGenericIndex;
@@ -28,17 +29,29 @@ pub(crate) fn analyze(db: &mut DbIndex, context: &mut AnalyzeContext) {
                 db,
                 in_filed_tree.file_id,
                 &mut generic_index,
-                comment,
+                Some(comment),
                 root.syntax().clone(),
                 context,
             );
             analyze_comment(&mut analyzer);
         }
+
+        if db.get_file_emmyrc(in_filed_tree.file_id).runtime.version == EmmyrcLuaVersion::Luau {
+            let mut analyzer = DocAnalyzer::new(
+                db,
+                in_filed_tree.file_id,
+                &mut generic_index,
+                None,
+                root.syntax().clone(),
+                context,
+            );
+            type_annotations::analyze_type_annotations(&mut analyzer, root.clone());
+        }
     }
 }
 
 fn analyze_comment(analyzer: &mut DocAnalyzer) -> Option<()> {
-    let comment = analyzer.comment.clone();
+    let comment = analyzer.comment.clone()?;
     for tag in comment.get_doc_tags() {
         tags::analyze_tag(analyzer, tag);
     }
@@ -61,7 +74,7 @@ pub struct DocAnalyzer<'a> {
     db: &'a mut DbIndex,
     generic_index: &'a mut FileGenericIndex,
     current_type_id: Option<LuaTypeDeclId>,
-    comment: LuaComment,
+    comment: Option<LuaComment>,
     root: LuaSyntaxNode,
     is_meta: bool,
     context: &'a mut AnalyzeContext,
@@ -72,7 +85,7 @@ impl<'a> DocAnalyzer<'a> {
         db: &'a mut DbIndex,
         file_id: FileId,
         generic_index: &'a mut FileGenericIndex,
-        comment: LuaComment,
+        comment: Option<LuaComment>,
         root: LuaSyntaxNode,
         context: &'a mut AnalyzeContext,
     ) -> DocAnalyzer<'a> {
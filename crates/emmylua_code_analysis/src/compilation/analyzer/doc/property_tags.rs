@@ -5,8 +5,8 @@ use super::{
     DocAnalyzer,
 };
 use emmylua_parser::{
-    LuaDocDescriptionOwner, LuaDocTagDeprecated, LuaDocTagNodiscard, LuaDocTagSource,
-    LuaDocTagVersion, LuaDocTagVisibility,
+    LuaDocDescriptionOwner, LuaDocTagDeprecated, LuaDocTagFormatString, LuaDocTagNodiscard,
+    LuaDocTagSource, LuaDocTagVersion, LuaDocTagVisibility,
 };
 
 pub fn analyze_visibility(
@@ -64,6 +64,23 @@ pub fn analyze_nodiscard(analyzer: &mut DocAnalyzer, nodiscard: LuaDocTagNodisca
     Some(())
 }
 
+pub fn analyze_format_string(
+    analyzer: &mut DocAnalyzer,
+    format_string: LuaDocTagFormatString,
+) -> Option<()> {
+    let name = format_string.get_name_token()?.get_name_text().to_string();
+    let closure = find_owner_closure(analyzer)?;
+    let signature_id = LuaSignatureId::from_closure(analyzer.file_id, &closure);
+    let signature = analyzer
+        .db
+        .get_signature_index_mut()
+        .get_mut(&signature_id)?;
+
+    signature.format_param = Some(signature.find_param_idx(&name)?);
+
+    Some(())
+}
+
 pub fn analyze_deprecated(analyzer: &mut DocAnalyzer, tag: LuaDocTagDeprecated) -> Option<()> {
     let message = if let Some(desc) = tag.get_description() {
         Some(desc.get_description_text().to_string())
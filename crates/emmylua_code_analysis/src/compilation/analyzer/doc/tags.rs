@@ -11,8 +11,8 @@ use super::{
     diagnostic_tags::analyze_diagnostic,
     field_or_operator_def_tags::{analyze_field, analyze_operator},
     property_tags::{
-        analyze_async, analyze_deprecated, analyze_nodiscard, analyze_source, analyze_version,
-        analyze_visibility,
+        analyze_async, analyze_deprecated, analyze_format_string, analyze_nodiscard,
+        analyze_source, analyze_version, analyze_visibility,
     },
     type_def_tags::{analyze_alias, analyze_class, analyze_enum, analyze_func_generic},
     type_ref_tags::{
@@ -77,6 +77,9 @@ pub fn analyze_tag(analyzer: &mut DocAnalyzer, tag: LuaDocTag) -> Option<()> {
         LuaDocTag::Async(_) => {
             analyze_async(analyzer)?;
         }
+        LuaDocTag::FormatString(format_string) => {
+            analyze_format_string(analyzer, format_string)?;
+        }
 
         // field or operator
         LuaDocTag::Field(filed) => {
@@ -111,7 +114,7 @@ pub fn analyze_tag(analyzer: &mut DocAnalyzer, tag: LuaDocTag) -> Option<()> {
 }
 
 pub fn find_owner_closure(analyzer: &DocAnalyzer) -> Option<LuaClosureExpr> {
-    if let Some(owner) = analyzer.comment.get_owner() {
+    if let Some(owner) = analyzer.comment.as_ref()?.get_owner() {
         match owner {
             LuaAst::LuaFuncStat(func) => {
                 if let Some(closure) = func.get_closure() {
@@ -133,7 +136,7 @@ pub fn find_owner_closure(analyzer: &DocAnalyzer) -> Option<LuaClosureExpr> {
 }
 
 pub fn get_owner_id(analyzer: &mut DocAnalyzer) -> Option<LuaSemanticDeclId> {
-    let owner = analyzer.comment.get_owner()?;
+    let owner = analyzer.comment.as_ref()?.get_owner()?;
     match owner {
         LuaAst::LuaAssignStat(assign) => {
             let first_var = assign.child::<LuaVarExpr>()?;
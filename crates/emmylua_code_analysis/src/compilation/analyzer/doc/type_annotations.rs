@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaAstToken, LuaChunk, LuaClosureExpr, LuaDocType, LuaForRangeStat,
+    LuaLocalName, LuaNameToken, LuaTypeAliasStat,
+};
+
+use crate::{
+    db_index::{LuaDeclId, LuaDocParamInfo, LuaDocReturnInfo, LuaSignatureId},
+    LuaTypeCache, SignatureReturnStatus,
+};
+
+use super::{infer_type::infer_type, type_def_tags::get_generic_params, DocAnalyzer};
+
+// luau writes types inline (`local x: number`), the annotations are bound the same way as the
+// equivalent doc tags
+pub fn analyze_type_annotations(analyzer: &mut DocAnalyzer, root: LuaChunk) {
+    for node in root.descendants::<LuaAst>() {
+        match node {
+            LuaAst::LuaTypeAliasStat(stat) => {
+                analyze_type_alias_stat(analyzer, stat);
+            }
+            LuaAst::LuaClosureExpr(closure) => {
+                analyze_closure_annotations(analyzer, closure);
+            }
+            LuaAst::LuaLocalName(local_name) => {
+                analyze_local_name_annotation(analyzer, local_name);
+            }
+            LuaAst::LuaForRangeStat(stat) => {
+                analyze_for_range_annotations(analyzer, stat);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn analyze_type_alias_stat(analyzer: &mut DocAnalyzer, stat: LuaTypeAliasStat) -> Option<()> {
+    let name = stat.get_name_token()?.get_name_text().to_string();
+    let alias_decl_id = {
+        let alias_decl = analyzer
+            .db
+            .get_type_index()
+            .find_type_decl(analyzer.file_id, &name)?;
+        if !alias_decl.is_alias() {
+            return None;
+        }
+
+        alias_decl.get_id()
+    };
+
+    if let Some(generic_decl_list) = stat.get_generic_decl_list() {
+        let params = get_generic_params(analyzer, generic_decl_list);
+        let params_index = params
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, _))| (name.clone(), idx))
+            .collect::<HashMap<_, _>>();
+
+        analyzer
+            .db
+            .get_type_index_mut()
+            .add_generic_params(alias_decl_id.clone(), params);
+        analyzer
+            .generic_index
+            .add_generic_scope(vec![stat.get_range()], params_index, false);
+    }
+
+    let origin_type = infer_type(analyzer, stat.get_type()?);
+    analyzer
+        .db
+        .get_type_index_mut()
+        .get_type_decl_mut(&alias_decl_id)?
+        .add_alias_origin(origin_type);
+
+    Some(())
+}
+
+fn analyze_closure_annotations(analyzer: &mut DocAnalyzer, closure: LuaClosureExpr) -> Option<()> {
+    let signature_id = LuaSignatureId::from_closure(analyzer.file_id, &closure);
+
+    if let Some(generic_decl_list) = closure.get_generic_decl_list() {
+        let params = get_generic_params(analyzer, generic_decl_list);
+        let params_index = params
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, _))| (name.clone(), idx))
+            .collect::<HashMap<_, _>>();
+
+        analyzer
+            .generic_index
+            .add_generic_scope(vec![closure.get_range()], params_index, true);
+        analyzer
+            .db
+            .get_signature_index_mut()
+            .get_or_create(signature_id)
+            .generic_params = params;
+    }
+
+    for param in closure.get_params_list()?.get_params() {
+        let Some(doc_type) = param.get_type() else {
+            continue;
+        };
+
+        let name = if let Some(name_token) = param.get_name_token() {
+            name_token.get_name_text().to_string()
+        } else if param.is_dots() {
+            "...".to_string()
+        } else {
+            continue;
+        };
+
+        let type_ref = infer_type(analyzer, doc_type);
+        let signature = analyzer
+            .db
+            .get_signature_index_mut()
+            .get_or_create(signature_id);
+        let Some(idx) = signature.find_param_idx(&name) else {
+            continue;
+        };
+        signature.param_docs.insert(
+            idx,
+            LuaDocParamInfo {
+                name,
+                type_ref,
+                nullable: false,
+                description: None,
+            },
+        );
+    }
+
+    if let Some(return_type_list) = closure.get_return_type_list() {
+        let mut return_docs = Vec::new();
+        for return_type in return_type_list.get_return_type_list() {
+            let (_, doc_type) = return_type.get_name_and_type();
+            let Some(doc_type) = doc_type else {
+                continue;
+            };
+
+            return_docs.push(LuaDocReturnInfo {
+                name: None,
+                type_ref: infer_type(analyzer, doc_type),
+                description: None,
+            });
+        }
+
+        let signature = analyzer
+            .db
+            .get_signature_index_mut()
+            .get_or_create(signature_id);
+        signature.return_docs.extend(return_docs);
+        signature.resolve_return = SignatureReturnStatus::DocResolve;
+    }
+
+    Some(())
+}
+
+fn analyze_local_name_annotation(
+    analyzer: &mut DocAnalyzer,
+    local_name: LuaLocalName,
+) -> Option<()> {
+    let doc_type = local_name.get_type()?;
+    let name_token = local_name.get_name_token()?;
+    bind_annotation(analyzer, name_token, doc_type);
+    Some(())
+}
+
+// for i: number, v: string in ipairs(t) do
+fn analyze_for_range_annotations(analyzer: &mut DocAnalyzer, stat: LuaForRangeStat) -> Option<()> {
+    let mut last_name_token = None;
+    for child in stat.syntax().children_with_tokens() {
+        if let Some(token) = child.as_token() {
+            if let Some(name_token) = LuaNameToken::cast(token.clone()) {
+                last_name_token = Some(name_token);
+            }
+        } else if let Some(doc_type) = child.into_node().and_then(LuaDocType::cast) {
+            if let Some(name_token) = last_name_token.take() {
+                bind_annotation(analyzer, name_token, doc_type);
+            }
+        }
+    }
+
+    Some(())
+}
+
+fn bind_annotation(analyzer: &mut DocAnalyzer, name_token: LuaNameToken, doc_type: LuaDocType) {
+    let type_ref = infer_type(analyzer, doc_type);
+    let decl_id = LuaDeclId::new(analyzer.file_id, name_token.get_position());
+    analyzer
+        .db
+        .get_type_index_mut()
+        .bind_type(decl_id.into(), LuaTypeCache::DocType(type_ref));
+}
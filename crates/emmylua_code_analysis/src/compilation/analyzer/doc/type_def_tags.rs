@@ -74,8 +74,11 @@ fn add_description_for_type_decl(
 ) {
     let mut description_text = String::new();
 
-    let comment = analyzer.comment.clone();
-    if let Some(description) = comment.get_description() {
+    if let Some(description) = analyzer
+        .comment
+        .as_ref()
+        .and_then(|comment| comment.get_description())
+    {
         let description = preprocess_description(&description.get_description_text());
         if !description.is_empty() {
             description_text.push_str(&description);
@@ -167,7 +170,7 @@ pub fn analyze_alias(analyzer: &mut DocAnalyzer, tag: LuaDocTagAlias) -> Option<
             .db
             .get_type_index_mut()
             .add_generic_params(alias_decl_id.clone(), params);
-        let range = analyzer.comment.get_range();
+        let range = analyzer.comment.as_ref()?.get_range();
         analyzer
             .generic_index
             .add_generic_scope(vec![range], params_index, false);
@@ -188,7 +191,7 @@ pub fn analyze_alias(analyzer: &mut DocAnalyzer, tag: LuaDocTagAlias) -> Option<
     Some(())
 }
 
-fn get_generic_params(
+pub(super) fn get_generic_params(
     analyzer: &mut DocAnalyzer,
     params: LuaDocGenericDeclList,
 ) -> Vec<(String, Option<LuaType>)> {
@@ -212,11 +215,14 @@ fn get_generic_params(
     params_result
 }
 
-fn add_generic_index(analyzer: &mut DocAnalyzer, params_index: HashMap<String, usize>) {
+fn add_generic_index(
+    analyzer: &mut DocAnalyzer,
+    params_index: HashMap<String, usize>,
+) -> Option<()> {
+    let comment = analyzer.comment.clone()?;
     let mut ranges = Vec::new();
-    let range = analyzer.comment.get_range();
-    ranges.push(range);
-    if let Some(comment_owner) = analyzer.comment.get_owner() {
+    ranges.push(comment.get_range());
+    if let Some(comment_owner) = comment.get_owner() {
         let range = comment_owner.get_range();
         ranges.push(range);
         match comment_owner {
@@ -237,6 +243,7 @@ fn add_generic_index(analyzer: &mut DocAnalyzer, params_index: HashMap<String, u
     analyzer
         .generic_index
         .add_generic_scope(ranges, params_index, false);
+    Some(())
 }
 
 fn get_local_stat_reference_ranges(
@@ -326,7 +333,7 @@ fn get_global_reference_ranges(
 }
 
 pub fn analyze_func_generic(analyzer: &mut DocAnalyzer, tag: LuaDocTagGeneric) -> Option<()> {
-    let comment_owner = analyzer.comment.get_owner()?;
+    let comment_owner = analyzer.comment.as_ref()?.get_owner()?;
     let mut params_result = HashMap::new();
     let mut param_info = Vec::new();
     if let Some(params_list) = tag.get_generic_decl_list() {
@@ -351,7 +358,7 @@ pub fn analyze_func_generic(analyzer: &mut DocAnalyzer, tag: LuaDocTagGeneric) -
     }
 
     let mut ranges = Vec::new();
-    let range = analyzer.comment.get_range();
+    let range = analyzer.comment.as_ref()?.get_range();
     ranges.push(range);
     let range = comment_owner.get_range();
     ranges.push(range);
@@ -371,7 +378,7 @@ pub fn analyze_func_generic(analyzer: &mut DocAnalyzer, tag: LuaDocTagGeneric) -
 }
 
 fn bind_def_type(analyzer: &mut DocAnalyzer, type_def: LuaType) -> Option<()> {
-    let owner = analyzer.comment.get_owner()?;
+    let owner = analyzer.comment.as_ref()?.get_owner()?;
     match owner {
         LuaAst::LuaLocalStat(local_stat) => {
             let local_name = local_stat.child::<LuaLocalName>()?;
@@ -32,7 +32,7 @@ pub fn analyze_type(analyzer: &mut DocAnalyzer, tag: LuaDocTagType) -> Option<()
     }
 
     // bind ref type
-    let owner = analyzer.comment.get_owner()?;
+    let owner = analyzer.comment.as_ref()?.get_owner()?;
     match owner {
         LuaAst::LuaAssignStat(assign_stat) => {
             let (vars, _) = assign_stat.get_var_and_expr_list();
@@ -135,7 +135,8 @@ pub fn analyze_param(analyzer: &mut DocAnalyzer, tag: LuaDocTagParam) -> Option<
         let idx = signature.find_param_idx(&name)?;
 
         signature.param_docs.insert(idx, param_info);
-    } else if let Some(LuaAst::LuaForRangeStat(for_range)) = analyzer.comment.get_owner() {
+    } else if let Some(LuaAst::LuaForRangeStat(for_range)) = analyzer.comment.as_ref()?.get_owner()
+    {
         for it_name_token in for_range.get_var_name_list() {
             let it_name = it_name_token.get_name_text();
             if it_name == name {
@@ -322,7 +323,7 @@ pub fn analyze_module(analyzer: &mut DocAnalyzer, tag: LuaDocTagModule) -> Optio
 pub fn analyze_as(analyzer: &mut DocAnalyzer, tag: LuaDocTagAs) -> Option<()> {
     let as_type = tag.get_type()?;
     let type_ref = infer_type(analyzer, as_type);
-    let comment = analyzer.comment.clone();
+    let comment = analyzer.comment.clone()?;
     let mut left_token = comment.syntax().first_token()?.prev_token()?;
     if left_token.kind() == LuaTokenKind::TkWhitespace.into() {
         left_token = left_token.prev_token()?;
@@ -165,7 +165,7 @@ pub fn build_flow_tree(db: &mut DbIndex, file_id: FileId, root: LuaChunk) -> Lua
     }
 
     for (flow_id, goto_stat) in goto_vecs {
-        build_goto_flow(db, &mut flow_tree, file_id, goto_stat, flow_id);
+        build_goto_flow(&mut flow_tree, goto_stat, flow_id);
     }
 
     flow_tree
@@ -324,18 +324,8 @@ fn build_label_flow(
     let label_name = label_token.get_name_text();
     let block = label.get_parent::<LuaBlock>()?;
     let block_id = BlockId::from_block(block);
+    // the duplicate label is reported by the `redefined-label` diagnostic
     if flow_tree.is_exist_label_in_same_block(label_name, block_id) {
-        db.get_diagnostic_index_mut().add_diagnostic(
-            file_id,
-            AnalyzeError::new(
-                DiagnosticCode::SyntaxError,
-                &t!(
-                    "Label `%{name}` already exists in the same block",
-                    name = label_name
-                ),
-                label.get_range(),
-            ),
-        );
         return None;
     }
 
@@ -344,29 +334,14 @@ fn build_label_flow(
 }
 
 fn build_goto_flow(
-    db: &mut DbIndex,
     builder: &mut LuaFlowTreeBuilder,
-    file_id: FileId,
     goto_stat: LuaGotoStat,
     flow_id: LuaFlowId,
 ) -> Option<()> {
     let flow_node = builder.get_flow_node_mut(flow_id)?;
     let label_token = goto_stat.get_label_name_token()?;
-    let label_name = label_token.get_name_text();
-    let label = flow_node.find_label(label_name, goto_stat.clone());
-    if label.is_none() {
-        db.get_diagnostic_index_mut().add_diagnostic(
-            file_id,
-            AnalyzeError::new(
-                DiagnosticCode::SyntaxError,
-                &t!("Label `%{name}` not found", name = label_name),
-                label_token.get_range(),
-            ),
-        );
-    }
-
-    let label = label?;
-
+    // the missing label is reported by the `undefined-label` diagnostic
+    let label = flow_node.find_label(label_token.get_name_text(), goto_stat.clone())?;
     flow_node.add_jump_to_stat(
         goto_stat.get_syntax_id(),
         LuaStat::cast(label.syntax().clone())?,
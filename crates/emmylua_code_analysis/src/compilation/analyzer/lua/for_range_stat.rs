@@ -15,7 +15,9 @@ pub fn analyze_for_range_stat(
 ) -> Option<()> {
     let var_name_list = for_range_stat.get_var_name_list();
     let first_iter_expr = for_range_stat.get_expr_list().next()?;
-    let first_iter_type = analyzer.infer_expr(&first_iter_expr);
+    let first_iter_type = analyzer
+        .infer_expr(&first_iter_expr)
+        .and_then(|typ| check_iter_signature_resolved(analyzer.db, typ));
 
     match first_iter_type {
         Ok(first_iter_type) => {
@@ -84,12 +86,37 @@ pub fn analyze_for_range_stat(
     Some(())
 }
 
+/// The returns of an iterator defined by a closure are inferred later, the loop variables have to
+/// wait for them
+pub fn check_iter_signature_resolved(
+    db: &DbIndex,
+    iter_expr_type: LuaType,
+) -> Result<LuaType, InferFailReason> {
+    if let LuaType::Signature(signature_id) = &iter_expr_type {
+        if db
+            .get_signature_index()
+            .get(signature_id)
+            .is_some_and(|signature| !signature.is_resolve_return())
+        {
+            return Err(InferFailReason::UnResolveSignatureReturn(*signature_id));
+        }
+    }
+
+    Ok(iter_expr_type)
+}
+
 pub fn infer_for_range_iter_expr_func(
     db: &mut DbIndex,
     iter_expr_type: LuaType,
 ) -> Option<Arc<LuaFunctionType>> {
     match iter_expr_type {
         LuaType::DocFunction(func) => Some(func),
+        // the iterator returned by a function such as a `__pairs` metamethod
+        LuaType::Signature(signature_id) => Some(
+            db.get_signature_index()
+                .get(&signature_id)?
+                .to_doc_func_type(),
+        ),
         LuaType::Ref(type_decl_id) => {
             let type_decl = db.get_type_index().get_type_decl(&type_decl_id)?;
             if type_decl.is_alias() {
@@ -11,13 +11,13 @@ use closure::analyze_closure;
 pub use closure::analyze_return_point;
 use emmylua_parser::{LuaAst, LuaAstNode, LuaExpr};
 use for_range_stat::analyze_for_range_stat;
-pub use for_range_stat::infer_for_range_iter_expr_func;
+pub use for_range_stat::{check_iter_signature_resolved, infer_for_range_iter_expr_func};
 pub use func_body::LuaReturnPoint;
 use metatable::analyze_setmetatable;
 use module::analyze_chunk_return;
 use stats::{
-    analyze_assign_stat, analyze_func_stat, analyze_local_func_stat, analyze_local_stat,
-    analyze_table_field,
+    analyze_assign_stat, analyze_func_stat, analyze_global_stat, analyze_local_func_stat,
+    analyze_local_stat, analyze_table_field,
 };
 
 use crate::{
@@ -58,6 +58,9 @@ fn analyze_node(analyzer: &mut LuaAnalyzer, node: LuaAst) {
         LuaAst::LuaAssignStat(assign_stat) => {
             analyze_assign_stat(analyzer, assign_stat);
         }
+        LuaAst::LuaGlobalStat(global_stat) => {
+            analyze_global_stat(analyzer, global_stat);
+        }
         LuaAst::LuaForRangeStat(for_range_stat) => {
             analyze_for_range_stat(analyzer, for_range_stat);
         }
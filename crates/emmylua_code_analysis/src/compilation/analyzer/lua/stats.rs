@@ -1,5 +1,5 @@
 use emmylua_parser::{
-    BinaryOperator, LuaAssignStat, LuaAstNode, LuaExpr, LuaFuncStat, LuaIndexExpr,
+    BinaryOperator, LuaAssignStat, LuaAstNode, LuaExpr, LuaFuncStat, LuaGlobalStat, LuaIndexExpr,
     LuaLocalFuncStat, LuaLocalStat, LuaTableField, LuaVarExpr, PathTrait,
 };
 
@@ -9,7 +9,7 @@ use crate::{
         unresolve::{UnResolveDecl, UnResolveMember},
     },
     db_index::{LuaDeclId, LuaMemberId, LuaMemberOwner, LuaType},
-    InFiled, InferFailReason, LuaTypeCache, LuaTypeOwner,
+    infer_compound_assign_value, InFiled, InferFailReason, LuaTypeCache, LuaTypeOwner,
 };
 
 use super::LuaAnalyzer;
@@ -250,7 +250,46 @@ fn set_index_expr_owner(analyzer: &mut LuaAnalyzer, var_expr: LuaVarExpr) -> Opt
 
 // assign stat is toooooooooo complex
 pub fn analyze_assign_stat(analyzer: &mut LuaAnalyzer, assign_stat: LuaAssignStat) -> Option<()> {
+    if assign_stat.get_compound_op_token().is_some() {
+        return analyze_compound_assign_stat(analyzer, assign_stat);
+    }
+
     let (var_list, expr_list) = assign_stat.get_var_and_expr_list();
+    analyze_assign_var_list(analyzer, var_list, expr_list)
+}
+
+// luau `a += 1` binds the type of `a + 1` instead of the right expression
+fn analyze_compound_assign_stat(
+    analyzer: &mut LuaAnalyzer,
+    assign_stat: LuaAssignStat,
+) -> Option<()> {
+    let (var_list, _) = assign_stat.get_var_and_expr_list();
+    let var = var_list.first()?.clone();
+    let type_owner = get_var_owner(analyzer, var.clone());
+    set_index_expr_owner(analyzer, var.clone());
+
+    let left_type = analyzer.infer_expr(&var.into()).ok()?;
+    let cache = analyzer
+        .context
+        .infer_manager
+        .get_infer_cache(analyzer.file_id);
+    let value_type =
+        infer_compound_assign_value(analyzer.db, cache, &assign_stat, left_type)?.ok()?;
+    assign_merge_type_owner_and_expr_type(analyzer, type_owner, &value_type, 0);
+    Some(())
+}
+
+pub fn analyze_global_stat(analyzer: &mut LuaAnalyzer, global_stat: LuaGlobalStat) -> Option<()> {
+    let (name_list, expr_list) = global_stat.get_var_and_expr_list();
+    let var_list = name_list.into_iter().map(LuaVarExpr::NameExpr).collect();
+    analyze_assign_var_list(analyzer, var_list, expr_list)
+}
+
+fn analyze_assign_var_list(
+    analyzer: &mut LuaAnalyzer,
+    var_list: Vec<LuaVarExpr>,
+    expr_list: Vec<LuaExpr>,
+) -> Option<()> {
     let expr_count = expr_list.len();
     let var_count = var_list.len();
     for i in 0..expr_count {
@@ -78,7 +78,8 @@ pub fn resolve_as_any(db: &mut DbIndex, reason: &InferFailReason) -> Option<()>
             let member_item = db.get_member_index().get_member_item(&owner, key)?;
             let opt_type = member_item.resolve_type(db).ok();
             if opt_type.is_none() {
-                let semantic_member_id = member_item.resolve_semantic_decl(db)?;
+                let semantic_member_id =
+                    member_item.resolve_semantic_decl(db, member_id.file_id)?;
                 if let LuaSemanticDeclId::Member(member_id) = semantic_member_id {
                     db.get_type_index_mut()
                         .bind_type(member_id.into(), LuaTypeCache::InferType(LuaType::Any));
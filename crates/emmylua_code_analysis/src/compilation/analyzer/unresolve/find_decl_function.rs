@@ -152,7 +152,7 @@ fn find_custom_type_function_member(
     let index_key = index_expr.get_index_key().ok_or(InferFailReason::None)?;
     let key = LuaMemberKey::from_index_key(db, cache, &index_key)?;
     if let Some(member_item) = db.get_member_index().get_member_item(&owner, &key) {
-        if let Some(semantic_decl_id) = member_item.resolve_semantic_decl(db) {
+        if let Some(semantic_decl_id) = member_item.resolve_semantic_decl(db, cache.get_file_id()) {
             if let LuaSemanticDeclId::Member(member_id) = semantic_decl_id {
                 let index_member_id = get_member_id(cache, &index_expr);
                 if index_member_id != member_id {
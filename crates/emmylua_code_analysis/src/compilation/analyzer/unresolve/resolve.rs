@@ -5,7 +5,9 @@ use emmylua_parser::{LuaAstNode, LuaExpr, LuaLocalStat, LuaTableExpr};
 use crate::{
     compilation::analyzer::{
         bind_type::{add_member, bind_type},
-        lua::{analyze_return_point, infer_for_range_iter_expr_func},
+        lua::{
+            analyze_return_point, check_iter_signature_resolved, infer_for_range_iter_expr_func,
+        },
     },
     db_index::{DbIndex, LuaMemberOwner, LuaType},
     semantic::{infer_expr, LuaInferCache},
@@ -209,6 +211,7 @@ pub fn try_resolve_iter_var(
     iter_var: &mut UnResolveIterVar,
 ) -> ResolveResult {
     let expr_type = infer_expr(db, cache, iter_var.iter_expr.clone())?;
+    let expr_type = check_iter_signature_resolved(db, expr_type)?;
     let func = match infer_for_range_iter_expr_func(db, expr_type.clone()) {
         Some(func) => func,
         None => {
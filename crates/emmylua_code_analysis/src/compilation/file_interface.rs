@@ -0,0 +1,364 @@
+use std::collections::{HashMap, HashSet};
+
+use emmylua_parser::{LuaAstNode, LuaIndexExpr, LuaSyntaxId};
+use smol_str::SmolStr;
+
+use crate::{
+    semantic::{infer_expr, is_sub_type_of},
+    DbIndex, FileId, GlobalId, InFiled, LuaDeclTypeKind, LuaFunctionType, LuaInferCache,
+    LuaMemberId, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId, LuaUnionType,
+};
+
+// a signature which returns itself would be expanded forever
+const MAX_EXPAND_DEPTH: usize = 3;
+
+/// A declaration which other files can depend on
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LuaInterfaceKey {
+    Module,
+    Global(SmolStr),
+    Type(LuaTypeDeclId),
+    Member(LuaInterfaceOwner, LuaMemberKey),
+}
+
+/// The owner of a member, the tables are not told apart because their ids are ranges
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LuaInterfaceOwner {
+    Type(LuaTypeDeclId),
+    GlobalPath(GlobalId),
+    Table,
+    Unresolved,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LuaInterfaceItem {
+    Type(LuaType),
+    TypeDecl {
+        kind: Option<LuaDeclTypeKind>,
+        alias_origin: Option<LuaType>,
+        generic_params: Vec<(String, Option<LuaType>)>,
+        super_types: Vec<LuaType>,
+    },
+}
+
+/// What the other files can see of a file. The types which refer to a position in the file are
+/// replaced by what they stand for, since the positions change whenever the text above a
+/// declaration is edited
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LuaFileInterface {
+    items: HashMap<LuaInterfaceKey, Vec<LuaInterfaceItem>>,
+}
+
+impl LuaFileInterface {
+    pub fn collect(db: &DbIndex, file_id: FileId) -> Self {
+        let mut items: HashMap<LuaInterfaceKey, Vec<LuaInterfaceItem>> = HashMap::new();
+        if let Some(export_type) = db
+            .get_module_index()
+            .get_module(file_id)
+            .and_then(|module| module.export_type.as_ref())
+        {
+            items.insert(
+                LuaInterfaceKey::Module,
+                vec![LuaInterfaceItem::Type(stable_type(db, export_type, 0))],
+            );
+        }
+
+        if let Some(decl_tree) = db.get_decl_index().get_decl_tree(&file_id) {
+            for decl in decl_tree.get_decls().values() {
+                if !decl.is_global() {
+                    continue;
+                }
+
+                let ty = db
+                    .get_type_index()
+                    .get_type_cache(&decl.get_id().into())
+                    .map(|cache| stable_type(db, cache.as_type(), 0))
+                    .unwrap_or(LuaType::Unknown);
+                items
+                    .entry(LuaInterfaceKey::Global(SmolStr::new(decl.get_name())))
+                    .or_default()
+                    .push(LuaInterfaceItem::Type(ty));
+            }
+        }
+
+        if let Some(type_ids) = db.get_type_index().get_file_types(&file_id) {
+            for type_id in type_ids {
+                items.insert(
+                    LuaInterfaceKey::Type(type_id.clone()),
+                    vec![collect_type_decl(db, type_id)],
+                );
+            }
+        }
+
+        let member_index = db.get_member_index();
+        for member in member_index.get_file_members(&file_id) {
+            let owner = match member_index.get_current_owner(&member.get_id()) {
+                Some(LuaMemberOwner::Type(type_id)) => LuaInterfaceOwner::Type(type_id.clone()),
+                Some(LuaMemberOwner::GlobalPath(global_id)) => {
+                    LuaInterfaceOwner::GlobalPath(global_id.clone())
+                }
+                Some(LuaMemberOwner::Element(_)) => LuaInterfaceOwner::Table,
+                Some(LuaMemberOwner::LocalUnresolve) | None => LuaInterfaceOwner::Unresolved,
+            };
+            let ty = db
+                .get_type_index()
+                .get_type_cache(&member.get_id().into())
+                .map(|cache| stable_type(db, cache.as_type(), 0))
+                .unwrap_or(LuaType::Unknown);
+            items
+                .entry(LuaInterfaceKey::Member(owner, member.get_key().clone()))
+                .or_default()
+                .push(LuaInterfaceItem::Type(ty));
+        }
+
+        Self { items }
+    }
+
+    /// The declarations which were added, removed or changed since the old interface
+    pub fn get_changed_keys(&self, old: &LuaFileInterface) -> Vec<LuaInterfaceKey> {
+        let mut changed_keys = Vec::new();
+        for (key, items) in self.items.iter() {
+            if !old
+                .items
+                .get(key)
+                .is_some_and(|old_items| is_same_items(items, old_items))
+            {
+                changed_keys.push(key.clone());
+            }
+        }
+
+        for key in old.items.keys() {
+            if !self.items.contains_key(key) {
+                changed_keys.push(key.clone());
+            }
+        }
+
+        changed_keys
+    }
+}
+
+/// Get the files which use a declaration of the file
+pub fn collect_interface_dependents(
+    db: &DbIndex,
+    file_id: FileId,
+    keys: &[LuaInterfaceKey],
+) -> HashSet<FileId> {
+    let mut dependents = HashSet::new();
+    let mut infer_caches = HashMap::new();
+    let reference_index = db.get_reference_index();
+    for key in keys {
+        match key {
+            LuaInterfaceKey::Module => {
+                dependents.extend(
+                    db.get_file_dependencies_index()
+                        .get_dependent_files(&file_id),
+                );
+            }
+            LuaInterfaceKey::Global(name) => {
+                if let Some(references) = reference_index.get_global_references(name) {
+                    dependents.extend(references.into_iter().map(|reference| reference.file_id));
+                }
+            }
+            LuaInterfaceKey::Type(type_id) => {
+                if let Some(references) = reference_index.get_type_references(type_id) {
+                    dependents.extend(references.into_iter().map(|reference| reference.file_id));
+                }
+            }
+            LuaInterfaceKey::Member(owner, member_key) => {
+                let Some(references) = reference_index.get_index_references(member_key) else {
+                    continue;
+                };
+                for reference in references {
+                    if reference.file_id != file_id
+                        && !dependents.contains(&reference.file_id)
+                        && is_owner_reference(db, &mut infer_caches, &reference, owner)
+                    {
+                        dependents.insert(reference.file_id);
+                    }
+                }
+            }
+        }
+    }
+
+    dependents.remove(&file_id);
+    dependents
+}
+
+/// Whether an index reference can use a member of the owner. Only the fields of another class
+/// and the index expressions whose prefix is inferred as another class are skipped, the others
+/// are kept since their owner is unknown
+fn is_owner_reference(
+    db: &DbIndex,
+    infer_caches: &mut HashMap<FileId, LuaInferCache>,
+    reference: &InFiled<LuaSyntaxId>,
+    owner: &LuaInterfaceOwner,
+) -> bool {
+    if matches!(
+        owner,
+        LuaInterfaceOwner::Table | LuaInterfaceOwner::Unresolved
+    ) {
+        return true;
+    }
+
+    // the fields declared in the other file tell their owner
+    let member_id = LuaMemberId::new(reference.value, reference.file_id);
+    if let Some(member_owner) = db.get_member_index().get_current_owner(&member_id) {
+        return match (member_owner, owner) {
+            (LuaMemberOwner::Type(type_id), _) => {
+                is_owner_type(db, &LuaType::Ref(type_id.clone()), owner)
+            }
+            (LuaMemberOwner::GlobalPath(global_id), LuaInterfaceOwner::GlobalPath(owner_id)) => {
+                global_id == owner_id
+            }
+            _ => true,
+        };
+    }
+
+    let Some(tree) = db.get_vfs().get_syntax_tree(&reference.file_id) else {
+        return true;
+    };
+    let Some(prefix_expr) = reference
+        .value
+        .to_node_from_root(&tree.get_red_root())
+        .and_then(LuaIndexExpr::cast)
+        .and_then(|index_expr| index_expr.get_prefix_expr())
+    else {
+        return true;
+    };
+
+    let cache = infer_caches
+        .entry(reference.file_id)
+        .or_insert_with(|| LuaInferCache::new(reference.file_id, Default::default()));
+    match infer_expr(db, cache, prefix_expr) {
+        Ok(prefix_type) => is_owner_type(db, &prefix_type, owner),
+        Err(_) => true,
+    }
+}
+
+fn is_owner_type(db: &DbIndex, ty: &LuaType, owner: &LuaInterfaceOwner) -> bool {
+    let type_id = match ty {
+        LuaType::Ref(type_id) | LuaType::Def(type_id) => type_id.clone(),
+        LuaType::Generic(generic) => generic.get_base_type_id(),
+        LuaType::Union(union) => {
+            return union
+                .get_types()
+                .iter()
+                .any(|ty| is_owner_type(db, ty, owner));
+        }
+        _ => return true,
+    };
+
+    // the aliases are not expanded
+    if db
+        .get_type_index()
+        .get_type_decl(&type_id)
+        .is_none_or(|type_decl| type_decl.is_alias())
+    {
+        return true;
+    }
+
+    match owner {
+        LuaInterfaceOwner::Type(owner_id) => is_sub_type_of(db, &type_id, owner_id),
+        _ => false,
+    }
+}
+
+// the declarations of a file are not visited in a stable order
+fn is_same_items(a: &[LuaInterfaceItem], b: &[LuaInterfaceItem]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut matched = vec![false; b.len()];
+    a.iter().all(|item| {
+        let found = b
+            .iter()
+            .enumerate()
+            .position(|(i, other)| !matched[i] && other == item);
+        match found {
+            Some(i) => {
+                matched[i] = true;
+                true
+            }
+            None => false,
+        }
+    })
+}
+
+/// Replace the signatures by the functions they declare and the table literals by `table`,
+/// the members of the tables are compared on their own
+fn stable_type(db: &DbIndex, ty: &LuaType, depth: usize) -> LuaType {
+    match ty {
+        LuaType::Signature(signature_id) => {
+            let Some(signature) = db.get_signature_index().get(signature_id) else {
+                return LuaType::Function;
+            };
+            if depth >= MAX_EXPAND_DEPTH {
+                return LuaType::Function;
+            }
+
+            let func = signature.to_doc_func_type();
+            let params = func
+                .get_params()
+                .iter()
+                .map(|(name, ty)| {
+                    (
+                        name.clone(),
+                        ty.as_ref().map(|ty| stable_type(db, ty, depth + 1)),
+                    )
+                })
+                .collect();
+            LuaType::DocFunction(
+                LuaFunctionType::new(
+                    func.is_async(),
+                    func.is_colon_define(),
+                    params,
+                    stable_type(db, func.get_ret(), depth + 1),
+                )
+                .into(),
+            )
+        }
+        LuaType::TableConst(_) => LuaType::Table,
+        LuaType::Union(union) => LuaType::Union(
+            LuaUnionType::new(
+                union
+                    .get_types()
+                    .iter()
+                    .map(|ty| stable_type(db, ty, depth))
+                    .collect(),
+            )
+            .into(),
+        ),
+        _ => ty.clone(),
+    }
+}
+
+fn collect_type_decl(db: &DbIndex, type_id: &LuaTypeDeclId) -> LuaInterfaceItem {
+    let type_index = db.get_type_index();
+    let type_decl = type_index.get_type_decl(type_id);
+    let kind = type_decl.and_then(|type_decl| {
+        if type_decl.is_class() {
+            Some(LuaDeclTypeKind::Class)
+        } else if type_decl.is_enum() {
+            Some(LuaDeclTypeKind::Enum)
+        } else if type_decl.is_alias() {
+            Some(LuaDeclTypeKind::Alias)
+        } else {
+            None
+        }
+    });
+    let alias_origin = type_decl
+        .and_then(|type_decl| type_decl.get_alias_ref())
+        .map(|origin| stable_type(db, origin, 0));
+    let generic_params = type_index
+        .get_generic_params(type_id)
+        .cloned()
+        .unwrap_or_default();
+
+    LuaInterfaceItem::TypeDecl {
+        kind,
+        alias_origin,
+        generic_params,
+        super_types: type_index.get_super_types(type_id).unwrap_or_default(),
+    }
+}
@@ -1,7 +1,13 @@
 mod analyzer;
+mod file_interface;
 mod test;
 
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use file_interface::{collect_interface_dependents, LuaFileInterface};
 
 use crate::{
     db_index::DbIndex, semantic::SemanticModel, Emmyrc, FileId, InFiled, LuaIndex, LuaInferCache,
@@ -10,17 +16,19 @@ use crate::{
 #[derive(Debug)]
 pub struct LuaCompilation {
     db: DbIndex,
-    emmyrc: Arc<Emmyrc>,
+    /// The interfaces of the changed files before their first change since the dependents were
+    /// updated
+    changed_interfaces: HashMap<FileId, LuaFileInterface>,
 }
 
 impl LuaCompilation {
     pub fn new(emmyrc: Arc<Emmyrc>) -> Self {
         let mut compilation = Self {
             db: DbIndex::new(),
-            emmyrc: emmyrc.clone(),
+            changed_interfaces: HashMap::new(),
         };
 
-        compilation.db.update_config(emmyrc.clone());
+        compilation.db.update_config(emmyrc);
         compilation
     }
 
@@ -31,7 +39,7 @@ impl LuaCompilation {
             file_id,
             &self.db,
             cache,
-            self.emmyrc.clone(),
+            self.db.get_file_emmyrc(file_id),
             tree.get_chunk_node(),
         ))
     }
@@ -52,7 +60,57 @@ impl LuaCompilation {
             });
         }
 
-        analyzer::analyze(&mut self.db, need_analyzed_files, self.emmyrc.clone());
+        analyzer::analyze(&mut self.db, need_analyzed_files);
+    }
+
+    /// Re-analyze the changed files. The files removed from the vfs are only removed from the
+    /// index. The files which depend on them are re-analyzed by `update_dependents_index`.
+    pub fn update_changed_index(&mut self, file_ids: Vec<FileId>) {
+        for file_id in &file_ids {
+            // compare with the interface the dependents were analyzed against
+            if !self.changed_interfaces.contains_key(file_id) {
+                let interface = LuaFileInterface::collect(&self.db, *file_id);
+                self.changed_interfaces.insert(*file_id, interface);
+            }
+        }
+
+        self.remove_index(file_ids.clone());
+        let exist_files = file_ids
+            .into_iter()
+            .filter(|file_id| self.db.get_vfs().get_syntax_tree(file_id).is_some())
+            .collect();
+        self.update_index(exist_files);
+    }
+
+    /// Re-analyze the files which use a declaration that changed since the last call, until no
+    /// more declarations change. Returns the dependent files which were re-analyzed.
+    pub fn update_dependents_index(&mut self) -> Vec<FileId> {
+        // the changed files may depend on each other, so they can be re-analyzed once more
+        let mut visited_files: HashSet<FileId> = HashSet::new();
+        let mut dependent_files = Vec::new();
+        let mut changed_interfaces = std::mem::take(&mut self.changed_interfaces);
+        while !changed_interfaces.is_empty() {
+            let mut next_files = Vec::new();
+            for (file_id, old_interface) in changed_interfaces {
+                let changed_keys =
+                    LuaFileInterface::collect(&self.db, file_id).get_changed_keys(&old_interface);
+                if changed_keys.is_empty() {
+                    continue;
+                }
+
+                for dependent_id in collect_interface_dependents(&self.db, file_id, &changed_keys) {
+                    if visited_files.insert(dependent_id) {
+                        next_files.push(dependent_id);
+                    }
+                }
+            }
+
+            dependent_files.extend(next_files.iter().copied());
+            self.update_changed_index(next_files);
+            changed_interfaces = std::mem::take(&mut self.changed_interfaces);
+        }
+
+        dependent_files
     }
 
     pub fn remove_index(&mut self, file_ids: Vec<FileId>) {
@@ -60,6 +118,7 @@ impl LuaCompilation {
     }
 
     pub fn clear_index(&mut self) {
+        self.changed_interfaces.clear();
         self.db.clear();
     }
 
@@ -72,7 +131,6 @@ impl LuaCompilation {
     }
 
     pub fn update_config(&mut self, config: Arc<Emmyrc>) {
-        self.emmyrc = config.clone();
         self.db.update_config(config);
     }
 }
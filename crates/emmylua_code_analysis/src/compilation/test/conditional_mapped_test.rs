@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_conditional_type() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@generic T
+            ---@param a T
+            ---@return T extends string and integer or boolean
+            function check(a)
+            end
+            "#,
+        );
+
+        let ty = ws.expr_ty("check('name')");
+        assert_eq!(ty, ws.ty("integer"));
+        let ty = ws.expr_ty("check(1)");
+        assert_eq!(ty, ws.ty("boolean"));
+    }
+
+    #[test]
+    fn test_mapped_type() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Config
+            ---@field name string
+            ---@field port integer
+
+            ---@alias Partial<T> { [K in keyof T]?: T[K] }
+
+            ---@type Partial<Config>
+            partial = {}
+            "#,
+        );
+
+        let ty = ws.expr_ty("partial.name");
+        assert_eq!(ws.humanize_type(ty), "string?");
+        let ty = ws.expr_ty("partial.port");
+        assert_eq!(ws.humanize_type(ty), "integer?");
+    }
+
+    #[test]
+    fn test_index_access_type() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Config
+            ---@field name string
+            ---@field port integer
+
+            ---@alias Port Config["port"]
+
+            ---@generic T, K
+            ---@param config T
+            ---@param key K
+            ---@return T[K]
+            function get(config, key)
+            end
+
+            ---@type Config
+            config = {}
+            "#,
+        );
+
+        let ty = ws.expr_ty("get(config, 'name')");
+        assert_eq!(ty, ws.ty("string"));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type Port
+            local port = 8080
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type Port
+            local port = "8080"
+            "#
+        ));
+    }
+}
@@ -74,4 +74,72 @@ mod test {
 
         assert_eq!(ws.expr_ty("d"), LuaType::String);
     }
+
+    #[test]
+    fn test_pairs_meta_method() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@class Proxy
+        ---@operator pairs: fun(t: any, k: any): string, integer
+
+        ---@class SubProxy: Proxy
+
+        ---@type SubProxy
+        local proxy
+
+        for k, v in pairs(proxy) do
+            a = k
+            b = v
+        end
+
+        local t = setmetatable({ 1 }, {
+            ---@return fun(t: any, k?: integer): integer?, boolean
+            __pairs = function(t)
+            end,
+        })
+
+        for k, v in pairs(t) do
+            c = k
+            d = v
+        end
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::String);
+        assert_eq!(ws.expr_ty("b"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("c"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("d"), LuaType::Boolean);
+    }
+
+    #[test]
+    fn test_tostring_meta_method() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@alias Color "red" | "green"
+
+        ---@class Paint
+        ---@operator tostring: Color
+
+        ---@type Paint
+        local paint
+
+        a = tostring(paint)
+        b = tostring(1)
+
+        ---@return integer
+        local function tostring(v) end
+        c = tostring(paint)
+        "#,
+        );
+
+        let ty = ws.expr_ty("a");
+        assert_eq!(ws.humanize_type(ty), "Color");
+        assert_eq!(ws.expr_ty("b"), LuaType::String);
+        // a local function with the same name is not the std function
+        assert_eq!(ws.expr_ty("c"), LuaType::Integer);
+    }
 }
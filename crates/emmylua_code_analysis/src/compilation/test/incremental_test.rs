@@ -0,0 +1,313 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::{Position, Range, TextDocumentContentChangeEvent, Uri};
+    use smol_str::SmolStr;
+
+    use crate::{LuaType, VirtualWorkspace};
+
+    #[test]
+    fn test_reanalyze_module_dependents() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "config.lua",
+            r#"
+            local M = {}
+            M.port = 8080
+            return M
+            "#,
+        );
+        let server_id = ws.def_file(
+            "server.lua",
+            r#"
+            local config = require("config")
+            ServerPort = config.port
+            "#,
+        );
+        assert_eq!(ws.expr_ty("ServerPort"), LuaType::IntegerConst(8080));
+        ws.analysis.update_dependents();
+
+        ws.def_file(
+            "config.lua",
+            r#"
+            local M = {}
+            M.port = "8080"
+            return M
+            "#,
+        );
+        // the dependents wait until the edits settle
+        assert_eq!(ws.expr_ty("ServerPort"), LuaType::IntegerConst(8080));
+        assert!(ws.analysis.update_dependents().contains(&server_id));
+        assert_eq!(
+            ws.expr_ty("ServerPort"),
+            LuaType::StringConst(SmolStr::new("8080").into())
+        );
+    }
+
+    #[test]
+    fn test_reanalyze_global_dependents() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@type string
+            Name = ""
+            "#,
+        );
+        ws.def_file(
+            "b.lua",
+            r#"
+            Copy = Name
+            "#,
+        );
+        assert_eq!(ws.expr_ty("Copy"), LuaType::String);
+
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@type integer
+            Name = 1
+            "#,
+        );
+        ws.analysis.update_dependents();
+        assert_eq!(ws.expr_ty("Copy"), LuaType::Integer);
+    }
+
+    #[test]
+    fn test_skip_unchanged_dependents() {
+        let mut ws = VirtualWorkspace::new();
+        let a_id = ws.def_file(
+            "a.lua",
+            r#"
+            ---@class A
+            ---@field x integer
+            local a = {}
+            function a.f(n) return n end
+            return a
+            "#,
+        );
+        ws.def_file(
+            "b.lua",
+            r#"
+            ---@type A
+            local b
+            local x = b.x
+            "#,
+        );
+
+        ws.analysis.update_dependents();
+
+        let uri = ws.analysis.get_uri(a_id).expect("file uri");
+        let edit = |ws: &mut VirtualWorkspace, uri: &Uri, text: &str| {
+            let compilation = &mut ws.analysis.compilation;
+            compilation
+                .get_db_mut()
+                .get_vfs_mut()
+                .set_file_content(uri, Some(text.to_string()));
+            compilation.update_changed_index(vec![a_id]);
+            compilation.update_dependents_index()
+        };
+
+        let unchanged = edit(
+            &mut ws,
+            &uri,
+            r#"
+
+            ---@class A
+            ---@field x integer
+            local a = {}
+            local unused = 1
+            -- the signature moved
+            function a.f(n) return n end
+            return a
+            "#,
+        );
+        assert!(unchanged.is_empty());
+
+        let changed = edit(
+            &mut ws,
+            &uri,
+            r#"
+            ---@class A
+            ---@field x string
+            local a = {}
+            function a.f(n) return n end
+            return a
+            "#,
+        );
+        assert_eq!(changed.len(), 1);
+    }
+
+    #[test]
+    fn test_skip_dependents_of_other_owner() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@class A
+            ---@field x integer
+            "#,
+        );
+        ws.def_file(
+            "b.lua",
+            r#"
+            ---@class B
+            ---@field x integer
+            "#,
+        );
+        let use_a_id = ws.def_file(
+            "use_a.lua",
+            r#"
+            ---@type A
+            local a
+            AX = a.x
+            "#,
+        );
+        ws.def_file(
+            "use_b.lua",
+            r#"
+            ---@type B
+            local b
+            BX = b.x
+            "#,
+        );
+        ws.analysis.update_dependents();
+
+        ws.def_file(
+            "a.lua",
+            r#"
+            ---@class A
+            ---@field x string
+            "#,
+        );
+        assert_eq!(ws.analysis.update_dependents(), vec![use_a_id]);
+        assert_eq!(ws.expr_ty("AX"), LuaType::String);
+        assert_eq!(ws.expr_ty("BX"), LuaType::Integer);
+    }
+
+    #[test]
+    fn test_detect_change_at_end_of_long_type() {
+        let mut ws = VirtualWorkspace::new();
+        let fields = (0..50)
+            .map(|i| format!("f{}: integer", i))
+            .collect::<Vec<_>>()
+            .join(", ");
+        ws.def_file(
+            "a.lua",
+            &format!(
+                "---@type {{ {}, last: integer }}
+Shape = {{}}
+",
+                fields
+            ),
+        );
+        let b_id = ws.def_file(
+            "b.lua",
+            "Last = Shape.last
+",
+        );
+        ws.analysis.update_dependents();
+
+        // the types are compared as a whole, not as their truncated descriptions
+        ws.def_file(
+            "a.lua",
+            &format!(
+                "---@type {{ {}, last: string }}
+Shape = {{}}
+",
+                fields
+            ),
+        );
+        assert_eq!(ws.analysis.update_dependents(), vec![b_id]);
+        assert_eq!(ws.expr_ty("Last"), LuaType::String);
+    }
+
+    #[test]
+    fn test_apply_changes_with_astral_chars() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def_file("emoji.lua", "local s = \"😀😀\"\nN = 1\n");
+        let uri = ws.analysis.get_uri(file_id).expect("file uri");
+        let change = |line: u32, start: u32, end: u32, text: &str| TextDocumentContentChangeEvent {
+            range: Some(Range::new(
+                Position::new(line, start),
+                Position::new(line, end),
+            )),
+            range_length: None,
+            text: text.to_string(),
+        };
+
+        // LSP positions count UTF-16 code units, each emoji takes two of them
+        ws.analysis
+            .update_file_by_changes(&uri, vec![change(0, 12, 14, "x"), change(1, 4, 5, "2")])
+            .expect("apply changes");
+        let vfs = ws.analysis.compilation.get_db().get_vfs();
+        assert_eq!(
+            vfs.get_file_content(&file_id).map(String::as_str),
+            Some("local s = \"😀x\"\nN = 2\n")
+        );
+
+        // a position past the end of the line stops before the line break
+        ws.analysis
+            .update_file_by_changes(&uri, vec![change(0, 15, 100, " -- 😀")])
+            .expect("apply changes");
+        let vfs = ws.analysis.compilation.get_db().get_vfs();
+        assert_eq!(
+            vfs.get_file_content(&file_id).map(String::as_str),
+            Some("local s = \"😀x\" -- 😀\nN = 2\n")
+        );
+        assert_eq!(ws.expr_ty("N"), LuaType::IntegerConst(2));
+    }
+
+    #[test]
+    fn test_invalid_change_reloads_from_disk() {
+        let dir = std::env::temp_dir().join(format!("emmylua_resync_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let path = dir.join("main.lua");
+        std::fs::write(&path, "N = 1\n").expect("write file");
+
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws
+            .analysis
+            .update_file_by_path(&path, Some("N = 2\n".to_string()))
+            .expect("open file");
+        let uri = ws.analysis.get_uri(file_id).expect("file uri");
+        let change = |range: Option<Range>, text: &str| TextDocumentContentChangeEvent {
+            range,
+            range_length: None,
+            text: text.to_string(),
+        };
+        let content = |ws: &VirtualWorkspace| {
+            let vfs = ws.analysis.compilation.get_db().get_vfs();
+            (
+                vfs.get_file_content(&file_id).cloned(),
+                vfs.is_out_of_sync(&file_id),
+            )
+        };
+
+        // the start is after the end
+        let invalid = Range::new(Position::new(0, 4), Position::new(0, 0));
+        ws.analysis
+            .update_file_by_changes(&uri, vec![change(Some(invalid), "x")]);
+        assert_eq!(content(&ws), (Some("N = 1\n".to_string()), true));
+
+        // the ranged changes are ignored until the whole text is sent
+        let valid = Range::new(Position::new(0, 4), Position::new(0, 5));
+        ws.analysis
+            .update_file_by_changes(&uri, vec![change(Some(valid), "3")]);
+        assert_eq!(content(&ws), (Some("N = 1\n".to_string()), true));
+        ws.analysis.update_file_by_changes(
+            &uri,
+            vec![change(None, "N = 4\n"), change(Some(valid), "5")],
+        );
+        assert_eq!(content(&ws), (Some("N = 5\n".to_string()), false));
+
+        // saving the file brings the out of sync text back
+        ws.analysis
+            .update_file_by_changes(&uri, vec![change(Some(invalid), "x")]);
+        std::fs::write(&path, "N = 6\n").expect("write file");
+        assert!(ws.analysis.reload_out_of_sync_file(&uri).is_some());
+        assert_eq!(content(&ws), (Some("N = 6\n".to_string()), false));
+        assert!(ws.analysis.reload_out_of_sync_file(&uri).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
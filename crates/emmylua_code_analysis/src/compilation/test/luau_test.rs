@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
+
+    fn create_luau_workspace() -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new();
+        let mut config = Emmyrc::default();
+        config.runtime.version = EmmyrcLuaVersion::Luau;
+        ws.analysis.update_config(config.into());
+        ws
+    }
+
+    #[test]
+    fn test_local_annotation() {
+        let mut ws = create_luau_workspace();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            local a: number = "hello"
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            local b: string? = nil
+            b ..= "world"
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_function_annotation() {
+        let mut ws = create_luau_workspace();
+        ws.def(
+            r#"
+            local function first<T>(list: {T}): T
+                return list[1]
+            end
+
+            function add(a: number, b: number): number
+                return a + b
+            end
+
+            local names: {string} = { "a", "b" }
+            A = first(names)
+            B = add(1, 2)
+            "#,
+        );
+
+        let a = ws.expr_ty("A");
+        assert_eq!(ws.humanize_type(a), "string");
+        let b = ws.expr_ty("B");
+        assert_eq!(ws.humanize_type(b), "number");
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            add("1", 2)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_type_alias() {
+        let mut ws = create_luau_workspace();
+        ws.def(
+            r#"
+            export type Point = { x: number, y: number }
+            type Callback = (number, string) -> boolean
+
+            local p: Point = { x = 1, y = 2 }
+            local cb: Callback = function(n, s) return true end
+            X = p.x
+            R = cb(1, "a")
+            S = `point {p.x}`
+            "#,
+        );
+
+        let x = ws.expr_ty("X");
+        assert_eq!(ws.humanize_type(x), "number");
+        let r = ws.expr_ty("R");
+        assert_eq!(ws.humanize_type(r), "boolean");
+        let s = ws.expr_ty("S");
+        assert_eq!(ws.humanize_type(s), "string");
+    }
+
+    #[test]
+    fn test_compound_assign() {
+        let mut ws = create_luau_workspace();
+        ws.def(
+            r#"
+            local name = "a"
+            name ..= 1
+            A = name
+
+            local count: number = 0
+            count += 1
+            B = count
+            "#,
+        );
+
+        let a = ws.expr_ty("A");
+        assert_eq!(ws.humanize_type(a), "string");
+        let b = ws.expr_ty("B");
+        assert_eq!(ws.humanize_type(b), "number");
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            local s: string = "a"
+            s ..= 1
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            local n: number = 1
+            n ..= 2
+            "#
+        ));
+    }
+}
@@ -3,12 +3,15 @@ mod annotation_test;
 mod closure_generic;
 mod closure_param_infer_test;
 mod closure_return_test;
+mod conditional_mapped_test;
 mod decl_test;
 mod diagnostic_disable_test;
 mod flow;
 mod for_range_var_infer_test;
+mod incremental_test;
 mod infer_str_tpl_test;
 mod inherit_type;
+mod luau_test;
 mod mathlib_test;
 mod member_infer_test;
 mod metatable_test;
@@ -4,7 +4,7 @@ use serde_json::Value;
 
 use crate::read_file_with_encoding;
 
-use super::{flatten_config::FlattenConfigObject, Emmyrc};
+use super::{flatten_config::FlattenConfigObject, luarc_compat::LuarcConfig, Emmyrc};
 
 pub fn load_configs(config_files: Vec<PathBuf>, partial_emmyrcs: Option<Vec<Value>>) -> Emmyrc {
     let mut config_jsons = Vec::new();
@@ -34,7 +34,16 @@ pub fn load_configs(config_files: Vec<PathBuf>, partial_emmyrcs: Option<Vec<Valu
             }
         };
 
-        config_jsons.push(config_json);
+        // the options of lua-language-server are translated, the unsupported ones are reported
+        // by `check_luarc_config`
+        let is_luarc = config_file
+            .file_name()
+            .is_some_and(|name| name == ".luarc.json");
+        if is_luarc {
+            config_jsons.push(LuarcConfig::parse(config_json).to_emmyrc());
+        } else {
+            config_jsons.push(config_json);
+        }
     }
 
     if let Some(partial_emmyrcs) = partial_emmyrcs {
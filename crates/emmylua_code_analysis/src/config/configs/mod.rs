@@ -4,6 +4,7 @@ mod diagnostics;
 mod document_color;
 mod hover;
 mod inlayhint;
+mod name_style;
 mod references;
 mod resource;
 mod runtime;
@@ -18,6 +19,7 @@ pub use diagnostics::EmmyrcDiagnostic;
 pub use document_color::EmmyrcDocumentColor;
 pub use hover::EmmyrcHover;
 pub use inlayhint::EmmyrcInlayHint;
+pub use name_style::{EmmyrcNameStyle, EmmyrcNameStyleKind, EmmyrcNameStyleRule};
 pub use references::EmmyrcReference;
 pub use resource::EmmyrcResource;
 pub use runtime::{EmmyrcLuaVersion, EmmyrcRuntime};
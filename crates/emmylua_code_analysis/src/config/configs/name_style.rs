@@ -0,0 +1,60 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The naming rules checked by the `name-style-check` diagnostic. A name is accepted when it
+/// matches any rule of its kind, the kinds without rules are not checked.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EmmyrcNameStyle {
+    /// The rules for local variables.
+    #[serde(default)]
+    pub local_name: Vec<EmmyrcNameStyleRule>,
+    /// The rules for local variables declared at the top level of a file, such as module
+    /// constants. Falls back to `localName` when empty.
+    #[serde(default)]
+    pub module_local_name: Vec<EmmyrcNameStyleRule>,
+    /// The rules for global variables.
+    #[serde(default)]
+    pub global_name: Vec<EmmyrcNameStyleRule>,
+    /// The rules for functions and methods declared with `function` or `local function`.
+    #[serde(default)]
+    pub function_name: Vec<EmmyrcNameStyleRule>,
+    /// The rules for function parameters.
+    #[serde(default)]
+    pub parameter_name: Vec<EmmyrcNameStyleRule>,
+    /// The rules for the names of `---@class` and `---@enum`.
+    #[serde(default)]
+    pub class_name: Vec<EmmyrcNameStyleRule>,
+    /// The rules for table fields and `---@field`.
+    #[serde(default)]
+    pub field_name: Vec<EmmyrcNameStyleRule>,
+    /// The rules for the fields and methods marked as private. Falls back to `fieldName`
+    /// when empty.
+    #[serde(default)]
+    pub private_field_name: Vec<EmmyrcNameStyleRule>,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum EmmyrcNameStyleRule {
+    /// A preset naming style, the leading underscores of a name are ignored.
+    Style(EmmyrcNameStyleKind),
+    /// A regular expression which must match the whole name.
+    Pattern { pattern: String },
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy, PartialEq, Eq)]
+pub enum EmmyrcNameStyleKind {
+    /// Lowercase words separated by underscores, such as `snake_case`.
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    /// Capitalized words except the first one, such as `camelCase`.
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    /// Capitalized words, such as `PascalCase`.
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+    /// Uppercase words separated by underscores, such as `UPPER_CASE`.
+    #[serde(rename = "UPPER_CASE")]
+    UpperCase,
+}
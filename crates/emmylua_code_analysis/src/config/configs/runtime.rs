@@ -51,6 +51,12 @@ pub enum EmmyrcLuaVersion {
     /// Lua 5.4
     #[serde(rename = "Lua5.4", alias = "Lua 5.4")]
     Lua54,
+    /// Lua 5.5
+    #[serde(rename = "Lua5.5", alias = "Lua 5.5")]
+    Lua55,
+    /// Luau (Roblox)
+    #[serde(rename = "Luau")]
+    Luau,
     /// Lua Latest
     #[serde(rename = "LuaLatest", alias = "Lua Latest")]
     LuaLatest,
@@ -70,6 +76,8 @@ impl EmmyrcLuaVersion {
             EmmyrcLuaVersion::Lua52 => LuaVersionNumber::new(5, 2, 0),
             EmmyrcLuaVersion::Lua53 => LuaVersionNumber::new(5, 3, 0),
             EmmyrcLuaVersion::Lua54 => LuaVersionNumber::new(5, 4, 0),
+            EmmyrcLuaVersion::Lua55 => LuaVersionNumber::new(5, 5, 0),
+            EmmyrcLuaVersion::Luau => LuaVersionNumber::new(5, 1, 0),
             EmmyrcLuaVersion::LuaLatest => LuaVersionNumber::new(5, 4, 0),
         }
     }
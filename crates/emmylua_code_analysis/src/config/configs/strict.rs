@@ -24,6 +24,9 @@ pub struct EmmyrcStrict {
     /// meta define overrides file define
     #[serde(default = "default_true")]
     pub meta_override_file_define: bool,
+    /// Whether a number can be passed or assigned where an integer is expected.
+    #[serde(default)]
+    pub cast_number_to_integer: bool,
 }
 
 impl Default for EmmyrcStrict {
@@ -33,6 +36,7 @@ impl Default for EmmyrcStrict {
             type_call: false,
             array_index: true,
             meta_override_file_define: true,
+            cast_number_to_integer: false,
         }
     }
 }
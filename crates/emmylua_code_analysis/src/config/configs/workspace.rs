@@ -33,7 +33,8 @@ pub struct EmmyrcWorkspace {
     /// when save a file, ls will reindex the workspace after reindex_duration milliseconds.
     #[serde(default = "reindex_duration_default")]
     pub reindex_duration: u64,
-    /// Enable reindex.
+    /// Enable reindex. The files which depend on a changed file are re-analyzed without it, a
+    /// full reindex is only a fallback.
     #[serde(default = "enable_reindex_default")]
     pub enable_reindex: bool,
 }
@@ -0,0 +1,293 @@
+use rowan::{TextRange, TextSize};
+
+/// A json value with the ranges of its parts, the parser keeps going on incomplete text so that
+/// a config which is being edited can still be completed
+#[derive(Debug, Clone)]
+pub struct JsonNode {
+    pub range: TextRange,
+    pub value: JsonValue,
+}
+
+#[derive(Debug, Clone)]
+pub enum JsonValue {
+    Object(Vec<JsonMember>),
+    Array(Vec<JsonNode>),
+    String(String),
+    Number {
+        is_integer: bool,
+    },
+    Bool,
+    Null,
+    /// A token which is not a json value
+    Invalid,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonMember {
+    pub key: String,
+    pub key_range: TextRange,
+    /// The offset after the colon, `None` when the colon is missing
+    pub colon_end: Option<TextSize>,
+    pub value: Option<JsonNode>,
+}
+
+#[derive(Debug)]
+pub struct JsonError {
+    pub offset: usize,
+    pub message: &'static str,
+}
+
+#[derive(Debug)]
+pub struct JsonTree {
+    pub root: Option<JsonNode>,
+    pub errors: Vec<JsonError>,
+}
+
+pub fn parse_json(text: &str) -> JsonTree {
+    let mut parser = JsonParser {
+        text,
+        pos: 0,
+        errors: Vec::new(),
+    };
+    parser.skip_trivia();
+    let root = parser.parse_value();
+    parser.skip_trivia();
+    if root.is_some() && parser.pos < text.len() {
+        parser.error("Unexpected content after the root value");
+    }
+
+    JsonTree {
+        root,
+        errors: parser.errors,
+    }
+}
+
+struct JsonParser<'a> {
+    text: &'a str,
+    pos: usize,
+    errors: Vec<JsonError>,
+}
+
+impl JsonParser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn error(&mut self, message: &'static str) {
+        self.errors.push(JsonError {
+            offset: self.pos,
+            message,
+        });
+    }
+
+    fn range_from(&self, start: usize) -> TextRange {
+        TextRange::new(TextSize::new(start as u32), TextSize::new(self.pos as u32))
+    }
+
+    /// Skips whitespace and the comments of jsonc
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = &self.text[self.pos..];
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if let Some(comment) = trimmed.strip_prefix("/*") {
+                self.pos += comment.find("*/").map_or(trimmed.len(), |end| end + 4);
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Option<JsonNode> {
+        let start = self.pos;
+        let value = match self.peek()? {
+            b'{' => self.parse_object(),
+            b'[' => self.parse_array(),
+            b'"' => JsonValue::String(self.parse_string()),
+            b'-' | b'0'..=b'9' => self.parse_number(),
+            _ => self.parse_literal(),
+        };
+
+        Some(JsonNode {
+            range: self.range_from(start),
+            value,
+        })
+    }
+
+    fn parse_object(&mut self) -> JsonValue {
+        // skip '{'
+        self.pos += 1;
+        let mut members = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => {
+                    self.error("Expected '}'");
+                    break;
+                }
+                Some(b'}') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b',') => {
+                    self.pos += 1;
+                }
+                Some(b'"') => members.push(self.parse_member()),
+                Some(_) => {
+                    self.error("Expected a property name");
+                    self.skip_invalid();
+                }
+            }
+        }
+
+        JsonValue::Object(members)
+    }
+
+    fn parse_member(&mut self) -> JsonMember {
+        let key_start = self.pos;
+        let key = self.parse_string();
+        let key_range = self.range_from(key_start);
+        self.skip_trivia();
+        if self.peek() != Some(b':') {
+            self.error("Expected ':'");
+            return JsonMember {
+                key,
+                key_range,
+                colon_end: None,
+                value: None,
+            };
+        }
+
+        self.pos += 1;
+        let colon_end = TextSize::new(self.pos as u32);
+        self.skip_trivia();
+        let value = match self.peek() {
+            Some(b',' | b'}') | None => {
+                self.error("Expected a value");
+                None
+            }
+            Some(_) => self.parse_value(),
+        };
+
+        JsonMember {
+            key,
+            key_range,
+            colon_end: Some(colon_end),
+            value,
+        }
+    }
+
+    fn parse_array(&mut self) -> JsonValue {
+        // skip '['
+        self.pos += 1;
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => {
+                    self.error("Expected ']'");
+                    break;
+                }
+                Some(b']') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b',') => {
+                    self.pos += 1;
+                }
+                Some(b'}') => {
+                    self.error("Expected ']'");
+                    break;
+                }
+                Some(_) => {
+                    if let Some(item) = self.parse_value() {
+                        items.push(item);
+                    }
+                }
+            }
+        }
+
+        JsonValue::Array(items)
+    }
+
+    /// Parses a string starting at the quote, an unterminated string ends at the end of line
+    fn parse_string(&mut self) -> String {
+        // skip '"'
+        self.pos += 1;
+        let mut value = String::new();
+        let mut chars = self.text[self.pos..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return value;
+                }
+                '\n' => {
+                    self.pos += i;
+                    self.error("Unterminated string");
+                    return value;
+                }
+                '\\' => match chars.next() {
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, 't')) => value.push('\t'),
+                    Some((_, 'r')) => value.push('\r'),
+                    Some((_, escaped)) => value.push(escaped),
+                    None => {}
+                },
+                _ => value.push(c),
+            }
+        }
+
+        self.pos = self.text.len();
+        self.error("Unterminated string");
+        value
+    }
+
+    fn parse_number(&mut self) -> JsonValue {
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+            .unwrap_or(rest.len());
+        let number = &rest[..len];
+        self.pos += len;
+        if number.parse::<f64>().is_err() {
+            self.error("Invalid number");
+            return JsonValue::Invalid;
+        }
+
+        JsonValue::Number {
+            is_integer: !number.contains(['.', 'e', 'E']),
+        }
+    }
+
+    fn parse_literal(&mut self) -> JsonValue {
+        let start = self.pos;
+        self.skip_invalid();
+        match &self.text[start..self.pos] {
+            "true" | "false" => JsonValue::Bool,
+            "null" => JsonValue::Null,
+            _ => {
+                self.pos = start;
+                self.error("Expected a value");
+                self.skip_invalid();
+                JsonValue::Invalid
+            }
+        }
+    }
+
+    /// Skips a word which can not start a json token
+    fn skip_invalid(&mut self) {
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, ',' | ':' | '{' | '}' | '[' | ']'))
+            .unwrap_or(rest.len());
+        if len > 0 {
+            self.pos += len;
+        } else if let Some(c) = rest.chars().next() {
+            // always make progress
+            self.pos += c.len_utf8();
+        }
+    }
+}
@@ -0,0 +1,619 @@
+mod json_tree;
+mod test;
+
+use std::{str::FromStr, sync::LazyLock};
+
+use emmylua_parser::LineIndex;
+use json_tree::{parse_json, JsonMember, JsonNode, JsonValue};
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Diagnostic, DiagnosticSeverity,
+    Documentation, Hover, HoverContents, MarkupContent, MarkupKind, NumberOrString, Position,
+    TextEdit,
+};
+use rowan::{TextRange, TextSize};
+use serde_json::Value;
+
+use crate::DiagnosticCode;
+
+/// The schema generated from `Emmyrc` by `schema_json_gen`
+static EMMYRC_SCHEMA: LazyLock<Value> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("../../../resources/schema.json")).unwrap_or_default()
+});
+
+/// The options whose keys are diagnostic codes
+const DIAGNOSTIC_CODE_MAPS: &[&str] = &["diagnostics.severity"];
+
+/// Validates a `.emmyrc.json` against the schema of `Emmyrc`
+pub fn check_emmyrc_config(text: &str) -> Vec<Diagnostic> {
+    let tree = parse_json(text);
+    let document = JsonDocument::new(text);
+    let mut diagnostics = Vec::new();
+    if let Some(error) = tree.errors.first() {
+        let offset = TextSize::new(error.offset as u32);
+        diagnostics.push(document.new_diagnostic(
+            TextRange::empty(offset),
+            t!("Invalid JSON: %{message}", message = error.message).to_string(),
+            DiagnosticSeverity::ERROR,
+        ));
+    }
+
+    if let Some(root) = &tree.root {
+        let mut checker = SchemaChecker {
+            document: &document,
+            diagnostics: &mut diagnostics,
+        };
+        checker.check_node(root, &EMMYRC_SCHEMA, &mut Vec::new());
+    }
+
+    diagnostics
+}
+
+/// Shows the description of the config key or the enum value under the cursor
+pub fn hover_emmyrc_config(text: &str, position: Position) -> Option<Hover> {
+    let document = JsonDocument::new(text);
+    let offset = document.get_offset(position)?;
+    let tree = parse_json(text);
+    let context = find_context(tree.root.as_ref()?, offset, &mut Vec::new())?;
+    let schema = get_schema_at_path(&context.path)?;
+    let key = context.path.join(".");
+    let mut contents = Vec::new();
+    match context.kind {
+        JsonContextKind::Key => {
+            contents.push(format!("```json\n\"{}\"\n```", key));
+            let object_key = context.path[..context.path.len() - 1].join(".");
+            if DIAGNOSTIC_CODE_MAPS.contains(&object_key.as_str()) {
+                let code = DiagnosticCode::from_str(context.path.last()?).ok()?;
+                contents.push(code.get_description());
+            } else if let Some(description) = get_description(schema) {
+                contents.push(description);
+            }
+            if let Some(default) = schema.get("default") {
+                contents.push(format!("Default: `{}`", default));
+            }
+        }
+        JsonContextKind::Value => {
+            let value = context.value?;
+            let (_, description) = get_enum_values(get_value_schema(schema))
+                .into_iter()
+                .find(|(name, _)| is_same_enum_value(name, &value))?;
+            contents.push(format!("```json\n\"{}\"\n```", value));
+            contents.push(description?);
+        }
+    }
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: contents.join("\n\n"),
+        }),
+        range: context.range.and_then(|range| document.to_lsp_range(range)),
+    })
+}
+
+/// Completes the config keys and the enum values, such as diagnostic codes
+pub fn complete_emmyrc_config(text: &str, position: Position) -> Vec<CompletionItem> {
+    let document = JsonDocument::new(text);
+    let Some(offset) = document.get_offset(position) else {
+        return Vec::new();
+    };
+    let tree = parse_json(text);
+    let Some(context) = tree
+        .root
+        .as_ref()
+        .and_then(|root| find_context(root, offset, &mut Vec::new()))
+    else {
+        return Vec::new();
+    };
+
+    let replace_range = context
+        .range
+        .and_then(|range| document.to_lsp_range(range))
+        .unwrap_or(lsp_types::Range {
+            start: position,
+            end: position,
+        });
+    let new_item =
+        |label: String, kind, description: Option<String>, new_text: String| CompletionItem {
+            label,
+            kind: Some(kind),
+            documentation: description.map(|description| {
+                Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: description,
+                })
+            }),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range: replace_range,
+                new_text,
+            })),
+            ..Default::default()
+        };
+
+    let mut items = Vec::new();
+    match context.kind {
+        JsonContextKind::Key => {
+            let object_path = &context.path[..context.path.len().saturating_sub(1)];
+            let Some(schema) = get_schema_at_path(object_path).map(get_value_schema) else {
+                return items;
+            };
+            let keys: Vec<(String, Option<String>)> =
+                if DIAGNOSTIC_CODE_MAPS.contains(&object_path.join(".").as_str()) {
+                    diagnostic_code_values()
+                } else {
+                    resolve(schema)
+                        .get("properties")
+                        .and_then(|properties| properties.as_object())
+                        .map(|properties| {
+                            properties
+                                .iter()
+                                .map(|(key, schema)| (key.clone(), get_description(schema)))
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                };
+
+            // the key which is being typed is the only one which is not skipped
+            let typing_key = context.path.last();
+            for (key, description) in keys {
+                if context.existing_keys.contains(&key) && Some(&key) != typing_key {
+                    continue;
+                }
+                let new_text = if context.range.is_some() {
+                    format!("\"{}\"", key)
+                } else {
+                    format!("\"{}\": ", key)
+                };
+                items.push(new_item(
+                    key,
+                    CompletionItemKind::PROPERTY,
+                    description,
+                    new_text,
+                ));
+            }
+        }
+        JsonContextKind::Value => {
+            let Some(schema) = get_schema_at_path(&context.path).map(get_value_schema) else {
+                return items;
+            };
+            for (value, description) in get_enum_values(schema) {
+                let new_text = format!("\"{}\"", value);
+                items.push(new_item(
+                    value,
+                    CompletionItemKind::ENUM_MEMBER,
+                    description,
+                    new_text,
+                ));
+            }
+            if has_type(resolve(schema), "boolean") {
+                for value in ["true", "false"] {
+                    items.push(new_item(
+                        value.to_string(),
+                        CompletionItemKind::VALUE,
+                        None,
+                        value.to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    items
+}
+
+struct JsonDocument<'a> {
+    text: &'a str,
+    line_index: LineIndex,
+}
+
+impl<'a> JsonDocument<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            line_index: LineIndex::parse(text),
+        }
+    }
+
+    fn get_offset(&self, position: Position) -> Option<TextSize> {
+        self.line_index.get_offset_by_utf16_col(
+            position.line as usize,
+            position.character as usize,
+            self.text,
+        )
+    }
+
+    fn to_lsp_position(&self, offset: TextSize) -> Option<Position> {
+        let (line, col) = self.line_index.get_line_utf16_col(offset, self.text)?;
+        Some(Position {
+            line: line as u32,
+            character: col as u32,
+        })
+    }
+
+    fn to_lsp_range(&self, range: TextRange) -> Option<lsp_types::Range> {
+        Some(lsp_types::Range {
+            start: self.to_lsp_position(range.start())?,
+            end: self.to_lsp_position(range.end())?,
+        })
+    }
+
+    fn new_diagnostic(
+        &self,
+        range: TextRange,
+        message: String,
+        severity: DiagnosticSeverity,
+    ) -> Diagnostic {
+        Diagnostic {
+            range: self.to_lsp_range(range).unwrap_or_default(),
+            severity: Some(severity),
+            code: Some(NumberOrString::String("emmyrc-schema".to_string())),
+            source: Some("EmmyLua".into()),
+            message,
+            ..Default::default()
+        }
+    }
+}
+
+struct SchemaChecker<'a, 'b> {
+    document: &'a JsonDocument<'a>,
+    diagnostics: &'b mut Vec<Diagnostic>,
+}
+
+impl SchemaChecker<'_, '_> {
+    fn check_node(&mut self, node: &JsonNode, schema: &Value, path: &mut Vec<String>) {
+        let schema = resolve(schema);
+        if let Some(any_of) = schema.get("anyOf").and_then(|it| it.as_array()) {
+            // the first alternative which accepts the value is used
+            let alternative = any_of
+                .iter()
+                .find(|alternative| is_type_accepted(&node.value, resolve(alternative)))
+                .or(any_of.first());
+            if let Some(alternative) = alternative {
+                self.check_node(node, alternative, path);
+            }
+            return;
+        }
+
+        if !is_type_accepted(&node.value, schema) {
+            self.report_invalid_value(node, path, None);
+            return;
+        }
+
+        match &node.value {
+            JsonValue::Object(members) => self.check_members(members, schema, path),
+            JsonValue::Array(items) => {
+                if let Some(items_schema) = schema.get("items") {
+                    for item in items {
+                        self.check_node(item, items_schema, path);
+                    }
+                }
+            }
+            JsonValue::String(value) => {
+                let enum_values = get_enum_values(schema);
+                if !enum_values.is_empty()
+                    && !enum_values
+                        .iter()
+                        .any(|(name, _)| is_same_enum_value(name, value))
+                {
+                    self.report_invalid_value(node, path, Some(value));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_members(&mut self, members: &[JsonMember], schema: &Value, path: &mut Vec<String>) {
+        let is_code_map = DIAGNOSTIC_CODE_MAPS.contains(&path.join(".").as_str());
+        for member in members {
+            path.push(member.key.clone());
+            if is_code_map && !is_diagnostic_code(&member.key) {
+                self.push_diagnostic(
+                    member.key_range,
+                    t!(
+                        "Invalid value '%{value}' for option '%{key}'",
+                        key = path[..path.len() - 1].join("."),
+                        value = member.key
+                    )
+                    .to_string(),
+                );
+            } else {
+                match get_property_schema(schema, &member.key) {
+                    Some(property_schema) => {
+                        if let Some(value) = &member.value {
+                            self.check_node(value, property_schema, path);
+                        }
+                    }
+                    None => {
+                        self.push_diagnostic(
+                            member.key_range,
+                            t!("Unknown option '%{key}'", key = path.join(".")).to_string(),
+                        );
+                    }
+                }
+            }
+            path.pop();
+        }
+    }
+
+    fn report_invalid_value(&mut self, node: &JsonNode, path: &[String], value: Option<&str>) {
+        let key = path.join(".");
+        let message = match value {
+            Some(value) => t!(
+                "Invalid value '%{value}' for option '%{key}'",
+                key = key,
+                value = value
+            ),
+            None => t!("Invalid value for option '%{key}'", key = key),
+        };
+        self.push_diagnostic(node.range, message.to_string());
+    }
+
+    fn push_diagnostic(&mut self, range: TextRange, message: String) {
+        self.diagnostics.push(self.document.new_diagnostic(
+            range,
+            message,
+            DiagnosticSeverity::WARNING,
+        ));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonContextKind {
+    Key,
+    Value,
+}
+
+/// What is under the cursor, `path` ends with the key of the value or the key being typed
+#[derive(Debug)]
+struct JsonContext {
+    kind: JsonContextKind,
+    path: Vec<String>,
+    /// The range of the string under the cursor, which is replaced by a completion
+    range: Option<TextRange>,
+    value: Option<String>,
+    existing_keys: Vec<String>,
+}
+
+fn find_context(node: &JsonNode, offset: TextSize, path: &mut Vec<String>) -> Option<JsonContext> {
+    if !contains_inclusive(node.range, offset) {
+        return None;
+    }
+
+    match &node.value {
+        JsonValue::Object(members) => {
+            // the cursor is on the braces
+            if offset == node.range.start() {
+                return None;
+            }
+            let existing_keys = members.iter().map(|member| member.key.clone()).collect();
+            for member in members {
+                if contains_inclusive(member.key_range, offset) && offset > member.key_range.start()
+                {
+                    path.push(member.key.clone());
+                    return Some(JsonContext {
+                        kind: JsonContextKind::Key,
+                        path: path.clone(),
+                        range: Some(member.key_range),
+                        value: None,
+                        existing_keys,
+                    });
+                }
+
+                let Some(colon_end) = member.colon_end else {
+                    continue;
+                };
+                match &member.value {
+                    Some(value) if contains_inclusive(value.range, offset) => {
+                        path.push(member.key.clone());
+                        return find_context(value, offset, path);
+                    }
+                    None if offset >= colon_end && !is_after_member(members, member, offset) => {
+                        path.push(member.key.clone());
+                        return Some(JsonContext {
+                            kind: JsonContextKind::Value,
+                            path: path.clone(),
+                            range: None,
+                            value: None,
+                            existing_keys: Vec::new(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
+            path.push(String::new());
+            Some(JsonContext {
+                kind: JsonContextKind::Key,
+                path: path.clone(),
+                range: None,
+                value: None,
+                existing_keys,
+            })
+        }
+        JsonValue::Array(items) => {
+            if offset == node.range.start() {
+                return None;
+            }
+            for item in items {
+                if contains_inclusive(item.range, offset) {
+                    return find_context(item, offset, path);
+                }
+            }
+
+            Some(JsonContext {
+                kind: JsonContextKind::Value,
+                path: path.clone(),
+                range: None,
+                value: None,
+                existing_keys: Vec::new(),
+            })
+        }
+        JsonValue::String(value) => {
+            if offset == node.range.start() {
+                return None;
+            }
+            Some(JsonContext {
+                kind: JsonContextKind::Value,
+                path: path.clone(),
+                range: Some(node.range),
+                value: Some(value.clone()),
+                existing_keys: Vec::new(),
+            })
+        }
+        _ => Some(JsonContext {
+            kind: JsonContextKind::Value,
+            path: path.clone(),
+            range: Some(node.range),
+            value: None,
+            existing_keys: Vec::new(),
+        }),
+    }
+}
+
+fn contains_inclusive(range: TextRange, offset: TextSize) -> bool {
+    range.start() <= offset && offset <= range.end()
+}
+
+fn is_after_member(members: &[JsonMember], member: &JsonMember, offset: TextSize) -> bool {
+    members
+        .iter()
+        .any(|it| it.key_range.start() > member.key_range.start() && it.key_range.start() < offset)
+}
+
+fn resolve(schema: &Value) -> &Value {
+    let mut schema = schema;
+    // a field with a doc comment refers to its type by a single `allOf`
+    while let Some(reference) = schema
+        .get("$ref")
+        .or_else(|| schema.pointer("/allOf/0/$ref"))
+        .and_then(|it| it.as_str())
+    {
+        let Some(target) = reference
+            .strip_prefix('#')
+            .and_then(|pointer| EMMYRC_SCHEMA.pointer(pointer))
+        else {
+            break;
+        };
+        schema = target;
+    }
+    schema
+}
+
+fn get_property_schema<'a>(schema: &'a Value, key: &str) -> Option<&'a Value> {
+    let schema = resolve(schema);
+    if let Some(property) = schema.pointer(&format!("/properties/{}", escape_pointer(key))) {
+        return Some(property);
+    }
+
+    match schema.get("additionalProperties") {
+        Some(Value::Bool(false)) | None => None,
+        Some(additional) => Some(additional),
+    }
+}
+
+fn get_schema_at_path(path: &[String]) -> Option<&'static Value> {
+    let mut schema: &Value = &EMMYRC_SCHEMA;
+    for key in path {
+        let resolved = resolve(schema);
+        schema = match resolved.get("items") {
+            // the keys of the path skip the arrays
+            Some(items) if resolved.get("properties").is_none() => {
+                get_property_schema(items, key).unwrap_or(items)
+            }
+            _ => get_property_schema(resolved, key)?,
+        };
+    }
+
+    Some(schema)
+}
+
+/// The schema of the values of an option, the items of an array are completed one by one
+fn get_value_schema(schema: &Value) -> &Value {
+    resolve(schema).get("items").unwrap_or(schema)
+}
+
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn get_description(schema: &Value) -> Option<String> {
+    schema
+        .get("description")
+        .or_else(|| resolve(schema).get("description"))
+        .and_then(|it| it.as_str())
+        .map(|it| it.to_string())
+}
+
+fn has_type(schema: &Value, expected: &str) -> bool {
+    match schema.get("type") {
+        Some(Value::String(typ)) => typ == expected,
+        Some(Value::Array(types)) => types.iter().any(|typ| typ.as_str() == Some(expected)),
+        _ => false,
+    }
+}
+
+fn is_type_accepted(value: &JsonValue, schema: &Value) -> bool {
+    if schema.get("type").is_none() {
+        // enums declare the type in each variant
+        return match schema.get("oneOf") {
+            Some(_) => matches!(value, JsonValue::String(_)),
+            None => true,
+        };
+    }
+
+    match value {
+        JsonValue::Object(_) => has_type(schema, "object"),
+        JsonValue::Array(_) => has_type(schema, "array"),
+        JsonValue::String(_) => has_type(schema, "string"),
+        JsonValue::Number { is_integer } => {
+            has_type(schema, "number") || (*is_integer && has_type(schema, "integer"))
+        }
+        JsonValue::Bool => has_type(schema, "boolean"),
+        JsonValue::Null => has_type(schema, "null"),
+        JsonValue::Invalid => true,
+    }
+}
+
+/// The values of an enum with their descriptions
+fn get_enum_values(schema: &Value) -> Vec<(String, Option<String>)> {
+    let schema = resolve(schema);
+    let variants: Vec<&Value> = match schema.get("oneOf").and_then(|it| it.as_array()) {
+        Some(variants) => variants.iter().collect(),
+        None => vec![schema],
+    };
+
+    let mut values = Vec::new();
+    for variant in variants {
+        let Some(names) = variant.get("enum").and_then(|it| it.as_array()) else {
+            continue;
+        };
+        for name in names.iter().filter_map(|it| it.as_str()) {
+            // the fallback of unknown diagnostic codes
+            if name == "none" {
+                continue;
+            }
+            let description = variant
+                .get("description")
+                .and_then(|it| it.as_str())
+                .map(|it| it.to_string());
+            values.push((name.to_string(), description));
+        }
+    }
+    values
+}
+
+/// The enums accept aliases with spaces, such as `Lua 5.4`
+fn is_same_enum_value(name: &str, value: &str) -> bool {
+    name == value || name.replace(' ', "") == value.replace(' ', "")
+}
+
+fn is_diagnostic_code(name: &str) -> bool {
+    DiagnosticCode::from_str(name).is_ok_and(|code| code != DiagnosticCode::None)
+}
+
+fn diagnostic_code_values() -> Vec<(String, Option<String>)> {
+    DiagnosticCode::all()
+        .into_iter()
+        .filter(|code| *code != DiagnosticCode::None)
+        .map(|code| (code.get_name().to_string(), Some(code.get_description())))
+        .collect()
+}
@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::{CompletionTextEdit, DiagnosticSeverity, HoverContents, Position};
+
+    use crate::{check_emmyrc_config, complete_emmyrc_config, hover_emmyrc_config};
+
+    fn diagnostic_messages(text: &str) -> Vec<(u32, String)> {
+        check_emmyrc_config(text)
+            .into_iter()
+            .map(|diagnostic| (diagnostic.range.start.line, diagnostic.message))
+            .collect()
+    }
+
+    fn completion_labels(text: &str, position: Position) -> Vec<String> {
+        complete_emmyrc_config(text, position)
+            .into_iter()
+            .map(|item| item.label)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config() {
+        let text = r#"{
+    "$schema": "https://github.com/CppCXY/emmylua-analyzer-rust/blob/main/crates/emmylua_code_analysis/resources/schema.json",
+    "runtime": {
+        "version": "Lua 5.4",
+        "requireLikeFunction": ["import"]
+    },
+    "diagnostics": {
+        "disable": ["undefined-global"],
+        "severity": {
+            "unused": "warning"
+        },
+        "diagnosticInterval": null
+    },
+    "workspace": {
+        "moduleMap": [
+            { "pattern": "^lib(.*)$", "replace": "script$1" }
+        ]
+    }
+}"#;
+        assert_eq!(diagnostic_messages(text), vec![]);
+    }
+
+    #[test]
+    fn test_unknown_key() {
+        let text = r#"{
+    "diagnostic": {
+        "disable": []
+    },
+    "runtime": {
+        "versions": "Lua5.4"
+    }
+}"#;
+        assert_eq!(
+            diagnostic_messages(text),
+            vec![
+                (1, "Unknown option 'diagnostic'".to_string()),
+                (5, "Unknown option 'runtime.versions'".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        let text = r#"{
+    "diagnostics": {
+        "enable": "yes",
+        "disable": ["undefined-globals"],
+        "severity": {
+            "unused": "fatal",
+            "not-a-code": "hint"
+        }
+    }
+}"#;
+        assert_eq!(
+            diagnostic_messages(text),
+            vec![
+                (
+                    2,
+                    "Invalid value for option 'diagnostics.enable'".to_string()
+                ),
+                (
+                    3,
+                    "Invalid value 'undefined-globals' for option 'diagnostics.disable'"
+                        .to_string()
+                ),
+                (
+                    5,
+                    "Invalid value 'fatal' for option 'diagnostics.severity.unused'".to_string()
+                ),
+                (
+                    6,
+                    "Invalid value 'not-a-code' for option 'diagnostics.severity'".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        let diagnostics = check_emmyrc_config("{\n    \"runtime\": {\n}");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+    }
+
+    #[test]
+    fn test_complete_keys() {
+        let text = r#"{
+    "diagnostics": {
+        "enable": true,
+        "dis"
+    }
+}"#;
+        let items = complete_emmyrc_config(text, Position::new(3, 12));
+        let labels: Vec<&str> = items.iter().map(|item| item.label.as_str()).collect();
+        assert!(labels.contains(&"disable"));
+        assert!(labels.contains(&"globals"));
+        // the existing keys are skipped
+        assert!(!labels.contains(&"enable"));
+
+        let edit = items
+            .iter()
+            .find(|item| item.label == "disable")
+            .and_then(|item| match &item.text_edit {
+                Some(CompletionTextEdit::Edit(edit)) => Some(edit.clone()),
+                _ => None,
+            });
+        assert_eq!(
+            edit.as_ref().map(|edit| edit.new_text.as_str()),
+            Some("\"disable\"")
+        );
+        assert_eq!(edit.map(|edit| edit.range.start), Some(Position::new(3, 8)));
+
+        let labels = completion_labels("{\n    \n}", Position::new(1, 4));
+        assert!(labels.contains(&"diagnostics".to_string()));
+        assert!(labels.contains(&"runtime".to_string()));
+    }
+
+    #[test]
+    fn test_complete_values() {
+        let labels = completion_labels(
+            r#"{ "diagnostics": { "disable": ["unu"] } }"#,
+            Position::new(0, 34),
+        );
+        assert!(labels.contains(&"unused".to_string()));
+        assert!(labels.contains(&"undefined-global".to_string()));
+        assert!(!labels.contains(&"none".to_string()));
+
+        let labels = completion_labels(
+            r#"{ "diagnostics": { "severity": { "unused":  } } }"#,
+            Position::new(0, 43),
+        );
+        assert!(labels.contains(&"warning".to_string()));
+
+        let labels = completion_labels(
+            r#"{ "diagnostics": { "severity": { "" } } }"#,
+            Position::new(0, 34),
+        );
+        assert!(labels.contains(&"unused".to_string()));
+
+        let labels = completion_labels(r#"{ "completion": { "enable":  } }"#, Position::new(0, 28));
+        assert_eq!(labels, vec!["true", "false"]);
+    }
+
+    #[test]
+    fn test_hover() {
+        let text = r#"{ "diagnostics": { "disable": ["unused"] } }"#;
+        let hover_value = |position| {
+            hover_emmyrc_config(text, position).and_then(|hover| match hover.contents {
+                HoverContents::Markup(markup) => Some(markup.value),
+                _ => None,
+            })
+        };
+
+        let key_hover = hover_value(Position::new(0, 22)).unwrap_or_default();
+        assert!(key_hover.contains("diagnostics.disable"));
+        assert!(key_hover.contains("A list of diagnostic codes that are disabled."));
+
+        let value_hover = hover_value(Position::new(0, 34)).unwrap_or_default();
+        assert!(value_hover.contains("Unused"));
+    }
+
+    #[test]
+    fn test_utf16_positions() {
+        // the emoji takes two UTF-16 code units
+        let diagnostics = check_emmyrc_config(r#"{ "$schema": "😀", "runtimes": {} }"#);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start, Position::new(0, 19));
+        assert_eq!(diagnostics[0].range.end, Position::new(0, 29));
+
+        let text = r#"{ "$schema": "😀", "runtimes": {}, "completion": { "enable":  } }"#;
+        let labels = completion_labels(text, Position::new(0, 61));
+        assert_eq!(labels, vec!["true", "false"]);
+    }
+}
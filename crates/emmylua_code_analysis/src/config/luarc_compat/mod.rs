@@ -0,0 +1,565 @@
+mod test;
+
+use std::str::FromStr;
+
+use emmylua_parser::LineIndex;
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+use rowan::{TextRange, TextSize};
+use serde_json::Value;
+
+use crate::DiagnosticCode;
+
+use super::{configs::EmmyrcLuaVersion, Emmyrc};
+
+/// luals options whose value is an object keyed by user defined names, they are translated as a
+/// whole instead of being flattened
+const MAP_KEYS: &[&str] = &[
+    "runtime.special",
+    "runtime.builtin",
+    "diagnostics.severity",
+    "diagnostics.neededFileStatus",
+    "diagnostics.groupSeverity",
+    "diagnostics.groupFileStatus",
+    "format.defaultConfig",
+    "nameStyle.config",
+];
+
+/// luals options which have no equivalent in emmylua
+const UNSUPPORTED_KEYS: &[&str] = &[
+    "runtime.builtin",
+    "runtime.meta",
+    "runtime.nonstandardSymbol",
+    "runtime.plugin",
+    "runtime.pluginArgs",
+    "runtime.unicodeName",
+    "runtime.exportEnvDefault",
+    "workspace.checkThirdParty",
+    "workspace.userThirdParty",
+    "workspace.ignoreSubmodules",
+    "workspace.useGitIgnore",
+    "workspace.maxPreload",
+    "workspace.supportScheme",
+    "diagnostics.workspaceDelay",
+    "diagnostics.workspaceEvent",
+    "diagnostics.workspaceRate",
+    "diagnostics.libraryFiles",
+    "diagnostics.ignoredFiles",
+    "diagnostics.groupSeverity",
+    "diagnostics.groupFileStatus",
+    "diagnostics.unusedLocalExclude",
+    "diagnostics.disableScheme",
+    "nameStyle.config",
+    "type.weakUnionCheck",
+    "type.weakNilCheck",
+    "type.inferParamType",
+    "type.checkTableShape",
+    "type.inferTableSize",
+    "completion.displayContext",
+    "completion.keywordSnippet",
+    "completion.showParams",
+    "completion.showWord",
+    "completion.workspaceWord",
+    "hint.await",
+    "hint.paramType",
+    "hint.semicolon",
+    "hover.previewFields",
+    "hover.viewString",
+    "hover.viewStringMax",
+    "hover.viewNumber",
+    "hover.fieldInfer",
+    "hover.enumsLimit",
+    "hover.expandAlias",
+    "semantic.annotation",
+    "semantic.keyword",
+    "semantic.variable",
+    "signatureHelp.enable",
+];
+
+/// luals diagnostics which have no emmylua diagnostic with the same meaning, emmylua either
+/// reports them under a wider code or does not report them at all
+const UNSUPPORTED_DIAGNOSTICS: &[&str] = &[
+    "unused-local",
+    "unused-function",
+    "unused-vararg",
+    "unused-label",
+    "cast-local-type",
+    "cast-type-mismatch",
+    "undefined-doc-class",
+    "duplicate-doc-alias",
+    "duplicate-doc-param",
+    "lowercase-global",
+    "global-in-nil-env",
+    "undefined-env-child",
+    "newline-call",
+    "newfield-call",
+    "ambiguity-1",
+    "count-down-loop",
+    "code-after-break",
+    "empty-block",
+    "redundant-value",
+    "trailing-space",
+    "no-unknown",
+    "not-yieldable",
+    "close-non-object",
+    "different-requires",
+    "global-element",
+    "missing-local-export-doc",
+    "spell-check",
+];
+
+/// luals option groups which have no equivalent in emmylua
+const UNSUPPORTED_GROUPS: &[&str] = &[
+    "format",
+    "misc",
+    "telemetry",
+    "window",
+    "spell",
+    "doc",
+    "language",
+    "addonManager",
+    "typeFormat",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuarcIssueKind {
+    /// A luals option which has no equivalent in emmylua
+    Unsupported,
+    /// Neither a luals option nor an emmylua option
+    Unknown,
+    /// An option whose value can not be translated
+    InvalidValue,
+    /// A luals diagnostic in a diagnostic option which has no equivalent in emmylua
+    UnsupportedDiagnostic,
+}
+
+#[derive(Debug, Clone)]
+pub struct LuarcIssue {
+    pub kind: LuarcIssueKind,
+    /// The option name without the `Lua.` prefix
+    pub key: String,
+    /// The keys of the option as written in the file, used to locate the option
+    pub raw_path: Vec<String>,
+    /// The offending value inside the option, when only a part of the value is invalid
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct LuarcEntry {
+    key: String,
+    raw_path: Vec<String>,
+    value: Value,
+}
+
+/// Translates a `.luarc.json` written for lua-language-server into the `.emmyrc.json` shape
+#[derive(Debug, Clone)]
+pub struct LuarcConfig {
+    emmyrc: Value,
+    issues: Vec<LuarcIssue>,
+}
+
+impl LuarcConfig {
+    pub fn parse(luarc_json: Value) -> Self {
+        let mut entries = Vec::new();
+        collect_entries(&mut Vec::new(), &luarc_json, &mut entries);
+
+        let emmyrc_keys = serde_json::to_value(Emmyrc::default()).unwrap_or_default();
+        let mut config = Self {
+            emmyrc: Value::Object(Default::default()),
+            issues: Vec::new(),
+        };
+        for entry in entries {
+            config.translate_entry(entry, &emmyrc_keys);
+        }
+        config
+    }
+
+    pub fn to_emmyrc(&self) -> Value {
+        self.emmyrc.clone()
+    }
+
+    pub fn get_issues(&self) -> &[LuarcIssue] {
+        &self.issues
+    }
+
+    fn translate_entry(&mut self, entry: LuarcEntry, emmyrc_keys: &Value) {
+        let value = entry.value.clone();
+        match entry.key.as_str() {
+            "$schema" => {}
+            "runtime.version" => {
+                if serde_json::from_value::<EmmyrcLuaVersion>(value.clone()).is_ok() {
+                    self.set("runtime.version", value);
+                } else {
+                    self.add_issue(LuarcIssueKind::InvalidValue, &entry, None);
+                }
+            }
+            "runtime.path" => self.set("runtime.requirePattern", value),
+            "runtime.pathStrict" => self.set("strict.requirePath", value),
+            "runtime.special" => self.translate_special(&entry),
+            "runtime.fileEncoding" => match value.as_str() {
+                Some("utf8") => self.set("workspace.encoding", "utf-8".into()),
+                Some("utf16le") => self.set("workspace.encoding", "utf-16le".into()),
+                Some("utf16be") => self.set("workspace.encoding", "utf-16be".into()),
+                _ => self.add_issue(LuarcIssueKind::InvalidValue, &entry, None),
+            },
+            "workspace.library" => self.set("workspace.library", value),
+            "workspace.ignoreDir" => self.set("workspace.ignoreDir", value),
+            "workspace.preloadFileSize" => self.set("workspace.preloadFileSize", value),
+            "diagnostics.enable" => self.set("diagnostics.enable", value),
+            "diagnostics.globals" => self.set("diagnostics.globals", value),
+            "diagnostics.globalsRegex" => self.set("diagnostics.globalsRegex", value),
+            "diagnostics.disable" => self.translate_code_list(&entry, "diagnostics.disable"),
+            "diagnostics.enables" => self.translate_code_list(&entry, "diagnostics.enables"),
+            "diagnostics.severity" => self.translate_severity(&entry),
+            "diagnostics.neededFileStatus" => self.translate_file_status(&entry),
+            "type.castNumberToInteger" => self.set("strict.castNumberToInteger", value),
+            "completion.callSnippet" => match value {
+                Value::String(snippet) => {
+                    self.set("completion.callSnippet", (snippet != "Disable").into())
+                }
+                _ => self.set("completion.callSnippet", value),
+            },
+            "completion.requireSeparator" => self.set("completion.autoRequireSeparator", value),
+            "hint.paramName" => match value.as_str() {
+                Some(param_name) => self.set("hint.paramHint", (param_name != "Disable").into()),
+                None => self.add_issue(LuarcIssueKind::InvalidValue, &entry, None),
+            },
+            "hint.setType" => self.set("hint.localHint", value),
+            "hint.arrayIndex" => match value.as_str() {
+                Some(array_index) => self.set("hint.indexHint", (array_index != "Disable").into()),
+                None => self.add_issue(LuarcIssueKind::InvalidValue, &entry, None),
+            },
+            "semantic.enable" => self.set("semanticTokens.enable", value),
+            key => {
+                if is_unsupported_key(key) {
+                    self.add_issue(LuarcIssueKind::Unsupported, &entry, None);
+                } else if is_emmyrc_key(emmyrc_keys, key) {
+                    // completion.enable, hover.enable and the native emmyrc options
+                    self.set(key, value);
+                } else {
+                    self.add_issue(LuarcIssueKind::Unknown, &entry, None);
+                }
+            }
+        }
+    }
+
+    fn translate_special(&mut self, entry: &LuarcEntry) {
+        let Some(special) = entry.value.as_object() else {
+            self.add_issue(LuarcIssueKind::InvalidValue, entry, None);
+            return;
+        };
+
+        for (name, kind) in special {
+            if kind.as_str() == Some("require") {
+                self.push("runtime.requireLikeFunction", name.as_str().into());
+            } else {
+                self.add_issue(LuarcIssueKind::Unsupported, entry, Some(name.clone()));
+            }
+        }
+    }
+
+    fn translate_code_list(&mut self, entry: &LuarcEntry, emmyrc_key: &str) {
+        let Some(names) = entry.value.as_array() else {
+            self.add_issue(LuarcIssueKind::InvalidValue, entry, None);
+            return;
+        };
+
+        // keeps an empty list, so that it still overrides the list of a global config
+        self.set(emmyrc_key, Value::Array(Vec::new()));
+        for name in names {
+            let Some(name) = name.as_str() else {
+                self.add_issue(LuarcIssueKind::InvalidValue, entry, None);
+                continue;
+            };
+            match to_diagnostic_code(name) {
+                Some(code) => self.push(emmyrc_key, code.get_name().into()),
+                None => self.add_code_issue(entry, name),
+            }
+        }
+    }
+
+    fn translate_severity(&mut self, entry: &LuarcEntry) {
+        let Some(severities) = entry.value.as_object() else {
+            self.add_issue(LuarcIssueKind::InvalidValue, entry, None);
+            return;
+        };
+
+        for (name, severity) in severities {
+            let code = to_diagnostic_code(name);
+            // luals marks a severity with `!` or `+` to override the group severity
+            let severity = severity
+                .as_str()
+                .map(|it| it.trim_end_matches(['!', '+']).to_lowercase());
+            match (code, severity.as_deref()) {
+                (Some(code), Some(severity @ ("error" | "warning" | "information" | "hint"))) => {
+                    self.set_object_field("diagnostics.severity", code.get_name(), severity.into());
+                }
+                (None, _) => self.add_code_issue(entry, name),
+                _ => self.add_issue(LuarcIssueKind::InvalidValue, entry, Some(name.clone())),
+            }
+        }
+    }
+
+    fn translate_file_status(&mut self, entry: &LuarcEntry) {
+        let Some(statuses) = entry.value.as_object() else {
+            self.add_issue(LuarcIssueKind::InvalidValue, entry, None);
+            return;
+        };
+
+        for (name, status) in statuses {
+            let code = to_diagnostic_code(name);
+            let status = status.as_str().map(|it| it.trim_end_matches(['!', '+']));
+            // emmylua does not distinguish opened files, `Opened` is treated as `Any`
+            match (code, status) {
+                (Some(code), Some("None")) => {
+                    self.push("diagnostics.disable", code.get_name().into())
+                }
+                (Some(code), Some("Any" | "Opened")) => {
+                    self.push("diagnostics.enables", code.get_name().into())
+                }
+                (None, _) => self.add_code_issue(entry, name),
+                _ => self.add_issue(LuarcIssueKind::InvalidValue, entry, Some(name.clone())),
+            }
+        }
+    }
+
+    fn add_issue(&mut self, kind: LuarcIssueKind, entry: &LuarcEntry, value: Option<String>) {
+        self.issues.push(LuarcIssue {
+            kind,
+            key: entry.key.clone(),
+            raw_path: entry.raw_path.clone(),
+            value,
+        });
+    }
+
+    // a diagnostic name which is not mapped to an emmylua code
+    fn add_code_issue(&mut self, entry: &LuarcEntry, name: &str) {
+        let kind = if UNSUPPORTED_DIAGNOSTICS.contains(&name) {
+            LuarcIssueKind::UnsupportedDiagnostic
+        } else {
+            LuarcIssueKind::InvalidValue
+        };
+        self.add_issue(kind, entry, Some(name.to_string()));
+    }
+
+    fn set(&mut self, key: &str, value: Value) {
+        if let Some(slot) = get_or_insert_slot(&mut self.emmyrc, key) {
+            *slot = value;
+        }
+    }
+
+    fn push(&mut self, key: &str, value: Value) {
+        if let Some(slot) = get_or_insert_slot(&mut self.emmyrc, key) {
+            if !slot.is_array() {
+                *slot = Value::Array(Vec::new());
+            }
+            if let Some(array) = slot.as_array_mut() {
+                if !array.contains(&value) {
+                    array.push(value);
+                }
+            }
+        }
+    }
+
+    fn set_object_field(&mut self, key: &str, field: &str, value: Value) {
+        if let Some(slot) = get_or_insert_slot(&mut self.emmyrc, key) {
+            if !slot.is_object() {
+                *slot = Value::Object(Default::default());
+            }
+            if let Some(object) = slot.as_object_mut() {
+                object.insert(field.to_string(), value);
+            }
+        }
+    }
+}
+
+fn collect_entries(raw_path: &mut Vec<String>, val: &Value, entries: &mut Vec<LuarcEntry>) {
+    let key = raw_path.join(".");
+    let key = key.strip_prefix("Lua.").unwrap_or(&key).to_string();
+    match val {
+        Value::Object(map) if !MAP_KEYS.contains(&key.as_str()) => {
+            for (k, v) in map.iter() {
+                raw_path.push(k.clone());
+                collect_entries(raw_path, v, entries);
+                raw_path.pop();
+            }
+        }
+        _ => {
+            if !raw_path.is_empty() {
+                entries.push(LuarcEntry {
+                    key,
+                    raw_path: raw_path.clone(),
+                    value: val.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn get_or_insert_slot<'a>(value: &'a mut Value, key: &str) -> Option<&'a mut Value> {
+    let mut current = value;
+    for key in key.split('.') {
+        if !current.is_object() {
+            *current = Value::Object(Default::default());
+        }
+        current = current
+            .as_object_mut()?
+            .entry(key.to_string())
+            .or_insert(Value::Null);
+    }
+    Some(current)
+}
+
+fn is_unsupported_key(key: &str) -> bool {
+    if UNSUPPORTED_KEYS.contains(&key) {
+        return true;
+    }
+
+    let group = key.split('.').next().unwrap_or_default();
+    UNSUPPORTED_GROUPS.contains(&group)
+}
+
+fn is_emmyrc_key(emmyrc_keys: &Value, key: &str) -> bool {
+    let mut current = emmyrc_keys;
+    for key in key.split('.') {
+        match current.get(key) {
+            Some(value) => current = value,
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Maps a luals diagnostic name to the emmylua diagnostic code with the same meaning, a code
+/// which would also cover other luals diagnostics is not a match
+fn to_diagnostic_code(name: &str) -> Option<DiagnosticCode> {
+    let code = match name {
+        "param-type-mismatch" => DiagnosticCode::ParamTypeNotMatch,
+        "undefined-doc-name" => DiagnosticCode::TypeNotFound,
+        "invisible" => DiagnosticCode::AccessInvisible,
+        "codestyle-check" => DiagnosticCode::CodeStyleCheck,
+        "name-style-check" => DiagnosticCode::NameStyleCheck,
+        _ => DiagnosticCode::from_str(name).unwrap_or(DiagnosticCode::None),
+    };
+
+    match code {
+        DiagnosticCode::None => None,
+        _ => Some(code),
+    }
+}
+
+/// Reports the options of a `.luarc.json` which are not honoured by emmylua
+pub fn check_luarc_config(text: &str) -> Vec<Diagnostic> {
+    let luarc_json: Value = match serde_json::from_str(text) {
+        Ok(json) => json,
+        Err(_) => return Vec::new(),
+    };
+
+    let line_index = LineIndex::parse(text);
+    let to_position = |offset: TextSize| {
+        let (line, col) = line_index.get_line_utf16_col(offset, text)?;
+        Some(lsp_types::Position {
+            line: line as u32,
+            character: col as u32,
+        })
+    };
+
+    let config = LuarcConfig::parse(luarc_json);
+    let mut diagnostics = Vec::new();
+    for issue in config.get_issues() {
+        let range = find_issue_range(text, issue).unwrap_or_default();
+        let (Some(start), Some(end)) = (to_position(range.start()), to_position(range.end()))
+        else {
+            continue;
+        };
+
+        let (message, severity) = match (issue.kind, &issue.value) {
+            (LuarcIssueKind::Unsupported, Some(value)) => (
+                t!(
+                    "Option '%{key}.%{value}' is not supported by EmmyLua and is ignored",
+                    key = issue.key,
+                    value = value
+                ),
+                DiagnosticSeverity::INFORMATION,
+            ),
+            (LuarcIssueKind::Unsupported, None) => (
+                t!(
+                    "Option '%{key}' is not supported by EmmyLua and is ignored",
+                    key = issue.key
+                ),
+                DiagnosticSeverity::INFORMATION,
+            ),
+            (LuarcIssueKind::Unknown, _) => (
+                t!("Unknown option '%{key}'", key = issue.key),
+                DiagnosticSeverity::WARNING,
+            ),
+            (LuarcIssueKind::InvalidValue, Some(value)) => (
+                t!(
+                    "Invalid value '%{value}' for option '%{key}'",
+                    key = issue.key,
+                    value = value
+                ),
+                DiagnosticSeverity::WARNING,
+            ),
+            (LuarcIssueKind::InvalidValue, None) => (
+                t!("Invalid value for option '%{key}'", key = issue.key),
+                DiagnosticSeverity::WARNING,
+            ),
+            (LuarcIssueKind::UnsupportedDiagnostic, value) => (
+                t!(
+                    "Diagnostic '%{name}' has no equivalent in EmmyLua and is ignored",
+                    name = value.as_deref().unwrap_or_default()
+                ),
+                DiagnosticSeverity::WARNING,
+            ),
+        };
+
+        diagnostics.push(Diagnostic {
+            range: lsp_types::Range { start, end },
+            severity: Some(severity),
+            code: Some(NumberOrString::String("luarc-compat".to_string())),
+            source: Some("EmmyLua".into()),
+            message: message.to_string(),
+            ..Default::default()
+        });
+    }
+
+    diagnostics
+}
+
+/// Finds the option key in the json text, or the offending value inside the option
+fn find_issue_range(text: &str, issue: &LuarcIssue) -> Option<TextRange> {
+    let mut offset = 0;
+    let mut range = None;
+    for key in &issue.raw_path {
+        let (start, end) = find_json_string(text, key, offset, true)?;
+        range = Some((start, end));
+        offset = end;
+    }
+
+    if let Some(value) = &issue.value {
+        if let Some((start, end)) = find_json_string(text, value, offset, false) {
+            range = Some((start, end));
+        }
+    }
+
+    let (start, end) = range?;
+    Some(TextRange::new(
+        TextSize::new(start as u32),
+        TextSize::new(end as u32),
+    ))
+}
+
+fn find_json_string(text: &str, value: &str, from: usize, is_key: bool) -> Option<(usize, usize)> {
+    let quoted = serde_json::to_string(value).ok()?;
+    let mut offset = from;
+    while let Some(pos) = text.get(offset..)?.find(&quoted) {
+        let start = offset + pos;
+        let end = start + quoted.len();
+        let next_char = text[end..].trim_start().chars().next();
+        if !is_key || next_char == Some(':') {
+            return Some((start, end));
+        }
+        offset = end;
+    }
+    None
+}
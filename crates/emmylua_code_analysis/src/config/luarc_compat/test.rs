@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::DiagnosticSeverity;
+
+    use crate::{
+        check_luarc_config, config::luarc_compat::LuarcConfig, DiagnosticCode, Emmyrc,
+        EmmyrcLuaVersion, LuarcIssueKind,
+    };
+
+    fn to_emmyrc(luarc_json: serde_json::Value) -> Emmyrc {
+        serde_json::from_value(LuarcConfig::parse(luarc_json).to_emmyrc()).unwrap_or_default()
+    }
+
+    #[test]
+    fn test_translate_luals_options() {
+        let emmyrc = to_emmyrc(serde_json::json!({
+            "$schema": "https://raw.githubusercontent.com/LuaLS/vscode-lua/master/setting/schema.json",
+            "runtime.version": "Lua 5.3",
+            "runtime.path": ["?.lua", "?/init.lua"],
+            "runtime.pathStrict": true,
+            "runtime.special": {
+                "import": "require"
+            },
+            "Lua.diagnostics.globals": ["vim"],
+            "diagnostics": {
+                "disable": ["unused-local", "undefined-doc-name"],
+                "severity": {
+                    "undefined-global": "Warning!"
+                },
+                "neededFileStatus": {
+                    "deprecated": "None",
+                    "missing-global-doc": "Any"
+                }
+            },
+            "workspace.library": ["./lib"],
+            "type.castNumberToInteger": true,
+            "completion.callSnippet": "Replace",
+            "hint.paramName": "Disable"
+        }));
+
+        assert_eq!(emmyrc.runtime.version, EmmyrcLuaVersion::Lua53);
+        assert_eq!(emmyrc.runtime.require_pattern, vec!["?.lua", "?/init.lua"]);
+        assert_eq!(emmyrc.runtime.require_like_function, vec!["import"]);
+        assert!(emmyrc.strict.require_path);
+        assert!(emmyrc.strict.cast_number_to_integer);
+        assert_eq!(emmyrc.diagnostics.globals, vec!["vim"]);
+        // `unused` also covers the unused parameters and the other luals diagnostics
+        assert_eq!(emmyrc.diagnostics.disable.len(), 2);
+        assert!(emmyrc
+            .diagnostics
+            .disable
+            .contains(&DiagnosticCode::TypeNotFound));
+        assert!(emmyrc
+            .diagnostics
+            .disable
+            .contains(&DiagnosticCode::Deprecated));
+        assert!(emmyrc
+            .diagnostics
+            .enables
+            .contains(&DiagnosticCode::MissingGlobalDoc));
+        assert!(emmyrc
+            .diagnostics
+            .severity
+            .contains_key(&DiagnosticCode::UndefinedGlobal));
+        assert_eq!(emmyrc.workspace.library, vec!["./lib"]);
+        assert!(emmyrc.completion.call_snippet);
+        assert!(!emmyrc.hint.param_hint);
+    }
+
+    #[test]
+    fn test_keep_emmyrc_options() {
+        let emmyrc = to_emmyrc(serde_json::json!({
+            "runtime": {
+                "requireLikeFunction": ["import"]
+            },
+            "completion": {
+                "callSnippet": true,
+                "autoRequire": false
+            }
+        }));
+
+        assert_eq!(emmyrc.runtime.require_like_function, vec!["import"]);
+        assert!(emmyrc.completion.call_snippet);
+        assert!(!emmyrc.completion.auto_require);
+    }
+
+    #[test]
+    fn test_issues() {
+        let config = LuarcConfig::parse(serde_json::json!({
+            "Lua": {
+                "workspace.checkThirdParty": false,
+                "diagnostic.globals": ["vim"],
+                "diagnostics.disable": ["not-a-diagnostic"],
+                "diagnostics.severity": { "cast-local-type": "Error" },
+                "runtime.version": "Lua 4.0"
+            }
+        }));
+        let mut issues: Vec<(LuarcIssueKind, String)> = config
+            .get_issues()
+            .iter()
+            .map(|issue| (issue.kind, issue.key.clone()))
+            .collect();
+        issues.sort_by(|a, b| a.1.cmp(&b.1));
+
+        assert_eq!(
+            issues,
+            vec![
+                (LuarcIssueKind::Unknown, "diagnostic.globals".to_string()),
+                (
+                    LuarcIssueKind::InvalidValue,
+                    "diagnostics.disable".to_string()
+                ),
+                (
+                    LuarcIssueKind::UnsupportedDiagnostic,
+                    "diagnostics.severity".to_string()
+                ),
+                (LuarcIssueKind::InvalidValue, "runtime.version".to_string()),
+                (
+                    LuarcIssueKind::Unsupported,
+                    "workspace.checkThirdParty".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_luarc_config() {
+        let text = r#"{
+    "workspace.checkThirdParty": false,
+    "diagnostics": {
+        "disable": ["unused-local", "not-a-diagnostic"]
+    }
+}"#;
+        let mut diagnostics = check_luarc_config(text);
+        diagnostics.sort_by_key(|diagnostic| diagnostic.range.start.line);
+        assert_eq!(diagnostics.len(), 3);
+
+        assert_eq!(diagnostics[0].range.start.line, 1);
+        assert_eq!(diagnostics[0].range.start.character, 4);
+        assert_eq!(
+            diagnostics[0].severity,
+            Some(DiagnosticSeverity::INFORMATION)
+        );
+
+        // the diagnostic names are marked instead of the key
+        diagnostics[1..].sort_by_key(|diagnostic| diagnostic.range.start.character);
+        assert_eq!(diagnostics[1].range.start.line, 3);
+        assert_eq!(diagnostics[1].range.start.character, 20);
+        assert_eq!(diagnostics[1].severity, Some(DiagnosticSeverity::WARNING));
+        assert!(diagnostics[1].message.contains("unused-local"));
+        assert_eq!(diagnostics[2].range.start.line, 3);
+        assert_eq!(diagnostics[2].range.start.character, 36);
+        assert_eq!(diagnostics[2].severity, Some(DiagnosticSeverity::WARNING));
+    }
+}
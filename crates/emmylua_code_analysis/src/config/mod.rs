@@ -1,6 +1,8 @@
 mod config_loader;
 mod configs;
+mod emmyrc_schema;
 mod flatten_config;
+mod luarc_compat;
 
 use std::{
     collections::{HashMap, HashSet},
@@ -16,7 +18,10 @@ use configs::{
     EmmyrcReference, EmmyrcResource, EmmyrcRuntime, EmmyrcSemanticToken, EmmyrcSignature,
     EmmyrcStrict, EmmyrcWorkspace,
 };
+pub use configs::{EmmyrcNameStyle, EmmyrcNameStyleKind, EmmyrcNameStyleRule};
 use emmylua_parser::{LuaLanguageLevel, ParserConfig, SpecialFunction};
+pub use emmyrc_schema::{check_emmyrc_config, complete_emmyrc_config, hover_emmyrc_config};
+pub use luarc_compat::{check_luarc_config, LuarcConfig, LuarcIssue, LuarcIssueKind};
 use regex::Regex;
 use rowan::NodeCache;
 use schemars::JsonSchema;
@@ -54,29 +59,33 @@ pub struct Emmyrc {
     pub hover: EmmyrcHover,
     #[serde(default)]
     pub document_color: EmmyrcDocumentColor,
+    #[serde(default)]
+    pub name_style: EmmyrcNameStyle,
 }
 
 impl Emmyrc {
-    pub fn get_parse_config<'cache>(
-        &self,
-        node_cache: &'cache mut NodeCache,
-    ) -> ParserConfig<'cache> {
-        let level = &self.runtime.version;
-
-        let lua_language_level = match level {
+    pub fn get_language_level(&self) -> LuaLanguageLevel {
+        match self.runtime.version {
             EmmyrcLuaVersion::Lua51 => LuaLanguageLevel::Lua51,
             EmmyrcLuaVersion::Lua52 => LuaLanguageLevel::Lua52,
             EmmyrcLuaVersion::Lua53 => LuaLanguageLevel::Lua53,
             EmmyrcLuaVersion::Lua54 => LuaLanguageLevel::Lua54,
+            EmmyrcLuaVersion::Lua55 => LuaLanguageLevel::Lua55,
             EmmyrcLuaVersion::LuaJIT => LuaLanguageLevel::LuaJIT,
+            EmmyrcLuaVersion::Luau => LuaLanguageLevel::Luau,
             EmmyrcLuaVersion::LuaLatest => LuaLanguageLevel::Lua54,
-        };
+        }
+    }
 
+    pub fn get_parse_config<'cache>(
+        &self,
+        node_cache: &'cache mut NodeCache,
+    ) -> ParserConfig<'cache> {
         let mut special_like = HashMap::new();
         for name in self.runtime.require_like_function.iter() {
             special_like.insert(name.clone(), SpecialFunction::Require);
         }
-        ParserConfig::new(lua_language_level, Some(node_cache), special_like)
+        ParserConfig::new(self.get_language_level(), Some(node_cache), special_like)
     }
 
     pub fn pre_process_emmyrc(&mut self, workspace_root: &Path) {
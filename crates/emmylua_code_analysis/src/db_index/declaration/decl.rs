@@ -2,11 +2,12 @@ use crate::FileId;
 use crate::{LuaMemberId, LuaSignatureId};
 use emmylua_parser::{LuaKind, LuaSyntaxId, LuaSyntaxKind};
 use rowan::{TextRange, TextSize};
+use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 
 use super::decl_id::LuaDeclId;
 
-#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+#[derive(Eq, PartialEq, Hash, Debug, Clone, Serialize, Deserialize)]
 pub struct LuaDecl {
     name: SmolStr,
     file_id: FileId,
@@ -15,7 +16,7 @@ pub struct LuaDecl {
     pub extra: LuaDeclExtra,
 }
 
-#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+#[derive(Eq, PartialEq, Hash, Debug, Clone, Serialize, Deserialize)]
 pub enum LuaDeclExtra {
     Local {
         kind: LuaKind,
@@ -98,7 +99,7 @@ impl LuaDecl {
     }
 }
 
-#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+#[derive(Eq, PartialEq, Hash, Debug, Clone, Serialize, Deserialize)]
 pub enum LocalAttribute {
     Const,
     Close,
@@ -26,7 +26,7 @@ impl Serialize for LuaDeclId {
         S: Serializer,
     {
         let value = format!("{}|{}", self.file_id.id, u32::from(self.position));
-        serializer.serialize_str(&value)
+        serializer.serialize_newtype_struct("LuaDeclId", &value)
     }
 }
 
@@ -44,6 +44,14 @@ impl<'de> Deserialize<'de> for LuaDeclId {
                 formatter.write_str("a string with format 'file_id:position'")
             }
 
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = String::deserialize(deserializer)?;
+                self.visit_str(&value)
+            }
+
             fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
             where
                 E: de::Error,
@@ -68,6 +76,6 @@ impl<'de> Deserialize<'de> for LuaDeclId {
             }
         }
 
-        deserializer.deserialize_str(LuaDeclIdVisitor)
+        deserializer.deserialize_newtype_struct("LuaDeclId", LuaDeclIdVisitor)
     }
 }
@@ -11,8 +11,9 @@ use emmylua_parser::{
 };
 use rowan::{TextRange, TextSize};
 use scope::{LuaScope, LuaScopeId, LuaScopeKind, ScopeOrDeclId};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaDeclarationTree {
     file_id: FileId,
     decls: HashMap<LuaDeclId, LuaDecl>,
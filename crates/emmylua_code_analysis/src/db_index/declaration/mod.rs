@@ -12,7 +12,7 @@ use std::collections::HashMap;
 
 use crate::FileId;
 
-use super::traits::LuaIndex;
+use super::traits::{LuaIndex, LuaIndexFileCache};
 
 #[derive(Debug)]
 pub struct LuaDeclIndex {
@@ -54,3 +54,17 @@ impl LuaIndex for LuaDeclIndex {
         self.decl_trees.clear();
     }
 }
+
+impl LuaIndexFileCache for LuaDeclIndex {
+    type FileCache = Option<LuaDeclarationTree>;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        self.decl_trees.get(&file_id).cloned()
+    }
+
+    fn add_file_cache(&mut self, _: FileId, cache: Self::FileCache) {
+        if let Some(tree) = cache {
+            self.add_decl_tree(tree);
+        }
+    }
+}
@@ -6,7 +6,7 @@ use file_dependency_relation::FileDependencyRelation;
 
 use crate::FileId;
 
-use super::LuaIndex;
+use super::{traits::LuaIndexFileCache, LuaIndex};
 
 #[derive(Debug)]
 pub struct LuaDependencyIndex {
@@ -31,11 +31,37 @@ impl LuaDependencyIndex {
         self.dependencies.get(file_id)
     }
 
+    /// Get the files which require the file directly
+    pub fn get_dependent_files(&self, file_id: &FileId) -> Vec<FileId> {
+        self.dependencies
+            .iter()
+            .filter(|(_, required_files)| required_files.contains(file_id))
+            .map(|(dependent_id, _)| *dependent_id)
+            .collect()
+    }
+
     pub fn get_file_dependencies<'a>(&'a self) -> FileDependencyRelation<'a> {
         FileDependencyRelation::new(&self.dependencies)
     }
 }
 
+impl LuaIndexFileCache for LuaDependencyIndex {
+    type FileCache = Vec<FileId>;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        self.dependencies
+            .get(&file_id)
+            .map(|required_files| required_files.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    fn add_file_cache(&mut self, file_id: FileId, cache: Self::FileCache) {
+        for dependency_id in cache {
+            self.add_required_file(file_id, dependency_id);
+        }
+    }
+}
+
 impl LuaIndex for LuaDependencyIndex {
     fn remove(&mut self, file_id: FileId) {
         self.dependencies.remove(&file_id);
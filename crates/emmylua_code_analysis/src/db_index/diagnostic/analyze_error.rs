@@ -1,8 +1,9 @@
 use rowan::TextRange;
+use serde::{Deserialize, Serialize};
 
 use crate::DiagnosticCode;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalyzeError {
     pub kind: DiagnosticCode,
     pub message: String,
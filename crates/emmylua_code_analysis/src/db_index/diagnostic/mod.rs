@@ -6,10 +6,11 @@ use std::collections::{HashMap, HashSet};
 pub use analyze_error::AnalyzeError;
 pub use diagnostic_action::{DiagnosticAction, DiagnosticActionKind};
 use rowan::TextRange;
+use serde::{Deserialize, Serialize};
 
 use crate::{DiagnosticCode, FileId};
 
-use super::traits::LuaIndex;
+use super::traits::{LuaIndex, LuaIndexFileCache};
 
 #[derive(Debug)]
 pub struct DiagnosticIndex {
@@ -98,6 +99,57 @@ impl DiagnosticIndex {
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct DiagnosticFileCache {
+    actions: Vec<DiagnosticAction>,
+    diagnostics: Vec<AnalyzeError>,
+    disabled: Vec<DiagnosticCode>,
+    enabled: Vec<DiagnosticCode>,
+}
+
+impl LuaIndexFileCache for DiagnosticIndex {
+    type FileCache = DiagnosticFileCache;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        DiagnosticFileCache {
+            actions: self
+                .diagnostic_actions
+                .get(&file_id)
+                .cloned()
+                .unwrap_or_default(),
+            diagnostics: self.diagnostics.get(&file_id).cloned().unwrap_or_default(),
+            disabled: self
+                .file_diagnostic_disabled
+                .get(&file_id)
+                .map(|codes| codes.iter().copied().collect())
+                .unwrap_or_default(),
+            enabled: self
+                .file_diagnostic_enabled
+                .get(&file_id)
+                .map(|codes| codes.iter().copied().collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn add_file_cache(&mut self, file_id: FileId, cache: Self::FileCache) {
+        for action in cache.actions {
+            self.add_diagnostic_action(file_id, action);
+        }
+
+        for diagnostic in cache.diagnostics {
+            self.add_diagnostic(file_id, diagnostic);
+        }
+
+        for code in cache.disabled {
+            self.add_file_diagnostic_disabled(file_id, code);
+        }
+
+        for code in cache.enabled {
+            self.add_file_diagnostic_enabled(file_id, code);
+        }
+    }
+}
+
 impl LuaIndex for DiagnosticIndex {
     fn remove(&mut self, file_id: FileId) {
         self.diagnostic_actions.remove(&file_id);
@@ -1,15 +1,16 @@
 use emmylua_parser::{LuaAstNode, LuaChunk, LuaClosureExpr, LuaSyntaxKind, LuaSyntaxNode};
 use rowan::{TextRange, TextSize};
+use serde::{Deserialize, Serialize};
 
 use super::{type_assert::TypeAssertion, LuaVarRefId};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaFlowChain {
     var_ref_id: LuaVarRefId,
     type_asserts: Vec<LuaFlowChainInfo>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaFlowChainInfo {
     pub range: TextRange,
     pub type_assert: TypeAssertion,
@@ -42,7 +43,7 @@ impl LuaFlowChain {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct LuaFlowId(TextRange);
 
 impl LuaFlowId {
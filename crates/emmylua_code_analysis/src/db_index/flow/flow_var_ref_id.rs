@@ -1,10 +1,11 @@
 use emmylua_parser::{LuaAstNode, LuaDocTagCast, LuaVarExpr};
 use rowan::{TextRange, TextSize};
+use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 
 use crate::LuaDeclId;
 
-#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+#[derive(Debug, Eq, PartialEq, Clone, Hash, Serialize, Deserialize)]
 pub enum LuaVarRefId {
     DeclId(LuaDeclId),
     Name(SmolStr),
@@ -9,8 +9,12 @@ pub use flow_var_ref_id::{LuaVarRefId, LuaVarRefNode};
 pub use type_assert::TypeAssertion;
 
 use crate::FileId;
+use serde::{Deserialize, Serialize};
 
-use super::{traits::LuaIndex, LuaSignatureId};
+use super::{
+    traits::{LuaIndex, LuaIndexFileCache},
+    LuaSignatureId,
+};
 
 #[derive(Debug)]
 pub struct LuaFlowIndex {
@@ -69,6 +73,52 @@ impl LuaFlowIndex {
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct LuaFlowFileCache {
+    chains: Vec<LuaFlowChain>,
+    call_casts: Vec<(LuaSignatureId, Vec<(String, TypeAssertion)>)>,
+}
+
+impl LuaIndexFileCache for LuaFlowIndex {
+    type FileCache = LuaFlowFileCache;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        let chains = self
+            .chains_map
+            .get(&file_id)
+            .into_iter()
+            .flat_map(|map| map.values().cloned())
+            .collect();
+        let call_casts = self
+            .call_cast
+            .get(&file_id)
+            .into_iter()
+            .flatten()
+            .map(|(signature_id, casts)| {
+                let casts = casts
+                    .iter()
+                    .map(|(name, assertion)| (name.clone(), assertion.clone()))
+                    .collect();
+                (*signature_id, casts)
+            })
+            .collect();
+
+        LuaFlowFileCache { chains, call_casts }
+    }
+
+    fn add_file_cache(&mut self, file_id: FileId, cache: Self::FileCache) {
+        for chain in cache.chains {
+            self.add_flow_chain(file_id, chain);
+        }
+
+        for (signature_id, casts) in cache.call_casts {
+            for (name, assertion) in casts {
+                self.add_call_cast(signature_id, &name, assertion);
+            }
+        }
+    }
+}
+
 impl LuaIndex for LuaFlowIndex {
     fn remove(&mut self, file_id: crate::FileId) {
         self.chains_map.remove(&file_id);
@@ -1,9 +1,13 @@
 use std::sync::Arc;
 
-use crate::{infer_expr, DbIndex, InferFailReason, LuaInferCache, LuaType, TypeOps};
-use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaSyntaxId, LuaSyntaxNode};
-
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+use crate::{
+    infer_compound_assign_value, infer_expr, DbIndex, InferFailReason, LuaInferCache, LuaType,
+    TypeOps,
+};
+use emmylua_parser::{LuaAssignStat, LuaAstNode, LuaCallExpr, LuaExpr, LuaSyntaxId, LuaSyntaxNode};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TypeAssertion {
     Exist,
     NotExist,
@@ -65,6 +69,14 @@ impl TypeAssertion {
             TypeAssertion::Reassign { id, idx } => {
                 let expr = LuaExpr::cast(id.to_node_from_root(root).ok_or(InferFailReason::None)?)
                     .ok_or(InferFailReason::None)?;
+                // `a += 1` assigns `a + 1` where `a` still has the source type
+                if let Some(assign_stat) = expr.get_parent::<LuaAssignStat>() {
+                    if let Some(value_type) =
+                        infer_compound_assign_value(db, cache, &assign_stat, source.clone())
+                    {
+                        return Ok(TypeOps::Narrow.apply(db, &source, &value_type?));
+                    }
+                }
                 let expr_type = infer_expr(db, cache, expr)?;
                 let expr_type = match &expr_type {
                     LuaType::Variadic(multi) => {
@@ -1,7 +1,8 @@
 use internment::ArcIntern;
+use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct GlobalId(pub ArcIntern<SmolStr>);
 
 impl GlobalId {
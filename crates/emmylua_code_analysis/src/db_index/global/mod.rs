@@ -6,7 +6,7 @@ pub use global_id::GlobalId;
 
 use crate::FileId;
 
-use super::{DbIndex, LuaDeclId, LuaIndex};
+use super::{traits::LuaIndexFileCache, DbIndex, LuaDeclId, LuaIndex};
 
 #[derive(Debug)]
 pub struct LuaGlobalIndex {
@@ -79,6 +79,30 @@ impl LuaGlobalIndex {
     }
 }
 
+impl LuaIndexFileCache for LuaGlobalIndex {
+    type FileCache = Vec<(GlobalId, Vec<LuaDeclId>)>;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        self.global_decl
+            .iter()
+            .filter_map(|(id, decl_ids)| {
+                let decl_ids: Vec<_> = decl_ids
+                    .iter()
+                    .filter(|decl_id| decl_id.file_id == file_id)
+                    .copied()
+                    .collect();
+                (!decl_ids.is_empty()).then(|| (id.clone(), decl_ids))
+            })
+            .collect()
+    }
+
+    fn add_file_cache(&mut self, _: FileId, cache: Self::FileCache) {
+        for (id, decl_ids) in cache {
+            self.global_decl.entry(id).or_default().extend(decl_ids);
+        }
+    }
+}
+
 impl LuaIndex for LuaGlobalIndex {
     fn remove(&mut self, file_id: FileId) {
         self.global_decl.retain(|_, v| {
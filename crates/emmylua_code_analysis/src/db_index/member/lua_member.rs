@@ -8,7 +8,7 @@ use smol_str::SmolStr;
 use super::lua_member_feature::LuaMemberFeature;
 use crate::{infer_expr, DbIndex, FileId, GlobalId, InferFailReason, LuaInferCache, LuaType};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaMember {
     member_id: LuaMemberId,
     key: LuaMemberKey,
@@ -90,7 +90,7 @@ impl LuaMemberId {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LuaMemberKey {
     None,
     Integer(i64),
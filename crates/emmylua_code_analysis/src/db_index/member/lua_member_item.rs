@@ -1,4 +1,4 @@
-use crate::{DbIndex, InferFailReason, LuaSemanticDeclId, LuaType, TypeOps};
+use crate::{DbIndex, FileId, InferFailReason, LuaSemanticDeclId, LuaType, TypeOps};
 
 use super::LuaMemberId;
 
@@ -13,8 +13,14 @@ impl LuaMemberIndexItem {
         resolve_member_type(db, &self)
     }
 
-    pub fn resolve_semantic_decl(&self, db: &DbIndex) -> Option<LuaSemanticDeclId> {
-        resolve_member_semantic_id(db, &self)
+    /// The runtime version of the config which applies to the file that looks the member up
+    /// picks among the declarations which are limited to some versions
+    pub fn resolve_semantic_decl(
+        &self,
+        db: &DbIndex,
+        file_id: FileId,
+    ) -> Option<LuaSemanticDeclId> {
+        resolve_member_semantic_id(db, self, file_id)
     }
 
     #[allow(unused)]
@@ -195,6 +201,7 @@ enum MemberTypeResolveState {
 fn resolve_member_semantic_id(
     db: &DbIndex,
     member_item: &LuaMemberIndexItem,
+    file_id: FileId,
 ) -> Option<LuaSemanticDeclId> {
     match member_item {
         LuaMemberIndexItem::One(member_id) => Some(LuaSemanticDeclId::Member(*member_id)),
@@ -223,7 +230,7 @@ fn resolve_member_semantic_id(
                         if feature.is_meta_decl() {
                             let semantic_id = LuaSemanticDeclId::Member(member.get_id());
                             last_valid_member = semantic_id.clone();
-                            if check_member_version(db, semantic_id.clone()) {
+                            if check_member_version(db, file_id, semantic_id.clone()) {
                                 return Some(semantic_id);
                             }
                         }
@@ -263,13 +270,17 @@ enum MemberSemanticDeclResolveState {
     FileDecl,
 }
 
-fn check_member_version(db: &DbIndex, semantic_id: LuaSemanticDeclId) -> bool {
+fn check_member_version(db: &DbIndex, file_id: FileId, semantic_id: LuaSemanticDeclId) -> bool {
     let Some(property) = db.get_property_index().get_property(&semantic_id) else {
         return true;
     };
 
     if let Some(version) = &property.version_conds {
-        let version_number = db.get_emmyrc().runtime.version.to_lua_version_number();
+        let version_number = db
+            .get_file_emmyrc(file_id)
+            .runtime
+            .version
+            .to_lua_version_number();
         return version.iter().any(|cond| cond.check(&version_number));
     }
 
@@ -1,10 +1,11 @@
 use internment::ArcIntern;
 use rowan::TextRange;
+use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 
 use crate::{GlobalId, InFiled, LuaTypeDeclId};
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum LuaMemberOwner {
     LocalUnresolve,
     Type(LuaTypeDeclId),
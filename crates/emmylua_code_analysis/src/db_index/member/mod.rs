@@ -5,7 +5,7 @@ mod lua_member_owner;
 
 use std::collections::{HashMap, HashSet};
 
-use super::traits::LuaIndex;
+use super::traits::{LuaIndex, LuaIndexFileCache};
 use crate::FileId;
 pub use lua_member::{LuaMember, LuaMemberId, LuaMemberKey};
 pub use lua_member_feature::LuaMemberFeature;
@@ -184,7 +184,6 @@ impl LuaMemberIndex {
         Some(members)
     }
 
-    #[allow(unused)]
     pub fn get_member_item_by_member_id(
         &self,
         member_id: LuaMemberId,
@@ -217,6 +216,43 @@ impl LuaMemberIndex {
     pub fn get_current_owner(&self, id: &LuaMemberId) -> Option<&LuaMemberOwner> {
         self.member_current_owner.get(id)
     }
+
+    pub fn get_file_members(&self, file_id: &FileId) -> Vec<&LuaMember> {
+        let Some(member_or_owners) = self.in_filed.get(file_id) else {
+            return Vec::new();
+        };
+
+        member_or_owners
+            .iter()
+            .filter_map(|member_or_owner| match member_or_owner {
+                MemberOrOwner::Member(member_id) => self.get_member(member_id),
+                MemberOrOwner::Owner(_) => None,
+            })
+            .collect()
+    }
+}
+
+impl LuaIndexFileCache for LuaMemberIndex {
+    type FileCache = Vec<(LuaMemberOwner, LuaMember)>;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        self.get_file_members(&file_id)
+            .into_iter()
+            .map(|member| {
+                let owner = self
+                    .get_current_owner(&member.get_id())
+                    .cloned()
+                    .unwrap_or(LuaMemberOwner::LocalUnresolve);
+                (owner, member.clone())
+            })
+            .collect()
+    }
+
+    fn add_file_cache(&mut self, _: FileId, cache: Self::FileCache) {
+        for (owner, member) in cache {
+            self.add_member(owner, member);
+        }
+    }
 }
 
 impl LuaIndex for LuaMemberIndex {
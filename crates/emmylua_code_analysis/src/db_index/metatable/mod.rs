@@ -4,7 +4,7 @@ use rowan::TextRange;
 
 use crate::{FileId, InFiled};
 
-use super::LuaIndex;
+use super::{traits::LuaIndexFileCache, LuaIndex};
 
 #[derive(Debug)]
 pub struct LuaMetatableIndex {
@@ -27,6 +27,24 @@ impl LuaMetatableIndex {
     }
 }
 
+impl LuaIndexFileCache for LuaMetatableIndex {
+    type FileCache = Vec<(InFiled<TextRange>, InFiled<TextRange>)>;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        self.metatables
+            .iter()
+            .filter(|(table, _)| table.file_id == file_id)
+            .map(|(table, metatable)| (table.clone(), metatable.clone()))
+            .collect()
+    }
+
+    fn add_file_cache(&mut self, _: FileId, cache: Self::FileCache) {
+        for (table, metatable) in cache {
+            self.add(table, metatable);
+        }
+    }
+}
+
 impl LuaIndex for LuaMetatableIndex {
     fn remove(&mut self, file_id: FileId) {
         self.metatables.retain(|key, _| key.file_id != file_id);
@@ -16,6 +16,8 @@ mod r#type;
 
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
+
 use crate::{Emmyrc, FileId, Vfs};
 pub use declaration::*;
 use dependency::LuaDependencyIndex;
@@ -33,6 +35,7 @@ pub use reference::*;
 pub use semantic_decl::*;
 pub use signature::*;
 pub use traits::LuaIndex;
+use traits::LuaIndexFileCache;
 
 #[derive(Debug)]
 pub struct DbIndex {
@@ -202,6 +205,83 @@ impl DbIndex {
     pub fn get_emmyrc(&self) -> &Emmyrc {
         &self.emmyrc
     }
+
+    /// The config which the file is analyzed with, the config of its workspace root when the root
+    /// has its own config
+    pub fn get_file_emmyrc(&self, file_id: FileId) -> Arc<Emmyrc> {
+        self.vfs
+            .get_file_emmyrc(&file_id)
+            .unwrap_or_else(|| self.emmyrc.clone())
+    }
+}
+
+/// Everything the analysis of one file added to the indexes
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct LuaFileIndexCache {
+    decl: <LuaDeclIndex as LuaIndexFileCache>::FileCache,
+    reference: <LuaReferenceIndex as LuaIndexFileCache>::FileCache,
+    types: <LuaTypeIndex as LuaIndexFileCache>::FileCache,
+    module: <LuaModuleIndex as LuaIndexFileCache>::FileCache,
+    member: <LuaMemberIndex as LuaIndexFileCache>::FileCache,
+    property: <LuaPropertyIndex as LuaIndexFileCache>::FileCache,
+    signature: <LuaSignatureIndex as LuaIndexFileCache>::FileCache,
+    diagnostic: <DiagnosticIndex as LuaIndexFileCache>::FileCache,
+    operator: <LuaOperatorIndex as LuaIndexFileCache>::FileCache,
+    flow: <LuaFlowIndex as LuaIndexFileCache>::FileCache,
+    file_dependencies: <LuaDependencyIndex as LuaIndexFileCache>::FileCache,
+    metatable: <LuaMetatableIndex as LuaIndexFileCache>::FileCache,
+    global: <LuaGlobalIndex as LuaIndexFileCache>::FileCache,
+}
+
+impl DbIndex {
+    pub(crate) fn get_file_index_cache(&self, file_id: FileId) -> LuaFileIndexCache {
+        LuaFileIndexCache {
+            decl: self.decl_index.get_file_cache(file_id),
+            reference: self.references_index.get_file_cache(file_id),
+            types: self.types_index.get_file_cache(file_id),
+            module: self.modules_index.get_file_cache(file_id),
+            member: self.members_index.get_file_cache(file_id),
+            property: self.property_index.get_file_cache(file_id),
+            signature: self.signature_index.get_file_cache(file_id),
+            diagnostic: self.diagnostic_index.get_file_cache(file_id),
+            operator: self.operator_index.get_file_cache(file_id),
+            flow: self.flow_index.get_file_cache(file_id),
+            file_dependencies: self.file_dependencies_index.get_file_cache(file_id),
+            metatable: self.metatable_index.get_file_cache(file_id),
+            global: self.global_index.get_file_cache(file_id),
+        }
+    }
+
+    /// Add the cached analysis of a file instead of analyzing it, the file must be in the vfs
+    pub(crate) fn add_file_index_cache(&mut self, file_id: FileId, cache: LuaFileIndexCache) {
+        self.remove(file_id);
+        if let Some(path) = self
+            .vfs
+            .get_file_path(&file_id)
+            .and_then(|path| path.to_str())
+        {
+            self.modules_index.add_module_by_path(file_id, path);
+        }
+
+        self.decl_index.add_file_cache(file_id, cache.decl);
+        self.references_index
+            .add_file_cache(file_id, cache.reference);
+        self.types_index.add_file_cache(file_id, cache.types);
+        self.modules_index.add_file_cache(file_id, cache.module);
+        self.members_index.add_file_cache(file_id, cache.member);
+        self.property_index.add_file_cache(file_id, cache.property);
+        self.signature_index
+            .add_file_cache(file_id, cache.signature);
+        self.diagnostic_index
+            .add_file_cache(file_id, cache.diagnostic);
+        self.operator_index.add_file_cache(file_id, cache.operator);
+        self.flow_index.add_file_cache(file_id, cache.flow);
+        self.file_dependencies_index
+            .add_file_cache(file_id, cache.file_dependencies);
+        self.metatable_index
+            .add_file_cache(file_id, cache.metatable);
+        self.global_index.add_file_cache(file_id, cache.global);
+    }
 }
 
 impl LuaIndex for DbIndex {
@@ -1,16 +1,20 @@
 mod module_info;
 mod module_node;
+mod module_pattern;
 mod test;
 mod workspace;
 
 use emmylua_parser::LuaVersionCondition;
-use log::{error, info};
 pub use module_info::ModuleInfo;
 use module_node::{ModuleNode, ModuleNodeId};
-use regex::Regex;
+use module_pattern::ModulePatterns;
+use serde::{Deserialize, Serialize};
 pub use workspace::{Workspace, WorkspaceId};
 
-use super::traits::LuaIndex;
+use super::{
+    traits::{LuaIndex, LuaIndexFileCache},
+    LuaSemanticDeclId, LuaType,
+};
 use crate::{Emmyrc, FileId};
 use std::{
     collections::HashMap,
@@ -20,7 +24,9 @@ use std::{
 
 #[derive(Debug)]
 pub struct LuaModuleIndex {
-    module_patterns: Vec<Regex>,
+    patterns: ModulePatterns,
+    /// The patterns of the workspace roots which have their own config
+    workspace_patterns: Vec<(PathBuf, ModulePatterns)>,
     module_root_id: ModuleNodeId,
     module_nodes: HashMap<ModuleNodeId, ModuleNode>,
     file_module_map: HashMap<FileId, ModuleInfo>,
@@ -28,13 +34,13 @@ pub struct LuaModuleIndex {
     workspaces: Vec<Workspace>,
     id_counter: u32,
     fuzzy_search: bool,
-    module_replace_vec: Vec<(Regex, String)>,
 }
 
 impl LuaModuleIndex {
     pub fn new() -> Self {
         let mut index = Self {
-            module_patterns: Vec::new(),
+            patterns: ModulePatterns::default(),
+            workspace_patterns: Vec::new(),
             module_root_id: ModuleNodeId { id: 0 },
             module_nodes: HashMap::new(),
             file_module_map: HashMap::new(),
@@ -42,7 +48,6 @@ impl LuaModuleIndex {
             workspaces: Vec::new(),
             id_counter: 1,
             fuzzy_search: false,
-            module_replace_vec: Vec::new(),
         };
 
         let root_node = ModuleNode::default();
@@ -53,45 +58,30 @@ impl LuaModuleIndex {
 
     // patterns like "?.lua" and "?/init.lua"
     pub fn set_module_extract_patterns(&mut self, patterns: Vec<String>) {
-        let mut patterns = patterns;
-        patterns.sort_by(|a, b| b.len().cmp(&a.len()));
-        patterns.dedup();
-        self.module_patterns.clear();
-        for item in patterns {
-            let regex_str = format!(
-                "^{}$",
-                regex::escape(&item.replace('\\', "/")).replace("\\?", "(.*)")
-            );
-            match Regex::new(&regex_str) {
-                Ok(re) => self.module_patterns.push(re),
-                Err(e) => {
-                    error!("Invalid module pattern: {}, error: {}", item, e);
-                    return;
-                }
-            };
-        }
-
-        info!("update module pattern: {:?}", self.module_patterns);
+        self.patterns.set_extract_patterns(patterns);
     }
 
-    pub fn set_module_replace_patterns(&mut self, patterns: HashMap<String, String>) {
-        self.module_replace_vec.clear();
-        for (key, value) in patterns {
-            let key_pattern = match Regex::new(&key) {
-                Ok(re) => re,
-                Err(e) => {
-                    error!("Invalid module replace pattern: {}, error: {}", key, e);
-                    return;
-                }
-            };
-
-            self.module_replace_vec.push((key_pattern, value));
+    /// Give the files in the root the module patterns of the config instead of the main config
+    pub fn set_workspace_config(&mut self, root: PathBuf, config: &Emmyrc) {
+        let patterns = ModulePatterns::from_emmyrc(config);
+        match self.workspace_patterns.iter_mut().find(|(r, _)| *r == root) {
+            Some((_, old_patterns)) => *old_patterns = patterns,
+            None => self.workspace_patterns.push((root, patterns)),
         }
+    }
+
+    pub fn remove_workspace_config(&mut self, root: &Path) {
+        self.workspace_patterns.retain(|(r, _)| r != root);
+    }
 
-        info!(
-            "update module replace pattern: {:?}",
-            self.module_replace_vec
-        );
+    // the patterns of the deepest root with its own config which contains the file
+    fn get_patterns(&self, path: &Path) -> &ModulePatterns {
+        self.workspace_patterns
+            .iter()
+            .filter(|(root, _)| path.starts_with(root))
+            .max_by_key(|(root, _)| root.components().count())
+            .map(|(_, patterns)| patterns)
+            .unwrap_or(&self.patterns)
     }
 
     pub fn add_module_by_path(&mut self, file_id: FileId, path: &str) -> Option<WorkspaceId> {
@@ -99,16 +89,23 @@ impl LuaModuleIndex {
             self.remove(file_id);
         }
 
-        let (module_path, workspace_id) = self.extract_module_path(&path)?;
-        let mut module_path = module_path.replace(['\\', '/'], ".");
-        if !self.module_replace_vec.is_empty() {
-            module_path = self.replace_module_path(&module_path);
-        }
-
+        let (module_path, workspace_id) = self.get_module_path_by_file_path(path)?;
         self.add_module_by_module_path(file_id, module_path, workspace_id);
         Some(workspace_id)
     }
 
+    /// Get the module path which the file at the path would have, the file does not need to be
+    /// added yet
+    pub fn get_module_path_by_file_path(&self, path: &str) -> Option<(String, WorkspaceId)> {
+        let (module_path, workspace_id) = self.extract_module_path(path)?;
+        let module_path = module_path.replace(['\\', '/'], ".");
+        let module_path = self
+            .get_patterns(Path::new(path))
+            .replace_module_path(&module_path);
+
+        Some((module_path, workspace_id))
+    }
+
     pub fn add_module_by_module_path(
         &mut self,
         file_id: FileId,
@@ -307,11 +304,12 @@ impl LuaModuleIndex {
 
     fn extract_module_path(&self, path: &str) -> Option<(String, WorkspaceId)> {
         let path = Path::new(path);
+        let patterns = self.get_patterns(path);
         let mut matched_module_path: Option<(String, WorkspaceId)> = None;
         for workspace in &self.workspaces {
             if let Ok(relative_path) = path.strip_prefix(&workspace.root) {
                 let relative_path_str = relative_path.to_str().unwrap_or("");
-                let module_path = self.match_pattern(relative_path_str);
+                let module_path = patterns.match_pattern(relative_path_str);
                 if let Some(module_path) = module_path {
                     if matched_module_path.is_none() {
                         matched_module_path = Some((module_path, workspace.id));
@@ -331,79 +329,18 @@ impl LuaModuleIndex {
         matched_module_path
     }
 
-    fn replace_module_path(&self, module_path: &str) -> String {
-        for (key, value) in &self.module_replace_vec {
-            return key.replace_all(&module_path, value).to_string();
-        }
-
-        module_path.to_string()
-    }
-
-    fn match_pattern(&self, path: &str) -> Option<String> {
-        for pattern in &self.module_patterns {
-            if let Some(captures) = pattern.captures(path) {
-                if let Some(matched) = captures.get(1) {
-                    return Some(matched.as_str().to_string());
-                }
-            }
-        }
-
-        None
-    }
-
     pub fn add_workspace_root(&mut self, root: PathBuf, workspace_id: WorkspaceId) {
         if !self.workspaces.iter().any(|w| w.root == root) {
             self.workspaces.push(Workspace::new(root, workspace_id));
         }
     }
 
-    #[allow(unused)]
     pub fn remove_workspace_root(&mut self, root: &Path) {
         self.workspaces.retain(|r| r.root != root);
     }
 
     pub fn update_config(&mut self, config: Arc<Emmyrc>) {
-        let mut extension_names = Vec::new();
-
-        for extension in &config.runtime.extensions {
-            if extension.starts_with(".") {
-                extension_names.push(extension[1..].to_string());
-            } else if extension.starts_with("*.") {
-                extension_names.push(extension[2..].to_string());
-            } else {
-                extension_names.push(extension.clone());
-            }
-        }
-
-        if !extension_names.contains(&"lua".to_string()) {
-            extension_names.push("lua".to_string());
-        }
-
-        let mut patterns = Vec::new();
-        for extension in &extension_names {
-            patterns.push(format!("?.{}", extension));
-        }
-
-        let require_pattern = config.runtime.require_pattern.clone();
-        if require_pattern.is_empty() {
-            // add default require pattern
-            for extension in &extension_names {
-                patterns.push(format!("?/init.{}", extension));
-            }
-        } else {
-            patterns.extend(require_pattern);
-        }
-
-        self.set_module_extract_patterns(patterns);
-        self.set_module_replace_patterns(
-            config
-                .workspace
-                .module_map
-                .iter()
-                .map(|m| (m.pattern.clone(), m.replace.clone()))
-                .collect(),
-        );
-
+        self.patterns = ModulePatterns::from_emmyrc(&config);
         self.fuzzy_search = !config.strict.require_path;
     }
 
@@ -464,6 +401,17 @@ impl LuaModuleIndex {
         file_ids
     }
 
+    pub fn get_workspace_file_ids(&self, workspace_id: WorkspaceId) -> Vec<FileId> {
+        let mut file_ids = Vec::new();
+        for module_info in self.file_module_map.values() {
+            if module_info.workspace_id == workspace_id {
+                file_ids.push(module_info.file_id);
+            }
+        }
+
+        file_ids
+    }
+
     pub fn set_meta(&mut self, file_id: FileId) {
         if let Some(module_info) = self.file_module_map.get_mut(&file_id) {
             module_info.is_meta = true;
@@ -487,6 +435,42 @@ impl LuaModuleIndex {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct LuaModuleFileCache {
+    visible: bool,
+    export_type: Option<LuaType>,
+    version_conds: Option<Vec<LuaVersionCondition>>,
+    property_owner_id: Option<LuaSemanticDeclId>,
+    is_meta: bool,
+}
+
+/// The module path comes from the file path and the config, so only what the analysis found is
+/// cached, the module must be added by its path before the cache is added
+impl LuaIndexFileCache for LuaModuleIndex {
+    type FileCache = Option<LuaModuleFileCache>;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        let module_info = self.get_module(file_id)?;
+        Some(LuaModuleFileCache {
+            visible: module_info.visible,
+            export_type: module_info.export_type.clone(),
+            version_conds: module_info.version_conds.as_deref().cloned(),
+            property_owner_id: module_info.property_owner_id.clone(),
+            is_meta: module_info.is_meta,
+        })
+    }
+
+    fn add_file_cache(&mut self, file_id: FileId, cache: Self::FileCache) {
+        if let (Some(module_info), Some(cache)) = (self.get_module_mut(file_id), cache) {
+            module_info.visible = cache.visible;
+            module_info.export_type = cache.export_type;
+            module_info.version_conds = cache.version_conds.map(Box::new);
+            module_info.property_owner_id = cache.property_owner_id;
+            module_info.is_meta = cache.is_meta;
+        }
+    }
+}
+
 impl LuaIndex for LuaModuleIndex {
     fn remove(&mut self, file_id: FileId) {
         let (mut parent_id, mut child_id) =
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use log::{error, info};
+use regex::Regex;
+
+use crate::Emmyrc;
+
+/// The patterns which turn the path of a file into its module path
+#[derive(Debug, Default)]
+pub struct ModulePatterns {
+    extract_patterns: Vec<Regex>,
+    replace_patterns: Vec<(Regex, String)>,
+}
+
+impl ModulePatterns {
+    pub fn from_emmyrc(config: &Emmyrc) -> Self {
+        let mut extension_names = Vec::new();
+
+        for extension in &config.runtime.extensions {
+            if let Some(name) = extension.strip_prefix(".") {
+                extension_names.push(name.to_string());
+            } else if let Some(name) = extension.strip_prefix("*.") {
+                extension_names.push(name.to_string());
+            } else {
+                extension_names.push(extension.clone());
+            }
+        }
+
+        if !extension_names.contains(&"lua".to_string()) {
+            extension_names.push("lua".to_string());
+        }
+
+        let mut patterns = Vec::new();
+        for extension in &extension_names {
+            patterns.push(format!("?.{}", extension));
+        }
+
+        let require_pattern = config.runtime.require_pattern.clone();
+        if require_pattern.is_empty() {
+            // add default require pattern
+            for extension in &extension_names {
+                patterns.push(format!("?/init.{}", extension));
+            }
+        } else {
+            patterns.extend(require_pattern);
+        }
+
+        let mut module_patterns = Self::default();
+        module_patterns.set_extract_patterns(patterns);
+        module_patterns.set_replace_patterns(
+            config
+                .workspace
+                .module_map
+                .iter()
+                .map(|m| (m.pattern.clone(), m.replace.clone()))
+                .collect(),
+        );
+        module_patterns
+    }
+
+    // patterns like "?.lua" and "?/init.lua"
+    pub fn set_extract_patterns(&mut self, patterns: Vec<String>) {
+        let mut patterns = patterns;
+        patterns.sort_by_key(|pattern| std::cmp::Reverse(pattern.len()));
+        patterns.dedup();
+        self.extract_patterns.clear();
+        for item in patterns {
+            let regex_str = format!(
+                "^{}$",
+                regex::escape(&item.replace('\\', "/")).replace("\\?", "(.*)")
+            );
+            match Regex::new(&regex_str) {
+                Ok(re) => self.extract_patterns.push(re),
+                Err(e) => {
+                    error!("Invalid module pattern: {}, error: {}", item, e);
+                    return;
+                }
+            };
+        }
+
+        info!("update module pattern: {:?}", self.extract_patterns);
+    }
+
+    pub fn set_replace_patterns(&mut self, patterns: HashMap<String, String>) {
+        self.replace_patterns.clear();
+        for (key, value) in patterns {
+            let key_pattern = match Regex::new(&key) {
+                Ok(re) => re,
+                Err(e) => {
+                    error!("Invalid module replace pattern: {}, error: {}", key, e);
+                    return;
+                }
+            };
+
+            self.replace_patterns.push((key_pattern, value));
+        }
+
+        info!("update module replace pattern: {:?}", self.replace_patterns);
+    }
+
+    pub fn match_pattern(&self, path: &str) -> Option<String> {
+        for pattern in &self.extract_patterns {
+            if let Some(captures) = pattern.captures(path) {
+                if let Some(matched) = captures.get(1) {
+                    return Some(matched.as_str().to_string());
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn replace_module_path(&self, module_path: &str) -> String {
+        match self.replace_patterns.first() {
+            Some((key, value)) => key.replace_all(module_path, value).to_string(),
+            None => module_path.to_string(),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use rowan::{TextRange, TextSize};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     db_index::{LuaType, LuaTypeDeclId},
@@ -10,7 +11,7 @@ use crate::{
 
 use super::lua_operator_meta_method::LuaOperatorMetaMethod;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaOperator {
     owner: LuaOperatorOwner,
     op: LuaOperatorMetaMethod,
@@ -19,7 +20,7 @@ pub struct LuaOperator {
     func: OperatorFunction,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OperatorFunction {
     Func(Arc<LuaFunctionType>),
     Signature(LuaSignatureId),
@@ -116,7 +117,7 @@ impl LuaOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct LuaOperatorId {
     pub file_id: FileId,
     pub position: TextSize,
@@ -128,7 +129,7 @@ impl LuaOperatorId {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LuaOperatorOwner {
     Table(InFiled<TextRange>),
     Type(LuaTypeDeclId),
@@ -1,27 +1,32 @@
-#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum LuaOperatorMetaMethod {
-    Add,    // +
-    Sub,    // -
-    Mul,    // *
-    Div,    // /
-    Mod,    // %
-    Pow,    // ^
-    Unm,    // -
-    IDiv,   // //
-    BAnd,   // &
-    BOr,    // |
-    BXor,   // ~
-    BNot,   // ~
-    Shl,    // <<
-    Shr,    // >>
-    Concat, // ..
-    Len,    // #
-    Eq,     // ==
-    Lt,     // <
-    Le,     // <=
-    Index,  // __index
-    Call,   // __call
-    Pairs,  // __pairs unimplemented
+    Add,      // +
+    Sub,      // -
+    Mul,      // *
+    Div,      // /
+    Mod,      // %
+    Pow,      // ^
+    Unm,      // -
+    IDiv,     // //
+    BAnd,     // &
+    BOr,      // |
+    BXor,     // ~
+    BNot,     // ~
+    Shl,      // <<
+    Shr,      // >>
+    Concat,   // ..
+    Len,      // #
+    Eq,       // ==
+    Lt,       // <
+    Le,       // <=
+    Index,    // __index
+    NewIndex, // __newindex
+    Call,     // __call
+    Pairs,    // __pairs
+    Close,    // __close
+    ToString, // __tostring
 }
 
 impl LuaOperatorMetaMethod {
@@ -46,8 +51,11 @@ impl LuaOperatorMetaMethod {
             "eq" => Some(LuaOperatorMetaMethod::Eq),
             "lt" => Some(LuaOperatorMetaMethod::Lt),
             "le" => Some(LuaOperatorMetaMethod::Le),
+            "newindex" => Some(LuaOperatorMetaMethod::NewIndex),
             "call" => Some(LuaOperatorMetaMethod::Call),
             "pairs" => Some(LuaOperatorMetaMethod::Pairs),
+            "close" => Some(LuaOperatorMetaMethod::Close),
+            "tostring" => Some(LuaOperatorMetaMethod::ToString),
             _ => None,
         }
     }
@@ -74,7 +82,11 @@ impl LuaOperatorMetaMethod {
             "__lt" => Some(LuaOperatorMetaMethod::Lt),
             "__le" => Some(LuaOperatorMetaMethod::Le),
             "__index" => Some(LuaOperatorMetaMethod::Index),
+            "__newindex" => Some(LuaOperatorMetaMethod::NewIndex),
             "__call" => Some(LuaOperatorMetaMethod::Call),
+            "__pairs" => Some(LuaOperatorMetaMethod::Pairs),
+            "__close" => Some(LuaOperatorMetaMethod::Close),
+            "__tostring" => Some(LuaOperatorMetaMethod::ToString),
             _ => None,
         }
     }
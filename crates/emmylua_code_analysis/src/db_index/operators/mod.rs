@@ -5,7 +5,7 @@ use std::collections::HashMap;
 
 use crate::FileId;
 
-use super::traits::LuaIndex;
+use super::traits::{LuaIndex, LuaIndexFileCache};
 pub use lua_operator::{LuaOperator, LuaOperatorId, LuaOperatorOwner, OperatorFunction};
 pub use lua_operator_meta_method::LuaOperatorMetaMethod;
 
@@ -58,6 +58,25 @@ impl LuaOperatorIndex {
     }
 }
 
+impl LuaIndexFileCache for LuaOperatorIndex {
+    type FileCache = Vec<LuaOperator>;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        self.in_filed_operator_map
+            .get(&file_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.operators.get(id).cloned())
+            .collect()
+    }
+
+    fn add_file_cache(&mut self, _: FileId, cache: Self::FileCache) {
+        for operator in cache {
+            self.add_operator(operator);
+        }
+    }
+}
+
 impl LuaIndex for LuaOperatorIndex {
     fn remove(&mut self, file_id: FileId) {
         if let Some(operator_ids) = self.in_filed_operator_map.remove(&file_id) {
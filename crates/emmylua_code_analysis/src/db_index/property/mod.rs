@@ -8,7 +8,10 @@ pub use property::{LuaDeprecated, LuaPropertyId};
 
 use crate::FileId;
 
-use super::{traits::LuaIndex, LuaSemanticDeclId};
+use super::{
+    traits::{LuaIndex, LuaIndexFileCache},
+    LuaSemanticDeclId,
+};
 
 #[derive(Debug)]
 pub struct LuaPropertyIndex {
@@ -56,12 +59,11 @@ impl LuaPropertyIndex {
             .id
             .clone();
         self.property_owners_map
-            .insert(same_property_owner_id, property_id);
+            .insert(same_property_owner_id.clone(), property_id);
 
-        self.in_filed_owner
-            .entry(file_id)
-            .or_insert_with(HashSet::new)
-            .insert(source_owner_id);
+        let in_filed_owner = self.in_filed_owner.entry(file_id).or_default();
+        in_filed_owner.insert(source_owner_id);
+        in_filed_owner.insert(same_property_owner_id);
 
         Some(())
     }
@@ -202,6 +204,49 @@ impl LuaPropertyIndex {
     }
 }
 
+impl LuaIndexFileCache for LuaPropertyIndex {
+    /// The owners which share a property, with the property
+    type FileCache = Vec<(Vec<LuaSemanticDeclId>, LuaCommonProperty)>;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        let mut owners_by_property: HashMap<LuaPropertyId, Vec<LuaSemanticDeclId>> = HashMap::new();
+        for owner_id in self.in_filed_owner.get(&file_id).into_iter().flatten() {
+            if let Some(property_id) = self.property_owners_map.get(owner_id) {
+                owners_by_property
+                    .entry(*property_id)
+                    .or_default()
+                    .push(owner_id.clone());
+            }
+        }
+
+        owners_by_property
+            .into_iter()
+            .filter_map(|(property_id, owner_ids)| {
+                Some((owner_ids, self.properties.get(&property_id)?.clone()))
+            })
+            .collect()
+    }
+
+    fn add_file_cache(&mut self, file_id: FileId, cache: Self::FileCache) {
+        for (owner_ids, property) in cache {
+            let Some(source_owner_id) = owner_ids.first().cloned() else {
+                continue;
+            };
+            let Some(new_property) = self.get_or_create_property(source_owner_id) else {
+                continue;
+            };
+            let id = new_property.id;
+            *new_property = LuaCommonProperty { id, ..property };
+
+            let in_filed_owner = self.in_filed_owner.entry(file_id).or_default();
+            for owner_id in owner_ids {
+                self.property_owners_map.insert(owner_id.clone(), id);
+                in_filed_owner.insert(owner_id);
+            }
+        }
+    }
+}
+
 impl LuaIndex for LuaPropertyIndex {
     fn remove(&mut self, file_id: FileId) {
         if let Some(property_owner_ids) = self.in_filed_owner.remove(&file_id) {
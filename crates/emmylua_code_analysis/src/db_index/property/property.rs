@@ -1,6 +1,7 @@
 use emmylua_parser::{LuaVersionCondition, VisibilityKind};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaCommonProperty {
     pub id: LuaPropertyId,
     pub description: Option<Box<String>>,
@@ -12,7 +13,7 @@ pub struct LuaCommonProperty {
     pub other_content: Option<Box<String>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LuaDeprecated {
     Deprecated,
     DeprecatedWithMessage(Box<String>),
@@ -33,7 +34,7 @@ impl LuaCommonProperty {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Copy, Serialize, Deserialize)]
 pub struct LuaPropertyId {
     id: u32,
 }
@@ -1,11 +1,15 @@
 use rowan::TextRange;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 use std::collections::HashMap;
 
 use crate::db_index::LuaDeclId;
 
-#[derive(Debug)]
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileReference {
     decl_references: HashMap<LuaDeclId, Vec<DeclReference>>,
+    #[serde_as(as = "Vec<(_, _)>")]
     references_to_decl: HashMap<TextRange, LuaDeclId>,
 }
 
@@ -44,7 +48,7 @@ impl FileReference {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct DeclReference {
     pub range: TextRange,
     pub is_write: bool,
@@ -6,12 +6,16 @@ use std::collections::{HashMap, HashSet};
 use emmylua_parser::LuaSyntaxId;
 pub use file_reference::{DeclReference, FileReference};
 use rowan::TextRange;
+use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 use string_reference::StringReference;
 
 use crate::{FileId, InFiled};
 
-use super::{traits::LuaIndex, LuaDeclId, LuaMemberKey, LuaTypeDeclId};
+use super::{
+    traits::{LuaIndex, LuaIndexFileCache},
+    LuaDeclId, LuaMemberKey, LuaTypeDeclId,
+};
 
 #[derive(Debug)]
 pub struct LuaReferenceIndex {
@@ -212,6 +216,79 @@ impl LuaReferenceIndex {
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct LuaReferenceFileCache {
+    file_reference: Option<FileReference>,
+    index_references: Vec<(LuaMemberKey, Vec<LuaSyntaxId>)>,
+    global_references: Vec<(SmolStr, Vec<LuaSyntaxId>)>,
+    string_reference: Option<StringReference>,
+    type_references: Vec<(LuaTypeDeclId, Vec<TextRange>)>,
+}
+
+impl LuaIndexFileCache for LuaReferenceIndex {
+    type FileCache = LuaReferenceFileCache;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        LuaReferenceFileCache {
+            file_reference: self.file_references.get(&file_id).cloned(),
+            index_references: self
+                .index_reference
+                .iter()
+                .filter_map(|(key, references)| {
+                    let syntax_ids = references.get(&file_id)?;
+                    Some((key.clone(), syntax_ids.iter().copied().collect()))
+                })
+                .collect(),
+            global_references: self
+                .global_references
+                .iter()
+                .filter_map(|(name, references)| {
+                    let syntax_ids = references.get(&file_id)?;
+                    Some((name.clone(), syntax_ids.iter().copied().collect()))
+                })
+                .collect(),
+            string_reference: self.string_references.get(&file_id).cloned(),
+            type_references: self
+                .type_references
+                .get(&file_id)
+                .into_iter()
+                .flatten()
+                .map(|(type_decl_id, ranges)| {
+                    (type_decl_id.clone(), ranges.iter().copied().collect())
+                })
+                .collect(),
+        }
+    }
+
+    fn add_file_cache(&mut self, file_id: FileId, cache: Self::FileCache) {
+        if let Some(file_reference) = cache.file_reference {
+            self.file_references.insert(file_id, file_reference);
+        }
+
+        for (key, syntax_ids) in cache.index_references {
+            for syntax_id in syntax_ids {
+                self.add_index_reference(key.clone(), file_id, syntax_id);
+            }
+        }
+
+        for (name, syntax_ids) in cache.global_references {
+            for syntax_id in syntax_ids {
+                self.add_global_reference(&name, file_id, syntax_id);
+            }
+        }
+
+        if let Some(string_reference) = cache.string_reference {
+            self.string_references.insert(file_id, string_reference);
+        }
+
+        for (type_decl_id, ranges) in cache.type_references {
+            for range in ranges {
+                self.add_type_reference(file_id, type_decl_id.clone(), range);
+            }
+        }
+    }
+}
+
 impl LuaIndex for LuaReferenceIndex {
     fn remove(&mut self, file_id: FileId) {
         self.file_references.remove(&file_id);
@@ -7,7 +7,7 @@ pub use signature::{
 
 use crate::FileId;
 
-use super::traits::LuaIndex;
+use super::traits::{LuaIndex, LuaIndexFileCache};
 
 mod signature;
 
@@ -44,6 +44,29 @@ impl LuaSignatureIndex {
     }
 }
 
+impl LuaIndexFileCache for LuaSignatureIndex {
+    type FileCache = Vec<(LuaSignatureId, LuaSignature)>;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        self.in_file_signatures
+            .get(&file_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| Some((*id, self.signatures.get(id)?.clone())))
+            .collect()
+    }
+
+    fn add_file_cache(&mut self, file_id: FileId, cache: Self::FileCache) {
+        for (signature_id, signature) in cache {
+            self.in_file_signatures
+                .entry(file_id)
+                .or_default()
+                .insert(signature_id);
+            self.signatures.insert(signature_id, signature);
+        }
+    }
+}
+
 impl LuaIndex for LuaSignatureIndex {
     fn remove(&mut self, file_id: FileId) {
         if let Some(signature_ids) = self.in_file_signatures.remove(&file_id) {
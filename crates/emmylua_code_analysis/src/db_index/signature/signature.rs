@@ -1,5 +1,6 @@
 use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::serde_as;
 use std::fmt;
 use std::{collections::HashMap, sync::Arc};
 
@@ -12,10 +13,12 @@ use crate::{
     FileId,
 };
 
-#[derive(Debug)]
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaSignature {
     pub generic_params: Vec<(String, Option<LuaType>)>,
     pub overloads: Vec<Arc<LuaFunctionType>>,
+    #[serde_as(as = "Vec<(_, _)>")]
     pub param_docs: HashMap<usize, LuaDocParamInfo>,
     pub params: Vec<String>,
     pub return_docs: Vec<LuaDocReturnInfo>,
@@ -23,9 +26,10 @@ pub struct LuaSignature {
     pub is_colon_define: bool,
     pub is_async: bool,
     pub nodiscard: Option<LuaNoDiscard>,
+    pub format_param: Option<usize>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LuaNoDiscard {
     NoDiscard,
     NoDiscardWithMessage(Box<String>),
@@ -43,6 +47,7 @@ impl LuaSignature {
             is_colon_define: false,
             is_async: false,
             nodiscard: None,
+            format_param: None,
         }
     }
 
@@ -149,7 +154,7 @@ impl LuaSignature {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaDocParamInfo {
     pub name: String,
     pub type_ref: LuaType,
@@ -157,7 +162,7 @@ pub struct LuaDocParamInfo {
     pub description: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaDocReturnInfo {
     pub name: Option<String>,
     pub type_ref: LuaType,
@@ -176,7 +181,7 @@ impl Serialize for LuaSignatureId {
         S: Serializer,
     {
         let value = format!("{}|{}", self.file_id.id, u32::from(self.position));
-        serializer.serialize_str(&value)
+        serializer.serialize_newtype_struct("LuaSignatureId", &value)
     }
 }
 
@@ -194,6 +199,14 @@ impl<'de> Deserialize<'de> for LuaSignatureId {
                 formatter.write_str("a string with format 'file_id:position'")
             }
 
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = String::deserialize(deserializer)?;
+                self.visit_str(&value)
+            }
+
             fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
             where
                 E: de::Error,
@@ -218,7 +231,7 @@ impl<'de> Deserialize<'de> for LuaSignatureId {
             }
         }
 
-        deserializer.deserialize_str(LuaSignatureIdVisitor)
+        deserializer.deserialize_newtype_struct("LuaSignatureId", LuaSignatureIdVisitor)
     }
 }
 
@@ -246,7 +259,7 @@ impl LuaSignatureId {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SignatureReturnStatus {
     UnResolve,
     DocResolve,
@@ -1,3 +1,5 @@
+use serde::{de::DeserializeOwned, Serialize};
+
 use crate::FileId;
 
 pub trait LuaIndex {
@@ -5,3 +7,13 @@ pub trait LuaIndex {
 
     fn clear(&mut self);
 }
+
+/// The part of an index which is built from one file, the index cache saves it so an unchanged
+/// file is not analyzed again
+pub(crate) trait LuaIndexFileCache {
+    type FileCache: Serialize + DeserializeOwned;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache;
+
+    fn add_file_cache(&mut self, file_id: FileId, cache: Self::FileCache);
+}
@@ -1,10 +1,10 @@
 use std::collections::HashSet;
 
 use crate::{
-    DbIndex, GenericTpl, LuaAliasCallType, LuaFunctionType, LuaGenericType, LuaInstanceType,
-    LuaIntersectionType, LuaMemberKey, LuaMemberOwner, LuaObjectType, LuaSignatureId,
-    LuaStringTplType, LuaTupleType, LuaType, LuaTypeDeclId, LuaUnionType, TypeSubstitutor,
-    VariadicType,
+    DbIndex, GenericTpl, LuaAliasCallKind, LuaAliasCallType, LuaConditionalType, LuaFunctionType,
+    LuaGenericType, LuaInstanceType, LuaIntersectionType, LuaMappedType, LuaMemberKey,
+    LuaMemberOwner, LuaObjectType, LuaSignatureId, LuaStringTplType, LuaTupleType, LuaType,
+    LuaTypeDeclId, LuaUnionType, TypeSubstitutor, VariadicType,
 };
 
 use super::LuaMultiLineUnion;
@@ -49,6 +49,7 @@ pub fn humanize_type(db: &DbIndex, ty: &LuaType, level: RenderLevel) -> String {
             humanize_table_const_type(db, member_owner, level)
         }
         LuaType::Global => "global".to_string(),
+        LuaType::Never => "never".to_string(),
         LuaType::Def(id) => humanize_def_type(db, id, level),
         LuaType::Union(union) => humanize_union_type(db, union, level),
         LuaType::Tuple(tuple) => humanize_tuple_type(db, tuple, level),
@@ -88,6 +89,8 @@ pub fn humanize_type(db: &DbIndex, ty: &LuaType, level: RenderLevel) -> String {
         LuaType::MultiLineUnion(multi_union) => {
             humanize_multi_line_union_type(db, multi_union, level)
         }
+        LuaType::Conditional(conditional) => humanize_conditional_type(db, conditional, level),
+        LuaType::Mapped(mapped) => humanize_mapped_type(db, mapped, level),
         _ => "unknown".to_string(),
     }
 }
@@ -323,14 +326,50 @@ fn humanize_array_type(db: &DbIndex, inner: &LuaType, level: RenderLevel) -> Str
     format!("{}[]", element_type)
 }
 
-#[allow(unused)]
 fn humanize_call_type(db: &DbIndex, inner: &LuaAliasCallType, level: RenderLevel) -> String {
-    // if level == RenderLevel::Minimal {
-    //     return "(keyof)".to_string();
-    // }
-    // let element_type = humanize_type(db, inner, level.next_level());
-    // format!("keyof {}", element_type)
-    "(call)".to_string()
+    let operands = inner
+        .get_operands()
+        .iter()
+        .map(|ty| humanize_type(db, ty, level.next_level()))
+        .collect::<Vec<_>>();
+    match (inner.get_call_kind(), operands.as_slice()) {
+        (LuaAliasCallKind::KeyOf, [base]) => format!("keyof {}", base),
+        (LuaAliasCallKind::Index, [base, key]) => format!("{}[{}]", base, key),
+        (LuaAliasCallKind::Extends, [left, right]) => format!("{} extends {}", left, right),
+        _ => "(call)".to_string(),
+    }
+}
+
+fn humanize_conditional_type(
+    db: &DbIndex,
+    conditional: &LuaConditionalType,
+    level: RenderLevel,
+) -> String {
+    if level == RenderLevel::Minimal {
+        return "(conditional)".to_string();
+    }
+
+    let condition = humanize_type(db, conditional.get_condition(), level.next_level());
+    let true_type = humanize_type(db, conditional.get_true_type(), level.next_level());
+    let false_type = humanize_type(db, conditional.get_false_type(), level.next_level());
+    format!("{} and {} or {}", condition, true_type, false_type)
+}
+
+fn humanize_mapped_type(db: &DbIndex, mapped: &LuaMappedType, level: RenderLevel) -> String {
+    if level == RenderLevel::Minimal {
+        return "{...}".to_string();
+    }
+
+    let keys = humanize_type(db, mapped.get_keys(), level.next_level());
+    let value = humanize_type(db, mapped.get_value(), level.next_level());
+    let optional = if mapped.is_nullable() { "?" } else { "" };
+    format!(
+        "{{ [{} in {}]{}: {} }}",
+        mapped.get_param().get_name(),
+        keys,
+        optional,
+        value
+    )
 }
 
 fn humanize_doc_function_type(
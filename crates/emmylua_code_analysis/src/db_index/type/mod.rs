@@ -5,9 +5,10 @@ mod type_ops;
 mod type_owner;
 mod types;
 
-use super::traits::LuaIndex;
+use super::traits::{LuaIndex, LuaIndexFileCache};
 use crate::{FileId, InFiled};
 pub use humanize_type::{format_union_type, humanize_type, RenderLevel};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 pub use type_decl::{
     LuaDeclLocation, LuaDeclTypeKind, LuaTypeAttribute, LuaTypeDecl, LuaTypeDeclId,
@@ -24,6 +25,7 @@ pub struct LuaTypeIndex {
     full_name_type_map: HashMap<LuaTypeDeclId, LuaTypeDecl>,
     generic_params: HashMap<LuaTypeDeclId, Vec<(String, Option<LuaType>)>>,
     supers: HashMap<LuaTypeDeclId, Vec<InFiled<LuaType>>>,
+    sub_types: HashMap<LuaTypeDeclId, Vec<InFiled<LuaTypeDeclId>>>,
     types: HashMap<LuaTypeOwner, LuaTypeCache>,
     in_filed_type_owner: HashMap<FileId, HashSet<LuaTypeOwner>>,
 }
@@ -37,6 +39,7 @@ impl LuaTypeIndex {
             full_name_type_map: HashMap::new(),
             generic_params: HashMap::new(),
             supers: HashMap::new(),
+            sub_types: HashMap::new(),
             types: HashMap::new(),
             in_filed_type_owner: HashMap::new(),
         }
@@ -175,6 +178,13 @@ impl LuaTypeIndex {
     }
 
     pub fn add_super_type(&mut self, decl_id: LuaTypeDeclId, file_id: FileId, super_type: LuaType) {
+        if let Some(super_id) = get_super_type_decl_id(&super_type) {
+            self.sub_types
+                .entry(super_id)
+                .or_insert_with(Vec::new)
+                .push(InFiled::new(file_id, decl_id.clone()));
+        }
+
         self.supers
             .entry(decl_id)
             .or_insert_with(Vec::new)
@@ -189,10 +199,26 @@ impl LuaTypeIndex {
         }
     }
 
+    /// 获取直接继承自 `decl_id` 的类型
+    pub fn get_sub_types(&self, decl_id: &LuaTypeDeclId) -> Option<Vec<LuaTypeDeclId>> {
+        let sub_types = self.sub_types.get(decl_id)?;
+        let mut result: Vec<LuaTypeDeclId> = Vec::new();
+        for sub_type in sub_types {
+            if !result.contains(&sub_type.value) {
+                result.push(sub_type.value.clone());
+            }
+        }
+        Some(result)
+    }
+
     pub fn get_type_decl(&self, decl_id: &LuaTypeDeclId) -> Option<&LuaTypeDecl> {
         self.full_name_type_map.get(decl_id)
     }
 
+    pub fn get_file_types(&self, file_id: &FileId) -> Option<&Vec<LuaTypeDeclId>> {
+        self.file_types.get(file_id)
+    }
+
     pub fn get_all_types(&self) -> Vec<&LuaTypeDecl> {
         self.full_name_type_map.values().collect()
     }
@@ -234,6 +260,18 @@ impl LuaIndex for LuaTypeIndex {
                 }
 
                 if let Some(supers) = self.supers.get_mut(&id) {
+                    for super_type in supers.iter().filter(|s| s.file_id == file_id) {
+                        let Some(super_id) = get_super_type_decl_id(&super_type.value) else {
+                            continue;
+                        };
+                        if let Some(sub_types) = self.sub_types.get_mut(&super_id) {
+                            sub_types.retain(|s| !(s.file_id == file_id && s.value == id));
+                            if sub_types.is_empty() {
+                                self.sub_types.remove(&super_id);
+                            }
+                        }
+                    }
+
                     supers.retain(|s| s.file_id != file_id);
                     if supers.is_empty() {
                         self.supers.remove(&id);
@@ -260,7 +298,110 @@ impl LuaIndex for LuaTypeIndex {
         self.full_name_type_map.clear();
         self.generic_params.clear();
         self.supers.clear();
+        self.sub_types.clear();
         self.types.clear();
         self.in_filed_type_owner.clear();
     }
 }
+
+type GenericParams = Vec<(String, Option<LuaType>)>;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct LuaTypeFileCache {
+    namespace: Option<String>,
+    using_namespaces: Vec<String>,
+    type_decls: Vec<LuaTypeDecl>,
+    generic_params: Vec<(LuaTypeDeclId, GenericParams)>,
+    super_types: Vec<(LuaTypeDeclId, LuaType)>,
+    types: Vec<(LuaTypeOwner, LuaTypeCache)>,
+}
+
+impl LuaIndexFileCache for LuaTypeIndex {
+    type FileCache = LuaTypeFileCache;
+
+    fn get_file_cache(&self, file_id: FileId) -> Self::FileCache {
+        let mut type_ids: Vec<&LuaTypeDeclId> = Vec::new();
+        for type_id in self.file_types.get(&file_id).into_iter().flatten() {
+            if !type_ids.contains(&type_id) {
+                type_ids.push(type_id);
+            }
+        }
+
+        let mut type_decls = Vec::new();
+        let mut generic_params = Vec::new();
+        let mut super_types = Vec::new();
+        for type_id in type_ids {
+            if let Some(decl) = self.full_name_type_map.get(type_id) {
+                let mut decl = decl.clone();
+                decl.get_mut_locations()
+                    .retain(|loc| loc.file_id == file_id);
+                type_decls.push(decl);
+            }
+
+            if let Some(params) = self.generic_params.get(type_id) {
+                generic_params.push((type_id.clone(), params.clone()));
+            }
+
+            for super_type in self.supers.get(type_id).into_iter().flatten() {
+                if super_type.file_id == file_id {
+                    super_types.push((type_id.clone(), super_type.value.clone()));
+                }
+            }
+        }
+
+        let types = self
+            .in_filed_type_owner
+            .get(&file_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|owner| Some((owner.clone(), self.types.get(owner)?.clone())))
+            .collect();
+
+        LuaTypeFileCache {
+            namespace: self.file_namespace.get(&file_id).cloned(),
+            using_namespaces: self
+                .file_using_namespace
+                .get(&file_id)
+                .cloned()
+                .unwrap_or_default(),
+            type_decls,
+            generic_params,
+            super_types,
+            types,
+        }
+    }
+
+    fn add_file_cache(&mut self, file_id: FileId, cache: Self::FileCache) {
+        if let Some(namespace) = cache.namespace {
+            self.add_file_namespace(file_id, namespace);
+        }
+
+        for namespace in cache.using_namespaces {
+            self.add_file_using_namespace(file_id, namespace);
+        }
+
+        for type_decl in cache.type_decls {
+            self.add_type_decl(file_id, type_decl);
+        }
+
+        for (type_id, params) in cache.generic_params {
+            self.add_generic_params(type_id, params);
+        }
+
+        for (type_id, super_type) in cache.super_types {
+            self.add_super_type(type_id, file_id, super_type);
+        }
+
+        for (owner, type_cache) in cache.types {
+            self.bind_type(owner, type_cache);
+        }
+    }
+}
+
+fn get_super_type_decl_id(super_type: &LuaType) -> Option<LuaTypeDeclId> {
+    match super_type {
+        LuaType::Ref(id) => Some(id.clone()),
+        LuaType::Generic(generic) => Some(generic.get_base_type_id()),
+        _ => None,
+    }
+}
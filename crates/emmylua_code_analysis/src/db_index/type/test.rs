@@ -5,7 +5,7 @@ mod test {
     use crate::db_index::r#type::LuaTypeIndex;
     use crate::db_index::traits::LuaIndex;
     use crate::db_index::{LuaDeclTypeKind, LuaTypeAttribute};
-    use crate::{FileId, LuaTypeDecl, LuaTypeDeclId};
+    use crate::{FileId, LuaType, LuaTypeDecl, LuaTypeDeclId};
 
     fn create_type_index() -> LuaTypeIndex {
         LuaTypeIndex::new()
@@ -161,4 +161,41 @@ mod test {
         assert_eq!(decl.get_namespace(), "test".into());
         assert_eq!(decl.get_full_name(), "test.new_type");
     }
+
+    #[test]
+    fn test_sub_types() {
+        let mut index = create_type_index();
+        let file_id = FileId { id: 1 };
+        let file_id2 = FileId { id: 2 };
+        let base_id = LuaTypeDeclId::new("Base");
+        let derived_id = LuaTypeDeclId::new("Derived");
+
+        let _ = index.add_type_decl(
+            file_id,
+            LuaTypeDecl::new(
+                file_id,
+                TextRange::new(0.into(), 4.into()),
+                "Base".to_string(),
+                LuaDeclTypeKind::Class,
+                LuaTypeAttribute::None.into(),
+                base_id.clone(),
+            ),
+        );
+        let _ = index.add_type_decl(
+            file_id2,
+            LuaTypeDecl::new(
+                file_id2,
+                TextRange::new(0.into(), 7.into()),
+                "Derived".to_string(),
+                LuaDeclTypeKind::Class,
+                LuaTypeAttribute::None.into(),
+                derived_id.clone(),
+            ),
+        );
+        index.add_super_type(derived_id.clone(), file_id2, LuaType::Ref(base_id.clone()));
+
+        assert_eq!(index.get_sub_types(&base_id), Some(vec![derived_id]));
+        index.remove(file_id2);
+        assert!(index.get_sub_types(&base_id).is_none());
+    }
 }
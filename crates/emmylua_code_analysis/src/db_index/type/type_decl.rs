@@ -28,7 +28,7 @@ flags! {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
 pub struct LuaTypeDecl {
     simple_name: String,
     locations: Vec<LuaDeclLocation>,
@@ -129,6 +129,18 @@ impl LuaTypeDecl {
             } => {
                 let substitutor = match substitutor {
                     Some(substitutor) => substitutor,
+                    // the doc types which compute a type are evaluated when they are used
+                    None if matches!(
+                        origin,
+                        LuaType::Call(_) | LuaType::Conditional(_) | LuaType::Mapped(_)
+                    ) =>
+                    {
+                        return Some(instantiate_type_generic(
+                            db,
+                            origin,
+                            &TypeSubstitutor::new(),
+                        ));
+                    }
                     None => return Some(origin.clone()),
                 };
 
@@ -303,14 +315,14 @@ impl<'de> Deserialize<'de> for LuaTypeDeclId {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaDeclLocation {
     pub file_id: FileId,
     pub range: TextRange,
     pub attrib: FlagSet<LuaTypeAttribute>,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LuaTypeExtra {
     Enum { base: Option<LuaType> },
     Class,
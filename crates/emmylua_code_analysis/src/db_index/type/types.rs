@@ -7,6 +7,8 @@ use std::{
 
 use internment::ArcIntern;
 use rowan::TextRange;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 use smol_str::SmolStr;
 
 use crate::{
@@ -16,7 +18,7 @@ use crate::{
 
 use super::{type_decl::LuaTypeDeclId, TypeOps};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum LuaType {
     Unknown,
     Any,
@@ -58,6 +60,10 @@ pub enum LuaType {
     Namespace(ArcIntern<SmolStr>),
     Call(Arc<LuaAliasCallType>),
     MultiLineUnion(Arc<LuaMultiLineUnion>),
+    Conditional(Arc<LuaConditionalType>),
+    Mapped(Arc<LuaMappedType>),
+    /// The return type of a function which never returns, such as `error`
+    Never,
 }
 
 impl PartialEq for LuaType {
@@ -103,6 +109,9 @@ impl PartialEq for LuaType {
             (LuaType::DocIntegerConst(a), LuaType::DocIntegerConst(b)) => a == b,
             (LuaType::Namespace(a), LuaType::Namespace(b)) => a == b,
             (LuaType::MultiLineUnion(a), LuaType::MultiLineUnion(b)) => a == b,
+            (LuaType::Conditional(a), LuaType::Conditional(b)) => a == b,
+            (LuaType::Mapped(a), LuaType::Mapped(b)) => a == b,
+            (LuaType::Never, LuaType::Never) => true,
             _ => false, // 不同变体之间不相等
         }
     }
@@ -174,6 +183,9 @@ impl Hash for LuaType {
                 let ptr = Arc::as_ptr(a);
                 (43, ptr).hash(state)
             }
+            LuaType::Conditional(a) => (44, a).hash(state),
+            LuaType::Mapped(a) => (45, a).hash(state),
+            LuaType::Never => 46.hash(state),
         }
     }
 }
@@ -188,6 +200,10 @@ impl LuaType {
         matches!(self, LuaType::Nil)
     }
 
+    pub fn is_never(&self) -> bool {
+        matches!(self, LuaType::Never)
+    }
+
     pub fn is_table(&self) -> bool {
         matches!(
             self,
@@ -387,6 +403,8 @@ impl LuaType {
             LuaType::StrTplRef(_) => true,
             LuaType::SelfInfer => true,
             LuaType::MultiLineUnion(inner) => inner.contain_tpl(),
+            LuaType::Conditional(inner) => inner.contain_tpl(),
+            LuaType::Mapped(inner) => inner.contain_tpl(),
             _ => false,
         }
     }
@@ -404,7 +422,7 @@ impl LuaType {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaTupleType {
     types: Vec<LuaType>,
 }
@@ -459,7 +477,7 @@ impl From<LuaTupleType> for LuaType {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaFunctionType {
     is_async: bool,
     is_colon_define: bool,
@@ -537,15 +555,17 @@ impl From<LuaFunctionType> for LuaType {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LuaIndexAccessKey {
     Integer(i64),
     String(SmolStr),
     Type(LuaType),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaObjectType {
+    #[serde_as(as = "Vec<(_, _)>")]
     fields: HashMap<LuaMemberKey, LuaType>,
     index_access: Vec<(LuaType, LuaType)>,
 }
@@ -652,7 +672,7 @@ impl From<LuaObjectType> for LuaType {
         LuaType::Object(t.into())
     }
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaUnionType {
     types: Vec<LuaType>,
 }
@@ -725,7 +745,7 @@ impl From<LuaUnionType> for LuaType {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaIntersectionType {
     types: Vec<LuaType>,
 }
@@ -754,7 +774,7 @@ impl From<LuaIntersectionType> for LuaType {
     }
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LuaAliasCallKind {
     KeyOf,
     Index,
@@ -765,7 +785,7 @@ pub enum LuaAliasCallKind {
     Unpack,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaAliasCallType {
     call_kind: LuaAliasCallKind,
     operand: Vec<LuaType>,
@@ -789,7 +809,7 @@ impl LuaAliasCallType {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaGenericType {
     base: LuaTypeDeclId,
     params: Vec<LuaType>,
@@ -823,7 +843,7 @@ impl From<LuaGenericType> for LuaType {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum VariadicType {
     Multi(Vec<LuaType>),
     Base(LuaType),
@@ -945,12 +965,13 @@ impl From<SmolStr> for LuaType {
             "io" => LuaType::Io,
             "global" => LuaType::Global,
             "self" => LuaType::SelfInfer,
+            "never" => LuaType::Never,
             _ => LuaType::Ref(LuaTypeDeclId::new_by_id(s.into())),
         }
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaInstanceType {
     base: LuaType,
     range: InFiled<TextRange>,
@@ -970,7 +991,7 @@ impl LuaInstanceType {
     }
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GenericTplId {
     Type(u32),
     Func(u32),
@@ -993,7 +1014,7 @@ impl GenericTplId {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GenericTpl {
     tpl_id: GenericTplId,
     name: ArcIntern<SmolStr>,
@@ -1013,7 +1034,7 @@ impl GenericTpl {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaStringTplType {
     prefix: ArcIntern<String>,
     tpl_id: GenericTplId,
@@ -1048,7 +1069,7 @@ impl LuaStringTplType {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LuaMultiLineUnion {
     unions: Vec<(LuaType, Option<String>)>,
 }
@@ -1075,3 +1096,80 @@ impl LuaMultiLineUnion {
         self.unions.iter().any(|(t, _)| t.contain_tpl())
     }
 }
+
+/// `<condition> and <true type> or <false type>`, the condition is usually `A extends B`
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LuaConditionalType {
+    condition: LuaType,
+    true_type: LuaType,
+    false_type: LuaType,
+}
+
+impl LuaConditionalType {
+    pub fn new(condition: LuaType, true_type: LuaType, false_type: LuaType) -> Self {
+        Self {
+            condition,
+            true_type,
+            false_type,
+        }
+    }
+
+    pub fn get_condition(&self) -> &LuaType {
+        &self.condition
+    }
+
+    pub fn get_true_type(&self) -> &LuaType {
+        &self.true_type
+    }
+
+    pub fn get_false_type(&self) -> &LuaType {
+        &self.false_type
+    }
+
+    pub fn contain_tpl(&self) -> bool {
+        self.condition.contain_tpl()
+            || self.true_type.contain_tpl()
+            || self.false_type.contain_tpl()
+    }
+}
+
+/// `{ [K in <keys>]: <value> }`, the value refers to each key by the param `K`
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LuaMappedType {
+    param: GenericTpl,
+    keys: LuaType,
+    value: LuaType,
+    is_nullable: bool,
+}
+
+impl LuaMappedType {
+    pub fn new(param: GenericTpl, keys: LuaType, value: LuaType, is_nullable: bool) -> Self {
+        Self {
+            param,
+            keys,
+            value,
+            is_nullable,
+        }
+    }
+
+    pub fn get_param(&self) -> &GenericTpl {
+        &self.param
+    }
+
+    pub fn get_keys(&self) -> &LuaType {
+        &self.keys
+    }
+
+    pub fn get_value(&self) -> &LuaType {
+        &self.value
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.is_nullable
+    }
+
+    pub fn contain_tpl(&self) -> bool {
+        // the keys are only expanded to the fields by an instantiation
+        true
+    }
+}
@@ -5,8 +5,9 @@ use emmylua_parser::{
 use rowan::TextRange;
 
 use crate::{
-    DiagnosticCode, LuaDeclExtra, LuaDeclId, LuaSemanticDeclId, LuaType, LuaTypeCache,
-    SemanticDeclLevel, SemanticModel, TypeCheckFailReason, TypeCheckResult,
+    get_meta_method_operators, DiagnosticCode, LuaDeclExtra, LuaDeclId, LuaOperatorMetaMethod,
+    LuaSemanticDeclId, LuaType, LuaTypeCache, SemanticDeclLevel, SemanticModel,
+    TypeCheckFailReason, TypeCheckResult,
 };
 
 use super::{humanize_lint_type, Checker, DiagnosticContext};
@@ -36,9 +37,15 @@ fn check_assign_stat(
     semantic_model: &SemanticModel,
     assign: &LuaAssignStat,
 ) -> Option<()> {
-    let (vars, exprs) = assign.get_var_and_expr_list();
-    let value_types =
-        semantic_model.infer_multi_value_adjusted_expression_types(&exprs, Some(vars.len()))?;
+    let (vars, mut exprs) = assign.get_var_and_expr_list();
+    let value_types = if assign.get_compound_op_token().is_some() {
+        // `a += 1` assigns the value of `a + 1` instead of the right expression
+        let value_type = semantic_model.infer_compound_assign_value(assign)?;
+        exprs.clear();
+        vec![(value_type, assign.get_range())]
+    } else {
+        semantic_model.infer_multi_value_adjusted_expression_types(&exprs, Some(vars.len()))?
+    };
 
     for (idx, var) in vars.iter().enumerate() {
         match var {
@@ -144,6 +151,9 @@ fn check_index_expr(
         }
         _ => {}
     }
+    if typ.is_none() && semantic_info.semantic_decl.is_none() {
+        typ = get_newindex_value_type(semantic_model, index_expr);
+    }
     if typ.is_none() {
         typ = Some(semantic_info.typ);
     }
@@ -162,6 +172,33 @@ fn check_index_expr(
     Some(())
 }
 
+/// The value type accepted by the `__newindex` metamethod when assigning a field which does not
+/// exist
+fn get_newindex_value_type(
+    semantic_model: &SemanticModel,
+    index_expr: &LuaIndexExpr,
+) -> Option<LuaType> {
+    let db = semantic_model.get_db();
+    let prefix_type = semantic_model
+        .infer_expr(index_expr.get_prefix_expr()?)
+        .ok()?;
+    let operator = get_meta_method_operators(db, &prefix_type, LuaOperatorMetaMethod::NewIndex)
+        .into_iter()
+        .next()?;
+    // the parameters of `__newindex` are the table, the key and the value
+    match operator.get_operator_func() {
+        LuaType::DocFunction(func) => func.get_params().get(2)?.1.clone(),
+        LuaType::Signature(signature_id) => Some(
+            db.get_signature_index()
+                .get(&signature_id)?
+                .get_param_info_by_id(2)?
+                .type_ref
+                .clone(),
+        ),
+        _ => None,
+    }
+}
+
 fn check_local_stat(
     context: &mut DiagnosticContext,
     semantic_model: &SemanticModel,
@@ -2,7 +2,10 @@ use std::collections::HashSet;
 
 use emmylua_parser::{LuaAst, LuaAstNode, LuaIndexExpr, LuaIndexKey, LuaVarExpr};
 
-use crate::{DiagnosticCode, InferFailReason, LuaMemberKey, LuaType, SemanticModel};
+use crate::{
+    get_meta_method_operators, DiagnosticCode, InferFailReason, LuaMemberKey,
+    LuaOperatorMetaMethod, LuaType, SemanticModel,
+};
 
 use super::{humanize_lint_type, Checker, DiagnosticContext};
 
@@ -141,6 +144,18 @@ fn is_valid_member(
         return Some(());
     }
 
+    // assigning a field which does not exist calls `__newindex`
+    if code == DiagnosticCode::InjectField
+        && !get_meta_method_operators(
+            semantic_model.get_db(),
+            prefix_typ,
+            LuaOperatorMetaMethod::NewIndex,
+        )
+        .is_empty()
+    {
+        return Some(());
+    }
+
     match prefix_typ {
         LuaType::Global => return Some(()),
         LuaType::Userdata => return Some(()),
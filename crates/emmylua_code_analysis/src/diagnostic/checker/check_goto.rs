@@ -0,0 +1,115 @@
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaGotoStat, LuaLabelStat, LuaStat};
+
+use crate::{find_goto_label, DiagnosticCode, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct CheckGotoChecker;
+
+impl Checker for CheckGotoChecker {
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::RedefinedLabel,
+        DiagnosticCode::UndefinedLabel,
+        DiagnosticCode::JumpIntoLocalScope,
+    ];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for label_stat in root.descendants::<LuaLabelStat>() {
+            check_redefined_label(context, &label_stat);
+        }
+
+        for goto_stat in root.descendants::<LuaGotoStat>() {
+            check_goto_stat(context, &goto_stat);
+        }
+    }
+}
+
+fn check_redefined_label(context: &mut DiagnosticContext, label_stat: &LuaLabelStat) -> Option<()> {
+    let name_token = label_stat.get_label_name_token()?;
+    let name = name_token.get_name_text();
+    let is_redefined = label_stat
+        .syntax()
+        .siblings(rowan::Direction::Prev)
+        .skip(1)
+        .filter_map(LuaLabelStat::cast)
+        .any(|prev_label| {
+            prev_label
+                .get_label_name_token()
+                .is_some_and(|token| token.get_name_text() == name)
+        });
+    if is_redefined {
+        context.add_diagnostic(
+            DiagnosticCode::RedefinedLabel,
+            name_token.get_range(),
+            t!(
+                "Label `%{name}` already exists in the same block",
+                name = name
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    Some(())
+}
+
+fn check_goto_stat(context: &mut DiagnosticContext, goto_stat: &LuaGotoStat) -> Option<()> {
+    let name_token = goto_stat.get_label_name_token()?;
+    let name = name_token.get_name_text();
+    let Some(label_stat) = find_goto_label(goto_stat) else {
+        context.add_diagnostic(
+            DiagnosticCode::UndefinedLabel,
+            name_token.get_range(),
+            t!("Label `%{name}` not found", name = name).to_string(),
+            None,
+        );
+        return Some(());
+    };
+
+    // jumping backward never enters the scope of a local, and the scope of a local ends at the
+    // last non-void statement of its block
+    let goto_position = goto_stat.get_position();
+    if label_stat.get_position() < goto_position || is_label_at_block_end(&label_stat) {
+        return Some(());
+    }
+
+    let local_name = label_stat
+        .syntax()
+        .siblings(rowan::Direction::Prev)
+        .skip(1)
+        .filter_map(LuaStat::cast)
+        .take_while(|stat| stat.get_position() > goto_position)
+        .find_map(|stat| get_last_local_name(&stat))?;
+    context.add_diagnostic(
+        DiagnosticCode::JumpIntoLocalScope,
+        goto_stat.get_range(),
+        t!(
+            "`goto %{label}` jumps into the scope of local `%{name}`",
+            label = name,
+            name = local_name
+        )
+        .to_string(),
+        None,
+    );
+
+    Some(())
+}
+
+fn is_label_at_block_end(label_stat: &LuaLabelStat) -> bool {
+    label_stat
+        .syntax()
+        .siblings(rowan::Direction::Next)
+        .skip(1)
+        .filter_map(LuaStat::cast)
+        .all(|stat| matches!(stat, LuaStat::LabelStat(_) | LuaStat::EmptyStat(_)))
+}
+
+fn get_last_local_name(stat: &LuaStat) -> Option<String> {
+    let local_name = match stat {
+        LuaStat::LocalStat(local_stat) => local_stat.get_local_name_list().last()?,
+        LuaStat::LocalFuncStat(local_func_stat) => local_func_stat.get_local_name()?,
+        _ => return None,
+    };
+    Some(local_name.get_name_token()?.get_name_text().to_string())
+}
@@ -90,7 +90,7 @@ fn check_missing_return(
             }
             real_min_len
         }
-        LuaType::Nil | LuaType::Any | LuaType::Unknown => 0,
+        LuaType::Nil | LuaType::Any | LuaType::Unknown | LuaType::Never => 0,
         _ if return_type.is_nullable() => 0,
         _ => 1,
     };
@@ -1,4 +1,4 @@
-use emmylua_codestyle::check_code_style;
+use emmylua_code_style::check_code_style;
 use rowan::TextRange;
 
 use crate::{DiagnosticCode, SemanticModel};
@@ -14,7 +14,11 @@ impl Checker for CodeStyleCheckChecker {
         let document = semantic_model.get_document();
         let file_path = document.get_file_path();
         let text = document.get_text();
-        let result = check_code_style(&file_path.to_string_lossy().to_string(), text);
+        let result = check_code_style(
+            file_path.to_string_lossy().as_ref(),
+            text,
+            semantic_model.get_emmyrc().get_language_level(),
+        );
         for diagnostic in result {
             let (Some(start), Some(end)) = (
                 document.get_offset(
@@ -0,0 +1,298 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaLanguageLevel};
+use rowan::TextRange;
+
+use crate::{DiagnosticCode, LuaType, LuaUnionType, SemanticModel, VariadicType};
+
+use super::{humanize_lint_type, Checker, DiagnosticContext};
+
+pub struct FormatStringChecker;
+
+impl Checker for FormatStringChecker {
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::FormatStringMismatch,
+        DiagnosticCode::RedundantFormatArg,
+    ];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_call_expr(context, semantic_model, &call_expr);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatArgKind {
+    // c d i o u x X
+    Integer,
+    // a A e E f F g G
+    Number,
+    // q
+    Literal,
+    // s p
+    Any,
+}
+
+#[derive(Debug)]
+struct FormatSpec {
+    text: String,
+    kind: FormatArgKind,
+}
+
+fn check_call_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: &LuaCallExpr,
+) -> Option<()> {
+    let prefix_expr = call_expr.get_prefix_expr()?;
+    let LuaType::Signature(signature_id) = semantic_model.infer_expr(prefix_expr.clone()).ok()?
+    else {
+        return Some(());
+    };
+    let signature = semantic_model
+        .get_db()
+        .get_signature_index()
+        .get(&signature_id)?;
+    let format_param = signature.format_param?;
+
+    // align the call arguments with the parameters of the signature
+    let mut arg_exprs = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    match (call_expr.is_colon_call(), signature.is_colon_define) {
+        (true, false) => {
+            let LuaExpr::IndexExpr(index_expr) = prefix_expr else {
+                return Some(());
+            };
+            arg_exprs.insert(0, index_expr.get_prefix_expr()?);
+        }
+        (false, true) => {
+            if arg_exprs.is_empty() {
+                return Some(());
+            }
+            arg_exprs.remove(0);
+        }
+        _ => {}
+    }
+
+    let format_expr = arg_exprs.get(format_param)?;
+    let format_text = match semantic_model.infer_expr(format_expr.clone()).ok()? {
+        LuaType::StringConst(text) | LuaType::DocStringConst(text) => text,
+        _ => return Some(()),
+    };
+
+    let level = semantic_model.get_emmyrc().get_language_level();
+    let specs = match parse_format_specs(&format_text, level) {
+        Ok(specs) => specs,
+        Err(spec) => {
+            context.add_diagnostic(
+                DiagnosticCode::FormatStringMismatch,
+                format_expr.get_range(),
+                t!("Invalid conversion `%{spec}` in format string", spec = spec).to_string(),
+                None,
+            );
+            return Some(());
+        }
+    };
+
+    let (value_types, is_variadic) =
+        infer_value_types(semantic_model, &arg_exprs[format_param + 1..])?;
+    for (spec, (value_type, range)) in specs.iter().zip(value_types.iter()) {
+        check_spec_type(context, semantic_model, spec, value_type, *range);
+    }
+
+    if is_variadic {
+        return Some(());
+    }
+
+    if specs.len() > value_types.len() {
+        context.add_diagnostic(
+            DiagnosticCode::FormatStringMismatch,
+            format_expr.get_range(),
+            t!(
+                "Format string expects %{num} arguments but found %{found_num}",
+                num = specs.len(),
+                found_num = value_types.len()
+            )
+            .to_string(),
+            None,
+        );
+    } else {
+        for (_, range) in value_types[specs.len()..].iter() {
+            context.add_diagnostic(
+                DiagnosticCode::RedundantFormatArg,
+                *range,
+                t!("Argument is not used by the format string").to_string(),
+                None,
+            );
+        }
+    }
+
+    Some(())
+}
+
+/// Infer the types of the values passed after the format string. The second element of the
+/// result is true when the last expression produces an unknown number of values.
+fn infer_value_types(
+    semantic_model: &SemanticModel,
+    value_exprs: &[LuaExpr],
+) -> Option<(Vec<(LuaType, TextRange)>, bool)> {
+    let mut value_types = Vec::new();
+    for (idx, value_expr) in value_exprs.iter().enumerate() {
+        let range = value_expr.get_range();
+        let value_type = semantic_model
+            .infer_expr(value_expr.clone())
+            .unwrap_or(LuaType::Unknown);
+        let LuaType::Variadic(variadic) = value_type else {
+            value_types.push((value_type, range));
+            continue;
+        };
+
+        if idx + 1 < value_exprs.len() {
+            let first_type = variadic.get_type(0).cloned().unwrap_or(LuaType::Nil);
+            value_types.push((first_type, range));
+            continue;
+        }
+
+        match variadic.as_ref() {
+            VariadicType::Base(base) => {
+                value_types.push((base.clone(), range));
+                return Some((value_types, true));
+            }
+            VariadicType::Multi(types) => {
+                for typ in types {
+                    if let LuaType::Variadic(_) = typ {
+                        return Some((value_types, true));
+                    }
+                    value_types.push((typ.clone(), range));
+                }
+            }
+        }
+    }
+
+    Some((value_types, false))
+}
+
+fn check_spec_type(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    spec: &FormatSpec,
+    value_type: &LuaType,
+    range: TextRange,
+) -> Option<()> {
+    if value_type.is_any() || value_type.is_unknown() {
+        return Some(());
+    }
+
+    let expected_type = match spec.kind {
+        FormatArgKind::Integer => LuaType::Integer,
+        FormatArgKind::Number => LuaType::Number,
+        FormatArgKind::Literal => LuaType::Union(
+            LuaUnionType::new(vec![
+                LuaType::String,
+                LuaType::Number,
+                LuaType::Boolean,
+                LuaType::Nil,
+            ])
+            .into(),
+        ),
+        FormatArgKind::Any => return Some(()),
+    };
+
+    let is_mismatch = match (spec.kind, value_type) {
+        (FormatArgKind::Integer, LuaType::FloatConst(f)) => f.fract() != 0.0,
+        // `number` is commonly used for values that hold integers
+        (FormatArgKind::Integer, _) => semantic_model
+            .type_check(&LuaType::Number, value_type)
+            .is_err(),
+        _ => semantic_model
+            .type_check(&expected_type, value_type)
+            .is_err(),
+    };
+    if !is_mismatch {
+        return Some(());
+    }
+
+    let db = semantic_model.get_db();
+    context.add_diagnostic(
+        DiagnosticCode::FormatStringMismatch,
+        range,
+        t!(
+            "Format specifier `%{spec}` expects `%{expected}` but found `%{found}`",
+            spec = spec.text,
+            expected = humanize_lint_type(db, &expected_type),
+            found = humanize_lint_type(db, value_type)
+        )
+        .to_string(),
+        None,
+    );
+
+    Some(())
+}
+
+/// Parse the conversion specifiers of a format string following the rules of `string.format` in
+/// the language level, returns the text of the first invalid specifier on failure.
+fn parse_format_specs(
+    format_text: &str,
+    level: LuaLanguageLevel,
+) -> Result<Vec<FormatSpec>, String> {
+    let is_lua51 = level == LuaLanguageLevel::Lua51;
+    let is_lua54 = matches!(level, LuaLanguageLevel::Lua54 | LuaLanguageLevel::Lua55);
+    let mut specs = Vec::new();
+    let mut chars = format_text.char_indices().peekable();
+    while let Some((start, ch)) = chars.next() {
+        if ch != '%' {
+            continue;
+        }
+
+        if let Some((_, '%')) = chars.peek() {
+            chars.next();
+            continue;
+        }
+
+        let mut has_modifier = false;
+        while let Some((_, '-' | '+' | ' ' | '#' | '0')) = chars.peek() {
+            chars.next();
+            has_modifier = true;
+        }
+
+        let mut width = 0;
+        while let Some((_, '0'..='9')) = chars.peek() {
+            chars.next();
+            width += 1;
+            has_modifier = true;
+        }
+
+        let mut precision = 0;
+        if let Some((_, '.')) = chars.peek() {
+            chars.next();
+            has_modifier = true;
+            while let Some((_, '0'..='9')) = chars.peek() {
+                chars.next();
+                precision += 1;
+            }
+        }
+
+        let Some((end, conversion)) = chars.next() else {
+            return Err(format_text[start..].to_string());
+        };
+        let text = format_text[start..end + conversion.len_utf8()].to_string();
+        if width > 2 || precision > 2 {
+            return Err(text);
+        }
+
+        let kind = match conversion {
+            'c' | 'd' | 'i' | 'o' | 'u' | 'x' | 'X' => FormatArgKind::Integer,
+            // Lua 5.1 has no hexadecimal float and no `%F`
+            'a' | 'A' | 'F' if is_lua51 => return Err(text),
+            'a' | 'A' | 'e' | 'E' | 'f' | 'F' | 'g' | 'G' => FormatArgKind::Number,
+            // Lua 5.4 rejects `%q` with flags, width or precision
+            'q' if is_lua54 && has_modifier => return Err(text),
+            'q' => FormatArgKind::Literal,
+            's' => FormatArgKind::Any,
+            'p' if is_lua54 => FormatArgKind::Any,
+            _ => return Err(text),
+        };
+        specs.push(FormatSpec { text, kind });
+    }
+
+    Ok(specs)
+}
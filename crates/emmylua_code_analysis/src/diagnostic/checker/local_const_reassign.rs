@@ -1,6 +1,11 @@
-use crate::{DiagnosticCode, LocalAttribute, LuaDeclExtra, LuaDeclId, SemanticModel};
+use emmylua_parser::{LuaAssignStat, LuaAstNode, LuaVarExpr};
 
-use super::{Checker, DiagnosticContext};
+use crate::{
+    DiagnosticCode, EmmyrcLuaVersion, LocalAttribute, LuaDeclExtra, LuaDeclId, LuaDeclarationTree,
+    SemanticModel,
+};
+
+use super::{get_global_decl_state, Checker, DiagnosticContext, GlobalDeclState};
 
 pub struct LocalConstReassignChecker;
 
@@ -31,6 +36,43 @@ impl Checker for LocalConstReassignChecker {
                 _ => {}
             }
         }
+
+        check_global_const_reassign(context, semantic_model, decl_tree);
+    }
+}
+
+/// Assigning a name which is declared by `global <const>` or `global <const> *`
+fn check_global_const_reassign(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    decl_tree: &LuaDeclarationTree,
+) {
+    let root = semantic_model.get_root();
+    for assign_stat in root.descendants::<LuaAssignStat>() {
+        let (vars, _) = assign_stat.get_var_and_expr_list();
+        for var in vars {
+            let LuaVarExpr::NameExpr(name_expr) = var else {
+                continue;
+            };
+            let Some(name) = name_expr.get_name_text() else {
+                continue;
+            };
+            if decl_tree
+                .find_local_decl(&name, name_expr.get_position())
+                .is_some_and(|decl| decl.is_local())
+            {
+                continue;
+            }
+
+            if get_global_decl_state(&name_expr) == (GlobalDeclState::Declared { is_const: true }) {
+                context.add_diagnostic(
+                    DiagnosticCode::LocalConstReassign,
+                    name_expr.get_range(),
+                    t!("Cannot reassign to a constant variable").to_string(),
+                    None,
+                );
+            }
+        }
     }
 }
 
@@ -55,6 +97,17 @@ fn check_local_const_reassign(
                         None,
                     );
                 }
+                // for-loop variables are read-only since Lua 5.5
+                LocalAttribute::IterConst
+                    if semantic_model.get_emmyrc().runtime.version == EmmyrcLuaVersion::Lua55 =>
+                {
+                    context.add_diagnostic(
+                        DiagnosticCode::LocalConstReassign,
+                        decl_ref.range.clone(),
+                        t!("Cannot assign to a for-loop variable").to_string(),
+                        None,
+                    );
+                }
                 LocalAttribute::IterConst => {
                     context.add_diagnostic(
                         DiagnosticCode::IterVariableReassign,
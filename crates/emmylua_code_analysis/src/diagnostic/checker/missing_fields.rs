@@ -58,21 +58,24 @@ fn check_table_expr(
 
     let missing_fields = required_fields
         .difference(&current_fields)
-        .map(|s| format!("`{}`", s))
+        .cloned()
         .sorted()
-        .join(", ");
+        .collect::<Vec<_>>();
 
     if !missing_fields.is_empty() {
+        let fields = missing_fields.iter().map(|s| format!("`{}`", s)).join(", ");
+        // the missing field names are attached so that the quick fix can insert them
+        let data = serde_json::to_value(&missing_fields).ok();
         context.add_diagnostic(
             DiagnosticCode::MissingFields,
             expr.get_range(),
             t!(
                 "Missing required fields in type `%{typ}`: %{fields}",
                 typ = humanize_lint_type(&db, &table_type),
-                fields = missing_fields
+                fields = fields
             )
             .to_string(),
-            None,
+            data,
         );
     }
 
@@ -3,6 +3,7 @@ mod analyze_error;
 mod assign_type_mismatch;
 mod await_in_sync;
 mod check_field;
+mod check_goto;
 mod check_param_count;
 mod check_return_count;
 mod circle_doc_class;
@@ -14,10 +15,13 @@ mod duplicate_field;
 mod duplicate_index;
 mod duplicate_require;
 mod duplicate_type;
+mod format_string;
 mod incomplete_signature_doc;
 mod local_const_reassign;
 mod missing_fields;
+mod name_style_check;
 mod need_check_nil;
+mod not_closable;
 mod param_type_check;
 mod redefined_local;
 mod return_type_mismatch;
@@ -27,10 +31,12 @@ mod undefined_doc_param;
 mod undefined_global;
 mod unnecessary_assert;
 mod unnecessary_if;
+mod unreachable_code;
 mod unused;
 
 use emmylua_parser::{
-    LuaAstNode, LuaClosureExpr, LuaComment, LuaReturnStat, LuaStat, LuaSyntaxKind,
+    LuaAstNode, LuaClosureExpr, LuaComment, LuaNameExpr, LuaReturnStat, LuaStat, LuaSyntaxKind,
+    LuaVarExpr,
 };
 use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString};
 use rowan::TextRange;
@@ -65,6 +71,7 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     run_check::<syntax_error::SyntaxErrorChecker>(context, semantic_model);
     run_check::<analyze_error::AnalyzeErrorChecker>(context, semantic_model);
     run_check::<unused::UnusedChecker>(context, semantic_model);
+    run_check::<unreachable_code::UnreachableCodeChecker>(context, semantic_model);
     run_check::<deprecated::DeprecatedChecker>(context, semantic_model);
     run_check::<undefined_global::UndefinedGlobalChecker>(context, semantic_model);
     run_check::<unnecessary_assert::UnnecessaryAssertChecker>(context, semantic_model);
@@ -76,6 +83,7 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     run_check::<param_type_check::ParamTypeCheckChecker>(context, semantic_model);
     run_check::<need_check_nil::NeedCheckNilChecker>(context, semantic_model);
     run_check::<code_style_check::CodeStyleCheckChecker>(context, semantic_model);
+    run_check::<name_style_check::NameStyleCheckChecker>(context, semantic_model);
     run_check::<return_type_mismatch::ReturnTypeMismatch>(context, semantic_model);
     run_check::<undefined_doc_param::UndefinedDocParamChecker>(context, semantic_model);
     run_check::<redefined_local::RedefinedLocalChecker>(context, semantic_model);
@@ -91,6 +99,9 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     run_check::<check_param_count::CheckParamCountChecker>(context, semantic_model);
     run_check::<duplicate_field::DuplicateFieldChecker>(context, semantic_model);
     run_check::<duplicate_index::DuplicateIndexChecker>(context, semantic_model);
+    run_check::<check_goto::CheckGotoChecker>(context, semantic_model);
+    run_check::<not_closable::NotClosableChecker>(context, semantic_model);
+    run_check::<format_string::FormatStringChecker>(context, semantic_model);
 
     run_check::<code_style::non_literal_expressions_in_assert::NonLiteralExpressionsInAssertChecker>(
         context,
@@ -268,6 +279,72 @@ pub fn get_return_stats(closure_expr: &LuaClosureExpr) -> impl Iterator<Item = L
         })
 }
 
+/// How the `global` declarations of Lua 5.5 which are visible at a free name declare it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlobalDeclState {
+    /// No `global` declaration is visible, every free name is a global
+    Implicit,
+    Declared {
+        is_const: bool,
+    },
+    /// Other names are declared, so this name is an error
+    Undeclared,
+}
+
+pub fn get_global_decl_state(name_expr: &LuaNameExpr) -> GlobalDeclState {
+    let Some(name) = name_expr.get_name_text() else {
+        return GlobalDeclState::Implicit;
+    };
+
+    let mut collective_state = None;
+    let mut has_global_decl = false;
+    for stat in name_expr.ancestors::<LuaStat>() {
+        for node in stat.syntax().siblings(rowan::Direction::Prev) {
+            let is_self = &node == stat.syntax();
+            match LuaStat::cast(node) {
+                Some(LuaStat::GlobalStat(global_stat)) if !is_self => {
+                    has_global_decl = true;
+                    if global_stat.is_collective() {
+                        if collective_state.is_none() {
+                            let is_const = global_stat
+                                .get_attrib()
+                                .is_some_and(|attrib| attrib.is_const());
+                            collective_state = Some(GlobalDeclState::Declared { is_const });
+                        }
+                        continue;
+                    }
+
+                    let (vars, _) = global_stat.get_var_and_expr_list();
+                    for var in vars {
+                        if var.get_name_text().as_deref() == Some(name.as_str()) {
+                            let is_const = global_stat
+                                .get_var_attrib(&var)
+                                .is_some_and(|attrib| attrib.is_const());
+                            return GlobalDeclState::Declared { is_const };
+                        }
+                    }
+                }
+                // the name of `global function f` is declared before its body
+                Some(LuaStat::FuncStat(func_stat)) if func_stat.is_global_decl() => {
+                    has_global_decl = true;
+                    if let Some(LuaVarExpr::NameExpr(func_name)) = func_stat.get_func_name() {
+                        if func_name.get_name_text().as_deref() == Some(name.as_str()) {
+                            return GlobalDeclState::Declared { is_const: false };
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    match collective_state {
+        Some(state) => state,
+        None if has_global_decl => GlobalDeclState::Undeclared,
+        None => GlobalDeclState::Implicit,
+    }
+}
+
 pub fn humanize_lint_type(db: &DbIndex, typ: &LuaType) -> String {
     match typ {
         LuaType::Ref(type_decl_id) => type_decl_id.get_simple_name().to_string(),
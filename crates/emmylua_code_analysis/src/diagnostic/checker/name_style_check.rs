@@ -0,0 +1,447 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaAstToken, LuaBlock, LuaChunk, LuaDocFieldKey, LuaExpr, LuaIndexKey,
+    LuaNameToken, LuaSyntaxId, LuaVarExpr, VisibilityKind,
+};
+use regex::Regex;
+
+use crate::{
+    DbIndex, DiagnosticCode, EmmyrcNameStyle, EmmyrcNameStyleKind, EmmyrcNameStyleRule, LuaDeclId,
+    LuaMemberId, LuaMemberOwner, LuaSemanticDeclId, SemanticModel,
+};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct NameStyleCheckChecker;
+
+impl Checker for NameStyleCheckChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::NameStyleCheck];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let emmyrc = semantic_model.get_emmyrc();
+        let mut name_style = NameStyleRules::new(&emmyrc.name_style);
+        let root = semantic_model.get_root().clone();
+        for node in root.descendants::<LuaAst>() {
+            match node {
+                LuaAst::LuaLocalStat(local_stat) => {
+                    let kind = if is_module_scope(local_stat.get_parent::<LuaBlock>()) {
+                        NameKind::ModuleLocal
+                    } else {
+                        NameKind::Local
+                    };
+                    let value_exprs = local_stat.get_value_exprs().collect::<Vec<_>>();
+                    for (i, local_name) in local_stat.get_local_name_list().enumerate() {
+                        let kind = match value_exprs.get(i) {
+                            Some(LuaExpr::ClosureExpr(_)) => NameKind::Function,
+                            _ => kind,
+                        };
+                        if let Some(name_token) = local_name.get_name_token() {
+                            check_name(context, &mut name_style, kind, name_token);
+                        }
+                    }
+                }
+                LuaAst::LuaLocalFuncStat(local_func_stat) => {
+                    if let Some(name_token) = local_func_stat
+                        .get_local_name()
+                        .and_then(|local_name| local_name.get_name_token())
+                    {
+                        check_name(context, &mut name_style, NameKind::Function, name_token);
+                    }
+                }
+                LuaAst::LuaForStat(for_stat) => {
+                    if let Some(name_token) = for_stat.get_var_name() {
+                        check_name(context, &mut name_style, NameKind::Local, name_token);
+                    }
+                }
+                LuaAst::LuaForRangeStat(for_range_stat) => {
+                    for name_token in for_range_stat.get_var_name_list() {
+                        check_name(context, &mut name_style, NameKind::Local, name_token);
+                    }
+                }
+                LuaAst::LuaParamName(param_name) => {
+                    if let Some(name_token) = param_name.get_name_token() {
+                        check_name(context, &mut name_style, NameKind::Parameter, name_token);
+                    }
+                }
+                LuaAst::LuaFuncStat(func_stat) => {
+                    if let Some(func_name) = func_stat.get_func_name() {
+                        check_var_name(
+                            context,
+                            semantic_model,
+                            &mut name_style,
+                            func_name,
+                            NameKind::Function,
+                        );
+                    }
+                }
+                LuaAst::LuaAssignStat(assign_stat) => {
+                    let (vars, value_exprs) = assign_stat.get_var_and_expr_list();
+                    for (i, var) in vars.into_iter().enumerate() {
+                        let kind = match value_exprs.get(i) {
+                            Some(LuaExpr::ClosureExpr(_)) => NameKind::Function,
+                            _ => NameKind::Global,
+                        };
+                        check_var_name(context, semantic_model, &mut name_style, var, kind);
+                    }
+                }
+                LuaAst::LuaGlobalStat(global_stat) => {
+                    let (vars, _) = global_stat.get_var_and_expr_list();
+                    for var in vars {
+                        if let Some(name_token) = var.get_name_token() {
+                            check_name(context, &mut name_style, NameKind::Global, name_token);
+                        }
+                    }
+                }
+                LuaAst::LuaTableField(table_field) => {
+                    let Some(LuaIndexKey::Name(name_token)) = table_field.get_field_key() else {
+                        continue;
+                    };
+                    let kind = match table_field.get_value_expr() {
+                        Some(LuaExpr::ClosureExpr(_)) => NameKind::Function,
+                        _ => get_member_kind(semantic_model, table_field.get_syntax_id()),
+                    };
+                    check_name(context, &mut name_style, kind, name_token);
+                }
+                LuaAst::LuaDocTagField(field_tag) => {
+                    if let Some(LuaDocFieldKey::Name(name_token)) = field_tag.get_field_key() {
+                        let kind = get_member_kind(semantic_model, field_tag.get_syntax_id());
+                        check_name(context, &mut name_style, kind, name_token);
+                    }
+                }
+                LuaAst::LuaDocTagClass(class_tag) => {
+                    if let Some(name_token) = class_tag.get_name_token() {
+                        check_name(context, &mut name_style, NameKind::Class, name_token);
+                    }
+                }
+                LuaAst::LuaDocTagEnum(enum_tag) => {
+                    if let Some(name_token) = enum_tag.get_name_token() {
+                        check_name(context, &mut name_style, NameKind::Class, name_token);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NameKind {
+    Local,
+    ModuleLocal,
+    Global,
+    Function,
+    Parameter,
+    Class,
+    Field,
+    PrivateField,
+}
+
+struct NameStyleRules<'a> {
+    name_style: &'a EmmyrcNameStyle,
+    /// The compiled patterns, an invalid pattern accepts every name
+    regexes: HashMap<&'a str, Option<Regex>>,
+}
+
+impl<'a> NameStyleRules<'a> {
+    fn new(name_style: &'a EmmyrcNameStyle) -> Self {
+        Self {
+            name_style,
+            regexes: HashMap::new(),
+        }
+    }
+
+    fn get_rules(&self, kind: NameKind) -> &'a [EmmyrcNameStyleRule] {
+        let name_style = self.name_style;
+        match kind {
+            NameKind::ModuleLocal if !name_style.module_local_name.is_empty() => {
+                &name_style.module_local_name
+            }
+            NameKind::Local | NameKind::ModuleLocal => &name_style.local_name,
+            NameKind::Global => &name_style.global_name,
+            NameKind::Function => &name_style.function_name,
+            NameKind::Parameter => &name_style.parameter_name,
+            NameKind::Class => &name_style.class_name,
+            NameKind::PrivateField if !name_style.private_field_name.is_empty() => {
+                &name_style.private_field_name
+            }
+            NameKind::Field | NameKind::PrivateField => &name_style.field_name,
+        }
+    }
+
+    fn is_match(&mut self, rule: &'a EmmyrcNameStyleRule, name: &str) -> bool {
+        match rule {
+            EmmyrcNameStyleRule::Style(style) => is_name_style(name, *style),
+            EmmyrcNameStyleRule::Pattern { pattern } => self
+                .regexes
+                .entry(pattern.as_str())
+                .or_insert_with(|| Regex::new(&format!("^(?:{})$", pattern)).ok())
+                .as_ref()
+                .is_none_or(|regex| regex.is_match(name)),
+        }
+    }
+}
+
+fn check_name(
+    context: &mut DiagnosticContext,
+    name_style: &mut NameStyleRules,
+    kind: NameKind,
+    name_token: LuaNameToken,
+) -> Option<()> {
+    let name = name_token.get_name_text();
+    if matches!(name, "_" | "self") {
+        return Some(());
+    }
+
+    let rules = name_style.get_rules(kind);
+    if rules.is_empty() || rules.iter().any(|rule| name_style.is_match(rule, name)) {
+        return Some(());
+    }
+
+    let styles = rules
+        .iter()
+        .map(|rule| match rule {
+            EmmyrcNameStyleRule::Style(style) => get_style_name(*style).to_string(),
+            EmmyrcNameStyleRule::Pattern { pattern } => format!("/{}/", pattern),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    // the first preset style is used to suggest a new name
+    let suggestion = rules.iter().find_map(|rule| match rule {
+        EmmyrcNameStyleRule::Style(style) => Some(convert_name_style(name, *style)),
+        _ => None,
+    });
+    let data = suggestion
+        .filter(|suggestion| suggestion != name && !suggestion.trim_start_matches('_').is_empty())
+        .and_then(|suggestion| serde_json::to_value(suggestion).ok());
+
+    context.add_diagnostic(
+        DiagnosticCode::NameStyleCheck,
+        name_token.get_range(),
+        t!(
+            "Name `%{name}` does not match the naming style: %{styles}",
+            name = name,
+            styles = styles
+        )
+        .to_string(),
+        data,
+    );
+    Some(())
+}
+
+/// Checks the name of a function statement or an assignment, only the assignments which declare
+/// a global or which are the first declaration of a member are checked
+fn check_var_name(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    name_style: &mut NameStyleRules,
+    var: LuaVarExpr,
+    kind: NameKind,
+) -> Option<()> {
+    match var {
+        LuaVarExpr::NameExpr(name_expr) => {
+            let name_token = name_expr.get_name_token()?;
+            let decl_id = LuaDeclId::new(semantic_model.get_file_id(), name_token.get_position());
+            let decl = semantic_model
+                .get_db()
+                .get_decl_index()
+                .get_decl(&decl_id)?;
+            if decl.is_global() {
+                check_name(context, name_style, kind, name_token);
+            }
+        }
+        LuaVarExpr::IndexExpr(index_expr) => {
+            let LuaIndexKey::Name(name_token) = index_expr.get_index_key()? else {
+                return None;
+            };
+            let member_id =
+                LuaMemberId::new(index_expr.get_syntax_id(), semantic_model.get_file_id());
+            if !is_defining_member(semantic_model.get_db(), member_id) {
+                return None;
+            }
+            let kind = match get_member_kind(semantic_model, index_expr.get_syntax_id()) {
+                NameKind::PrivateField => NameKind::PrivateField,
+                _ if matches!(kind, NameKind::Function) => NameKind::Function,
+                _ => NameKind::Field,
+            };
+            check_name(context, name_style, kind, name_token);
+        }
+    }
+
+    Some(())
+}
+
+// the assignment is the first declaration of the member, and the owner is not declared by the
+// std or a library, whose names the user can not change
+fn is_defining_member(db: &DbIndex, member_id: LuaMemberId) -> bool {
+    let member_index = db.get_member_index();
+    let (Some(owner), Some(item)) = (
+        member_index.get_current_owner(&member_id),
+        member_index.get_member_item_by_member_id(member_id),
+    ) else {
+        return false;
+    };
+
+    let first_member_id = item
+        .get_member_ids()
+        .into_iter()
+        .filter_map(|id| member_index.get_member(&id))
+        .min_by_key(|member| member.get_sort_key())
+        .map(|member| member.get_id());
+    if first_member_id != Some(member_id) {
+        return false;
+    }
+
+    let module_index = db.get_module_index();
+    let owner_file_ids = match owner {
+        LuaMemberOwner::Type(type_decl_id) => db
+            .get_type_index()
+            .get_type_decl(type_decl_id)
+            .map(|type_decl| {
+                type_decl
+                    .get_locations()
+                    .iter()
+                    .map(|location| location.file_id)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        LuaMemberOwner::GlobalPath(global_id) => {
+            let name = global_id.get_name();
+            let root_name = name.split('.').next().unwrap_or(name);
+            db.get_global_index()
+                .get_global_decl_ids(root_name)
+                .map(|decl_ids| decl_ids.iter().map(|decl_id| decl_id.file_id).collect())
+                .unwrap_or_default()
+        }
+        LuaMemberOwner::Element(range) => vec![range.file_id],
+        LuaMemberOwner::LocalUnresolve => Vec::new(),
+    };
+    !owner_file_ids
+        .iter()
+        .any(|file_id| module_index.is_std(file_id) || module_index.is_library(file_id))
+}
+
+fn get_member_kind(semantic_model: &SemanticModel, syntax_id: LuaSyntaxId) -> NameKind {
+    let member_id = LuaMemberId::new(syntax_id, semantic_model.get_file_id());
+    let is_private = semantic_model
+        .get_db()
+        .get_property_index()
+        .get_property(&LuaSemanticDeclId::Member(member_id))
+        .is_some_and(|property| property.visibility == Some(VisibilityKind::Private));
+    if is_private {
+        NameKind::PrivateField
+    } else {
+        NameKind::Field
+    }
+}
+
+fn is_module_scope(block: Option<LuaBlock>) -> bool {
+    block.is_some_and(|block| block.get_parent::<LuaChunk>().is_some())
+}
+
+fn get_style_name(style: EmmyrcNameStyleKind) -> &'static str {
+    match style {
+        EmmyrcNameStyleKind::SnakeCase => "snake_case",
+        EmmyrcNameStyleKind::CamelCase => "camelCase",
+        EmmyrcNameStyleKind::PascalCase => "PascalCase",
+        EmmyrcNameStyleKind::UpperCase => "UPPER_CASE",
+    }
+}
+
+fn is_name_style(name: &str, style: EmmyrcNameStyleKind) -> bool {
+    let name = name.trim_start_matches('_');
+    let Some(first) = name.chars().next() else {
+        return true;
+    };
+
+    match style {
+        EmmyrcNameStyleKind::SnakeCase => {
+            !first.is_numeric()
+                && name
+                    .chars()
+                    .all(|c| c == '_' || c.is_numeric() || !c.is_uppercase())
+        }
+        EmmyrcNameStyleKind::UpperCase => {
+            !first.is_numeric()
+                && name
+                    .chars()
+                    .all(|c| c == '_' || c.is_numeric() || !c.is_lowercase())
+        }
+        EmmyrcNameStyleKind::CamelCase => !first.is_uppercase() && !name.contains('_'),
+        EmmyrcNameStyleKind::PascalCase => first.is_uppercase() && !name.contains('_'),
+    }
+}
+
+/// Converts a name to the style, the leading underscores are kept
+fn convert_name_style(name: &str, style: EmmyrcNameStyleKind) -> String {
+    let body = name.trim_start_matches('_');
+    let prefix = &name[..name.len() - body.len()];
+    let words = split_words(body);
+    let converted = match style {
+        EmmyrcNameStyleKind::SnakeCase => words
+            .iter()
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        EmmyrcNameStyleKind::UpperCase => words
+            .iter()
+            .map(|word| word.to_uppercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        EmmyrcNameStyleKind::CamelCase => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.to_lowercase()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect(),
+        EmmyrcNameStyleKind::PascalCase => words.iter().map(|word| capitalize(word)).collect(),
+    };
+
+    format!("{}{}", prefix, converted)
+}
+
+/// Splits a name at the underscores and the case changes, `HTTPServer_port` is split into
+/// `HTTP`, `Server` and `port`
+fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if !prev.is_uppercase() || next_is_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
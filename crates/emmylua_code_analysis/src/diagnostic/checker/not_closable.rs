@@ -0,0 +1,115 @@
+use emmylua_parser::{LuaAstNode, LuaLocalStat};
+
+use crate::{
+    get_meta_method_operators, DiagnosticCode, LuaOperatorMetaMethod, LuaType, SemanticModel,
+};
+
+use super::{humanize_lint_type, Checker, DiagnosticContext};
+
+pub struct NotClosableChecker;
+
+impl Checker for NotClosableChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::NotClosable];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for local_stat in root.descendants::<LuaLocalStat>() {
+            check_local_stat(context, semantic_model, &local_stat);
+        }
+    }
+}
+
+fn check_local_stat(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    local_stat: &LuaLocalStat,
+) -> Option<()> {
+    let local_names = local_stat.get_local_name_list().collect::<Vec<_>>();
+    if !local_names.iter().any(|local_name| {
+        local_name
+            .get_attrib()
+            .is_some_and(|attrib| attrib.is_close())
+    }) {
+        return Some(());
+    }
+
+    let value_exprs = local_stat.get_value_exprs().collect::<Vec<_>>();
+    let value_types = semantic_model
+        .infer_multi_value_adjusted_expression_types(&value_exprs, Some(local_names.len()))?;
+    for (local_name, (value_type, range)) in local_names.iter().zip(value_types) {
+        if !local_name
+            .get_attrib()
+            .is_some_and(|attrib| attrib.is_close())
+        {
+            continue;
+        }
+
+        if !is_not_closable(semantic_model, &value_type) {
+            continue;
+        }
+
+        let name_token = local_name.get_name_token()?;
+        context.add_diagnostic(
+            DiagnosticCode::NotClosable,
+            range,
+            t!(
+                "Close variable `%{name}` gets a value of type `%{typ}` which has no `__close` metamethod",
+                name = name_token.get_name_text(),
+                typ = humanize_lint_type(semantic_model.get_db(), &value_type)
+            )
+            .to_string(),
+            None,
+        );
+    }
+
+    Some(())
+}
+
+/// Whether the value can not be closed, a close variable accepts `nil`, `false` and the values
+/// whose metatable has `__close`
+fn is_not_closable(semantic_model: &SemanticModel, typ: &LuaType) -> bool {
+    match typ {
+        LuaType::Union(union) => union
+            .get_types()
+            .iter()
+            .any(|typ| is_not_closable(semantic_model, typ)),
+        LuaType::String
+        | LuaType::StringConst(_)
+        | LuaType::DocStringConst(_)
+        | LuaType::Number
+        | LuaType::FloatConst(_)
+        | LuaType::Integer
+        | LuaType::IntegerConst(_)
+        | LuaType::DocIntegerConst(_)
+        | LuaType::BooleanConst(true)
+        | LuaType::DocBooleanConst(true)
+        | LuaType::Function
+        | LuaType::DocFunction(_)
+        | LuaType::Signature(_) => true,
+        LuaType::Ref(type_id) | LuaType::Def(type_id) => {
+            let db = semantic_model.get_db();
+            if !db
+                .get_type_index()
+                .get_type_decl(type_id)
+                .is_some_and(|type_decl| type_decl.is_class())
+            {
+                return false;
+            }
+
+            get_meta_method_operators(db, typ, LuaOperatorMetaMethod::Close).is_empty()
+                && !has_close_member(semantic_model, typ)
+        }
+        _ => false,
+    }
+}
+
+/// A class which is used as its own metatable declares `__close` as a method
+fn has_close_member(semantic_model: &SemanticModel, typ: &LuaType) -> bool {
+    semantic_model
+        .infer_member_infos(typ)
+        .is_some_and(|members| {
+            members
+                .iter()
+                .any(|member| member.key.get_name() == Some("__close"))
+        })
+}
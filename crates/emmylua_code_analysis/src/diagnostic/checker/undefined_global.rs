@@ -1,11 +1,11 @@
 use std::collections::HashSet;
 
-use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaNameExpr};
+use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaGlobalStat, LuaNameExpr};
 use rowan::TextRange;
 
 use crate::{DiagnosticCode, LuaSignatureId, SemanticModel};
 
-use super::{Checker, DiagnosticContext};
+use super::{get_global_decl_state, Checker, DiagnosticContext, GlobalDeclState};
 
 pub struct UndefinedGlobalChecker;
 
@@ -54,12 +54,20 @@ fn check_name_expr(
         return Some(());
     }
 
-    if semantic_model
-        .get_db()
-        .get_global_index()
-        .is_exist_global_decl(&name_text)
-    {
-        return Some(());
+    let decl_state = get_global_decl_state(&name_expr);
+    match decl_state {
+        GlobalDeclState::Declared { .. } => return Some(()),
+        GlobalDeclState::Undeclared if is_global_stat_var(&name_expr) => return Some(()),
+        GlobalDeclState::Implicit => {
+            if semantic_model
+                .get_db()
+                .get_global_index()
+                .is_exist_global_decl(&name_text)
+            {
+                return Some(());
+            }
+        }
+        GlobalDeclState::Undeclared => {}
     }
 
     if context
@@ -85,6 +93,21 @@ fn check_name_expr(
         }
     }
 
+    // the `global` declarations in scope make the other free names an error
+    if decl_state == GlobalDeclState::Undeclared {
+        context.add_diagnostic(
+            DiagnosticCode::UndefinedGlobal,
+            name_range,
+            t!(
+                "global variable %{name} is not declared in this scope",
+                name = name_text
+            )
+            .to_string(),
+            None,
+        );
+        return Some(());
+    }
+
     context.add_diagnostic(
         DiagnosticCode::UndefinedGlobal,
         name_range,
@@ -95,6 +118,13 @@ fn check_name_expr(
     Some(())
 }
 
+fn is_global_stat_var(name_expr: &LuaNameExpr) -> bool {
+    let Some(global_stat) = name_expr.get_parent::<LuaGlobalStat>() else {
+        return false;
+    };
+    global_stat.get_var_and_expr_list().0.contains(name_expr)
+}
+
 fn check_self_name(semantic_model: &SemanticModel, name_expr: LuaNameExpr) -> Option<()> {
     let closure_expr = name_expr.ancestors::<LuaClosureExpr>();
     for closure_expr in closure_expr {
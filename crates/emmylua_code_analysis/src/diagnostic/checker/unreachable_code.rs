@@ -0,0 +1,113 @@
+use emmylua_parser::{LuaAstNode, LuaBlock, LuaCallExprStat, LuaExpr, LuaIfStat, LuaStat};
+use rowan::TextRange;
+
+use crate::{DiagnosticCode, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct UnreachableCodeChecker;
+
+impl Checker for UnreachableCodeChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UnreachableCode];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for block in root.descendants::<LuaBlock>() {
+            for range in get_unreachable_ranges(semantic_model, &block) {
+                context.add_diagnostic(
+                    DiagnosticCode::UnreachableCode,
+                    range,
+                    t!("Unreachable code").to_string(),
+                    None,
+                );
+            }
+        }
+    }
+}
+
+/// The ranges of the statements after a statement which never completes, a label ends the range
+/// because it can be reached by `goto`
+fn get_unreachable_ranges(semantic_model: &SemanticModel, block: &LuaBlock) -> Vec<TextRange> {
+    let mut ranges = Vec::new();
+    let mut unreachable_range: Option<TextRange> = None;
+    let mut is_terminated = false;
+    for stat in block.get_stats() {
+        if matches!(stat, LuaStat::LabelStat(_)) {
+            ranges.extend(unreachable_range.take());
+            is_terminated = false;
+            continue;
+        }
+
+        if is_terminated {
+            let range = stat.get_range();
+            unreachable_range = Some(match unreachable_range {
+                Some(unreachable_range) => unreachable_range.cover(range),
+                None => range,
+            });
+        } else if is_terminate_stat(semantic_model, &stat) {
+            is_terminated = true;
+        }
+    }
+
+    ranges.extend(unreachable_range);
+    ranges
+}
+
+/// Whether the end of the block can not be reached
+fn is_terminate_block(semantic_model: &SemanticModel, block: &LuaBlock) -> bool {
+    let mut is_terminated = false;
+    for stat in block.get_stats() {
+        match stat {
+            LuaStat::LabelStat(_) => is_terminated = false,
+            _ if !is_terminated => is_terminated = is_terminate_stat(semantic_model, &stat),
+            _ => {}
+        }
+    }
+
+    is_terminated
+}
+
+/// Whether the statement after this statement can not be reached
+fn is_terminate_stat(semantic_model: &SemanticModel, stat: &LuaStat) -> bool {
+    match stat {
+        LuaStat::ReturnStat(_)
+        | LuaStat::BreakStat(_)
+        | LuaStat::GotoStat(_)
+        | LuaStat::ContinueStat(_) => true,
+        LuaStat::CallExprStat(call_expr_stat) => is_never_call(semantic_model, call_expr_stat),
+        LuaStat::DoStat(do_stat) => do_stat
+            .get_block()
+            .is_some_and(|block| is_terminate_block(semantic_model, &block)),
+        LuaStat::IfStat(if_stat) => is_terminate_if_stat(semantic_model, if_stat),
+        _ => false,
+    }
+}
+
+/// An `if` statement never completes when it has an `else` and none of its branches completes
+fn is_terminate_if_stat(semantic_model: &SemanticModel, if_stat: &LuaIfStat) -> bool {
+    if if_stat.get_else_clause().is_none() {
+        return false;
+    }
+
+    let is_terminate = |block: Option<LuaBlock>| {
+        block.is_some_and(|block| is_terminate_block(semantic_model, &block))
+    };
+    is_terminate(if_stat.get_block())
+        && if_stat
+            .get_all_clause()
+            .all(|clause| is_terminate(clause.get_block()))
+}
+
+/// Calls `error` or a function which returns `never`, such as `os.exit`
+fn is_never_call(semantic_model: &SemanticModel, call_expr_stat: &LuaCallExprStat) -> bool {
+    let Some(call_expr) = call_expr_stat.get_call_expr() else {
+        return false;
+    };
+    if call_expr.is_error() {
+        return true;
+    }
+
+    semantic_model
+        .infer_expr(LuaExpr::CallExpr(call_expr))
+        .is_ok_and(|typ| typ.is_never())
+}
@@ -0,0 +1,439 @@
+use emmylua_code_style::range_format_code;
+use emmylua_parser::{
+    BinaryOperator, LuaAst, LuaAstNode, LuaCallExpr, LuaClosureExpr, LuaCommentOwner,
+    LuaDocTagParam, LuaKind, LuaNameExpr, LuaParamName, LuaStat, LuaTableExpr, LuaTokenKind,
+    LuaVarExpr,
+};
+use lsp_types::{Range, TextEdit};
+use rowan::{TextRange, TextSize};
+
+use crate::{DbIndex, LuaDeclExtra, LuaDeclId, LuaDocument, LuaType, SemanticModel};
+
+pub(super) fn build_add_doc_param_changes(
+    semantic_model: &SemanticModel,
+    range: Range,
+) -> Option<Vec<TextEdit>> {
+    let document = semantic_model.get_document();
+    let param = find_node_by_range::<LuaParamName>(semantic_model, range)?;
+    let name = param.get_name_token()?.get_name_text().to_string();
+    let closure_expr = param.ancestors::<LuaClosureExpr>().next()?;
+    let stat = closure_expr.ancestors::<LuaStat>().next()?;
+
+    // insert after the last `---@param` so the order of the tags is kept, otherwise right
+    // above the function
+    let last_param_tag = stat
+        .get_left_comment()
+        .and_then(|comment| comment.children::<LuaDocTagParam>().last());
+    let insert_line = match last_param_tag {
+        Some(tag) => document.get_line(tag.get_range().end())? + 1,
+        None => document.get_line(stat.get_position())?,
+    };
+    let indent = get_line_indent(&document, stat.get_position())?;
+    let offset = document.get_line_range(insert_line)?.start();
+
+    let text_edit = TextEdit {
+        range: document.to_lsp_range(TextRange::new(offset, offset))?,
+        new_text: format!("{}---@param {} any\n", indent, name),
+    };
+    Some(vec![text_edit])
+}
+
+pub(super) fn build_remove_doc_param_changes(
+    semantic_model: &SemanticModel,
+    range: Range,
+) -> Option<Vec<TextEdit>> {
+    let document = semantic_model.get_document();
+    let text_range = document.to_rowan_range(range)?;
+    let root = semantic_model.get_root();
+    let token = root
+        .syntax()
+        .token_at_offset(text_range.start())
+        .right_biased()?;
+    let tag = token.parent_ancestors().find_map(LuaDocTagParam::cast)?;
+
+    // the tag node starts after `---@`, so the comment prefix has to be removed as well
+    let mut tag_range = tag.get_range();
+    let line_start = document
+        .get_line_range(document.get_line(tag_range.start())?)?
+        .start();
+    let prefix = document.get_text_slice(TextRange::new(line_start, tag_range.start()));
+    if prefix.trim_start().starts_with("---") && prefix.trim_end().ends_with('@') {
+        tag_range = TextRange::new(line_start, tag_range.end());
+    }
+
+    let text_edit = TextEdit {
+        range: document.to_lsp_range(expand_to_lines(&document, tag_range)?)?,
+        new_text: String::new(),
+    };
+    Some(vec![text_edit])
+}
+
+pub(super) fn build_missing_fields_changes(
+    semantic_model: &SemanticModel,
+    range: Range,
+    missing_fields: Vec<String>,
+) -> Option<Vec<TextEdit>> {
+    if missing_fields.is_empty() {
+        return None;
+    }
+
+    let document = semantic_model.get_document();
+    let table_expr = find_node_by_range::<LuaTableExpr>(semantic_model, range)?;
+    let member_map = semantic_model
+        .infer_table_should_be(table_expr.clone())
+        .and_then(|table_type| semantic_model.infer_member_map(&table_type))
+        .unwrap_or_default();
+    let fields = missing_fields
+        .iter()
+        .map(|name| {
+            let field_type = member_map
+                .iter()
+                .find(|(key, _)| key.to_path() == *name)
+                .and_then(|(_, infos)| infos.first())
+                .map(|info| info.typ.clone())
+                .unwrap_or(LuaType::Unknown);
+            format!(
+                "{} = {}",
+                to_field_key(name),
+                get_placeholder_value(semantic_model.get_db(), &field_type, 0)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let Some(last_field) = table_expr.get_fields().last() else {
+        let text_edit = TextEdit {
+            range: document.to_lsp_range(table_expr.get_range())?,
+            new_text: format!("{{ {} }}", fields.join(", ")),
+        };
+        return Some(vec![text_edit]);
+    };
+
+    // `{ a = 1, }` already ends with a separator, so new fields go after it
+    let mut insert_offset = last_field.get_range().end();
+    let mut has_separator = false;
+    let mut next = last_field.syntax().next_sibling_or_token();
+    while let Some(element) = next {
+        match element.kind() {
+            LuaKind::Token(LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine) => {}
+            LuaKind::Token(LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon) => {
+                has_separator = true;
+                insert_offset = element.text_range().end();
+                break;
+            }
+            _ => break,
+        }
+        next = element.next_sibling_or_token();
+    }
+
+    let mut new_text = if has_separator {
+        String::new()
+    } else {
+        ",".to_string()
+    };
+    let is_multiline = table_expr.syntax().text().contains_char('\n');
+    if is_multiline {
+        let first_field = table_expr.get_fields().next()?;
+        let indent = get_line_indent(&document, first_field.get_position())?;
+        for field in fields {
+            new_text.push_str(&format!("\n{}{},", indent, field));
+        }
+    } else {
+        new_text.push(' ');
+        new_text.push_str(&fields.join(", "));
+    }
+
+    let text_edit = TextEdit {
+        range: document.to_lsp_range(TextRange::new(insert_offset, insert_offset))?,
+        new_text,
+    };
+    Some(vec![text_edit])
+}
+
+pub(super) fn build_add_local_changes(
+    semantic_model: &SemanticModel,
+    range: Range,
+) -> Option<Vec<TextEdit>> {
+    let document = semantic_model.get_document();
+    let name_expr = find_node_by_range::<LuaNameExpr>(semantic_model, range)?;
+    let name = name_expr.get_name_text()?;
+    let stat = name_expr.ancestors::<LuaStat>().next()?;
+
+    // `name = value` becomes `local name = value`, `local` only accepts plain names
+    if let LuaStat::AssignStat(assign_stat) = &stat {
+        let (vars, _) = assign_stat.get_var_and_expr_list();
+        if vars
+            .iter()
+            .all(|var| matches!(var, LuaVarExpr::NameExpr(_)))
+            && vars.iter().any(|var| var.syntax() == name_expr.syntax())
+        {
+            let offset = assign_stat.get_position();
+            let text_edit = TextEdit {
+                range: document.to_lsp_range(TextRange::new(offset, offset))?,
+                new_text: "local ".to_string(),
+            };
+            return Some(vec![text_edit]);
+        }
+    }
+
+    // otherwise declare the name right above the statement that uses it, a local without a
+    // value would always be nil, so it gets a placeholder of the type its use expects
+    let expected_type = infer_expected_type(semantic_model, &name_expr)?;
+    let value = get_placeholder_value(semantic_model.get_db(), &expected_type, 0);
+    if value == "nil" {
+        return None;
+    }
+
+    let indent = get_line_indent(&document, stat.get_position())?;
+    let offset = document
+        .get_line_range(document.get_line(stat.get_position())?)?
+        .start();
+    let text_edit = TextEdit {
+        range: document.to_lsp_range(TextRange::new(offset, offset))?,
+        new_text: format!("{}local {} = {}\n", indent, name, value),
+    };
+    Some(vec![text_edit])
+}
+
+/// Infers the type which the context of `name_expr` expects it to have.
+fn infer_expected_type(semantic_model: &SemanticModel, name_expr: &LuaNameExpr) -> Option<LuaType> {
+    let parent = name_expr.syntax().parent()?;
+    match LuaAst::cast(parent)? {
+        LuaAst::LuaBinaryExpr(binary_expr) => match binary_expr.get_op_token()?.get_op() {
+            BinaryOperator::OpConcat => Some(LuaType::String),
+            BinaryOperator::OpAdd
+            | BinaryOperator::OpSub
+            | BinaryOperator::OpMul
+            | BinaryOperator::OpDiv
+            | BinaryOperator::OpIDiv
+            | BinaryOperator::OpMod
+            | BinaryOperator::OpPow
+            | BinaryOperator::OpLt
+            | BinaryOperator::OpLe
+            | BinaryOperator::OpGt
+            | BinaryOperator::OpGe => Some(LuaType::Number),
+            BinaryOperator::OpBAnd
+            | BinaryOperator::OpBOr
+            | BinaryOperator::OpBXor
+            | BinaryOperator::OpShl
+            | BinaryOperator::OpShr => Some(LuaType::Integer),
+            _ => None,
+        },
+        LuaAst::LuaIndexExpr(_) => Some(LuaType::Table),
+        LuaAst::LuaCallExpr(_) => Some(LuaType::Function),
+        LuaAst::LuaCallArgList(arg_list) => {
+            let call_expr = LuaCallExpr::cast(arg_list.syntax().parent()?)?;
+            let arg_idx = arg_list
+                .get_args()
+                .position(|arg| arg.syntax() == name_expr.syntax())?;
+            let func = semantic_model.infer_call_expr_func(call_expr.clone(), None)?;
+            let param_idx = match (call_expr.is_colon_call(), func.is_colon_define()) {
+                (true, false) => arg_idx + 1,
+                (false, true) => arg_idx.checked_sub(1)?,
+                _ => arg_idx,
+            };
+            func.get_params().get(param_idx)?.1.clone()
+        }
+        _ => None,
+    }
+}
+
+pub(super) fn build_prefix_unused_changes(
+    semantic_model: &SemanticModel,
+    range: Range,
+) -> Option<Vec<TextEdit>> {
+    let document = semantic_model.get_document();
+    let text_range = document.to_rowan_range(range)?;
+    let name = document.get_text_slice(text_range);
+    if name.starts_with('_') || name == "self" || name == "..." {
+        return None;
+    }
+
+    let text_edit = TextEdit {
+        range: Range {
+            start: range.start,
+            end: range.start,
+        },
+        new_text: "_".to_string(),
+    };
+    Some(vec![text_edit])
+}
+
+/// The unused declaration is a local or a parameter without a `---@param`, which would no
+/// longer match the renamed parameter
+pub(super) fn is_prefix_unused_safe(semantic_model: &SemanticModel, range: Range) -> bool {
+    let document = semantic_model.get_document();
+    let Some(text_range) = document.to_rowan_range(range) else {
+        return false;
+    };
+    let db = semantic_model.get_db();
+    let decl_id = LuaDeclId::new(semantic_model.get_file_id(), text_range.start());
+    let Some(decl) = db.get_decl_index().get_decl(&decl_id) else {
+        return false;
+    };
+
+    match &decl.extra {
+        LuaDeclExtra::Local { .. } => true,
+        LuaDeclExtra::Param {
+            idx, signature_id, ..
+        } => db
+            .get_signature_index()
+            .get(signature_id)
+            .is_some_and(|signature| !signature.param_docs.contains_key(idx)),
+        LuaDeclExtra::Global { .. } => false,
+    }
+}
+
+pub(super) fn build_remove_unreachable_changes(
+    semantic_model: &SemanticModel,
+    range: Range,
+) -> Option<Vec<TextEdit>> {
+    let document = semantic_model.get_document();
+    let text_range = document.to_rowan_range(range)?;
+    let text_edit = TextEdit {
+        range: document.to_lsp_range(expand_to_lines(&document, text_range)?)?,
+        new_text: String::new(),
+    };
+    Some(vec![text_edit])
+}
+
+fn find_node_by_range<N: LuaAstNode>(semantic_model: &SemanticModel, range: Range) -> Option<N> {
+    let document = semantic_model.get_document();
+    let text_range = document.to_rowan_range(range)?;
+    let root = semantic_model.get_root();
+    let element = root.syntax().covering_element(text_range);
+    let node = match element {
+        rowan::NodeOrToken::Node(node) => node,
+        rowan::NodeOrToken::Token(token) => token.parent()?,
+    };
+
+    node.ancestors()
+        .filter_map(N::cast)
+        .find(|node| node.get_range() == text_range)
+}
+
+/// Returns the whitespace between the start of the line and `offset`.
+fn get_line_indent(document: &LuaDocument, offset: TextSize) -> Option<String> {
+    let line = document.get_line(offset)?;
+    let line_start = document.get_line_range(line)?.start();
+    let prefix = document.get_text_slice(TextRange::new(line_start, offset));
+    Some(
+        prefix
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .collect::<String>(),
+    )
+}
+
+/// Expands `range` to cover whole lines when nothing else shares those lines.
+fn expand_to_lines(document: &LuaDocument, range: TextRange) -> Option<TextRange> {
+    let start_line = document.get_line(range.start())?;
+    let end_line = document.get_line(range.end())?;
+    let start_line_range = document.get_line_range(start_line)?;
+    let end_line_range = document.get_line_range(end_line)?;
+
+    let prefix = document.get_text_slice(TextRange::new(start_line_range.start(), range.start()));
+    let suffix = document.get_text_slice(TextRange::new(range.end(), end_line_range.end()));
+    if !prefix.trim().is_empty() || !suffix.trim().is_empty() {
+        return Some(range);
+    }
+
+    Some(TextRange::new(
+        start_line_range.start(),
+        end_line_range.end(),
+    ))
+}
+
+/// Returns a value of `typ` which stands in for a missing field until the user fills it in.
+fn get_placeholder_value(db: &DbIndex, typ: &LuaType, depth: usize) -> String {
+    if depth > 8 {
+        return "nil".to_string();
+    }
+
+    match typ {
+        LuaType::String => "\"\"".to_string(),
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => format!("{:?}", s.as_str()),
+        LuaType::Integer | LuaType::Number => "0".to_string(),
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => i.to_string(),
+        LuaType::FloatConst(f) => f.to_string(),
+        LuaType::Boolean => "false".to_string(),
+        LuaType::BooleanConst(b) | LuaType::DocBooleanConst(b) => b.to_string(),
+        LuaType::Function | LuaType::DocFunction(_) | LuaType::Signature(_) => {
+            "function() end".to_string()
+        }
+        LuaType::Table
+        | LuaType::TableConst(_)
+        | LuaType::Array(_)
+        | LuaType::Tuple(_)
+        | LuaType::Object(_)
+        | LuaType::TableGeneric(_)
+        | LuaType::Generic(_)
+        | LuaType::Def(_) => "{}".to_string(),
+        LuaType::Ref(type_decl_id) => {
+            let Some(type_decl) = db.get_type_index().get_type_decl(type_decl_id) else {
+                return "nil".to_string();
+            };
+            if type_decl.is_alias() {
+                match type_decl.get_alias_origin(db, None) {
+                    Some(origin) => get_placeholder_value(db, &origin, depth + 1),
+                    None => "nil".to_string(),
+                }
+            } else if type_decl.is_enum() {
+                "nil".to_string()
+            } else {
+                "{}".to_string()
+            }
+        }
+        LuaType::Union(union) => union
+            .get_types()
+            .iter()
+            .find(|typ| !typ.is_nil())
+            .map(|typ| get_placeholder_value(db, typ, depth + 1))
+            .unwrap_or_else(|| "nil".to_string()),
+        _ => "nil".to_string(),
+    }
+}
+
+fn to_field_key(name: &str) -> String {
+    let is_name = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_name || name.starts_with('[') {
+        name.to_string()
+    } else {
+        format!("[\"{}\"]", name)
+    }
+}
+
+/// Format the statements of the diagnosed lines
+pub(super) fn build_format_changes(
+    semantic_model: &SemanticModel,
+    range: Range,
+) -> Option<Vec<TextEdit>> {
+    let document = semantic_model.get_document();
+    let file_path = document.get_file_path().to_string_lossy().to_string();
+    let level = semantic_model.get_emmyrc().get_language_level();
+    let result = range_format_code(
+        document.get_text(),
+        &file_path,
+        level,
+        range.start.line as i32,
+        0,
+        range.end.line as i32 + 1,
+        0,
+    )?;
+
+    let start = document.get_line_range(result.start_line as usize)?.start();
+    let end = document.get_line_range(result.end_line as usize)?.end();
+    let text_range = TextRange::new(start, end);
+    if document.get_text_slice(text_range) == result.text {
+        return None;
+    }
+
+    let text_edit = TextEdit {
+        range: document.to_lsp_range(text_range)?,
+        new_text: result.text,
+    };
+    Some(vec![text_edit])
+}
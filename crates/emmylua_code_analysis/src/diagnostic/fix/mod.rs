@@ -0,0 +1,87 @@
+mod build_fix_code;
+
+use std::str::FromStr;
+
+use build_fix_code::*;
+use lsp_types::{Diagnostic, NumberOrString, TextEdit};
+
+use crate::{DiagnosticCode, SemanticModel};
+
+/// A fix of a diagnostic, shared by the code actions and `emmylua_check --fix`
+#[derive(Debug, Clone)]
+pub struct DiagnosticFix {
+    pub title: String,
+    /// The edits of the diagnosed file
+    pub edits: Vec<TextEdit>,
+    /// The fix keeps the behavior of the code, so it can be applied without review
+    pub is_machine_applicable: bool,
+    /// The fix is the most likely way to solve the diagnostic
+    pub is_preferred: bool,
+}
+
+pub fn get_diagnostic_fix(
+    semantic_model: &SemanticModel,
+    diagnostic: &Diagnostic,
+) -> Option<DiagnosticFix> {
+    let Some(NumberOrString::String(code)) = &diagnostic.code else {
+        return None;
+    };
+    let diagnostic_code = DiagnosticCode::from_str(code).ok()?;
+    let range = diagnostic.range;
+    let (title, edits, is_machine_applicable) = match diagnostic_code {
+        DiagnosticCode::IncompleteSignatureDoc | DiagnosticCode::MissingGlobalDoc => (
+            t!("Add missing @param annotation"),
+            build_add_doc_param_changes(semantic_model, range),
+            false,
+        ),
+        DiagnosticCode::UndefinedDocParam => (
+            t!("Remove undefined @param annotation"),
+            build_remove_doc_param_changes(semantic_model, range),
+            true,
+        ),
+        DiagnosticCode::MissingFields => {
+            let missing_fields =
+                serde_json::from_value::<Vec<String>>(diagnostic.data.clone()?).ok()?;
+            (
+                t!("Add missing fields"),
+                build_missing_fields_changes(semantic_model, range, missing_fields),
+                false,
+            )
+        }
+        DiagnosticCode::UndefinedGlobal => (
+            t!("Declare as local variable"),
+            build_add_local_changes(semantic_model, range),
+            false,
+        ),
+        DiagnosticCode::Unused => (
+            t!("Prefix with underscore"),
+            build_prefix_unused_changes(semantic_model, range),
+            is_prefix_unused_safe(semantic_model, range),
+        ),
+        DiagnosticCode::UnreachableCode => (
+            t!("Remove unreachable code"),
+            // the code is often left there on purpose while debugging
+            build_remove_unreachable_changes(semantic_model, range),
+            false,
+        ),
+        DiagnosticCode::CodeStyleCheck => (
+            t!("Format the code"),
+            build_format_changes(semantic_model, range),
+            true,
+        ),
+        _ => return None,
+    };
+
+    // the inserted values are placeholders which still have to be filled in by the user
+    let is_preferred = !matches!(
+        diagnostic_code,
+        DiagnosticCode::MissingFields | DiagnosticCode::UndefinedGlobal
+    );
+
+    Some(DiagnosticFix {
+        title: title.to_string(),
+        edits: edits?,
+        is_machine_applicable,
+        is_preferred,
+    })
+}
@@ -1,4 +1,8 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub use super::checker::DiagnosticContext;
 use super::{checker::check_file, lua_diagnostic_config::LuaDiagnosticConfig};
@@ -10,6 +14,8 @@ use tokio_util::sync::CancellationToken;
 pub struct LuaDiagnostic {
     enable: bool,
     config: Arc<LuaDiagnosticConfig>,
+    /// The enable flag and the config of the workspace roots which have their own config
+    workspace_configs: HashMap<PathBuf, (bool, Arc<LuaDiagnosticConfig>)>,
 }
 
 impl LuaDiagnostic {
@@ -17,6 +23,7 @@ impl LuaDiagnostic {
         Self {
             enable: true,
             config: Arc::new(LuaDiagnosticConfig::default()),
+            workspace_configs: HashMap::new(),
         }
     }
 
@@ -25,13 +32,25 @@ impl LuaDiagnostic {
         self.config = LuaDiagnosticConfig::new(&emmyrc).into();
     }
 
+    pub fn set_workspace_config(&mut self, root: PathBuf, emmyrc: &Emmyrc) {
+        let config = LuaDiagnosticConfig::new(emmyrc).into();
+        self.workspace_configs
+            .insert(root, (emmyrc.diagnostics.enable, config));
+    }
+
+    pub fn remove_workspace_config(&mut self, root: &Path) {
+        self.workspace_configs.remove(root);
+    }
+
     pub fn diagnose_file(
         &self,
         compilation: &LuaCompilation,
         file_id: FileId,
         cancel_token: CancellationToken,
     ) -> Option<Vec<Diagnostic>> {
-        if !self.enable {
+        let db = compilation.get_db();
+        let (enable, config) = self.get_file_config(compilation, file_id);
+        if !enable {
             return None;
         }
 
@@ -39,12 +58,31 @@ impl LuaDiagnostic {
             return None;
         }
 
-        let db = compilation.get_db();
         let mut semantic_model = compilation.get_semantic_model(file_id)?;
-        let mut context = DiagnosticContext::new(file_id, db, self.config.clone());
+        let mut context = DiagnosticContext::new(file_id, db, config);
 
         check_file(&mut context, &mut semantic_model);
 
         Some(context.get_diagnostics())
     }
+
+    pub fn is_file_enable(&self, compilation: &LuaCompilation, file_id: FileId) -> bool {
+        self.get_file_config(compilation, file_id).0
+    }
+
+    fn get_file_config(
+        &self,
+        compilation: &LuaCompilation,
+        file_id: FileId,
+    ) -> (bool, Arc<LuaDiagnosticConfig>) {
+        match compilation
+            .get_db()
+            .get_vfs()
+            .get_file_config_root(&file_id)
+            .and_then(|root| self.workspace_configs.get(root))
+        {
+            Some((enable, config)) => (*enable, config.clone()),
+            None => (self.enable, self.config.clone()),
+        }
+    }
 }
@@ -96,11 +96,116 @@ pub enum DiagnosticCode {
     DuplicateSetField,
     /// duplicate-index
     DuplicateIndex,
+    /// Undefined label
+    UndefinedLabel,
+    /// Jump into the scope of a local
+    JumpIntoLocalScope,
+    /// Not closable value
+    NotClosable,
+    /// Format string mismatch
+    FormatStringMismatch,
+    /// Redundant format argument
+    RedundantFormatArg,
 
     #[serde(other)]
     None,
 }
 
+impl DiagnosticCode {
+    /// The localized description of the diagnostic
+    pub fn get_description(&self) -> String {
+        let description = match self {
+            DiagnosticCode::SyntaxError => t!("Syntax error in annotations"),
+            DiagnosticCode::LuaSyntaxError => t!("Syntax error in Lua code"),
+            DiagnosticCode::TypeNotFound => t!("The annotated type is not defined"),
+            DiagnosticCode::MissingReturn => {
+                t!("A function with return annotations does not return on every path")
+            }
+            DiagnosticCode::ParamTypeNotMatch => {
+                t!("The argument type does not match the parameter type")
+            }
+            DiagnosticCode::MissingParameter => t!("A required parameter is not passed"),
+            DiagnosticCode::RedundantParameter => {
+                t!("More arguments are passed than the function accepts")
+            }
+            DiagnosticCode::InjectFieldFail => t!("A field cannot be injected into the type"),
+            DiagnosticCode::UnreachableCode => t!("Code that can never be executed"),
+            DiagnosticCode::Unused => t!("A local variable or parameter is never used"),
+            DiagnosticCode::UndefinedGlobal => t!("A global variable is used but never defined"),
+            DiagnosticCode::Deprecated => t!("A deprecated symbol is used"),
+            DiagnosticCode::AccessInvisible => {
+                t!("A private, protected or package member is accessed outside its scope")
+            }
+            DiagnosticCode::DiscardReturns => {
+                t!("The return value of a function marked nodiscard is discarded")
+            }
+            DiagnosticCode::DisableGlobalDefine => t!("Defining global variables is disabled"),
+            DiagnosticCode::UndefinedField => t!("The field is not defined on the type"),
+            DiagnosticCode::LocalConstReassign => {
+                t!("A const or close local variable is reassigned")
+            }
+            DiagnosticCode::IterVariableReassign => t!("A for loop variable is reassigned"),
+            DiagnosticCode::DuplicateType => t!("The type is defined more than once"),
+            DiagnosticCode::RedefinedLocal => {
+                t!("A local variable shadows another local with the same name")
+            }
+            DiagnosticCode::RedefinedLabel => t!("A label is defined more than once"),
+            DiagnosticCode::NameStyleCheck => t!("The name does not follow the naming style"),
+            DiagnosticCode::CodeStyleCheck => t!("The code does not follow the code style"),
+            DiagnosticCode::NeedCheckNil => {
+                t!("A value which may be nil is used without a nil check")
+            }
+            DiagnosticCode::AwaitInSync => t!("An async function is called in a sync function"),
+            DiagnosticCode::AnnotationUsageError => t!("An annotation is used incorrectly"),
+            DiagnosticCode::ReturnTypeMismatch => {
+                t!("The returned value does not match the return type")
+            }
+            DiagnosticCode::MissingReturnValue => t!("Fewer values are returned than annotated"),
+            DiagnosticCode::RedundantReturnValue => t!("More values are returned than annotated"),
+            DiagnosticCode::UndefinedDocParam => {
+                t!("An annotated parameter does not exist in the function")
+            }
+            DiagnosticCode::DuplicateDocField => t!("A field is annotated more than once"),
+            DiagnosticCode::MissingFields => t!("A table is missing required fields of its class"),
+            DiagnosticCode::InjectField => t!("A field which is not declared is added to a class"),
+            DiagnosticCode::CircleDocClass => t!("A class inherits from itself"),
+            DiagnosticCode::IncompleteSignatureDoc => {
+                t!("The function annotations do not cover every parameter and return value")
+            }
+            DiagnosticCode::MissingGlobalDoc => t!("A global function has no annotations"),
+            DiagnosticCode::AssignTypeMismatch => {
+                t!("The assigned value does not match the variable type")
+            }
+            DiagnosticCode::DuplicateRequire => t!("A module is required more than once"),
+            DiagnosticCode::NonLiteralExpressionsInAssert => {
+                t!("The assert message is not a literal")
+            }
+            DiagnosticCode::UnbalancedAssignments => {
+                t!("The number of values does not match the number of variables")
+            }
+            DiagnosticCode::UnnecessaryAssert => t!("The assert condition is always true"),
+            DiagnosticCode::UnnecessaryIf => t!("The if condition is always true or always false"),
+            DiagnosticCode::DuplicateSetField => t!("A field is set more than once"),
+            DiagnosticCode::DuplicateIndex => t!("A table index is defined more than once"),
+            DiagnosticCode::UndefinedLabel => t!("A goto jumps to a label which is not visible"),
+            DiagnosticCode::JumpIntoLocalScope => {
+                t!("A goto jumps forward into the scope of a local variable")
+            }
+            DiagnosticCode::NotClosable => {
+                t!("A value without a __close metamethod is assigned to a close variable")
+            }
+            DiagnosticCode::FormatStringMismatch => {
+                t!("The arguments do not match the literal format string")
+            }
+            DiagnosticCode::RedundantFormatArg => {
+                t!("An argument is passed which the literal format string does not use")
+            }
+            DiagnosticCode::None => return String::new(),
+        };
+        description.to_string()
+    }
+}
+
 // Update functions to match enum variants
 pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
     match code {
@@ -123,6 +228,11 @@ pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
         DiagnosticCode::DuplicateType => DiagnosticSeverity::WARNING,
         DiagnosticCode::AnnotationUsageError => DiagnosticSeverity::ERROR,
         DiagnosticCode::RedefinedLocal => DiagnosticSeverity::HINT,
+        DiagnosticCode::RedefinedLabel => DiagnosticSeverity::ERROR,
+        DiagnosticCode::UndefinedLabel => DiagnosticSeverity::ERROR,
+        DiagnosticCode::JumpIntoLocalScope => DiagnosticSeverity::ERROR,
+        DiagnosticCode::NotClosable => DiagnosticSeverity::ERROR,
+        DiagnosticCode::RedundantFormatArg => DiagnosticSeverity::HINT,
         _ => DiagnosticSeverity::WARNING,
     }
 }
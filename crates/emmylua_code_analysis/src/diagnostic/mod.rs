@@ -1,8 +1,10 @@
 mod checker;
+mod fix;
 mod lua_diagnostic;
 mod lua_diagnostic_code;
 mod lua_diagnostic_config;
 mod test;
 
+pub use fix::{get_diagnostic_fix, DiagnosticFix};
 pub use lua_diagnostic::LuaDiagnostic;
-pub use lua_diagnostic_code::DiagnosticCode;
+pub use lua_diagnostic_code::{get_default_severity, DiagnosticCode};
@@ -858,4 +858,31 @@ return t
         "#
         ));
     }
+
+    #[test]
+    fn test_newindex_meta_method() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+                ---@class Store
+                ---@operator newindex(string, number)
+
+                ---@type Store
+                local store
+                store.count = "1"
+        "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+                ---@class Store
+                ---@operator newindex(string, number)
+
+                ---@type Store
+                local store
+                store.count = 1
+        "#
+        ));
+    }
 }
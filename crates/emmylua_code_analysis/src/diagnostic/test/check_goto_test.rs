@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_redefined_label() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RedefinedLabel,
+            r#"
+            ::retry::
+            print(1)
+            ::retry::
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::RedefinedLabel,
+            r#"
+            for i = 1, 10 do
+                ::continue::
+            end
+            for i = 1, 10 do
+                ::continue::
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_undefined_label() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedLabel,
+            r#"
+            goto done
+            "#
+        ));
+        // a label is not visible from a nested function
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedLabel,
+            r#"
+            local function f()
+                goto done
+            end
+            ::done::
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedLabel,
+            r#"
+            for i = 1, 10 do
+                if i % 2 == 0 then
+                    goto continue
+                end
+                ::continue::
+            end
+            ::top::
+            do
+                goto top
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_jump_into_local_scope() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::JumpIntoLocalScope,
+            r#"
+            goto done
+            local a = 1
+            ::done::
+            print(a)
+            "#
+        ));
+        // a label at the end of the block is outside the scope of the locals
+        assert!(ws.check_code_for(
+            DiagnosticCode::JumpIntoLocalScope,
+            r#"
+            for i = 1, 10 do
+                if i % 2 == 0 then
+                    goto continue
+                end
+                local a = i
+                print(a)
+                ::continue::
+                ;
+            end
+            "#
+        ));
+        // jumping backward never enters the scope of a local
+        assert!(ws.check_code_for(
+            DiagnosticCode::JumpIntoLocalScope,
+            r#"
+            ::retry::
+            local a = 1
+            if a then
+                goto retry
+            end
+            print(a)
+            "#
+        ));
+    }
+}
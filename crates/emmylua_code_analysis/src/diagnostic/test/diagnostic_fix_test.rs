@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{
+        get_diagnostic_fix, DiagnosticCode, DiagnosticFix, Emmyrc, EmmyrcLuaVersion,
+        VirtualWorkspace,
+    };
+
+    fn get_fix(
+        ws: &mut VirtualWorkspace,
+        diagnostic_code: DiagnosticCode,
+        block_str: &str,
+    ) -> Option<DiagnosticFix> {
+        let file_id = ws.def(block_str);
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())?;
+        let code = Some(NumberOrString::String(
+            diagnostic_code.get_name().to_string(),
+        ));
+        let diagnostic = diagnostics
+            .into_iter()
+            .find(|diagnostic| diagnostic.code == code)?;
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id)?;
+        get_diagnostic_fix(&semantic_model, &diagnostic)
+    }
+
+    #[test]
+    fn test_machine_applicable_fix() {
+        let mut ws = VirtualWorkspace::new();
+        let fix = get_fix(
+            &mut ws,
+            DiagnosticCode::Unused,
+            r#"
+            local a = 1
+            "#,
+        );
+        assert!(fix.is_some_and(|fix| fix.is_machine_applicable
+            && fix.edits.len() == 1
+            && fix.edits[0].new_text == "_"));
+
+        // the `---@param` would no longer match the renamed parameter
+        let fix = get_fix(
+            &mut ws,
+            DiagnosticCode::Unused,
+            r#"
+            ---@param value integer
+            local function show(value) end
+
+            return show
+            "#,
+        );
+        assert!(fix.is_some_and(|fix| !fix.is_machine_applicable && fix.edits[0].new_text == "_"));
+
+        // declaring a local changes which variable the code refers to
+        let fix = get_fix(
+            &mut ws,
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            local s = undefined_name .. "!"
+            "#,
+        );
+        assert!(fix.is_some_and(|fix| !fix.is_machine_applicable
+            && !fix.is_preferred
+            && fix.edits[0]
+                .new_text
+                .ends_with("local undefined_name = \"\"\n")));
+    }
+
+    #[test]
+    fn test_no_nil_placeholder_fix() {
+        let mut ws = VirtualWorkspace::new();
+        // a local without a value would always be nil, and nothing tells the type of a value
+        // passed as `any`
+        let fix = get_fix(
+            &mut ws,
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            ---@param value any
+            local function show(value) end
+
+            show(undefined_name)
+            "#,
+        );
+        assert!(fix.is_none());
+
+        let fix = get_fix(
+            &mut ws,
+            DiagnosticCode::MissingFields,
+            r#"
+            ---@class Item
+            ---@field id integer
+            ---@field name string
+
+            ---@type Item
+            local item = {}
+            "#,
+        );
+        assert!(fix.is_some_and(
+            |fix| !fix.is_preferred && fix.edits[0].new_text == "{ id = 0, name = \"\" }"
+        ));
+    }
+
+    #[test]
+    fn test_format_fix() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::CodeStyleCheck);
+        let fix = get_fix(
+            &mut ws,
+            DiagnosticCode::CodeStyleCheck,
+            "print(1)\nlocal  a = 1\nprint(a)\n",
+        );
+        // only the diagnosed line is replaced
+        assert!(fix.is_some_and(|fix| fix.is_machine_applicable
+            && fix.edits[0].range.start.line == 1
+            && fix.edits[0].range.end.line == 2
+            && fix.edits[0].new_text == "local a = 1\n"));
+    }
+
+    #[test]
+    fn test_format_fix_luau() {
+        let mut ws = VirtualWorkspace::new();
+        let mut config = Emmyrc::default();
+        config.runtime.version = EmmyrcLuaVersion::Luau;
+        ws.analysis.update_config(config.into());
+        ws.enable_check(DiagnosticCode::CodeStyleCheck);
+        let fix = get_fix(
+            &mut ws,
+            DiagnosticCode::CodeStyleCheck,
+            "local  count: number = 0\ncount  += 1\n",
+        );
+        assert!(fix.is_some_and(|fix| fix.edits[0].new_text == "local count: number = 0\n"));
+    }
+
+    #[test]
+    fn test_remove_unreachable_fix() {
+        let mut ws = VirtualWorkspace::new();
+        let fix = get_fix(
+            &mut ws,
+            DiagnosticCode::UnreachableCode,
+            "local function f()\n    do return end\n    print(1)\nend\n",
+        );
+        assert!(fix.is_some_and(|fix| !fix.is_machine_applicable
+            && fix.edits.len() == 1
+            && fix.edits[0].range.start.line == 2
+            && fix.edits[0].new_text.is_empty()));
+    }
+}
@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
+
+    fn create_workspace(version: EmmyrcLuaVersion) -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.version = version;
+        ws.analysis.update_config(emmyrc.into());
+        ws
+    }
+
+    #[test]
+    fn test_format_arg_count() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%d items in %s", 1)
+            "#
+        ));
+        // an unused argument is only a hint
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RedundantFormatArg,
+            r#"
+            local s = string.format("%s", "a", "b")
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%s", "a", "b")
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("100%% of %5.2f, %-10s", 1.5, "a")
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local function f(...)
+                return string.format("%s %s %s", ...)
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_format_arg_type() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            ---@type string
+            local name
+            local s = string.format("%d items in %s", name, "box")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%x", 1.5)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%q", {})
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            ---@type number
+            local count
+            local s = string.format("%d %x %g %s %q", count, 2, 3, {}, "a")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_invalid_conversion() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%y", 1)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%123d", 1)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_method_call() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = ("%d: %s"):format(1)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = ("%d: %s"):format(1, "a")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_format_string_tag() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Logger
+            local Logger = {}
+
+            ---@param level integer
+            ---@param fmt string
+            ---@param ... any
+            ---@format_string fmt
+            function Logger:log(level, fmt, ...)
+            end
+
+            ---@type Logger
+            logger = {}
+            "#,
+        );
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            logger:log(1, "%s: %d", "a", "b")
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            logger:log(1, "%s: %d", "a", 1)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            logger.log(logger, 1, "%s: %d", "a")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_lua51_conversions() {
+        let mut ws = create_workspace(EmmyrcLuaVersion::Lua51);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%a", 1.5)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%p", {})
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%5.1f %-5q %e", 1.5, "a", 2)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_lua54_conversions() {
+        let mut ws = create_workspace(EmmyrcLuaVersion::Lua54);
+        assert!(ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%p %a %q", {}, 1.5, "a")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%5q", "a")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::FormatStringMismatch,
+            r#"
+            local s = string.format("%-q", "a")
+            "#
+        ));
+    }
+}
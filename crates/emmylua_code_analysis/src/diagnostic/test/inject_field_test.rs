@@ -144,4 +144,22 @@ mod test {
     //     "#
     //     ));
     // }
+
+    #[test]
+    fn test_newindex_meta_method() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::InjectField,
+            r#"
+            ---@class Store
+            ---@operator newindex(string, number)
+
+            ---@class SubStore: Store
+
+            ---@type SubStore
+            local store
+            store.count = 1
+        "#
+        ));
+    }
 }
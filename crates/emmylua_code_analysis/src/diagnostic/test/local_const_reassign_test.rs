@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
+
+    fn create_workspace(version: EmmyrcLuaVersion) -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new();
+        let mut config = Emmyrc::default();
+        config.runtime.version = version;
+        ws.analysis.update_config(config.into());
+        ws
+    }
+
+    #[test]
+    fn test_local_const_reassign() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            local a <const> = 1
+            a = 2
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_for_variable_reassign_lua55() {
+        let mut ws = create_workspace(EmmyrcLuaVersion::Lua55);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            for i = 1, 10 do
+                i = i + 1
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            for k, v in pairs({}) do
+                v = nil
+            end
+            "#
+        ));
+
+        let mut ws = create_workspace(EmmyrcLuaVersion::Lua54);
+        assert!(ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            for i = 1, 10 do
+                i = i + 1
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_global_const_reassign_lua55() {
+        let mut ws = create_workspace(EmmyrcLuaVersion::Lua55);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            global <const> max_count = 10
+            max_count = 20
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            global width <const>, height = 1, 2
+            width = 3
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            global <const> *
+            local function reset()
+                counter = 0
+            end
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            global width <const>, height = 1, 2
+            height = 3
+            do
+                local width = 0
+                width = 4
+            end
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            global <const> *
+            global counter
+            counter = 0
+            "#
+        ));
+    }
+}
@@ -1,17 +1,23 @@
 mod access_invisible_test;
 mod assign_type_mismatch_test;
 mod await_in_sync_test;
+mod check_goto_test;
 mod check_return_count_test;
 mod code_style;
+mod diagnostic_fix_test;
 mod disable_line_test;
 mod duplicate_field_test;
 mod duplicate_index_test;
 mod duplicate_require_test;
+mod format_string_test;
 mod incomplete_signature_doc_test;
 mod inject_field_test;
+mod local_const_reassign_test;
 mod missing_fields_test;
 mod missing_parameter_test;
+mod name_style_check_test;
 mod need_check_nil_test;
+mod not_closable_test;
 mod param_type_check_test;
 mod redefined_local_test;
 mod redundant_parameter_test;
@@ -23,3 +29,5 @@ mod undefined_field_test;
 mod undefined_global_test;
 mod unnecessary_assert_test;
 mod unnecessary_if_test;
+mod unreachable_code_test;
+mod workspace_config_test;
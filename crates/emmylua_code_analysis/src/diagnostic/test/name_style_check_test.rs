@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{
+        DiagnosticCode, Emmyrc, EmmyrcNameStyle, EmmyrcNameStyleKind, EmmyrcNameStyleRule,
+        VirtualWorkspace,
+    };
+
+    fn create_workspace(name_style: EmmyrcNameStyle) -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new();
+        let config = Emmyrc {
+            name_style,
+            ..Default::default()
+        };
+        ws.analysis.update_config(config.into());
+        ws
+    }
+
+    fn style(kind: EmmyrcNameStyleKind) -> Vec<EmmyrcNameStyleRule> {
+        vec![EmmyrcNameStyleRule::Style(kind)]
+    }
+
+    /// The names and the suggested names of the diagnostics
+    fn get_name_style_results(ws: &mut VirtualWorkspace, block_str: &str) -> Vec<(String, String)> {
+        let file_id = ws.def(block_str);
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::NameStyleCheck.get_name().to_string(),
+        ));
+        let document = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_document(&file_id);
+        ws.analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|diagnostic| diagnostic.code == code)
+            .map(|diagnostic| {
+                let name = document
+                    .as_ref()
+                    .and_then(|document| document.to_rowan_range(diagnostic.range))
+                    .map(|range| block_str[range].to_string())
+                    .unwrap_or_default();
+                let suggestion = diagnostic
+                    .data
+                    .and_then(|data| data.as_str().map(|it| it.to_string()))
+                    .unwrap_or_default();
+                (name, suggestion)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_no_rules() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::NameStyleCheck,
+            r#"
+            local myValue = 1
+            ---@class my_class
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_preset_styles() {
+        let mut ws = create_workspace(EmmyrcNameStyle {
+            local_name: style(EmmyrcNameStyleKind::SnakeCase),
+            module_local_name: vec![
+                EmmyrcNameStyleRule::Style(EmmyrcNameStyleKind::UpperCase),
+                EmmyrcNameStyleRule::Style(EmmyrcNameStyleKind::SnakeCase),
+            ],
+            function_name: style(EmmyrcNameStyleKind::CamelCase),
+            parameter_name: style(EmmyrcNameStyleKind::SnakeCase),
+            class_name: style(EmmyrcNameStyleKind::PascalCase),
+            ..Default::default()
+        });
+
+        let results = get_name_style_results(
+            &mut ws,
+            r#"
+            local MAX_SIZE = 10
+            local fileName = "a"
+
+            ---@class http_server
+            local server = {}
+
+            local function get_size(bufSize, _unused, ...)
+                local HTTPServer = 1
+                for i, itemValue in ipairs({}) do
+                end
+                return bufSize
+            end
+            "#,
+        );
+        assert_eq!(
+            results,
+            vec![
+                ("fileName".to_string(), "FILE_NAME".to_string()),
+                ("http_server".to_string(), "HttpServer".to_string()),
+                ("get_size".to_string(), "getSize".to_string()),
+                ("bufSize".to_string(), "buf_size".to_string()),
+                ("HTTPServer".to_string(), "http_server".to_string()),
+                ("itemValue".to_string(), "item_value".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_globals_and_fields() {
+        let mut ws = create_workspace(EmmyrcNameStyle {
+            global_name: style(EmmyrcNameStyleKind::UpperCase),
+            function_name: style(EmmyrcNameStyleKind::SnakeCase),
+            field_name: style(EmmyrcNameStyleKind::SnakeCase),
+            private_field_name: vec![EmmyrcNameStyleRule::Pattern {
+                pattern: "_[a-z_]+".to_string(),
+            }],
+            ..Default::default()
+        });
+
+        let results = get_name_style_results(
+            &mut ws,
+            r#"
+            GlobalConfig = {}
+            GLOBAL_NAME = 1
+
+            ---@class Point
+            ---@field xValue number
+            ---@field private y number
+            local Point = {
+                zValue = 1,
+            }
+
+            function Point:moveTo()
+                self.x_value = 1
+            end
+
+            ---@private
+            function Point:_update()
+            end
+            "#,
+        );
+        assert_eq!(
+            results,
+            vec![
+                ("GlobalConfig".to_string(), "GLOBAL_CONFIG".to_string()),
+                ("xValue".to_string(), "x_value".to_string()),
+                ("y".to_string(), String::new()),
+                ("zValue".to_string(), "z_value".to_string()),
+                ("moveTo".to_string(), "move_to".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rules_from_config() {
+        let emmyrc: Emmyrc = serde_json::from_str(
+            r#"{
+                "nameStyle": {
+                    "localName": ["snake_case", { "pattern": "[A-Z]" }]
+                }
+            }"#,
+        )
+        .unwrap_or_default();
+        let mut ws = create_workspace(emmyrc.name_style);
+        assert!(ws.check_code_for(
+            DiagnosticCode::NameStyleCheck,
+            r#"
+            local value_name = 1
+            local N = 2
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::NameStyleCheck,
+            r#"
+            local valueName = 1
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_fields_checked_once() {
+        let mut ws = create_workspace(EmmyrcNameStyle {
+            field_name: style(EmmyrcNameStyleKind::SnakeCase),
+            ..Default::default()
+        });
+        let library = ws.virtual_url_generator.new_path("lib");
+        ws.analysis.add_library_workspace(library);
+        ws.def_file(
+            "lib/lib.lua",
+            r#"
+            ---@class LibClass
+            ---@field someField number
+
+            LibTable = {}
+            "#,
+        );
+
+        let results = get_name_style_results(
+            &mut ws,
+            r#"
+            ---@type LibClass
+            local obj
+            obj.someField = 1
+            LibTable.otherField = 1
+
+            local M = {}
+            M.myField = 1
+            M.myField = 2
+            "#,
+        );
+        assert_eq!(
+            results,
+            vec![("myField".to_string(), "my_field".to_string())]
+        );
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_not_closable() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::NotClosable,
+            r#"
+            local f <close> = "file"
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::NotClosable,
+            r#"
+            ---@class Handle
+
+            ---@type Handle
+            local handle
+            local h <close> = handle
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_closable() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::NotClosable,
+            r#"
+            ---@class Lock
+            ---@operator close
+
+            ---@class FileLock: Lock
+
+            ---@class Transaction
+            local Transaction = {}
+            Transaction.__index = Transaction
+
+            function Transaction:__close()
+            end
+
+            ---@type FileLock
+            local lock
+            ---@type Transaction
+            local transaction
+            ---@type Transaction?
+            local maybe
+
+            local a <close> = lock
+            local b <close> = transaction
+            local c <close> = maybe
+            local d <close> = nil
+            local e <close> = false
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_std_file_closable() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(ws.check_code_for(
+            DiagnosticCode::NotClosable,
+            r#"
+            local f <close> = io.open("x")
+            local t <close> = io.tmpfile()
+            "#
+        ));
+    }
+}
@@ -2,7 +2,7 @@
 mod test {
     use std::sync::Arc;
 
-    use crate::{DiagnosticCode, VirtualWorkspace};
+    use crate::{DiagnosticCode, Emmyrc, VirtualWorkspace};
 
     #[test]
     fn test_issue_216() {
@@ -920,4 +920,24 @@ mod test {
         "#
         ));
     }
+
+    #[test]
+    fn test_cast_number_to_integer() {
+        let mut ws = VirtualWorkspace::new();
+        let code = r#"
+            ---@param _n integer
+            local function take(_n)
+            end
+
+            ---@type number
+            local a
+            take(a)
+        "#;
+        assert!(!ws.check_code_for(DiagnosticCode::ParamTypeNotMatch, code));
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.strict.cast_number_to_integer = true;
+        ws.analysis.update_config(emmyrc.into());
+        assert!(ws.check_code_for(DiagnosticCode::ParamTypeNotMatch, code));
+    }
 }
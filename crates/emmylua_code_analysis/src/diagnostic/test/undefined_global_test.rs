@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-    use crate::{DiagnosticCode, VirtualWorkspace};
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
 
     #[test]
     fn test_issue_250() {
@@ -20,4 +20,92 @@ mod test {
             "#
         ));
     }
+
+    #[test]
+    fn test_global_decl_lua55() {
+        let mut ws = VirtualWorkspace::new();
+        let mut config = Emmyrc::default();
+        config.runtime.version = EmmyrcLuaVersion::Lua55;
+        ws.analysis.update_config(config.into());
+        assert!(ws.check_code_for(
+            DiagnosticCode::SyntaxError,
+            r#"
+            global <const> max_count = 10
+            global *
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            global <const> max_count = 10
+            global counter
+            global function reset()
+                counter = 0
+            end
+
+            reset()
+            return counter + max_count
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_collective_global_decl_lua55() {
+        let mut ws = VirtualWorkspace::new();
+        let mut config = Emmyrc::default();
+        config.runtime.version = EmmyrcLuaVersion::Lua55;
+        ws.analysis.update_config(config.into());
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            global *
+            return not_defined_anywhere
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            global <const> *
+            return not_defined_anywhere
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_undeclared_global_lua55() {
+        let mut ws = VirtualWorkspace::new();
+        let mut config = Emmyrc::default();
+        config.runtime.version = EmmyrcLuaVersion::Lua55;
+        ws.analysis.update_config(config.into());
+        ws.def(
+            r#"
+            Shared = 1
+            "#,
+        );
+        // once a name is declared, the other free names must be declared as well
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            global counter
+            return Shared
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            global counter, Shared
+            return Shared + counter
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            local function f()
+                return Shared
+            end
+            global counter
+            return f
+            "#
+        ));
+    }
 }
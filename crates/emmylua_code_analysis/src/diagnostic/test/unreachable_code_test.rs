@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::{DiagnosticTag, NumberOrString};
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_after_jump() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f()
+                do return end
+                print(1)
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            for i = 1, 10 do
+                break
+                print(i)
+            end
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            goto done
+            print(1)
+            ::done::
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_label_is_reachable() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            for i = 1, 10 do
+                if i % 2 == 0 then
+                    goto continue
+                end
+                print(i)
+                ::continue::
+            end
+
+            goto done
+            ::done::
+            print(1)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_never_calls() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            error("fail")
+            print(1)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            os.exit(1)
+            print(1)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            ---@return never
+            local function fail()
+                error("fail")
+            end
+
+            fail()
+            print(1)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            print(1)
+            print(2)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_branches() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f(a)
+                if a then
+                    return 1
+                elseif a == nil then
+                    error("nil")
+                else
+                    do
+                        return 2
+                    end
+                end
+                print(a)
+            end
+            "#
+        ));
+        // the statements after an `if` without `else` or a loop can be reached
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f(a)
+                if a then
+                    return 1
+                end
+                while a do
+                    return 2
+                end
+                print(a)
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_unreachable_range() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            local function f()
+                do return end
+                print(1)
+                print(2)
+            end
+            "#,
+        );
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::UnreachableCode.get_name().to_string(),
+        ));
+        let diagnostics: Vec<_> = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|diagnostic| diagnostic.code == code)
+            .collect();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start.line, 3);
+        assert_eq!(diagnostics[0].range.end.line, 4);
+        assert_eq!(diagnostics[0].tags, Some(vec![DiagnosticTag::UNNECESSARY]));
+    }
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod test {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, FileId, VirtualWorkspace};
+
+    fn has_diagnostic(ws: &VirtualWorkspace, file_id: FileId, code: DiagnosticCode) -> bool {
+        let code = Some(NumberOrString::String(code.get_name().to_string()));
+        ws.analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default()
+            .iter()
+            .any(|diagnostic| diagnostic.code == code)
+    }
+
+    #[test]
+    fn test_workspace_config() {
+        let mut ws = VirtualWorkspace::new();
+        let mut config = Emmyrc::default();
+        config.runtime.version = EmmyrcLuaVersion::Lua55;
+        config.diagnostics.globals.push("my_global".to_string());
+        let root_b = ws.virtual_url_generator.new_path("b");
+        ws.analysis.set_workspace_emmyrc(root_b, config.into());
+
+        let code = "local a = my_global";
+        let file_a = ws.def_file("a/main.lua", code);
+        let file_b = ws.def_file("b/main.lua", code);
+        assert!(has_diagnostic(&ws, file_a, DiagnosticCode::UndefinedGlobal));
+        assert!(!has_diagnostic(
+            &ws,
+            file_b,
+            DiagnosticCode::UndefinedGlobal
+        ));
+
+        let compilation = &ws.analysis.compilation;
+        assert!(compilation
+            .get_semantic_model(file_b)
+            .is_some_and(|model| model.get_emmyrc().runtime.version == EmmyrcLuaVersion::Lua55));
+        assert!(compilation
+            .get_semantic_model(file_a)
+            .is_some_and(|model| model.get_emmyrc().runtime.version != EmmyrcLuaVersion::Lua55));
+    }
+
+    #[test]
+    fn test_remove_main_workspace() {
+        let mut ws = VirtualWorkspace::new();
+        let root = ws.virtual_url_generator.base.clone();
+        ws.analysis.add_main_workspace(root.join("b"));
+        let file_a = ws.def_file("a/main.lua", "local a = 1");
+        let file_b = ws.def_file("b/main.lua", "local b = 1");
+
+        let removed_uris = ws.analysis.remove_main_workspace(&root);
+        assert_eq!(
+            removed_uris,
+            vec![ws.virtual_url_generator.new_uri("a/main.lua")]
+        );
+        let vfs = ws.analysis.compilation.get_db().get_vfs();
+        assert!(vfs.get_syntax_tree(&file_a).is_none());
+        assert!(vfs.get_syntax_tree(&file_b).is_some());
+    }
+
+    #[test]
+    fn test_workspace_config_in_analysis() {
+        let mut ws = VirtualWorkspace::new();
+        let mut config = Emmyrc::default();
+        config.strict.cast_number_to_integer = true;
+        config.runtime.require_pattern = vec!["src/?.lua".to_string()];
+        let root_b = ws.virtual_url_generator.new_path("b");
+        ws.analysis.add_main_workspace(root_b.clone());
+        ws.analysis.set_workspace_emmyrc(root_b, config.into());
+
+        let code = r#"
+            ---@type number
+            local n = 1.5
+            ---@type integer
+            local i = n
+        "#;
+        let file_a = ws.def_file("a/main.lua", code);
+        let file_b = ws.def_file("b/main.lua", code);
+        assert!(has_diagnostic(
+            &ws,
+            file_a,
+            DiagnosticCode::AssignTypeMismatch
+        ));
+        assert!(!has_diagnostic(
+            &ws,
+            file_b,
+            DiagnosticCode::AssignTypeMismatch
+        ));
+
+        let module_a = ws.def_file("a/src/util.lua", "return {}");
+        let module_b = ws.def_file("b/src/util.lua", "return {}");
+        let module_index = ws.analysis.compilation.get_db().get_module_index();
+        assert!(module_index
+            .get_module(module_a)
+            .is_some_and(|module| module.full_module_name == "a.src.util"));
+        assert!(module_index
+            .get_module(module_b)
+            .is_some_and(|module| module.full_module_name == "util"));
+    }
+
+    #[test]
+    fn test_remove_main_workspace_library() {
+        let mut ws = VirtualWorkspace::new();
+        let root = ws.virtual_url_generator.base.clone();
+        let root_b = root.join("b");
+        let library = root.join("lib");
+        let mut config = Emmyrc::default();
+        config
+            .workspace
+            .library
+            .push(library.to_string_lossy().to_string());
+        ws.analysis
+            .compilation
+            .get_db_mut()
+            .get_module_index_mut()
+            .remove_workspace_root(&root);
+        ws.analysis.add_main_workspace(root_b.clone());
+        ws.analysis.add_library_workspace(library);
+        ws.analysis
+            .set_workspace_emmyrc(root_b.clone(), config.into());
+        let file_lib = ws.def_file("lib/lib.lua", "local l = 1");
+        let file_b = ws.def_file("b/main.lua", "local b = 1");
+
+        let mut removed_uris = ws.analysis.remove_main_workspace(&root_b);
+        removed_uris.sort_by_key(|uri| uri.to_string());
+        assert_eq!(
+            removed_uris,
+            vec![
+                ws.virtual_url_generator.new_uri("b/main.lua"),
+                ws.virtual_url_generator.new_uri("lib/lib.lua"),
+            ]
+        );
+        let db = ws.analysis.compilation.get_db();
+        assert!(db.get_vfs().get_syntax_tree(&file_lib).is_none());
+        assert!(db.get_vfs().get_syntax_tree(&file_b).is_none());
+        assert!(db
+            .get_module_index()
+            .get_module_path_by_file_path(&root.join("lib/other.lua").to_string_lossy())
+            .is_none());
+    }
+
+    #[test]
+    fn test_add_main_workspace_with_emmyrc() {
+        let mut ws = VirtualWorkspace::new();
+        let root = ws.virtual_url_generator.base.clone();
+        let library = root.join("lib");
+        let shared_library = root.join("shared");
+        let mut main_config = Emmyrc::default();
+        main_config
+            .workspace
+            .library
+            .push(shared_library.to_string_lossy().to_string());
+        ws.analysis.update_config(main_config.into());
+
+        let mut config = Emmyrc::default();
+        config.workspace.library = vec![
+            library.to_string_lossy().to_string(),
+            shared_library.to_string_lossy().to_string(),
+        ];
+        // the library of the main config is loaded already
+        let roots = ws
+            .analysis
+            .add_main_workspace_with_emmyrc(root.join("b"), config.into());
+        assert_eq!(roots, vec![root.join("b"), library]);
+        let file_b = ws.def_file("b/main.lua", "local b = 1");
+        assert!(ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_file_config_root(&file_b)
+            .is_some_and(|config_root| *config_root == root.join("b")));
+    }
+}
@@ -0,0 +1,368 @@
+use std::{collections::HashMap, fmt};
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+
+/// Deserializes the indexes saved by another run, which gave other ids to the files. Every file
+/// id is replaced by the id the file has now, the file ids are written as `FileId` newtypes and
+/// the declaration and signature ids embed them in their `file_id|position` text.
+pub(super) struct FileIdMapDeserializer<'a, D> {
+    inner: D,
+    file_id_map: &'a HashMap<u32, u32>,
+}
+
+impl<'a, D> FileIdMapDeserializer<'a, D> {
+    pub(super) fn new(inner: D, file_id_map: &'a HashMap<u32, u32>) -> Self {
+        Self { inner, file_id_map }
+    }
+}
+
+fn map_file_id<E: de::Error>(file_id_map: &HashMap<u32, u32>, id: u32) -> Result<u32, E> {
+    file_id_map
+        .get(&id)
+        .copied()
+        .ok_or_else(|| E::custom(format!("file id {} is not mapped", id)))
+}
+
+fn map_id_text<E: de::Error>(file_id_map: &HashMap<u32, u32>, text: &str) -> Result<String, E> {
+    let Some((file_id, position)) = text.split_once('|') else {
+        return Err(E::custom("expected format 'file_id:position'"));
+    };
+    let file_id = file_id
+        .parse()
+        .map_err(|e| E::custom(format!("invalid file_id: {}", e)))?;
+    Ok(format!(
+        "{}|{}",
+        map_file_id::<E>(file_id_map, file_id)?,
+        position
+    ))
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, D::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.inner.$method(Wrap::new(visitor, self.file_id_map))
+            }
+        )*
+    };
+}
+
+impl<'de, D> Deserializer<'de> for FileIdMapDeserializer<'_, D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32
+        deserialize_u64 deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char
+        deserialize_str deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_option deserialize_unit deserialize_seq deserialize_map
+        deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_unit_struct(name, Wrap::new(visitor, self.file_id_map))
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match name {
+            "FileId" => {
+                let id = <u32 as de::Deserialize>::deserialize(self.inner)?;
+                let id = map_file_id::<D::Error>(self.file_id_map, id)?;
+                visitor.visit_newtype_struct(id.into_deserializer())
+            }
+            "LuaDeclId" | "LuaSignatureId" => {
+                let text = <String as de::Deserialize>::deserialize(self.inner)?;
+                let text = map_id_text::<D::Error>(self.file_id_map, &text)?;
+                visitor.visit_newtype_struct(text.into_deserializer())
+            }
+            _ => self
+                .inner
+                .deserialize_newtype_struct(name, Wrap::new(visitor, self.file_id_map)),
+        }
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_tuple(len, Wrap::new(visitor, self.file_id_map))
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_tuple_struct(name, len, Wrap::new(visitor, self.file_id_map))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_struct(name, fields, Wrap::new(visitor, self.file_id_map))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .deserialize_enum(name, variants, Wrap::new(visitor, self.file_id_map))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// Passes the file id map down to the nested values of whatever the wrapped value visits
+struct Wrap<'a, T> {
+    inner: T,
+    file_id_map: &'a HashMap<u32, u32>,
+}
+
+impl<'a, T> Wrap<'a, T> {
+    fn new(inner: T, file_id_map: &'a HashMap<u32, u32>) -> Self {
+        Self { inner, file_id_map }
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method<E>(self, value: $ty) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.inner.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V> Visitor<'de> for Wrap<'_, V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool) visit_i8(i8) visit_i16(i16) visit_i32(i32) visit_i64(i64)
+        visit_i128(i128) visit_u8(u8) visit_u16(u16) visit_u32(u32) visit_u64(u64)
+        visit_u128(u128) visit_f32(f32) visit_f64(f64) visit_char(char) visit_str(&str)
+        visit_borrowed_str(&'de str) visit_string(String) visit_bytes(&[u8])
+        visit_borrowed_bytes(&'de [u8]) visit_byte_buf(Vec<u8>)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner
+            .visit_some(FileIdMapDeserializer::new(deserializer, self.file_id_map))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner
+            .visit_newtype_struct(FileIdMapDeserializer::new(deserializer, self.file_id_map))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.inner.visit_seq(Wrap::new(seq, self.file_id_map))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.inner.visit_map(Wrap::new(map, self.file_id_map))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.inner.visit_enum(Wrap::new(data, self.file_id_map))
+    }
+}
+
+impl<'de, T> DeserializeSeed<'de> for Wrap<'_, T>
+where
+    T: DeserializeSeed<'de>,
+{
+    type Value = T::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.inner
+            .deserialize(FileIdMapDeserializer::new(deserializer, self.file_id_map))
+    }
+}
+
+impl<'de, A> SeqAccess<'de> for Wrap<'_, A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, A::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.inner
+            .next_element_seed(Wrap::new(seed, self.file_id_map))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A> MapAccess<'de> for Wrap<'_, A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.inner.next_key_seed(Wrap::new(seed, self.file_id_map))
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, A::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.inner
+            .next_value_seed(Wrap::new(seed, self.file_id_map))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, 'a, A> EnumAccess<'de> for Wrap<'a, A>
+where
+    A: EnumAccess<'de>,
+{
+    type Error = A::Error;
+    type Variant = Wrap<'a, A::Variant>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), A::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        Ok((value, Wrap::new(variant, self.file_id_map)))
+    }
+}
+
+impl<'de, A> VariantAccess<'de> for Wrap<'_, A>
+where
+    A: VariantAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, A::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.inner
+            .newtype_variant_seed(Wrap::new(seed, self.file_id_map))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, A::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .tuple_variant(len, Wrap::new(visitor, self.file_id_map))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.inner
+            .struct_variant(fields, Wrap::new(visitor, self.file_id_map))
+    }
+}
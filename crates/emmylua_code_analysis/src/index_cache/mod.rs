@@ -0,0 +1,215 @@
+mod file_id_map;
+mod test;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use file_id_map::FileIdMapDeserializer;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    db_index::LuaFileIndexCache, file_path_to_uri, read_file_with_encoding, DbIndex, Emmyrc,
+    FileId, WorkspaceId,
+};
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Bump it whenever the layout of the saved indexes changes, the caches saved by a development
+/// build keep the same package version
+const FORMAT_VERSION: u32 = 2;
+
+/// The saved analysis of all the files of a std or library workspace
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexCacheFile {
+    version: String,
+    format_version: u32,
+    config_hash: u64,
+    /// The paths of the file ids when the cache was saved, the same file gets another id when the
+    /// cache is loaded
+    file_paths: Vec<(u32, PathBuf)>,
+    files: Vec<IndexCacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexCacheEntry {
+    path: PathBuf,
+    content_hash: u64,
+    index: serde_json::Value,
+}
+
+pub fn hash_file_content(content: &str) -> u64 {
+    fnv1a_hash(content.as_bytes())
+}
+
+// the std hasher isn't guaranteed to be stable between releases, the cache outlives the build
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+fn hash_config(emmyrc: &Emmyrc) -> u64 {
+    // the json object keeps its keys sorted, so the same config always gives the same text
+    let config = serde_json::to_value(emmyrc)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    hash_file_content(&config)
+}
+
+fn get_cache_path(cache_dir: &Path, root: &Path) -> PathBuf {
+    let hash = fnv1a_hash(root.to_string_lossy().as_bytes());
+    cache_dir.join(format!("{:016x}.json", hash))
+}
+
+/// Load the saved analysis of the workspace into the db, the files of the workspace are read from
+/// `files` when it is given, else from the disk. Returns the content hash of every loaded file, or
+/// None when the cache is missing or any file was changed since the cache was saved.
+pub(crate) fn load_index_cache(
+    db: &mut DbIndex,
+    cache_dir: &Path,
+    root: &Path,
+    files: Option<Vec<(PathBuf, String)>>,
+) -> Option<HashMap<FileId, u64>> {
+    let cache_path = get_cache_path(cache_dir, root);
+    let text = std::fs::read_to_string(&cache_path).ok()?;
+    let cache: IndexCacheFile = match serde_json::from_str(&text) {
+        Ok(cache) => cache,
+        Err(e) => {
+            log::warn!("invalid index cache {:?}: {}", cache_path, e);
+            return None;
+        }
+    };
+    if cache.version != VERSION
+        || cache.format_version != FORMAT_VERSION
+        || cache.config_hash != hash_config(db.get_emmyrc())
+    {
+        return None;
+    }
+
+    let mut given_files: Option<HashMap<PathBuf, String>> =
+        files.map(|files| files.into_iter().collect());
+    if let Some(given_files) = &given_files {
+        if given_files.len() != cache.files.len() {
+            return None;
+        }
+    }
+
+    let encoding = db.get_emmyrc().workspace.encoding.clone();
+    let mut contents = Vec::with_capacity(cache.files.len());
+    for entry in &cache.files {
+        let content = match &mut given_files {
+            Some(given_files) => given_files.remove(&entry.path)?,
+            None => read_file_with_encoding(&entry.path, &encoding)?,
+        };
+        if hash_file_content(&content) != entry.content_hash {
+            return None;
+        }
+
+        contents.push(content);
+    }
+
+    let mut content_hashes = HashMap::new();
+    for (entry, content) in cache.files.iter().zip(contents) {
+        let uri = file_path_to_uri(&entry.path)?;
+        let file_id = db.get_vfs_mut().set_file_content(&uri, Some(content));
+        content_hashes.insert(file_id, entry.content_hash);
+    }
+
+    let mut file_id_map = HashMap::from([(FileId::VIRTUAL.id, FileId::VIRTUAL.id)]);
+    for (old_id, path) in &cache.file_paths {
+        if let Some(file_id) = file_path_to_uri(path).and_then(|uri| db.get_vfs().get_file_id(&uri))
+        {
+            file_id_map.insert(*old_id, file_id.id);
+        }
+    }
+
+    let indexes = cache
+        .files
+        .into_iter()
+        .map(|entry| {
+            let file_id = file_path_to_uri(&entry.path)
+                .and_then(|uri| db.get_vfs().get_file_id(&uri))
+                .ok_or_else(|| format!("{:?} is not loaded", entry.path))?;
+            let deserializer = FileIdMapDeserializer::new(entry.index, &file_id_map);
+            let index = LuaFileIndexCache::deserialize(deserializer).map_err(|e| e.to_string())?;
+            Ok((file_id, index))
+        })
+        .collect::<Result<Vec<_>, String>>();
+    let indexes = match indexes {
+        Ok(indexes) => indexes,
+        Err(e) => {
+            log::warn!("invalid index cache {:?}: {}", cache_path, e);
+            return None;
+        }
+    };
+
+    for (file_id, index) in indexes {
+        db.add_file_index_cache(file_id, index);
+    }
+
+    log::info!(
+        "load index cache of {:?}, {} files",
+        root,
+        content_hashes.len()
+    );
+    Some(content_hashes)
+}
+
+/// Save the analysis of all the files of the workspace, so the next start can load it instead of
+/// analyzing the files again
+pub(crate) fn save_index_cache(
+    db: &DbIndex,
+    cache_dir: &Path,
+    root: &Path,
+    workspace_id: WorkspaceId,
+) -> Option<()> {
+    let vfs = db.get_vfs();
+    let files = db
+        .get_module_index()
+        .get_workspace_file_ids(workspace_id)
+        .into_iter()
+        .map(|file_id| {
+            let path = vfs.get_file_path(&file_id)?.clone();
+            let content_hash = hash_file_content(vfs.get_file_content(&file_id)?);
+            let index = serde_json::to_value(db.get_file_index_cache(file_id)).ok()?;
+            Some(IndexCacheEntry {
+                path,
+                content_hash,
+                index,
+            })
+        })
+        .collect::<Option<Vec<_>>>();
+
+    let cache = IndexCacheFile {
+        version: VERSION.to_string(),
+        format_version: FORMAT_VERSION,
+        config_hash: hash_config(db.get_emmyrc()),
+        // the indexes may refer to the files of the other workspaces too
+        file_paths: vfs
+            .get_all_file_ids()
+            .into_iter()
+            .filter_map(|file_id| Some((file_id.id, vfs.get_file_path(&file_id)?.clone())))
+            .collect(),
+        files: files?,
+    };
+
+    let cache_path = get_cache_path(cache_dir, root);
+    let text = serde_json::to_string(&cache).ok()?;
+    // write the whole cache before it replaces the old one, so another server never reads half
+    // of a cache
+    let temp_path = cache_path.with_extension(format!("{}.tmp", std::process::id()));
+    let result = std::fs::create_dir_all(cache_dir)
+        .and_then(|_| std::fs::write(&temp_path, text))
+        .and_then(|_| std::fs::rename(&temp_path, &cache_path));
+    if let Err(e) = result {
+        log::error!("Failed to save index cache {:?}: {}", cache_path, e);
+        let _ = std::fs::remove_file(&temp_path);
+        return None;
+    }
+
+    Some(())
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::Path};
+
+    use rowan::TextSize;
+    use serde::Deserialize;
+
+    use crate::{
+        file_path_to_uri, index_cache::file_id_map::FileIdMapDeserializer, EmmyLuaAnalysis, FileId,
+        LuaDeclId, LuaMemberOwner, LuaType, LuaTypeDeclId,
+    };
+
+    const LIB_CODE: &str = r#"
+        ---@class Lib.Point
+        ---@field x number
+        local Point = {}
+
+        ---@param x number
+        ---@return Lib.Point
+        function Point.new(x)
+        end
+
+        LibPoint = Point
+        return Point
+    "#;
+
+    fn create_analysis(cache_dir: &Path, lib_dir: &Path) -> EmmyLuaAnalysis {
+        let mut analysis = EmmyLuaAnalysis::new();
+        analysis.set_index_cache_dir(Some(cache_dir.to_path_buf()));
+        analysis.add_library_workspace(lib_dir.to_path_buf());
+        analysis
+    }
+
+    fn check_library_index_cache(test_dir: &Path) -> Option<()> {
+        let cache_dir = test_dir.join("index_cache");
+        let lib_dir = test_dir.join("lib");
+        let lib_file = lib_dir.join("point.lua");
+        std::fs::create_dir_all(&lib_dir).ok()?;
+        std::fs::write(&lib_file, LIB_CODE).ok()?;
+        let lib_uri = file_path_to_uri(&lib_file)?;
+
+        // nothing is saved yet, so the library is analyzed and then saved
+        let mut analysis = create_analysis(&cache_dir, &lib_dir);
+        assert!(analysis.get_file_id(&lib_uri).is_none());
+        let updated_files =
+            analysis.update_files_by_path(vec![(lib_file.clone(), Some(LIB_CODE.to_string()))]);
+        assert_eq!(updated_files.len(), 1);
+        analysis.save_index_cache();
+
+        let mut analysis = create_analysis(&cache_dir, &lib_dir);
+        let file_id = analysis.get_file_id(&lib_uri)?;
+        let db = analysis.compilation.get_db();
+        let type_id = LuaTypeDeclId::new("Lib.Point");
+        assert!(db.get_type_index().get_type_decl(&type_id).is_some());
+        assert!(db
+            .get_member_index()
+            .get_members(&LuaMemberOwner::Type(type_id))
+            .is_some_and(|members| members.len() == 2));
+        assert!(db.get_global_index().is_exist_global_decl("LibPoint"));
+        assert!(db
+            .get_module_index()
+            .get_module(file_id)
+            .is_some_and(|module| module.export_type.is_some()));
+        // the loaded file is not analyzed again while it is unchanged
+        let updated_files =
+            analysis.update_files_by_path(vec![(lib_file.clone(), Some(LIB_CODE.to_string()))]);
+        assert!(updated_files.is_empty());
+
+        let changed_code = format!("{}\nLibChanged = 1\n", LIB_CODE);
+        std::fs::write(&lib_file, &changed_code).ok()?;
+        let analysis = create_analysis(&cache_dir, &lib_dir);
+        assert!(analysis.get_file_id(&lib_uri).is_none());
+        Some(())
+    }
+
+    #[test]
+    fn test_library_index_cache() {
+        let test_dir =
+            std::env::temp_dir().join(format!("emmylua_index_cache_{}", std::process::id()));
+        let result = check_library_index_cache(&test_dir);
+        let _ = std::fs::remove_dir_all(&test_dir);
+        assert!(result.is_some());
+    }
+
+    fn get_global_type(analysis: &EmmyLuaAnalysis, name: &str) -> Option<LuaType> {
+        let db = analysis.compilation.get_db();
+        let decl_id = *db.get_global_index().get_global_decl_ids(name)?.first()?;
+        Some(
+            db.get_type_index()
+                .get_type_cache(&decl_id.into())?
+                .as_type()
+                .clone(),
+        )
+    }
+
+    fn check_cached_dependents(test_dir: &Path) -> Option<()> {
+        let cache_dir = test_dir.join("index_cache");
+        let lib_dir = test_dir.join("lib");
+        let value_file = lib_dir.join("value.lua");
+        let user_file = lib_dir.join("user.lua");
+        let value_code = "---@type integer\nLibValue = 1\n";
+        let user_code = "UserValue = LibValue\n";
+        std::fs::create_dir_all(&lib_dir).ok()?;
+        std::fs::write(&value_file, value_code).ok()?;
+        std::fs::write(&user_file, user_code).ok()?;
+
+        let mut analysis = create_analysis(&cache_dir, &lib_dir);
+        analysis.update_files_by_path(vec![
+            (value_file.clone(), Some(value_code.to_string())),
+            (user_file.clone(), Some(user_code.to_string())),
+        ]);
+        analysis.save_index_cache();
+
+        let mut analysis = create_analysis(&cache_dir, &lib_dir);
+        assert_eq!(
+            get_global_type(&analysis, "UserValue"),
+            Some(LuaType::Integer)
+        );
+        // the unchanged file which uses the changed declaration is analyzed again
+        analysis.update_files_by_path(vec![
+            (
+                value_file.clone(),
+                Some("---@type string\nLibValue = ''\n".to_string()),
+            ),
+            (user_file.clone(), Some(user_code.to_string())),
+        ]);
+        assert_eq!(
+            get_global_type(&analysis, "UserValue"),
+            Some(LuaType::String)
+        );
+        Some(())
+    }
+
+    #[test]
+    fn test_cached_dependents() {
+        let test_dir = std::env::temp_dir().join(format!(
+            "emmylua_index_cache_dependents_{}",
+            std::process::id()
+        ));
+        let result = check_cached_dependents(&test_dir);
+        let _ = std::fs::remove_dir_all(&test_dir);
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn test_file_id_map() {
+        let saved = (
+            vec![FileId::new(3), FileId::new(4)],
+            LuaDeclId::new(FileId::new(3), TextSize::new(5)),
+            Some(vec![LuaDeclId::new(FileId::new(4), TextSize::new(1))]),
+        );
+        let value = serde_json::to_value(&saved).expect("serialize the ids");
+        // the json keeps the plain ids
+        assert_eq!(value, serde_json::json!([[3, 4], "3|5", ["4|1"]]));
+
+        let file_id_map = HashMap::from([(3, 7), (4, 8)]);
+        let loaded = <(Vec<FileId>, LuaDeclId, Option<Vec<LuaDeclId>>)>::deserialize(
+            FileIdMapDeserializer::new(value.clone(), &file_id_map),
+        );
+        assert!(loaded.is_ok_and(|loaded| loaded
+            == (
+                vec![FileId::new(7), FileId::new(8)],
+                LuaDeclId::new(FileId::new(7), TextSize::new(5)),
+                Some(vec![LuaDeclId::new(FileId::new(8), TextSize::new(1))]),
+            )));
+
+        let file_id_map = HashMap::from([(3, 7)]);
+        let unmapped = <(Vec<FileId>, LuaDeclId, Option<Vec<LuaDeclId>>)>::deserialize(
+            FileIdMapDeserializer::new(value, &file_id_map),
+        );
+        assert!(unmapped.is_err());
+    }
+}
@@ -9,6 +9,7 @@ mod compilation;
 mod config;
 mod db_index;
 mod diagnostic;
+mod index_cache;
 mod locale;
 mod profile;
 mod resources;
@@ -20,13 +21,22 @@ pub use compilation::*;
 pub use config::*;
 pub use db_index::*;
 pub use diagnostic::*;
-pub use emmylua_codestyle::*;
+pub use emmylua_code_style::{
+    check_code_style, range_format_code, reformat_code, remove_code_style, update_code_style,
+    CodeStyleDiagnostic, RangeFormatResult,
+};
+use index_cache::{hash_file_content, load_index_cache, save_index_cache};
 pub use locale::get_locale_code;
-use lsp_types::Uri;
+use lsp_types::{TextDocumentContentChangeEvent, Uri};
 pub use profile::Profile;
+pub use resources::get_index_cache_dir;
 use resources::load_resource_std;
 pub use semantic::*;
-use std::{collections::HashSet, path::PathBuf, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 pub use test_lib::VirtualWorkspace;
 use tokio_util::sync::CancellationToken;
 pub use vfs::*;
@@ -46,6 +56,11 @@ pub struct EmmyLuaAnalysis {
     pub diagnostic: LuaDiagnostic,
     pub emmyrc: Arc<Emmyrc>,
     lib_workspace_counter: u32,
+    index_cache_dir: Option<PathBuf>,
+    /// The std and library workspaces which were analyzed instead of loaded from the index cache
+    unsaved_cache_workspaces: Vec<(PathBuf, WorkspaceId)>,
+    /// The content hashes of the files loaded from the index cache
+    cached_files: HashMap<FileId, u64>,
 }
 
 impl EmmyLuaAnalysis {
@@ -56,27 +71,44 @@ impl EmmyLuaAnalysis {
             diagnostic: LuaDiagnostic::new(),
             emmyrc,
             lib_workspace_counter: 2,
+            index_cache_dir: None,
+            unsaved_cache_workspaces: Vec::new(),
+            cached_files: HashMap::new(),
         }
     }
 
+    /// Load the std and library workspaces from the index cache in the directory, and save the
+    /// ones which are analyzed with `save_index_cache`
+    pub fn set_index_cache_dir(&mut self, index_cache_dir: Option<PathBuf>) {
+        self.index_cache_dir = index_cache_dir;
+    }
+
     pub fn init_std_lib(&mut self, create_resources_dir: Option<String>) {
         let is_jit = matches!(self.emmyrc.runtime.version, EmmyrcLuaVersion::LuaJIT);
         let (std_root, files) = load_resource_std(create_resources_dir, is_jit);
         self.compilation
             .get_db_mut()
             .get_module_index_mut()
-            .add_workspace_root(std_root, WorkspaceId::STD);
+            .add_workspace_root(std_root.clone(), WorkspaceId::STD);
 
         let files = files
             .into_iter()
             .filter_map(|file| {
                 if file.path.ends_with(".lua") {
-                    Some((PathBuf::from(file.path), Some(file.content)))
+                    Some((PathBuf::from(file.path), file.content))
                 } else {
                     None
                 }
             })
             .collect::<Vec<_>>();
+        if self.load_index_cache(&std_root, WorkspaceId::STD, Some(files.clone())) {
+            return;
+        }
+
+        let files = files
+            .into_iter()
+            .map(|(path, content)| (path, Some(content)))
+            .collect();
         self.update_files_by_path(files);
     }
 
@@ -95,6 +127,156 @@ impl EmmyLuaAnalysis {
             .add_workspace_root(root, WorkspaceId::MAIN);
     }
 
+    /// Remove the main workspace root with its config, the workspace roots and libraries which
+    /// only its config adds, and the files which no other workspace contains. Returns the removed
+    /// files
+    pub fn remove_main_workspace(&mut self, root: &Path) -> Vec<Uri> {
+        let removed_roots = self.collect_workspace_own_roots(root);
+        self.remove_workspace_emmyrc(root);
+        let db = self.compilation.get_db_mut();
+        for removed_root in &removed_roots {
+            db.get_module_index_mut()
+                .remove_workspace_root(removed_root);
+        }
+
+        let module_index = db.get_module_index();
+        let vfs = db.get_vfs();
+        let removed_uris: Vec<Uri> = vfs
+            .get_all_file_ids()
+            .into_iter()
+            .filter(|file_id| !module_index.is_std(file_id))
+            .filter_map(|file_id| {
+                let path = vfs.get_file_path(&file_id)?;
+                if !removed_roots
+                    .iter()
+                    .any(|removed_root| path.starts_with(removed_root))
+                {
+                    return None;
+                }
+                // the file still belongs to a nested or an overlapping workspace
+                if module_index
+                    .get_module_path_by_file_path(path.to_str()?)
+                    .is_some()
+                {
+                    return None;
+                }
+
+                vfs.get_uri(&file_id)
+            })
+            .collect();
+
+        let mut removed_files = Vec::new();
+        for uri in &removed_uris {
+            if let Some(file_id) = self.compilation.get_db_mut().get_vfs_mut().remove_file(uri) {
+                removed_files.push(file_id);
+            }
+        }
+        self.compilation.update_changed_index(removed_files);
+        self.compilation.update_dependents_index();
+        removed_uris
+    }
+
+    /// Add the main workspace root with its config, and the workspace roots and libraries which
+    /// no other config adds yet. Returns the added roots, their files still have to be loaded
+    pub fn add_main_workspace_with_emmyrc(
+        &mut self,
+        root: PathBuf,
+        emmyrc: Arc<Emmyrc>,
+    ) -> Vec<PathBuf> {
+        let other_emmyrcs: Vec<Arc<Emmyrc>> = std::iter::once(self.emmyrc.clone())
+            .chain(
+                self.compilation
+                    .get_db()
+                    .get_vfs()
+                    .get_workspace_emmyrcs()
+                    .iter()
+                    .filter(|(r, _)| *r != root)
+                    .map(|(_, emmyrc)| emmyrc.clone()),
+            )
+            .collect();
+        let is_new = |path: &String| {
+            !other_emmyrcs.iter().any(|other| {
+                other.workspace.workspace_roots.contains(path)
+                    || other.workspace.library.contains(path)
+            })
+        };
+        let workspace_roots: Vec<PathBuf> = emmyrc
+            .workspace
+            .workspace_roots
+            .iter()
+            .filter(|path| is_new(path))
+            .map(PathBuf::from)
+            .collect();
+        let libraries: Vec<PathBuf> = emmyrc
+            .workspace
+            .library
+            .iter()
+            .filter(|path| is_new(path))
+            .map(PathBuf::from)
+            .collect();
+
+        self.add_main_workspace(root.clone());
+        self.set_workspace_emmyrc(root.clone(), emmyrc);
+        for workspace_root in &workspace_roots {
+            self.add_main_workspace(workspace_root.clone());
+        }
+        for library in &libraries {
+            self.add_library_workspace(library.clone());
+        }
+
+        std::iter::once(root)
+            .chain(workspace_roots)
+            .chain(libraries)
+            .collect()
+    }
+
+    // the root with the workspace roots and libraries of its config which no other config adds
+    fn collect_workspace_own_roots(&self, root: &Path) -> Vec<PathBuf> {
+        let mut roots = vec![root.to_path_buf()];
+        let workspace_emmyrcs = self.compilation.get_db().get_vfs().get_workspace_emmyrcs();
+        let Some((_, emmyrc)) = workspace_emmyrcs.iter().find(|(r, _)| r == root) else {
+            return roots;
+        };
+
+        let other_emmyrcs: Vec<&Arc<Emmyrc>> = std::iter::once(&self.emmyrc)
+            .chain(
+                workspace_emmyrcs
+                    .iter()
+                    .filter(|(r, _)| r != root)
+                    .map(|(_, emmyrc)| emmyrc),
+            )
+            .collect();
+        let workspace = &emmyrc.workspace;
+        for path in workspace.workspace_roots.iter().chain(&workspace.library) {
+            let is_shared = other_emmyrcs.iter().any(|other| {
+                other.workspace.workspace_roots.contains(path)
+                    || other.workspace.library.contains(path)
+            });
+            if !is_shared {
+                roots.push(PathBuf::from(path));
+            }
+        }
+
+        roots
+    }
+
+    /// Analyze the files in the workspace root with the config instead of the main config, the
+    /// files which are already loaded need to be updated again
+    pub fn set_workspace_emmyrc(&mut self, root: PathBuf, emmyrc: Arc<Emmyrc>) {
+        self.diagnostic.set_workspace_config(root.clone(), &emmyrc);
+        let db = self.compilation.get_db_mut();
+        db.get_module_index_mut()
+            .set_workspace_config(root.clone(), &emmyrc);
+        db.get_vfs_mut().set_workspace_emmyrc(root, emmyrc);
+    }
+
+    pub fn remove_workspace_emmyrc(&mut self, root: &Path) {
+        self.diagnostic.remove_workspace_config(root);
+        let db = self.compilation.get_db_mut();
+        db.get_module_index_mut().remove_workspace_config(root);
+        db.get_vfs_mut().remove_workspace_emmyrc(root);
+    }
+
     pub fn add_library_workspace(&mut self, root: PathBuf) {
         let id = WorkspaceId {
             id: self.lib_workspace_counter,
@@ -104,25 +286,99 @@ impl EmmyLuaAnalysis {
         self.compilation
             .get_db_mut()
             .get_module_index_mut()
-            .add_workspace_root(root, id);
+            .add_workspace_root(root.clone(), id);
+        self.load_index_cache(&root, id, None);
+    }
+
+    fn load_index_cache(
+        &mut self,
+        root: &Path,
+        workspace_id: WorkspaceId,
+        files: Option<Vec<(PathBuf, String)>>,
+    ) -> bool {
+        let Some(index_cache_dir) = &self.index_cache_dir else {
+            return false;
+        };
+
+        let _p = Profile::new("load index cache");
+        match load_index_cache(self.compilation.get_db_mut(), index_cache_dir, root, files) {
+            Some(content_hashes) => {
+                self.cached_files.extend(content_hashes);
+                true
+            }
+            None => {
+                self.unsaved_cache_workspaces
+                    .push((root.to_path_buf(), workspace_id));
+                false
+            }
+        }
+    }
+
+    /// Save the std and library workspaces which were analyzed, call it after their files are
+    /// indexed
+    pub fn save_index_cache(&mut self) {
+        let Some(index_cache_dir) = &self.index_cache_dir else {
+            return;
+        };
+
+        let _p = Profile::new("save index cache");
+        for (root, workspace_id) in std::mem::take(&mut self.unsaved_cache_workspaces) {
+            save_index_cache(
+                self.compilation.get_db(),
+                index_cache_dir,
+                &root,
+                workspace_id,
+            );
+        }
     }
 
     pub fn update_file_by_uri(&mut self, uri: &Uri, text: Option<String>) -> Option<FileId> {
-        let is_removed = text.is_none();
         let file_id = self
             .compilation
             .get_db_mut()
             .get_vfs_mut()
             .set_file_content(uri, text);
 
-        self.compilation.remove_index(vec![file_id]);
-        if !is_removed {
-            self.compilation.update_index(vec![file_id]);
-        }
+        self.cached_files.remove(&file_id);
+        self.compilation.update_changed_index(vec![file_id]);
+        Some(file_id)
+    }
+
+    pub fn update_file_by_changes(
+        &mut self,
+        uri: &Uri,
+        changes: Vec<TextDocumentContentChangeEvent>,
+    ) -> Option<FileId> {
+        let file_id = self
+            .compilation
+            .get_db_mut()
+            .get_vfs_mut()
+            .apply_file_changes(uri, changes)?;
 
+        self.cached_files.remove(&file_id);
+        self.compilation.update_changed_index(vec![file_id]);
         Some(file_id)
     }
 
+    /// Reload an opened file whose changes could not be applied, the text on disk is the text of
+    /// the client once the file is saved
+    pub fn reload_out_of_sync_file(&mut self, uri: &Uri) -> Option<FileId> {
+        let vfs = self.compilation.get_db().get_vfs();
+        let file_id = vfs.get_file_id(uri)?;
+        if !vfs.is_out_of_sync(&file_id) {
+            return None;
+        }
+
+        let encoding = &self
+            .compilation
+            .get_db()
+            .get_file_emmyrc(file_id)
+            .workspace
+            .encoding;
+        let text = read_file_with_encoding(vfs.get_file_path(&file_id)?, encoding)?;
+        self.update_file_by_uri(uri, Some(text))
+    }
+
     pub fn update_file_by_path(&mut self, path: &PathBuf, text: Option<String>) -> Option<FileId> {
         let uri = file_path_to_uri(&path)?;
         self.update_file_by_uri(&uri, text)
@@ -131,9 +387,31 @@ impl EmmyLuaAnalysis {
     pub fn update_files_by_uri(&mut self, files: Vec<(Uri, Option<String>)>) -> Vec<FileId> {
         let mut removed_files = HashSet::new();
         let mut updated_files = HashSet::new();
+        let mut changed_cached_files = Vec::new();
         {
             let _p = Profile::new("update files");
             for (uri, text) in files {
+                // the files loaded from the index cache are analyzed only when they changed
+                if let Some(file_id) = self.compilation.get_db().get_vfs().get_file_id(&uri) {
+                    if let Some(content_hash) = self.cached_files.get(&file_id) {
+                        if text.as_deref().map(hash_file_content) == Some(*content_hash) {
+                            continue;
+                        }
+
+                        self.cached_files.remove(&file_id);
+                        let is_new_text = text.is_some();
+                        self.compilation
+                            .get_db_mut()
+                            .get_vfs_mut()
+                            .set_file_content(&uri, text);
+                        changed_cached_files.push(file_id);
+                        if is_new_text {
+                            updated_files.insert(file_id);
+                        }
+                        continue;
+                    }
+                }
+
                 let is_new_text = text.is_some();
                 let file_id = self
                     .compilation
@@ -148,20 +426,39 @@ impl EmmyLuaAnalysis {
         }
         self.compilation
             .remove_index(removed_files.into_iter().collect());
-        let updated_files: Vec<FileId> = updated_files.into_iter().collect();
-        self.compilation.update_index(updated_files.clone());
-        updated_files
+        self.compilation.update_index(
+            updated_files
+                .iter()
+                .filter(|file_id| !changed_cached_files.contains(file_id))
+                .copied()
+                .collect(),
+        );
+        // the cached files which use a declaration of a changed file were analyzed against the
+        // old declaration
+        if !changed_cached_files.is_empty() {
+            self.compilation.update_changed_index(changed_cached_files);
+            self.compilation.update_dependents_index();
+        }
+        updated_files.into_iter().collect()
     }
 
     pub fn remove_file_by_uri(&mut self, uri: &Uri) -> Option<FileId> {
         if let Some(file_id) = self.compilation.get_db_mut().get_vfs_mut().remove_file(uri) {
-            self.compilation.remove_index(vec![file_id]);
+            self.cached_files.remove(&file_id);
+            self.compilation.update_changed_index(vec![file_id]);
             return Some(file_id);
         }
 
         None
     }
 
+    /// Re-analyze the files which depend on a declaration that the updated files changed,
+    /// it is left to the caller to run it when the edits settle. Returns the re-analyzed files.
+    pub fn update_dependents(&mut self) -> Vec<FileId> {
+        let _p = Profile::new("update dependents");
+        self.compilation.update_dependents_index()
+    }
+
     pub fn update_files_by_path(&mut self, files: Vec<(PathBuf, Option<String>)>) -> Vec<FileId> {
         let files = files
             .into_iter()
@@ -192,6 +489,11 @@ impl EmmyLuaAnalysis {
             .diagnose_file(&self.compilation, file_id, cancel_token)
     }
 
+    /// Whether the diagnostics are enabled by the config which applies to the file
+    pub fn is_diagnostic_enabled(&self, file_id: FileId) -> bool {
+        self.diagnostic.is_file_enable(&self.compilation, file_id)
+    }
+
     pub fn reindex(&mut self) {
         let module = self.compilation.get_db().get_module_index();
         let std_file_ids = module.get_std_file_ids();
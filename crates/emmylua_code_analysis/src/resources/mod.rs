@@ -10,6 +10,11 @@ use crate::{load_workspace_files, LuaFileInfo};
 static RESOURCE_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/resources");
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The directory of the saved index caches, next to the resources directory
+pub fn get_index_cache_dir() -> PathBuf {
+    get_best_resources_dir().with_file_name("index_cache")
+}
+
 pub fn load_resource_std(
     create_resources_dir: Option<String>,
     is_jit: bool,
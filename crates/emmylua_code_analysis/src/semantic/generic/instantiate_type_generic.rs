@@ -6,7 +6,8 @@ use crate::{
         LuaUnionType, VariadicType,
     },
     semantic::{member::infer_members, type_check},
-    DbIndex, GenericTpl, LuaAliasCallKind, LuaAliasCallType, LuaMemberKey, LuaSignatureId, TypeOps,
+    DbIndex, GenericTpl, LuaAliasCallKind, LuaAliasCallType, LuaConditionalType, LuaIndexAccessKey,
+    LuaMappedType, LuaMemberKey, LuaSignatureId, TypeOps,
 };
 
 use super::type_substitutor::{SubstitutorValue, TypeSubstitutor};
@@ -33,6 +34,8 @@ pub fn instantiate_type_generic(
         LuaType::Signature(sig_id) => instantiate_signature(db, sig_id, substitutor),
         LuaType::Call(alias_call) => instantiate_alias_call(db, alias_call, substitutor),
         LuaType::Variadic(variadic) => instantiate_variadic_type(db, variadic, substitutor),
+        LuaType::Conditional(conditional) => instantiate_conditional(db, conditional, substitutor),
+        LuaType::Mapped(mapped) => instantiate_mapped(db, mapped, substitutor),
         LuaType::SelfInfer => {
             if let Some(typ) = substitutor.get_self_type() {
                 typ.clone()
@@ -234,6 +237,22 @@ fn instantiate_generic(
     LuaType::Generic(LuaGenericType::new(type_decl_id, new_params).into())
 }
 
+/// The instantiated origin of a generic alias which computes a type from its params, such as a
+/// mapped or conditional type
+pub fn get_generic_alias_origin(db: &DbIndex, generic: &LuaGenericType) -> Option<LuaType> {
+    let type_decl_id = generic.get_base_type_id();
+    let type_decl = db.get_type_index().get_type_decl(&type_decl_id)?;
+    if !matches!(
+        type_decl.get_alias_ref()?,
+        LuaType::Call(_) | LuaType::Conditional(_) | LuaType::Mapped(_)
+    ) {
+        return None;
+    }
+
+    let substitutor = TypeSubstitutor::from_alias(generic.get_params().clone(), type_decl_id);
+    type_decl.get_alias_origin(db, Some(&substitutor))
+}
+
 fn instantiate_table_generic(
     db: &DbIndex,
     table_params: &Vec<LuaType>,
@@ -347,9 +366,30 @@ fn instantiate_alias_call(
                 return LuaType::Unknown;
             }
 
-            let compact = type_check::check_type_compact(db, &operands[0], &operands[1]).is_ok();
+            // the result is unknown until the generic params are instantiated
+            if operands.iter().any(|it| it.contain_tpl()) {
+                return LuaType::Call(
+                    LuaAliasCallType::new(LuaAliasCallKind::Extends, operands).into(),
+                );
+            }
+
+            // `A extends B` holds when `A` can be assigned to `B`
+            let compact = type_check::check_type_compact(db, &operands[1], &operands[0]).is_ok();
             return LuaType::BooleanConst(compact);
         }
+        LuaAliasCallKind::Index => {
+            if operands.len() != 2 {
+                return LuaType::Unknown;
+            }
+
+            if operands.iter().any(|it| it.contain_tpl()) {
+                return LuaType::Call(
+                    LuaAliasCallType::new(LuaAliasCallKind::Index, operands).into(),
+                );
+            }
+
+            return instantiate_index_call(db, &operands[0], &operands[1]);
+        }
         LuaAliasCallKind::Select => {
             if operands.len() != 2 {
                 return LuaType::Unknown;
@@ -363,6 +403,42 @@ fn instantiate_alias_call(
     LuaType::Unknown
 }
 
+fn instantiate_index_call(db: &DbIndex, source: &LuaType, key: &LuaType) -> LuaType {
+    if let LuaType::Union(keys) = key {
+        let types = keys
+            .get_types()
+            .iter()
+            .map(|key| instantiate_index_call(db, source, key))
+            .collect::<Vec<_>>();
+        return types
+            .into_iter()
+            .reduce(|acc, ty| TypeOps::Union.apply(db, &acc, &ty))
+            .unwrap_or(LuaType::Unknown);
+    }
+
+    if let LuaType::Array(base) = source {
+        if key.is_integer() || key.is_number() {
+            return base.deref().clone();
+        }
+    }
+
+    let member_key = match key {
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => {
+            LuaMemberKey::Name(s.deref().clone())
+        }
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => LuaMemberKey::Integer(*i),
+        _ => LuaMemberKey::Expr(key.clone()),
+    };
+
+    infer_members(db, source)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|member| member.key == member_key)
+        .map(|member| member.typ)
+        .reduce(|acc, ty| TypeOps::Union.apply(db, &acc, &ty))
+        .unwrap_or(LuaType::Unknown)
+}
+
 enum NumOrLen {
     Num(i64),
     Len,
@@ -478,3 +554,71 @@ fn instantiate_variadic_type(
 
     LuaType::Variadic(variadic.clone().into())
 }
+
+fn instantiate_conditional(
+    db: &DbIndex,
+    conditional: &LuaConditionalType,
+    substitutor: &TypeSubstitutor,
+) -> LuaType {
+    let condition = instantiate_type_generic(db, conditional.get_condition(), substitutor);
+    match condition {
+        LuaType::BooleanConst(true) | LuaType::DocBooleanConst(true) => {
+            instantiate_type_generic(db, conditional.get_true_type(), substitutor)
+        }
+        LuaType::BooleanConst(false) | LuaType::DocBooleanConst(false) => {
+            instantiate_type_generic(db, conditional.get_false_type(), substitutor)
+        }
+        _ => {
+            let true_type = instantiate_type_generic(db, conditional.get_true_type(), substitutor);
+            let false_type =
+                instantiate_type_generic(db, conditional.get_false_type(), substitutor);
+            LuaType::Conditional(LuaConditionalType::new(condition, true_type, false_type).into())
+        }
+    }
+}
+
+fn instantiate_mapped(
+    db: &DbIndex,
+    mapped: &LuaMappedType,
+    substitutor: &TypeSubstitutor,
+) -> LuaType {
+    let keys = instantiate_type_generic(db, mapped.get_keys(), substitutor);
+    if keys.contain_tpl() {
+        let value = instantiate_type_generic(db, mapped.get_value(), substitutor);
+        return LuaType::Mapped(
+            LuaMappedType::new(
+                mapped.get_param().clone(),
+                keys,
+                value,
+                mapped.is_nullable(),
+            )
+            .into(),
+        );
+    }
+
+    let keys = match keys {
+        LuaType::Union(union) => union.into_types(),
+        key => vec![key],
+    };
+
+    let mut fields = Vec::new();
+    for key in keys {
+        let mut key_substitutor = substitutor.clone();
+        key_substitutor.insert_type(mapped.get_param().get_tpl_id(), key.clone());
+        let mut value = instantiate_type_generic(db, mapped.get_value(), &key_substitutor);
+        if mapped.is_nullable() {
+            value = TypeOps::Union.apply(db, &value, &LuaType::Nil);
+        }
+
+        let key = match key {
+            LuaType::StringConst(s) | LuaType::DocStringConst(s) => {
+                LuaIndexAccessKey::String(s.deref().clone())
+            }
+            LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => LuaIndexAccessKey::Integer(i),
+            key => LuaIndexAccessKey::Type(key),
+        };
+        fields.push((key, value));
+    }
+
+    LuaType::Object(LuaObjectType::new(fields).into())
+}
@@ -5,6 +5,7 @@ mod tpl_pattern;
 mod type_substitutor;
 
 pub use instantiate_func_generic::instantiate_func_generic;
+pub use instantiate_type_generic::get_generic_alias_origin;
 pub use instantiate_type_generic::instantiate_doc_function;
 pub use instantiate_type_generic::instantiate_type_generic;
 pub use type_substitutor::TypeSubstitutor;
@@ -1,6 +1,6 @@
 mod infer_binary_or;
 
-use emmylua_parser::{BinaryOperator, LuaBinaryExpr};
+use emmylua_parser::{BinaryOperator, LuaAssignStat, LuaBinaryExpr};
 use infer_binary_or::{infer_binary_expr_or, special_or_rule};
 use smol_str::SmolStr;
 
@@ -35,6 +35,27 @@ pub fn infer_binary_expr(
     infer_binary_expr_type(db, left_type, right_type, op)
 }
 
+/// The value of a luau compound assignment like `a += 1` is `a + 1`, returns None when the
+/// assignment is not a compound one
+pub fn infer_compound_assign_value(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    assign_stat: &LuaAssignStat,
+    left_type: LuaType,
+) -> Option<InferResult> {
+    let op = assign_stat.get_compound_op_token()?.get_op();
+    let (_, exprs) = assign_stat.get_var_and_expr_list();
+    let right_type = match infer_expr(db, cache, exprs.first()?.clone()) {
+        Ok(right_type) => right_type,
+        Err(reason) => return Some(Err(reason)),
+    };
+
+    if let Some(ty) = infer_union_binary_expr(db, op, &left_type, &right_type) {
+        return Some(Ok(ty));
+    }
+    Some(infer_binary_expr_type(db, left_type, right_type, op))
+}
+
 fn infer_union_binary_expr(
     db: &DbIndex,
     op: BinaryOperator,
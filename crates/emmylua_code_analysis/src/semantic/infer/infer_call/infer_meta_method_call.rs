@@ -0,0 +1,57 @@
+use emmylua_parser::{LuaCallExpr, LuaExpr};
+
+use crate::{
+    get_meta_method_operators, infer_expr, semantic::check_type_compact,
+    semantic::infer::InferResult, DbIndex, LuaInferCache, LuaOperatorMetaMethod, LuaType,
+};
+
+/// `pairs(t)` and `tostring(t)` call the `__pairs` and `__tostring` metamethods of `t`, so the
+/// call returns what the metamethod returns. Returns `None` when `t` has no such metamethod or
+/// when the name does not refer to the std function.
+pub fn infer_meta_method_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: &LuaCallExpr,
+) -> Option<InferResult> {
+    let prefix_expr = call_expr.get_prefix_expr()?;
+    let LuaExpr::NameExpr(name_expr) = &prefix_expr else {
+        return None;
+    };
+    let op = match name_expr.get_name_text()?.as_str() {
+        "pairs" => LuaOperatorMetaMethod::Pairs,
+        "tostring" => LuaOperatorMetaMethod::ToString,
+        _ => return None,
+    };
+    let LuaType::Signature(signature_id) = infer_expr(db, cache, prefix_expr).ok()? else {
+        return None;
+    };
+    if !db.get_module_index().is_std(&signature_id.get_file_id()) {
+        return None;
+    }
+
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    if args.len() != 1 {
+        return None;
+    }
+
+    let arg_type = infer_expr(db, cache, args[0].clone()).ok()?;
+    let operator = get_meta_method_operators(db, &arg_type, op)
+        .into_iter()
+        .next()?;
+    let result = operator.get_result(db);
+    if op == LuaOperatorMetaMethod::ToString {
+        // `tostring` raises an error when `__tostring` does not return a string
+        return Some(result.map(|typ| {
+            if !typ.is_unknown()
+                && !typ.is_any()
+                && check_type_compact(db, &LuaType::String, &typ).is_ok()
+            {
+                typ
+            } else {
+                LuaType::String
+            }
+        }));
+    }
+
+    Some(result)
+}
@@ -16,9 +16,11 @@ use crate::{
     CacheEntry, CacheKey, DbIndex, InFiled, LuaFunctionType, LuaGenericType, LuaInstanceType,
     LuaOperatorMetaMethod, LuaOperatorOwner, LuaSignatureId, LuaType, LuaTypeDeclId, LuaUnionType,
 };
+use infer_meta_method_call::infer_meta_method_call;
 use infer_require::infer_require_call;
 use infer_setmetatable::infer_setmetatable_call;
 
+mod infer_meta_method_call;
 mod infer_require;
 mod infer_setmetatable;
 
@@ -619,6 +621,8 @@ pub fn infer_call_expr(
         return infer_require_call(db, cache, call_expr);
     } else if call_expr.is_setmetatable() {
         return infer_setmetatable_call(db, cache, call_expr);
+    } else if let Some(result) = infer_meta_method_call(db, cache, &call_expr) {
+        return result;
     }
 
     check_can_infer(db, cache, &call_expr)?;
@@ -12,7 +12,7 @@ use crate::{
         LuaOperatorMetaMethod, LuaTupleType, LuaType, LuaTypeDeclId, LuaUnionType,
     },
     semantic::{
-        generic::{instantiate_type_generic, TypeSubstitutor},
+        generic::{get_generic_alias_origin, instantiate_type_generic, TypeSubstitutor},
         member::get_buildin_type_map_type_id,
         type_check::{self, check_type_compact},
         InferGuard,
@@ -167,7 +167,7 @@ fn infer_array_member(
     index_expr: LuaIndexMemberExpr,
 ) -> Result<LuaType, InferFailReason> {
     let key = index_expr.get_index_key().ok_or(InferFailReason::None)?;
-    let expression_type = if db.get_emmyrc().strict.array_index {
+    let expression_type = if db.get_file_emmyrc(cache.get_file_id()).strict.array_index {
         TypeOps::Union.apply(db, array_type, &LuaType::Nil)
     } else {
         array_type.clone()
@@ -564,6 +564,11 @@ fn infer_generic_member(
     let generic_params = generic_type.get_params();
     let substitutor = TypeSubstitutor::from_type_array(generic_params.clone());
 
+    // the mapped or conditional alias origin is only known after the instantiation
+    if let Some(origin) = get_generic_alias_origin(db, generic_type) {
+        return infer_member_by_member_key(db, cache, &origin, index_expr, &mut InferGuard::new());
+    }
+
     // TODO: this is just a hack to support inheritance from the generic objects
     // like `---@class box<T>: T`. Should be rewritten: generic types should
     // be passed to the called instantiate_type_generic() in some kind of a
@@ -788,7 +793,7 @@ fn infer_member_by_index_array(
     index_expr: LuaIndexMemberExpr,
 ) -> InferResult {
     let member_key = index_expr.get_index_key().ok_or(InferFailReason::None)?;
-    let expression_type = if db.get_emmyrc().strict.array_index {
+    let expression_type = if db.get_file_emmyrc(cache.get_file_id()).strict.array_index {
         TypeOps::Union.apply(db, base, &LuaType::Nil)
     } else {
         base.clone()
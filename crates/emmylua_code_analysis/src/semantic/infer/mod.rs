@@ -7,13 +7,14 @@ mod infer_table;
 mod infer_unary;
 mod test;
 
-use std::ops::Deref;
+use std::{collections::HashSet, ops::Deref};
 
 use emmylua_parser::{
     LuaAst, LuaAstNode, LuaClosureExpr, LuaExpr, LuaLiteralExpr, LuaLiteralToken, LuaTableExpr,
     LuaVarExpr,
 };
 use infer_binary::infer_binary_expr;
+pub use infer_binary::infer_compound_assign_value;
 use infer_call::infer_call_expr;
 pub use infer_call::infer_call_expr_func;
 pub use infer_fail_reason::InferFailReason;
@@ -28,7 +29,9 @@ use rowan::TextRange;
 use smol_str::SmolStr;
 
 use crate::{
-    db_index::{DbIndex, LuaOperator, LuaOperatorMetaMethod, LuaSignatureId, LuaType},
+    db_index::{
+        DbIndex, LuaOperator, LuaOperatorMetaMethod, LuaOperatorOwner, LuaSignatureId, LuaType,
+    },
     InFiled, VariadicType,
 };
 
@@ -77,6 +80,7 @@ pub fn infer_expr(db: &DbIndex, cache: &mut LuaInferCache, expr: LuaExpr) -> Inf
         ),
         LuaExpr::NameExpr(name_expr) => infer_name_expr(db, cache, name_expr),
         LuaExpr::IndexExpr(index_expr) => infer_index_expr(db, cache, index_expr),
+        LuaExpr::InterpStringExpr(_) => Ok(LuaType::String),
     };
 
     match &result_type {
@@ -170,6 +174,61 @@ fn get_custom_type_operator(
     }
 }
 
+/// Find the operators of a metamethod, a class also gets the operators of its super classes and a
+/// table gets the operators of its metatable
+pub fn get_meta_method_operators<'a>(
+    db: &'a DbIndex,
+    typ: &LuaType,
+    op: LuaOperatorMetaMethod,
+) -> Vec<&'a LuaOperator> {
+    let operator_index = db.get_operator_index();
+    let mut operators = Vec::new();
+    let mut type_ids = match typ {
+        LuaType::Ref(type_id) | LuaType::Def(type_id) => vec![type_id.clone()],
+        LuaType::Generic(generic) => vec![generic.get_base_type_id()],
+        LuaType::TableConst(table_range) => {
+            if let Some(metatable) = db.get_metatable_index().get(table_range) {
+                let owner = LuaOperatorOwner::Table(metatable.clone());
+                operators.extend(
+                    operator_index
+                        .get_operators(&owner, op)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|id| operator_index.get_operator(id)),
+                );
+            }
+            return operators;
+        }
+        _ => return operators,
+    };
+
+    let mut visited = HashSet::new();
+    while let Some(type_id) = type_ids.pop() {
+        if !visited.insert(type_id.clone()) {
+            continue;
+        }
+
+        operators.extend(
+            operator_index
+                .get_operators(&type_id.clone().into(), op)
+                .into_iter()
+                .flatten()
+                .filter_map(|id| operator_index.get_operator(id)),
+        );
+        for super_type in db
+            .get_type_index()
+            .get_super_types(&type_id)
+            .unwrap_or_default()
+        {
+            if let LuaType::Ref(super_id) = super_type {
+                type_ids.push(super_id);
+            }
+        }
+    }
+
+    operators
+}
+
 pub fn infer_multi_value_adjusted_expression_types(
     db: &DbIndex,
     cache: &mut LuaInferCache,
@@ -4,7 +4,7 @@ use smol_str::SmolStr;
 
 use crate::{
     semantic::{
-        generic::{instantiate_type_generic, TypeSubstitutor},
+        generic::{get_generic_alias_origin, instantiate_type_generic, TypeSubstitutor},
         InferGuard,
     },
     DbIndex, FileId, LuaGenericType, LuaInstanceType, LuaIntersectionType, LuaMemberKey,
@@ -270,6 +270,10 @@ fn infer_generic_members(
     generic_type: &LuaGenericType,
     infer_guard: &mut InferGuard,
 ) -> InferMembersResult {
+    if let Some(origin) = get_generic_alias_origin(db, generic_type) {
+        return infer_members_guard(db, &origin, infer_guard);
+    }
+
     let base_type = generic_type.get_base_type();
     let mut members = infer_members_guard(db, &base_type, infer_guard)?;
 
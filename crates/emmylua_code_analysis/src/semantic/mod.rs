@@ -14,7 +14,8 @@ use std::{collections::HashSet, sync::Arc};
 
 pub use cache::{CacheEntry, CacheKey, CacheOptions, LuaAnalysisPhase, LuaInferCache};
 use emmylua_parser::{
-    LuaCallExpr, LuaChunk, LuaExpr, LuaIndexKey, LuaSyntaxNode, LuaSyntaxToken, LuaTableExpr,
+    LuaAssignStat, LuaCallExpr, LuaChunk, LuaExpr, LuaIndexKey, LuaSyntaxNode, LuaSyntaxToken,
+    LuaTableExpr,
 };
 use infer::{infer_left_value_type_from_right_value, infer_multi_value_adjusted_expression_types};
 pub use infer::{infer_table_field_value_should_be, infer_table_should_be};
@@ -22,6 +23,7 @@ use lsp_types::Uri;
 pub use member::infer_member_map;
 use member::infer_members;
 pub use member::LuaMemberInfo;
+pub use reference::find_goto_label;
 use reference::is_reference_to;
 use rowan::{NodeOrToken, TextRange};
 pub(crate) use semantic_info::infer_node_semantic_decl;
@@ -30,7 +32,8 @@ use semantic_info::{
     infer_node_semantic_info, infer_token_semantic_decl, infer_token_semantic_info,
 };
 pub(crate) use type_check::check_type_compact;
-use type_check::is_sub_type_of;
+use type_check::check_type_compact_in_file;
+pub(crate) use type_check::is_sub_type_of;
 use visibility::check_visibility;
 
 use crate::{db_index::LuaTypeDeclId, Emmyrc, LuaDocument, LuaSemanticDeclId};
@@ -40,9 +43,9 @@ use crate::{
 };
 use crate::{LuaFunctionType, LuaMemberKey, LuaTypeOwner};
 pub use generic::{instantiate_func_generic, instantiate_type_generic, TypeSubstitutor};
-pub use infer::infer_param;
 pub use infer::InferFailReason;
-pub(crate) use infer::{infer_call_expr_func, infer_expr};
+pub use infer::{get_meta_method_operators, infer_param};
+pub(crate) use infer::{infer_call_expr_func, infer_compound_assign_value, infer_expr};
 use overload_resolve::resolve_signature;
 pub use semantic_info::SemanticDeclLevel;
 pub use type_check::{TypeCheckFailReason, TypeCheckResult};
@@ -122,7 +125,7 @@ impl<'a> SemanticModel<'a> {
     }
 
     pub fn type_check(&self, source: &LuaType, compact_type: &LuaType) -> TypeCheckResult {
-        check_type_compact(self.db, source, compact_type)
+        check_type_compact_in_file(self.db, self.file_id, source, compact_type)
     }
 
     pub fn infer_call_expr_func(
@@ -162,6 +165,19 @@ impl<'a> SemanticModel<'a> {
         )
     }
 
+    /// The value assigned by a luau compound assignment like `a += 1`, None for other assignments
+    pub fn infer_compound_assign_value(&self, assign_stat: &LuaAssignStat) -> Option<LuaType> {
+        let (vars, _) = assign_stat.get_var_and_expr_list();
+        let left_type = self.infer_expr(vars.first()?.clone().into()).ok()?;
+        infer_compound_assign_value(
+            self.db,
+            &mut self.infer_cache.borrow_mut(),
+            assign_stat,
+            left_type,
+        )?
+        .ok()
+    }
+
     /// 从右值推断左值已绑定的类型
     pub fn infer_left_value_type_from_right_value(&self, expr: LuaExpr) -> Option<LuaType> {
         infer_left_value_type_from_right_value(self.db, &mut self.infer_cache.borrow_mut(), expr)
@@ -1,4 +1,6 @@
-use emmylua_parser::LuaSyntaxNode;
+use emmylua_parser::{
+    LuaAstNode, LuaBlock, LuaGotoStat, LuaLabelStat, LuaSyntaxKind, LuaSyntaxNode,
+};
 
 use crate::{DbIndex, LuaMemberId, LuaSemanticDeclId};
 
@@ -34,3 +36,29 @@ fn is_member_reference_to(
 
     Some(node_owner == owner)
 }
+
+/// Find the label which the `goto` jumps to, the label must be visible in the blocks enclosing
+/// the `goto` and can not be outside the current function
+pub fn find_goto_label(goto_stat: &LuaGotoStat) -> Option<LuaLabelStat> {
+    let name = goto_stat.get_label_name_token()?;
+    let name = name.get_name_text();
+    for node in goto_stat.syntax().ancestors() {
+        if node.kind() == LuaSyntaxKind::ClosureExpr.into() {
+            break;
+        }
+
+        let Some(block) = LuaBlock::cast(node) else {
+            continue;
+        };
+        let label = block.children::<LuaLabelStat>().find(|label| {
+            label
+                .get_label_name_token()
+                .is_some_and(|token| token.get_name_text() == name)
+        });
+        if label.is_some() {
+            return label;
+        }
+    }
+
+    None
+}
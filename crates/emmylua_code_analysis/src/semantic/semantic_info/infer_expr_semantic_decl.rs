@@ -203,7 +203,7 @@ fn infer_member_semantic_decl_by_member_key(
     match &prefix_type {
         LuaType::TableConst(id) => {
             let owner = LuaMemberOwner::Element(id.clone());
-            infer_table_member_semantic_decl(db, owner, member_key)
+            infer_table_member_semantic_decl(db, cache, owner, member_key)
         }
         LuaType::String | LuaType::Io | LuaType::StringConst(_) | LuaType::DocStringConst(_) => {
             let decl_id = get_buildin_type_map_type_id(&prefix_type)?;
@@ -257,11 +257,12 @@ fn infer_member_semantic_decl_by_member_key(
 
 fn infer_table_member_semantic_decl(
     db: &DbIndex,
+    cache: &LuaInferCache,
     owner: LuaMemberOwner,
     member_key: &LuaMemberKey,
 ) -> Option<LuaSemanticDeclId> {
     let member_item = db.get_member_index().get_member_item(&owner, member_key)?;
-    member_item.resolve_semantic_decl(db)
+    member_item.resolve_semantic_decl(db, cache.get_file_id())
 }
 
 fn infer_custom_type_member_semantic_decl(
@@ -295,7 +296,7 @@ fn infer_custom_type_member_semantic_decl(
 
     let owner = LuaMemberOwner::Type(prefix_type_id.clone());
     if let Some(member_item) = db.get_member_index().get_member_item(&owner, member_key) {
-        return member_item.resolve_semantic_decl(db);
+        return member_item.resolve_semantic_decl(db, cache.get_file_id());
     }
 
     if type_decl.is_class() {
@@ -359,7 +360,7 @@ fn infer_instance_member_semantic_decl_by_member_key(
     }
 
     let owner = LuaMemberOwner::Element(range.clone());
-    infer_table_member_semantic_decl(db, owner, member_key)
+    infer_table_member_semantic_decl(db, cache, owner, member_key)
 }
 
 fn infer_global_member_semantic_decl_by_member_key(
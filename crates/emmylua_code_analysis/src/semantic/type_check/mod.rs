@@ -16,7 +16,10 @@ use simple_type::check_simple_type_compact;
 pub use type_check_fail_reason::TypeCheckFailReason;
 use type_check_guard::TypeCheckGuard;
 
-use crate::db_index::{DbIndex, LuaType};
+use crate::{
+    db_index::{DbIndex, LuaType},
+    FileId,
+};
 pub use sub_type::is_sub_type_of;
 pub type TypeCheckResult = Result<(), TypeCheckFailReason>;
 
@@ -28,6 +31,21 @@ pub fn check_type_compact(
     check_general_type_compact(db, source, compact_type, TypeCheckGuard::new())
 }
 
+/// Check the types with the strict options of the config which applies to the file
+pub fn check_type_compact_in_file(
+    db: &DbIndex,
+    file_id: FileId,
+    source: &LuaType,
+    compact_type: &LuaType,
+) -> TypeCheckResult {
+    check_general_type_compact(
+        db,
+        source,
+        compact_type,
+        TypeCheckGuard::new_in_file(file_id),
+    )
+}
+
 fn check_general_type_compact(
     db: &DbIndex,
     source: &LuaType,
@@ -98,7 +116,7 @@ fn check_general_type_compact(
         }
 
         // need think how to do that
-        LuaType::Call(_) => Ok(()),
+        LuaType::Call(_) | LuaType::Conditional(_) | LuaType::Mapped(_) => Ok(()),
 
         // generic type
         LuaType::Generic(generic) => {
@@ -119,7 +137,12 @@ fn check_general_type_compact(
 fn is_like_any(ty: &LuaType) -> bool {
     matches!(
         ty,
-        LuaType::Any | LuaType::Unknown | LuaType::TplRef(_) | LuaType::StrTplRef(_)
+        LuaType::Any
+            | LuaType::Unknown
+            | LuaType::TplRef(_)
+            | LuaType::StrTplRef(_)
+            // a value of `never` is never produced
+            | LuaType::Never
     )
 }
 
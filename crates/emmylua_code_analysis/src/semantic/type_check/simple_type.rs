@@ -84,6 +84,12 @@ pub fn check_simple_type_compact(
             LuaType::Integer | LuaType::IntegerConst(_) | LuaType::DocIntegerConst(_) => {
                 return Ok(());
             }
+            LuaType::Number
+                if matches!(source, LuaType::Integer)
+                    && is_cast_number_to_integer(db, check_guard) =>
+            {
+                return Ok(());
+            }
             LuaType::Ref(_) => {
                 if check_base_type_for_ref_compact(db, source, compact_type, check_guard).is_ok() {
                     return Ok(());
@@ -318,3 +324,10 @@ fn check_variadic_type_compact(
 
     Ok(())
 }
+
+fn is_cast_number_to_integer(db: &DbIndex, check_guard: TypeCheckGuard) -> bool {
+    match check_guard.get_file_id() {
+        Some(file_id) => db.get_file_emmyrc(file_id).strict.cast_number_to_integer,
+        None => db.get_emmyrc().strict.cast_number_to_integer,
+    }
+}
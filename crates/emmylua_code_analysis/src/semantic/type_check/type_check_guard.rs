@@ -1,3 +1,5 @@
+use crate::FileId;
+
 use super::type_check_fail_reason::TypeCheckFailReason;
 
 const MAX_TYPE_CHECK_LEVEL: i32 = 100;
@@ -6,11 +8,27 @@ pub type TypeCheckLevelResult = Result<TypeCheckGuard, TypeCheckFailReason>;
 #[derive(Debug, Clone, Copy)]
 pub struct TypeCheckGuard {
     stack_level: i32,
+    // the file whose config decides the strict checks
+    file_id: Option<FileId>,
 }
 
 impl TypeCheckGuard {
     pub fn new() -> Self {
-        Self { stack_level: 0 }
+        Self {
+            stack_level: 0,
+            file_id: None,
+        }
+    }
+
+    pub fn new_in_file(file_id: FileId) -> Self {
+        Self {
+            stack_level: 0,
+            file_id: Some(file_id),
+        }
+    }
+
+    pub fn get_file_id(&self) -> Option<FileId> {
+        self.file_id
     }
 
     pub fn next_level(&self) -> TypeCheckLevelResult {
@@ -21,6 +39,7 @@ impl TypeCheckGuard {
 
         Ok(Self {
             stack_level: next_level,
+            file_id: self.file_id,
         })
     }
 }
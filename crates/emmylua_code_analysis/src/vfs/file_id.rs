@@ -1,18 +1,19 @@
-use std::cmp;
+use std::{cmp, fmt};
 
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Eq, PartialEq, Hash, Debug, Clone, Copy, PartialOrd)]
 pub struct FileId {
     pub id: u32,
 }
 
+// written as a newtype so the index cache can tell the file ids from the other numbers
 impl Serialize for FileId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_u32(self.id)
+        serializer.serialize_newtype_struct("FileId", &self.id)
     }
 }
 
@@ -21,8 +22,25 @@ impl<'de> Deserialize<'de> for FileId {
     where
         D: Deserializer<'de>,
     {
-        let id = u32::deserialize(deserializer)?;
-        Ok(FileId { id })
+        struct FileIdVisitor;
+
+        impl<'de> Visitor<'de> for FileIdVisitor {
+            type Value = FileId;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a file id")
+            }
+
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let id = u32::deserialize(deserializer)?;
+                Ok(FileId { id })
+            }
+        }
+
+        deserializer.deserialize_newtype_struct("FileId", FileIdVisitor)
     }
 }
 
@@ -46,7 +64,7 @@ impl cmp::Ord for FileId {
     }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InFiled<N> {
     pub file_id: FileId,
     pub value: N,
@@ -9,10 +9,10 @@ use emmylua_parser::{LineIndex, LuaParser, LuaSyntaxTree};
 pub use file_id::{FileId, InFiled};
 pub use file_uri_handler::{file_path_to_uri, uri_to_file_path};
 pub use loader::{load_workspace_files, read_file_with_encoding, LuaFileInfo};
-use lsp_types::Uri;
+use lsp_types::{TextDocumentContentChangeEvent, Uri};
 use rowan::{NodeCache, TextRange};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 pub use virtual_url::VirtualUrlGenerator;
 
@@ -26,7 +26,12 @@ pub struct Vfs {
     line_index_map: HashMap<FileId, LineIndex>,
     tree_map: HashMap<FileId, LuaSyntaxTree>,
     emmyrc: Option<Arc<Emmyrc>>,
+    /// The configs of the workspace roots which have their own config
+    workspace_emmyrcs: Vec<(PathBuf, Arc<Emmyrc>)>,
     node_cache: NodeCache,
+    /// The opened files whose text may differ from the text of the client, because a change could
+    /// not be applied. Their ranged changes are ignored until the whole text is sent again.
+    out_of_sync_files: HashSet<FileId>,
 }
 
 impl Vfs {
@@ -38,7 +43,9 @@ impl Vfs {
             line_index_map: HashMap::new(),
             tree_map: HashMap::new(),
             emmyrc: None,
+            workspace_emmyrcs: Vec::new(),
             node_cache: NodeCache::default(),
+            out_of_sync_files: HashSet::new(),
         }
     }
 
@@ -80,12 +87,12 @@ impl Vfs {
     pub fn set_file_content(&mut self, uri: &Uri, data: Option<String>) -> FileId {
         let fid = self.file_id(uri);
         log::debug!("file_id: {:?}, uri: {}", fid, uri.as_str());
+        self.out_of_sync_files.remove(&fid);
 
         if let Some(data) = &data {
             let line_index = LineIndex::parse(&data);
             let parse_config = self
-                .emmyrc
-                .as_ref()
+                .get_file_emmyrc(&fid)
                 .unwrap()
                 .get_parse_config(&mut self.node_cache);
             let tree = LuaParser::parse(&data, parse_config);
@@ -99,6 +106,78 @@ impl Vfs {
         fid
     }
 
+    /// Applies the content changes of an opened file in order, only the statements touched by a
+    /// ranged change are parsed again. When a change can not be applied the text is reloaded from
+    /// disk and the file is marked as out of sync until the client sends the whole text again.
+    pub fn apply_file_changes(
+        &mut self,
+        uri: &Uri,
+        changes: Vec<TextDocumentContentChangeEvent>,
+    ) -> Option<FileId> {
+        let fid = self.get_file_id(uri)?;
+        let mut text = self.get_file_content(&fid)?.clone();
+        let mut tree = self.tree_map.get(&fid)?.clone();
+        let mut line_index = self.line_index_map.get(&fid)?.clone();
+        let mut is_out_of_sync = self.out_of_sync_files.contains(&fid);
+
+        let emmyrc = self.get_file_emmyrc(&fid)?;
+        let file_path = self.get_file_path(&fid).cloned();
+        for change in changes {
+            let parse_config = emmyrc.get_parse_config(&mut self.node_cache);
+            match change.range {
+                // the changes are relative to the text of the client, which is unknown
+                Some(_) if is_out_of_sync => continue,
+                Some(range) => {
+                    let Some(change_range) = get_change_range(&line_index, &text, range) else {
+                        log::warn!(
+                            "invalid change range {:?} for {}, reload the file from disk",
+                            range,
+                            uri.as_str()
+                        );
+                        is_out_of_sync = true;
+                        if let Some(disk_text) = file_path.as_ref().and_then(|path| {
+                            read_file_with_encoding(path, &emmyrc.workspace.encoding)
+                        }) {
+                            text = disk_text;
+                        }
+                        tree = LuaParser::parse(&text, parse_config);
+                        line_index = LineIndex::parse(&text);
+                        continue;
+                    };
+
+                    text.replace_range(
+                        usize::from(change_range.start())..usize::from(change_range.end()),
+                        &change.text,
+                    );
+                    tree =
+                        LuaParser::reparse(&text, &tree, change_range, &change.text, parse_config);
+                }
+                None => {
+                    is_out_of_sync = false;
+                    text = change.text;
+                    tree = LuaParser::parse(&text, parse_config);
+                }
+            }
+            line_index = LineIndex::parse(&text);
+        }
+
+        if is_out_of_sync {
+            self.out_of_sync_files.insert(fid);
+        } else {
+            self.out_of_sync_files.remove(&fid);
+        }
+        self.tree_map.insert(fid, tree);
+        self.line_index_map.insert(fid, line_index);
+        self.file_data[fid.id as usize] = Some(text);
+        Some(fid)
+    }
+
+    /// Whether a change of the opened file could not be applied, the text is reloaded from disk and
+    /// is only in sync again after the client sends the whole text or the file is saved
+    pub fn is_out_of_sync(&self, fid: &FileId) -> bool {
+        self.out_of_sync_files.contains(fid)
+    }
+
     pub fn remove_file(&mut self, uri: &Uri) -> Option<FileId> {
         let fid = self.get_file_id(uri)?;
         if let Some(path) = self.file_path_map.remove(&fid.id) {
@@ -109,6 +188,7 @@ impl Vfs {
         }
         self.line_index_map.remove(&fid);
         self.tree_map.remove(&fid);
+        self.out_of_sync_files.remove(&fid);
         Some(fid)
     }
 
@@ -116,6 +196,45 @@ impl Vfs {
         self.emmyrc = Some(emmyrc);
     }
 
+    /// Parse the files in the root with the config of the root instead of the main config
+    pub fn set_workspace_emmyrc(&mut self, root: PathBuf, emmyrc: Arc<Emmyrc>) {
+        match self.workspace_emmyrcs.iter_mut().find(|(r, _)| *r == root) {
+            Some((_, old_emmyrc)) => *old_emmyrc = emmyrc,
+            None => self.workspace_emmyrcs.push((root, emmyrc)),
+        }
+    }
+
+    pub fn remove_workspace_emmyrc(&mut self, root: &Path) {
+        self.workspace_emmyrcs.retain(|(r, _)| r != root);
+    }
+
+    pub fn get_workspace_emmyrcs(&self) -> &[(PathBuf, Arc<Emmyrc>)] {
+        &self.workspace_emmyrcs
+    }
+
+    /// The deepest workspace root which has its own config and contains the file
+    pub fn get_file_config_root(&self, id: &FileId) -> Option<&PathBuf> {
+        let path = self.file_path_map.get(&id.id)?;
+        self.workspace_emmyrcs
+            .iter()
+            .filter(|(root, _)| path.starts_with(root))
+            .max_by_key(|(root, _)| root.components().count())
+            .map(|(root, _)| root)
+    }
+
+    /// The config of the workspace root which contains the file, or the main config
+    pub fn get_file_emmyrc(&self, id: &FileId) -> Option<Arc<Emmyrc>> {
+        if let Some(root) = self.get_file_config_root(id) {
+            return self
+                .workspace_emmyrcs
+                .iter()
+                .find(|(r, _)| r == root)
+                .map(|(_, emmyrc)| emmyrc.clone());
+        }
+
+        self.emmyrc.clone()
+    }
+
     pub fn get_file_content(&self, id: &FileId) -> Option<&String> {
         let opt = &self.file_data[id.id as usize];
         if let Some(s) = opt {
@@ -164,3 +283,26 @@ impl Vfs {
             .collect()
     }
 }
+
+// the byte range of a change in the text, `None` when the range does not fit the text
+fn get_change_range(
+    line_index: &LineIndex,
+    text: &str,
+    range: lsp_types::Range,
+) -> Option<TextRange> {
+    let start = line_index.get_offset_by_utf16_col(
+        range.start.line as usize,
+        range.start.character as usize,
+        text,
+    )?;
+    let end = line_index.get_offset_by_utf16_col(
+        range.end.line as usize,
+        range.end.character as usize,
+        text,
+    )?;
+    if start > end || usize::from(end) > text.len() {
+        return None;
+    }
+
+    Some(TextRange::new(start, end))
+}
@@ -1,26 +1,207 @@
-use emmylua_parser::{LuaAst, LuaAstNode};
-use rowan::NodeOrToken;
+mod printer;
+
+pub use printer::print;
+use std::collections::{HashMap, HashSet};
+
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaSyntaxKind, LuaSyntaxNode, LuaSyntaxToken, LuaTokenKind,
+};
+use rowan::{NodeOrToken, TextRange, TextSize, WalkEvent};
+
+use crate::styles::LuaCodeStyle;
+
+/// A unit of the output: a significant token, or a whole comment which is printed as is
+#[derive(Debug, Clone)]
+pub enum FormatElement {
+    Token(LuaSyntaxToken),
+    Comment(LuaSyntaxNode),
+}
+
+impl FormatElement {
+    pub fn get_range(&self) -> TextRange {
+        match self {
+            FormatElement::Token(token) => token.text_range(),
+            FormatElement::Comment(node) => node.text_range(),
+        }
+    }
+
+    pub fn get_position(&self) -> TextSize {
+        self.get_range().start()
+    }
+
+    pub fn get_token_kind(&self) -> Option<LuaTokenKind> {
+        match self {
+            FormatElement::Token(token) => Some(token.kind().into()),
+            FormatElement::Comment(_) => None,
+        }
+    }
+
+    pub fn is_comment(&self) -> bool {
+        matches!(self, FormatElement::Comment(_))
+    }
+
+    pub fn get_parent(&self) -> Option<LuaSyntaxNode> {
+        match self {
+            FormatElement::Token(token) => token.parent(),
+            FormatElement::Comment(node) => node.parent(),
+        }
+    }
+
+    pub fn get_parent_kind(&self) -> Option<LuaSyntaxKind> {
+        self.get_parent().map(|node| node.kind().into())
+    }
+}
 
-#[allow(unused)]
 #[derive(Debug)]
 pub struct LuaFormatter {
     root: LuaAst,
+    elements: Vec<FormatElement>,
+    // the layout of the source, the style rulers rewrite it
+    line_breaks: Vec<usize>,
+    has_space: Vec<bool>,
+    spaces: Vec<Option<usize>>,
+    replaced: HashMap<usize, String>,
+    removed: HashSet<usize>,
+    appended: HashMap<usize, String>,
+    align_groups: HashMap<usize, usize>,
+    align_group_count: usize,
+    aligned_lists: HashSet<usize>,
 }
 
-#[allow(unused)]
 impl LuaFormatter {
     pub fn new(root: LuaAst) -> Self {
-        Self { root }
+        let mut formatter = Self {
+            root,
+            elements: Vec::new(),
+            line_breaks: Vec::new(),
+            has_space: Vec::new(),
+            spaces: Vec::new(),
+            replaced: HashMap::new(),
+            removed: HashSet::new(),
+            appended: HashMap::new(),
+            align_groups: HashMap::new(),
+            align_group_count: 0,
+            aligned_lists: HashSet::new(),
+        };
+        formatter.collect_elements();
+        formatter
     }
 
-    pub fn get_formatted_text(&self) -> String {
-        let mut formatted_text = String::new();
-        for node_or_token in self.root.syntax().descendants_with_tokens() {
-            if let NodeOrToken::Token(token) = node_or_token {
-                formatted_text.push_str(&token.text());
-            }
+    fn collect_elements(&mut self) {
+        let mut line_breaks = 0;
+        let mut has_space = false;
+        let mut preorder = self.root.syntax().preorder_with_tokens();
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(element) = event else {
+                continue;
+            };
+
+            let element = match element {
+                NodeOrToken::Node(node) => {
+                    if node.kind() != LuaSyntaxKind::Comment.into() {
+                        continue;
+                    }
+                    preorder.skip_subtree();
+                    FormatElement::Comment(node)
+                }
+                NodeOrToken::Token(token) => match token.kind().into() {
+                    LuaTokenKind::TkWhitespace => {
+                        has_space = true;
+                        continue;
+                    }
+                    LuaTokenKind::TkEndOfLine => {
+                        line_breaks += 1;
+                        continue;
+                    }
+                    LuaTokenKind::TkEof => continue,
+                    _ => FormatElement::Token(token),
+                },
+            };
+
+            self.elements.push(element);
+            self.line_breaks.push(line_breaks);
+            self.has_space.push(has_space);
+            self.spaces.push(None);
+            line_breaks = 0;
+            has_space = false;
         }
+    }
+
+    pub fn get_root(&self) -> &LuaAst {
+        &self.root
+    }
+
+    pub fn get_elements(&self) -> &[FormatElement] {
+        &self.elements
+    }
+
+    /// The index of the first element of the node
+    pub fn get_node_first_index(&self, node: &LuaSyntaxNode) -> Option<usize> {
+        let range = node.text_range();
+        let idx = self
+            .elements
+            .partition_point(|element| element.get_position() < range.start());
+        (idx < self.elements.len() && range.contains(self.elements[idx].get_position()))
+            .then_some(idx)
+    }
+
+    /// The index of the last element of the node
+    pub fn get_node_last_index(&self, node: &LuaSyntaxNode) -> Option<usize> {
+        let range = node.text_range();
+        let idx = self
+            .elements
+            .partition_point(|element| element.get_position() < range.end());
+        (idx > 0 && range.contains(self.elements[idx - 1].get_position())).then(|| idx - 1)
+    }
+
+    pub fn get_line_breaks_before(&self, idx: usize) -> usize {
+        self.line_breaks[idx]
+    }
+
+    pub fn set_line_breaks_before(&mut self, idx: usize, line_breaks: usize) {
+        self.line_breaks[idx] = line_breaks;
+    }
+
+    pub fn set_space_before(&mut self, idx: usize, space: usize) {
+        self.spaces[idx] = Some(space);
+    }
+
+    /// The space before the element, the source spacing is kept when no ruler decides it
+    pub fn get_space_before(&self, idx: usize) -> usize {
+        self.spaces[idx].unwrap_or(if self.has_space[idx] { 1 } else { 0 })
+    }
+
+    pub fn replace_text(&mut self, idx: usize, text: String) {
+        self.replaced.insert(idx, text);
+    }
+
+    pub fn remove_element(&mut self, idx: usize) {
+        self.removed.insert(idx);
+    }
+
+    pub fn is_removed(&self, idx: usize) -> bool {
+        self.removed.contains(&idx)
+    }
+
+    pub fn append_text(&mut self, idx: usize, text: String) {
+        self.appended.insert(idx, text);
+    }
+
+    /// Pad the elements so they start at the same column
+    pub fn add_align_group(&mut self, indexes: Vec<usize>) {
+        let group = self.align_group_count;
+        self.align_group_count += 1;
+        for idx in indexes {
+            self.align_groups.insert(idx, group);
+        }
+    }
+
+    /// Indent the wrapped items of the bracketed list to the column of its first item
+    pub fn add_aligned_list(&mut self, open_idx: usize) {
+        self.aligned_lists.insert(open_idx);
+    }
 
-        formatted_text
+    pub fn get_formatted_text(&self, styles: &LuaCodeStyle) -> String {
+        print(self, styles).text
     }
 }
@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{LuaAstNode, LuaSyntaxKind, LuaSyntaxNode, LuaTokenKind};
+use rowan::NodeOrToken;
+
+use crate::styles::LuaCodeStyle;
+
+use super::{FormatElement, LuaFormatter};
+
+#[derive(Debug)]
+pub struct PrintResult {
+    pub text: String,
+    /// The output lines where each element starts and ends
+    pub element_lines: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct LineIndent {
+    level: usize,
+    // extra spaces after the indent, used to align with a column
+    align: usize,
+}
+
+impl LineIndent {
+    fn add_level(self) -> Self {
+        Self {
+            level: self.level + 1,
+            align: self.align,
+        }
+    }
+}
+
+struct AlignMark {
+    group: usize,
+    line: usize,
+    offset: usize,
+    column: usize,
+}
+
+struct Printer<'a> {
+    formatter: &'a LuaFormatter,
+    styles: &'a LuaCodeStyle,
+    lines: Vec<String>,
+    line: String,
+    line_indent: LineIndent,
+    indents: Vec<LineIndent>,
+    columns: Vec<usize>,
+    element_lines: Vec<(usize, usize)>,
+    align_marks: Vec<AlignMark>,
+}
+
+pub fn print(formatter: &LuaFormatter, styles: &LuaCodeStyle) -> PrintResult {
+    let mut printer = Printer {
+        formatter,
+        styles,
+        lines: Vec::new(),
+        line: String::new(),
+        line_indent: LineIndent::default(),
+        indents: Vec::new(),
+        columns: Vec::new(),
+        element_lines: Vec::new(),
+        align_marks: Vec::new(),
+    };
+
+    let mut is_first = true;
+    for idx in 0..formatter.elements.len() {
+        if formatter.is_removed(idx) {
+            printer.skip_element();
+            continue;
+        }
+
+        let mut line_breaks = formatter.get_line_breaks_before(idx);
+        let space = formatter.get_space_before(idx);
+        if is_first {
+            line_breaks = 0;
+        } else if line_breaks == 0 && printer.should_wrap(idx, space) {
+            line_breaks = 1;
+        }
+
+        if line_breaks > 0 {
+            printer.new_lines(line_breaks);
+            let indent = printer.get_indent(idx);
+            printer.line_indent = indent;
+            printer.line.push_str(&printer.get_indent_text(indent));
+        } else if !is_first {
+            printer.line.push_str(&" ".repeat(space));
+        }
+
+        printer.print_element(idx);
+        is_first = false;
+    }
+
+    printer.finish()
+}
+
+impl Printer<'_> {
+    fn skip_element(&mut self) {
+        let line = self.lines.len();
+        self.indents.push(self.line_indent);
+        self.columns.push(self.line.chars().count());
+        self.element_lines.push((line, line));
+    }
+
+    fn print_element(&mut self, idx: usize) {
+        let start_line = self.lines.len();
+        let column = self.line.chars().count();
+        self.indents.push(self.line_indent);
+        self.columns.push(column);
+        if let Some(group) = self.formatter.align_groups.get(&idx) {
+            self.align_marks.push(AlignMark {
+                group: *group,
+                line: start_line,
+                offset: self.line.len(),
+                column,
+            });
+        }
+
+        match &self.formatter.elements[idx] {
+            FormatElement::Token(token) => match self.formatter.replaced.get(&idx) {
+                Some(text) => self.write_text(text),
+                None => self.write_text(token.text()),
+            },
+            FormatElement::Comment(comment) => self.write_comment(comment),
+        }
+
+        if let Some(text) = self.formatter.appended.get(&idx) {
+            self.line.push_str(text);
+        }
+
+        self.element_lines.push((start_line, self.lines.len()));
+    }
+
+    // the text of long strings and long comments is kept verbatim
+    fn write_text(&mut self, text: &str) {
+        let mut parts = text.split('\n');
+        if let Some(first) = parts.next() {
+            self.line.push_str(first.trim_end_matches('\r'));
+        }
+        for part in parts {
+            let line = std::mem::take(&mut self.line);
+            self.lines.push(line);
+            self.line.push_str(part.trim_end_matches('\r'));
+        }
+    }
+
+    // the lines of a comment are indented like its first line
+    fn write_comment(&mut self, comment: &LuaSyntaxNode) {
+        let mut is_line_start = false;
+        for element in comment.descendants_with_tokens() {
+            let NodeOrToken::Token(token) = element else {
+                continue;
+            };
+
+            match token.kind().into() {
+                LuaTokenKind::TkEndOfLine => {
+                    self.new_lines(1);
+                    self.line.push_str(&self.get_indent_text(self.line_indent));
+                    is_line_start = true;
+                }
+                LuaTokenKind::TkWhitespace if is_line_start => {}
+                _ => {
+                    self.write_text(token.text());
+                    is_line_start = false;
+                }
+            }
+        }
+    }
+
+    fn new_lines(&mut self, count: usize) {
+        let line = std::mem::take(&mut self.line);
+        self.lines.push(line.trim_end().to_string());
+        for _ in 1..count {
+            self.lines.push(String::new());
+        }
+    }
+
+    fn get_indent_text(&self, indent: LineIndent) -> String {
+        let mut text = self.styles.indent.get_indent_text(indent.level);
+        text.push_str(&" ".repeat(indent.align));
+        text
+    }
+
+    fn get_node_indent(&self, node: &LuaSyntaxNode) -> LineIndent {
+        match self.formatter.get_node_first_index(node) {
+            Some(idx) if idx < self.indents.len() => self.indents[idx],
+            _ => LineIndent::default(),
+        }
+    }
+
+    // the open and close brackets of `{ ... }`, `( ... )` and `[ ... ]` nodes
+    fn get_brackets(&self, node: &LuaSyntaxNode) -> Option<(usize, usize)> {
+        let open_idx = self.formatter.get_node_first_index(node)?;
+        let close_idx = self.formatter.get_node_last_index(node)?;
+        let open = self.formatter.elements[open_idx].get_token_kind()?;
+        let close = self.formatter.elements[close_idx].get_token_kind()?;
+        let is_pair = matches!(
+            (open, close),
+            (LuaTokenKind::TkLeftBrace, LuaTokenKind::TkRightBrace)
+                | (LuaTokenKind::TkLeftParen, LuaTokenKind::TkRightParen)
+                | (LuaTokenKind::TkLeftBracket, LuaTokenKind::TkRightBracket)
+        );
+        let is_own = self.formatter.elements[open_idx].get_parent().as_ref() == Some(node)
+            && self.formatter.elements[close_idx].get_parent().as_ref() == Some(node);
+        (is_pair && is_own).then_some((open_idx, close_idx))
+    }
+
+    // the indent of an element which starts a line, it's relative to the line of the innermost
+    // construct that began on an earlier line
+    fn get_indent(&self, idx: usize) -> LineIndent {
+        let element = &self.formatter.elements[idx];
+        let token_kind = element.get_token_kind();
+        let mut node = element.get_parent();
+        while let Some(current) = node {
+            let kind: LuaSyntaxKind = current.kind().into();
+            if kind == LuaSyntaxKind::Block {
+                return match current.parent() {
+                    Some(owner) if owner.kind() != LuaSyntaxKind::Chunk.into() => {
+                        self.get_node_indent(&owner).add_level()
+                    }
+                    _ => LineIndent::default(),
+                };
+            }
+
+            if let Some((open_idx, close_idx)) = self.get_brackets(&current)
+                && open_idx < idx
+            {
+                let open_indent = self.indents[open_idx];
+                if close_idx == idx {
+                    return open_indent;
+                }
+                if self.formatter.aligned_lists.contains(&open_idx) {
+                    let level_width = self
+                        .styles
+                        .indent
+                        .get_indent_text(open_indent.level)
+                        .chars()
+                        .count();
+                    return LineIndent {
+                        level: open_indent.level,
+                        align: self.columns[open_idx + 1].saturating_sub(level_width),
+                    };
+                }
+                return open_indent.add_level();
+            }
+
+            let is_stat_like = current
+                .parent()
+                .is_some_and(|parent| parent.kind() == LuaSyntaxKind::Block.into())
+                || matches!(
+                    kind,
+                    LuaSyntaxKind::ElseIfClauseStat
+                        | LuaSyntaxKind::ElseClauseStat
+                        | LuaSyntaxKind::ClosureExpr
+                );
+            if is_stat_like && self.formatter.get_node_first_index(&current) != Some(idx) {
+                let indent = self.get_node_indent(&current);
+                return if token_kind.is_some_and(is_closing_keyword) {
+                    indent
+                } else {
+                    indent.add_level()
+                };
+            }
+
+            node = current.parent();
+        }
+
+        LineIndent::default()
+    }
+
+    // wrap before the next list item or binary operator when it would overflow the line
+    fn should_wrap(&self, idx: usize, space: usize) -> bool {
+        let max_line_width = self.styles.max_line_width;
+        if max_line_width == 0 {
+            return false;
+        }
+
+        let Some(prev_idx) = (0..idx).rev().find(|i| !self.formatter.is_removed(*i)) else {
+            return false;
+        };
+        let element = &self.formatter.elements[idx];
+        let prev = &self.formatter.elements[prev_idx];
+        let width = if matches!(
+            prev.get_token_kind(),
+            Some(LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon)
+        ) {
+            let Some(list) = prev.get_parent() else {
+                return false;
+            };
+            match element {
+                FormatElement::Token(token) => {
+                    match token
+                        .parent_ancestors()
+                        .find(|node| node.parent().as_ref() == Some(&list))
+                    {
+                        Some(item) => get_flat_width(&item) + 1,
+                        None => token.text().chars().count(),
+                    }
+                }
+                FormatElement::Comment(_) => return false,
+            }
+        } else if element.get_parent_kind() == Some(LuaSyntaxKind::BinaryExpr)
+            && !element.is_comment()
+        {
+            let Some(right) = element.get_parent().and_then(|parent| parent.last_child()) else {
+                return false;
+            };
+            let FormatElement::Token(token) = element else {
+                return false;
+            };
+            token.text().chars().count() + 1 + get_flat_width(&right)
+        } else {
+            return false;
+        };
+
+        let column = self.line.chars().count();
+        let indent_width = self.get_indent_text(self.line_indent).chars().count();
+        column > indent_width && column + space + width > max_line_width
+    }
+
+    fn finish(mut self) -> PrintResult {
+        let line = std::mem::take(&mut self.line);
+        self.lines.push(line);
+        self.apply_align_marks();
+
+        while self.lines.len() > 1 && self.lines.last().is_some_and(|line| line.is_empty()) {
+            self.lines.pop();
+        }
+
+        let root = self.formatter.get_root().syntax();
+        let first_eol = root
+            .descendants_with_tokens()
+            .find_map(|element| match element {
+                NodeOrToken::Token(token) if token.kind() == LuaTokenKind::TkEndOfLine.into() => {
+                    Some(token.text().to_string())
+                }
+                _ => None,
+            });
+        let eol = self
+            .styles
+            .end_of_line
+            .get_eol_text(first_eol.as_deref().unwrap_or("\n"));
+        let mut text = self.lines.join(eol);
+        let is_chunk = root.kind() == LuaSyntaxKind::Chunk.into();
+        if is_chunk && self.styles.insert_final_newline && !text.is_empty() {
+            text.push_str(eol);
+        }
+
+        PrintResult {
+            text,
+            element_lines: self.element_lines,
+        }
+    }
+
+    fn apply_align_marks(&mut self) {
+        let mut max_columns: HashMap<usize, usize> = HashMap::new();
+        for mark in &self.align_marks {
+            let column = max_columns.entry(mark.group).or_default();
+            *column = (*column).max(mark.column);
+        }
+
+        self.align_marks
+            .sort_by(|a, b| a.line.cmp(&b.line).then(b.offset.cmp(&a.offset)));
+        for mark in &self.align_marks {
+            let padding = max_columns[&mark.group] - mark.column;
+            if padding > 0 {
+                self.lines[mark.line].insert_str(mark.offset, &" ".repeat(padding));
+            }
+        }
+    }
+}
+
+fn is_closing_keyword(kind: LuaTokenKind) -> bool {
+    matches!(
+        kind,
+        LuaTokenKind::TkEnd
+            | LuaTokenKind::TkElse
+            | LuaTokenKind::TkElseIf
+            | LuaTokenKind::TkUntil
+            | LuaTokenKind::TkThen
+            | LuaTokenKind::TkDo
+    )
+}
+
+// the width of the first line of the node when its whitespace is collapsed
+fn get_flat_width(node: &LuaSyntaxNode) -> usize {
+    let text = node.text().to_string();
+    let first_line = text.lines().next().unwrap_or("");
+    first_line
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .count()
+}
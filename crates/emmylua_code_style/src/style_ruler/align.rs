@@ -0,0 +1,97 @@
+use emmylua_parser::{LuaAstNode, LuaSyntaxKind, LuaSyntaxNode, LuaTokenKind};
+
+use crate::{format::LuaFormatter, styles::LuaCodeStyle};
+
+use super::StyleRuler;
+
+pub struct AlignRuler;
+
+impl StyleRuler for AlignRuler {
+    fn apply_style(formatter: &mut LuaFormatter, styles: &LuaCodeStyle) {
+        let root = formatter.get_root().syntax().clone();
+        for node in root.descendants() {
+            match node.kind().into() {
+                LuaSyntaxKind::TableObjectExpr if styles.align_table_field => {
+                    align_table_fields(formatter, &node);
+                }
+                LuaSyntaxKind::CallArgList if styles.align_call_args => {
+                    align_call_args(formatter, &node);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// local t = {
+//     a    = 1,
+//     bbbb = 2,
+// }
+fn align_table_fields(formatter: &mut LuaFormatter, table: &LuaSyntaxNode) {
+    let mut group = Vec::new();
+    for field in table.children() {
+        if field.kind() != LuaSyntaxKind::TableFieldAssign.into() {
+            continue;
+        }
+
+        match get_field_assign_index(formatter, &field) {
+            Some((first_idx, assign_idx)) => {
+                // a blank line or a comment between fields ends the group
+                let is_continuous = formatter.get_line_breaks_before(first_idx) == 1
+                    && !formatter.get_elements()[first_idx - 1].is_comment();
+                if !is_continuous {
+                    add_align_group(formatter, std::mem::take(&mut group));
+                }
+                group.push(assign_idx);
+            }
+            None => add_align_group(formatter, std::mem::take(&mut group)),
+        }
+    }
+
+    add_align_group(formatter, group);
+}
+
+// the field starts its own line and its `=` is on that line
+fn get_field_assign_index(
+    formatter: &LuaFormatter,
+    field: &LuaSyntaxNode,
+) -> Option<(usize, usize)> {
+    let first_idx = formatter.get_node_first_index(field)?;
+    if first_idx == 0 || formatter.get_line_breaks_before(first_idx) == 0 {
+        return None;
+    }
+
+    let last_idx = formatter.get_node_last_index(field)?;
+    let assign_idx = (first_idx..=last_idx).find(|idx| {
+        let element = &formatter.get_elements()[*idx];
+        element.get_token_kind() == Some(LuaTokenKind::TkAssign)
+            && element.get_parent().as_ref() == Some(field)
+    })?;
+    let is_same_line =
+        (first_idx + 1..=assign_idx).all(|idx| formatter.get_line_breaks_before(idx) == 0);
+    is_same_line.then_some((first_idx, assign_idx))
+}
+
+fn add_align_group(formatter: &mut LuaFormatter, group: Vec<usize>) {
+    if group.len() > 1 {
+        formatter.add_align_group(group);
+    }
+}
+
+// foo(a,
+//     b)
+fn align_call_args(formatter: &mut LuaFormatter, call_args: &LuaSyntaxNode) {
+    let Some(open_idx) = formatter.get_node_first_index(call_args) else {
+        return;
+    };
+    let elements = formatter.get_elements();
+    if elements[open_idx].get_token_kind() != Some(LuaTokenKind::TkLeftParen)
+        || open_idx + 1 >= elements.len()
+        || formatter.get_line_breaks_before(open_idx + 1) > 0
+        || elements[open_idx + 1].get_token_kind() == Some(LuaTokenKind::TkRightParen)
+    {
+        return;
+    }
+
+    formatter.add_aligned_list(open_idx);
+}
@@ -1,9 +1,159 @@
-use crate::{format::LuaFormatter, styles::LuaCodeStyle};
+use emmylua_parser::{LuaSyntaxKind, LuaTokenKind};
+
+use crate::{
+    format::{FormatElement, LuaFormatter},
+    styles::LuaCodeStyle,
+};
 
 use super::StyleRuler;
 
 pub struct BasicSpaceRuler;
 
 impl StyleRuler for BasicSpaceRuler {
-    fn apply_style(_: &mut LuaFormatter, _: &LuaCodeStyle) {}
+    fn apply_style(formatter: &mut LuaFormatter, _: &LuaCodeStyle) {
+        for idx in 1..formatter.get_elements().len() {
+            let elements = formatter.get_elements();
+            if let Some(space) = get_space_between(&elements[idx - 1], &elements[idx]) {
+                formatter.set_space_before(idx, space);
+            }
+        }
+    }
+}
+
+// None keeps the space of the source
+fn get_space_between(prev: &FormatElement, next: &FormatElement) -> Option<usize> {
+    let (Some(prev_kind), Some(next_kind)) = (prev.get_token_kind(), next.get_token_kind()) else {
+        // a trailing comment, or code after an inline long comment
+        return Some(1);
+    };
+    let prev_parent = prev.get_parent_kind().unwrap_or(LuaSyntaxKind::None);
+    let next_parent = next.get_parent_kind().unwrap_or(LuaSyntaxKind::None);
+
+    match next_kind {
+        LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon => return Some(0),
+        LuaTokenKind::TkRightParen | LuaTokenKind::TkRightBracket => return Some(0),
+        LuaTokenKind::TkDocQuestion => return Some(0),
+        _ => {}
+    }
+
+    match prev_kind {
+        LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon => return Some(1),
+        LuaTokenKind::TkLeftParen | LuaTokenKind::TkLeftBracket => return Some(0),
+        LuaTokenKind::TkLeftBrace => {
+            return Some(if next_kind == LuaTokenKind::TkRightBrace {
+                0
+            } else {
+                1
+            });
+        }
+        _ => {}
+    }
+
+    if next_kind == LuaTokenKind::TkRightBrace {
+        return Some(1);
+    }
+
+    // `a.b`, `a:b()`, `::label::`
+    if is_index_token(prev_kind, prev_parent) || is_index_token(next_kind, next_parent) {
+        return Some(0);
+    }
+    if prev_kind == LuaTokenKind::TkDbColon && prev_parent == LuaSyntaxKind::LabelStat {
+        let is_open = next_kind == LuaTokenKind::TkName;
+        return Some(if is_open { 0 } else { 1 });
+    }
+    if next_kind == LuaTokenKind::TkDbColon && next_parent == LuaSyntaxKind::LabelStat {
+        return Some(if prev_kind == LuaTokenKind::TkName {
+            0
+        } else {
+            1
+        });
+    }
+    // luau compound assignment `a += 1`, the operator is a direct child of the statement
+    if next_kind == LuaTokenKind::TkAssign && prev_parent == LuaSyntaxKind::AssignStat {
+        return Some(0);
+    }
+    // luau annotation `x: number`
+    if next_kind == LuaTokenKind::TkColon {
+        return Some(0);
+    }
+
+    // `f(a)`, `function(a)`, `t[1]`
+    match next_kind {
+        LuaTokenKind::TkLeftParen
+            if matches!(
+                next_parent,
+                LuaSyntaxKind::CallArgList | LuaSyntaxKind::ParamList
+            ) =>
+        {
+            return Some(0);
+        }
+        LuaTokenKind::TkLeftBracket if next_parent == LuaSyntaxKind::IndexExpr => {
+            return Some(0);
+        }
+        _ => {}
+    }
+
+    // `f "a"` and `f {}` keep the source spacing
+    if is_single_arg_call(next) {
+        return None;
+    }
+
+    // interpolated string pieces hug their expressions
+    if prev_kind == LuaTokenKind::TkInterpString && text_ends_with(prev, '{') {
+        return Some(0);
+    }
+    if next_kind == LuaTokenKind::TkInterpString && text_starts_with(next, '}') {
+        return Some(0);
+    }
+
+    if prev_parent == LuaSyntaxKind::UnaryExpr && !matches!(prev_kind, LuaTokenKind::TkNot) {
+        return Some(0);
+    }
+
+    // `<const>` attributes and luau generics `f<T>(...)`
+    if matches!(prev_kind, LuaTokenKind::TkLt) && prev_parent != LuaSyntaxKind::BinaryExpr {
+        return Some(0);
+    }
+    if matches!(next_kind, LuaTokenKind::TkGt) && next_parent != LuaSyntaxKind::BinaryExpr {
+        return Some(0);
+    }
+    if matches!(next_kind, LuaTokenKind::TkLt) && next_parent != LuaSyntaxKind::BinaryExpr {
+        return Some(if next_parent == LuaSyntaxKind::Attribute {
+            1
+        } else {
+            0
+        });
+    }
+
+    Some(1)
+}
+
+fn is_index_token(kind: LuaTokenKind, parent: LuaSyntaxKind) -> bool {
+    matches!(kind, LuaTokenKind::TkDot | LuaTokenKind::TkColon)
+        && parent == LuaSyntaxKind::IndexExpr
+        || kind == LuaTokenKind::TkDot && parent == LuaSyntaxKind::TypeName
+}
+
+fn is_single_arg_call(element: &FormatElement) -> bool {
+    let FormatElement::Token(token) = element else {
+        return false;
+    };
+    let Some(list) = token.parent().and_then(|parent| parent.parent()) else {
+        return false;
+    };
+    list.kind() == LuaSyntaxKind::CallArgList.into() && list.first_token().as_ref() == Some(token)
+}
+
+fn text_ends_with(element: &FormatElement, c: char) -> bool {
+    match element {
+        FormatElement::Token(token) => token.text().ends_with(c),
+        FormatElement::Comment(_) => false,
+    }
+}
+
+fn text_starts_with(element: &FormatElement, c: char) -> bool {
+    match element {
+        FormatElement::Token(token) => token.text().starts_with(c),
+        FormatElement::Comment(_) => false,
+    }
 }
@@ -0,0 +1,67 @@
+use emmylua_parser::{LuaAstNode, LuaSyntaxKind};
+
+use crate::{format::LuaFormatter, styles::LuaCodeStyle};
+
+use super::StyleRuler;
+
+pub struct BlankLineRuler;
+
+impl StyleRuler for BlankLineRuler {
+    fn apply_style(formatter: &mut LuaFormatter, styles: &LuaCodeStyle) {
+        let max_line_breaks = styles.max_continuous_blank_lines + 1;
+        for idx in 0..formatter.get_elements().len() {
+            let line_breaks = formatter.get_line_breaks_before(idx);
+            if line_breaks > max_line_breaks {
+                formatter.set_line_breaks_before(idx, max_line_breaks);
+            }
+        }
+
+        let root = formatter.get_root().syntax().clone();
+        for node in root.descendants() {
+            match node.kind().into() {
+                LuaSyntaxKind::Block if !styles.keep_block_edge_blank_lines => {
+                    let is_chunk_block = node
+                        .parent()
+                        .is_none_or(|parent| parent.kind() == LuaSyntaxKind::Chunk.into());
+                    if is_chunk_block {
+                        continue;
+                    }
+
+                    let (Some(first_idx), Some(last_idx)) = (
+                        formatter.get_node_first_index(&node),
+                        formatter.get_node_last_index(&node),
+                    ) else {
+                        continue;
+                    };
+                    limit_line_breaks(formatter, first_idx, 1);
+                    if last_idx + 1 < formatter.get_elements().len() {
+                        limit_line_breaks(formatter, last_idx + 1, 1);
+                    }
+                }
+                LuaSyntaxKind::FuncStat | LuaSyntaxKind::LocalFuncStat
+                    if styles.min_blank_lines_after_function > 0 =>
+                {
+                    // only between statements of the same block
+                    let Some(next) = node.next_sibling() else {
+                        continue;
+                    };
+                    let Some(next_idx) = formatter.get_node_first_index(&next) else {
+                        continue;
+                    };
+                    let line_breaks = formatter.get_line_breaks_before(next_idx);
+                    let min_line_breaks = styles.min_blank_lines_after_function + 1;
+                    if line_breaks > 0 && line_breaks < min_line_breaks {
+                        formatter.set_line_breaks_before(next_idx, min_line_breaks);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn limit_line_breaks(formatter: &mut LuaFormatter, idx: usize, max_line_breaks: usize) {
+    if formatter.get_line_breaks_before(idx) > max_line_breaks {
+        formatter.set_line_breaks_before(idx, max_line_breaks);
+    }
+}
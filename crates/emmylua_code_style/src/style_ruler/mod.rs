@@ -1,10 +1,18 @@
+mod align;
 mod basic_space;
+mod blank_line;
+mod quote_style;
+mod trailing_separator;
 
 use crate::{format::LuaFormatter, styles::LuaCodeStyle};
 
-#[allow(unused)]
 pub fn apply_styles(formatter: &mut LuaFormatter, styles: &LuaCodeStyle) {
     apply_style::<basic_space::BasicSpaceRuler>(formatter, styles);
+    apply_style::<blank_line::BlankLineRuler>(formatter, styles);
+    apply_style::<quote_style::QuoteStyleRuler>(formatter, styles);
+    apply_style::<trailing_separator::TrailingSeparatorRuler>(formatter, styles);
+    // alignment depends on the final line breaks
+    apply_style::<align::AlignRuler>(formatter, styles);
 }
 
 pub trait StyleRuler {
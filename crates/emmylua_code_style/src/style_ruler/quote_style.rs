@@ -0,0 +1,47 @@
+use emmylua_parser::LuaTokenKind;
+
+use crate::{
+    format::{FormatElement, LuaFormatter},
+    styles::{LuaCodeStyle, LuaQuoteStyle},
+};
+
+use super::StyleRuler;
+
+pub struct QuoteStyleRuler;
+
+impl StyleRuler for QuoteStyleRuler {
+    fn apply_style(formatter: &mut LuaFormatter, styles: &LuaCodeStyle) {
+        let (quote, other_quote) = match styles.quote_style {
+            LuaQuoteStyle::None => return,
+            LuaQuoteStyle::Single => ('\'', '"'),
+            LuaQuoteStyle::Double => ('"', '\''),
+        };
+
+        let mut replaced = Vec::new();
+        for (idx, element) in formatter.get_elements().iter().enumerate() {
+            let FormatElement::Token(token) = element else {
+                continue;
+            };
+            if token.kind() != LuaTokenKind::TkString.into() {
+                continue;
+            }
+
+            let text = token.text();
+            if text.len() < 2 || !text.starts_with(other_quote) || !text.ends_with(other_quote) {
+                continue;
+            }
+
+            // a string which contains the wanted quote keeps its quotes to avoid escaping
+            let content = &text[1..text.len() - 1];
+            if content.contains(quote) {
+                continue;
+            }
+
+            replaced.push((idx, format!("{}{}{}", quote, content, quote)));
+        }
+
+        for (idx, text) in replaced {
+            formatter.replace_text(idx, text);
+        }
+    }
+}
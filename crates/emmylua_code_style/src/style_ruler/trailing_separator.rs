@@ -0,0 +1,74 @@
+use emmylua_parser::{LuaAstNode, LuaSyntaxKind, LuaTokenKind};
+
+use crate::{
+    format::LuaFormatter,
+    styles::{LuaCodeStyle, LuaTrailingSeparator},
+};
+
+use super::StyleRuler;
+
+pub struct TrailingSeparatorRuler;
+
+impl StyleRuler for TrailingSeparatorRuler {
+    fn apply_style(formatter: &mut LuaFormatter, styles: &LuaCodeStyle) {
+        if styles.trailing_table_separator == LuaTrailingSeparator::Keep {
+            return;
+        }
+
+        let root = formatter.get_root().syntax().clone();
+        for table in root.descendants() {
+            if !matches!(
+                table.kind().into(),
+                LuaSyntaxKind::TableArrayExpr | LuaSyntaxKind::TableObjectExpr
+            ) {
+                continue;
+            }
+
+            let Some(last_field) = table
+                .children()
+                .filter(|node| is_field(node.kind().into()))
+                .last()
+            else {
+                continue;
+            };
+            let (Some(field_last_idx), Some(close_idx)) = (
+                formatter.get_node_last_index(&last_field),
+                formatter.get_node_last_index(&table),
+            ) else {
+                continue;
+            };
+            if formatter.get_elements()[close_idx].get_token_kind()
+                != Some(LuaTokenKind::TkRightBrace)
+            {
+                continue;
+            }
+
+            let separator_idx = (field_last_idx + 1..close_idx).find(|idx| {
+                matches!(
+                    formatter.get_elements()[*idx].get_token_kind(),
+                    Some(LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon)
+                )
+            });
+            let is_multiline = formatter.get_line_breaks_before(close_idx) > 0;
+            let need_separator = match styles.trailing_table_separator {
+                LuaTrailingSeparator::Always => true,
+                LuaTrailingSeparator::Never => false,
+                LuaTrailingSeparator::Smart => is_multiline,
+                LuaTrailingSeparator::Keep => continue,
+            };
+
+            match separator_idx {
+                Some(idx) if !need_separator => formatter.remove_element(idx),
+                None if need_separator => formatter.append_text(field_last_idx, ",".to_string()),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn is_field(kind: LuaSyntaxKind) -> bool {
+    matches!(
+        kind,
+        LuaSyntaxKind::TableFieldAssign | LuaSyntaxKind::TableFieldValue
+    )
+}
@@ -0,0 +1,177 @@
+use super::{LuaCodeStyle, LuaEndOfLine, LuaIndent, LuaQuoteStyle, LuaTrailingSeparator};
+
+impl LuaCodeStyle {
+    /// Build the style from the sections of an `.editorconfig` that match lua files,
+    /// later sections override earlier ones like editorconfig does
+    pub fn from_editorconfig(content: &str) -> Self {
+        let mut style = LuaCodeStyle::default();
+        let mut in_lua_section = false;
+        let mut indent_size = None;
+        let mut use_tab = None;
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            if line.starts_with('[') && line.ends_with(']') {
+                in_lua_section = is_lua_section(&line[1..line.len() - 1]);
+                continue;
+            }
+
+            if !in_lua_section {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once(['=', ':']) else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim().to_lowercase();
+            match key.as_str() {
+                "indent_style" => use_tab = Some(value == "tab"),
+                "indent_size" | "tab_width" => {
+                    if let Ok(size) = value.parse::<usize>()
+                        && (key == "indent_size" || indent_size.is_none())
+                    {
+                        indent_size = Some(size);
+                    }
+                }
+                _ => apply_property(&mut style, &key, &value),
+            }
+        }
+
+        match (use_tab, indent_size) {
+            (Some(true), _) => style.indent = LuaIndent::Tab,
+            (_, Some(size)) => style.indent = LuaIndent::Space(size),
+            _ => {}
+        }
+
+        style
+    }
+}
+
+fn apply_property(style: &mut LuaCodeStyle, key: &str, value: &str) {
+    match key {
+        "max_line_length" => {
+            if value == "off" {
+                style.max_line_width = 0;
+            } else if let Ok(width) = value.parse() {
+                style.max_line_width = width;
+            }
+        }
+        "end_of_line" => {
+            style.end_of_line = match value {
+                "lf" => LuaEndOfLine::Lf,
+                "crlf" => LuaEndOfLine::CrLf,
+                _ => LuaEndOfLine::Auto,
+            }
+        }
+        "insert_final_newline" => {
+            if let Some(value) = parse_bool(value) {
+                style.insert_final_newline = value;
+            }
+        }
+        "quote_style" => {
+            style.quote_style = match value {
+                "single" => LuaQuoteStyle::Single,
+                "double" => LuaQuoteStyle::Double,
+                _ => LuaQuoteStyle::None,
+            }
+        }
+        "trailing_table_separator" => {
+            style.trailing_table_separator = match value {
+                "never" => LuaTrailingSeparator::Never,
+                "always" => LuaTrailingSeparator::Always,
+                "smart" => LuaTrailingSeparator::Smart,
+                _ => LuaTrailingSeparator::Keep,
+            }
+        }
+        "align_call_args" => {
+            if let Some(value) = parse_bool(value) {
+                style.align_call_args = value;
+            }
+        }
+        // `align_continuous_rect_table_field` is the name used by EmmyLuaCodeStyle
+        "align_table_field" | "align_continuous_rect_table_field" => {
+            if let Some(value) = parse_bool(value) {
+                style.align_table_field = value;
+            }
+        }
+        "max_continuous_blank_lines" => {
+            if let Ok(count) = value.parse() {
+                style.max_continuous_blank_lines = count;
+            }
+        }
+        "keep_block_edge_blank_lines" => {
+            if let Some(value) = parse_bool(value) {
+                style.keep_block_edge_blank_lines = value;
+            }
+        }
+        "min_blank_lines_after_function" => {
+            if let Ok(count) = value.parse() {
+                style.min_blank_lines_after_function = count;
+            }
+        }
+        _ => {}
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+// [*], [*.lua], [{*.lua,*.luau}], [**/*.lua], [*.{lua,txt}]
+fn is_lua_section(pattern: &str) -> bool {
+    expand_braces(pattern).iter().any(|pattern| {
+        let (dir, file) = match pattern.rsplit_once('/') {
+            Some((dir, file)) => (dir, file),
+            None => ("", pattern.as_str()),
+        };
+        matches!(dir, "" | "**") && wildcard_match(file, "main.lua")
+    })
+}
+
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let (Some(start), Some(end)) = (pattern.find('{'), pattern.find('}')) else {
+        return vec![pattern.to_string()];
+    };
+    if end < start {
+        return vec![pattern.to_string()];
+    }
+
+    let prefix = &pattern[..start];
+    let suffix = &pattern[end + 1..];
+    pattern[start + 1..end]
+        .split(',')
+        .map(|alternative| format!("{}{}{}", prefix, alternative.trim(), suffix))
+        .collect()
+}
+
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+    let mut star = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum LuaEndOfLine {
+    /// Use the first line ending found in the file
+    #[default]
+    Auto,
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl LuaEndOfLine {
+    pub fn get_eol_text(&self, code: &str) -> &'static str {
+        match self {
+            LuaEndOfLine::Lf => "\n",
+            LuaEndOfLine::CrLf => "\r\n",
+            LuaEndOfLine::Auto => match code.find('\n') {
+                Some(pos) if pos > 0 && code.as_bytes()[pos - 1] == b'\r' => "\r\n",
+                _ => "\n",
+            },
+        }
+    }
+}
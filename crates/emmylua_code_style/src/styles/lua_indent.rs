@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum LuaIndent {
     /// Use tabs for indentation
     Tab,
@@ -13,3 +13,12 @@ impl Default for LuaIndent {
         LuaIndent::Space(4)
     }
 }
+
+impl LuaIndent {
+    pub fn get_indent_text(&self, level: usize) -> String {
+        match self {
+            LuaIndent::Tab => "\t".repeat(level),
+            LuaIndent::Space(size) => " ".repeat(size * level),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum LuaQuoteStyle {
+    /// Keep the quotes as written
+    #[default]
+    None,
+    /// Prefer `'`
+    Single,
+    /// Prefer `"`
+    Double,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum LuaTrailingSeparator {
+    /// Keep the separator after the last table field as written
+    #[default]
+    Keep,
+    /// Remove the separator after the last table field
+    Never,
+    /// Always add a separator after the last table field
+    Always,
+    /// Add it when the table spans multiple lines, remove it otherwise
+    Smart,
+}
@@ -1,12 +1,55 @@
+mod editorconfig;
+mod lua_end_of_line;
 mod lua_indent;
+mod lua_quote_style;
+mod lua_trailing_separator;
 
-use lua_indent::LuaIndent;
+pub use lua_end_of_line::LuaEndOfLine;
+pub use lua_indent::LuaIndent;
+pub use lua_quote_style::LuaQuoteStyle;
+pub use lua_trailing_separator::LuaTrailingSeparator;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LuaCodeStyle {
     /// The indentation style to use
     pub indent: LuaIndent,
-    /// The maximum width of a line before wrapping
+    /// The maximum width of a line before wrapping, 0 disables wrapping
     pub max_line_width: usize,
+    /// The line ending to use
+    pub end_of_line: LuaEndOfLine,
+    /// Whether the file ends with a line ending
+    pub insert_final_newline: bool,
+    /// The quote style of short strings
+    pub quote_style: LuaQuoteStyle,
+    /// Whether a separator follows the last table field
+    pub trailing_table_separator: LuaTrailingSeparator,
+    /// Align the wrapped call arguments with the first argument
+    pub align_call_args: bool,
+    /// Align the `=` of table fields written on consecutive lines
+    pub align_table_field: bool,
+    /// The maximum number of consecutive blank lines
+    pub max_continuous_blank_lines: usize,
+    /// Keep the blank lines at the start and the end of a block
+    pub keep_block_edge_blank_lines: bool,
+    /// The minimum number of blank lines after a function statement
+    pub min_blank_lines_after_function: usize,
+}
+
+impl Default for LuaCodeStyle {
+    fn default() -> Self {
+        Self {
+            indent: LuaIndent::default(),
+            max_line_width: 120,
+            end_of_line: LuaEndOfLine::default(),
+            insert_final_newline: true,
+            quote_style: LuaQuoteStyle::default(),
+            trailing_table_separator: LuaTrailingSeparator::default(),
+            align_call_args: false,
+            align_table_field: true,
+            max_continuous_blank_lines: 1,
+            keep_block_edge_blank_lines: false,
+            min_blank_lines_after_function: 0,
+        }
+    }
 }
@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod test {
-    use crate::{reformat_lua_code, styles::LuaCodeStyle};
+    use crate::{
+        LuaIndent, LuaQuoteStyle, LuaTrailingSeparator, check_code_style, range_format_code,
+        reformat_code, reformat_lua_code, styles::LuaCodeStyle,
+    };
+    use emmylua_parser::LuaLanguageLevel;
 
     #[test]
     fn test_reformat_lua_code() {
@@ -15,4 +19,150 @@ mod test {
         let formatted_code = reformat_lua_code(code, &styles);
         println!("Formatted code:\n{}", formatted_code);
     }
+
+    #[test]
+    fn test_space_and_indent() {
+        let code = r#"local   a={1,2,3}
+function foo(a,b)
+  if a==b then
+  return a+b
+  else
+      print( 'hello' ,t.x[1], obj:m() ) -- comment
+  end
+end
+"#;
+        let styles = LuaCodeStyle::default();
+        let expected = r#"local a = { 1, 2, 3 }
+function foo(a, b)
+    if a == b then
+        return a + b
+    else
+        print('hello', t.x[1], obj:m()) -- comment
+    end
+end
+"#;
+        assert_eq!(reformat_lua_code(code, &styles), expected);
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let code = "local function f()\n\n    return 1\n\nend\nlocal a = 1\n\n\n\nlocal b = 2\n";
+        let styles = LuaCodeStyle {
+            min_blank_lines_after_function: 1,
+            ..Default::default()
+        };
+        let expected = "local function f()\n    return 1\nend\n\nlocal a = 1\n\nlocal b = 2\n";
+        assert_eq!(reformat_lua_code(code, &styles), expected);
+    }
+
+    #[test]
+    fn test_quote_and_trailing_separator() {
+        let code = "local t = {\n    'a',\n    \"b\",\n    'it\"s'\n}\nlocal u = { 1, 2, }\n";
+        let styles = LuaCodeStyle {
+            quote_style: LuaQuoteStyle::Double,
+            trailing_table_separator: LuaTrailingSeparator::Smart,
+            ..Default::default()
+        };
+        let expected = "local t = {\n    \"a\",\n    \"b\",\n    'it\"s',\n}\nlocal u = { 1, 2 }\n";
+        assert_eq!(reformat_lua_code(code, &styles), expected);
+    }
+
+    #[test]
+    fn test_align() {
+        let code = "local t = {\n    a = 1,\n    bbb = 2,\n\n    cc = 3,\n}\nf(aaa,\nbbb)\n";
+        let styles = LuaCodeStyle {
+            align_call_args: true,
+            ..Default::default()
+        };
+        let expected =
+            "local t = {\n    a   = 1,\n    bbb = 2,\n\n    cc = 3,\n}\nf(aaa,\n  bbb)\n";
+        assert_eq!(reformat_lua_code(code, &styles), expected);
+    }
+
+    #[test]
+    fn test_line_wrap() {
+        let code = "local x = call(argument_one, argument_two, argument_three)\n";
+        let styles = LuaCodeStyle {
+            max_line_width: 40,
+            ..Default::default()
+        };
+        let expected = "local x = call(argument_one,\n    argument_two, argument_three)\n";
+        assert_eq!(reformat_lua_code(code, &styles), expected);
+
+        let code = "local x = first_value and second_value or third_value\n";
+        let expected = "local x = first_value and second_value\n    or third_value\n";
+        assert_eq!(reformat_lua_code(code, &styles), expected);
+    }
+
+    #[test]
+    fn test_editorconfig() {
+        let content = r#"
+root = true
+
+[*]
+indent_size = 2
+
+[*.{lua,luau}]
+indent_style = tab
+max_line_length = off
+quote_style = single
+trailing_table_separator = always
+
+[*.md]
+indent_size = 8
+"#;
+        let styles = LuaCodeStyle::from_editorconfig(content);
+        assert_eq!(styles.indent, LuaIndent::Tab);
+        assert_eq!(styles.max_line_width, 0);
+        assert_eq!(styles.quote_style, LuaQuoteStyle::Single);
+        assert_eq!(
+            styles.trailing_table_separator,
+            LuaTrailingSeparator::Always
+        );
+    }
+
+    #[test]
+    fn test_range_format() {
+        let code = "local a=1\nif a then\n  local  b=2\n  local   c=3\nend\n";
+        let result = range_format_code(
+            code,
+            "file:///test.lua",
+            LuaLanguageLevel::Lua54,
+            2,
+            0,
+            3,
+            0,
+        )
+        .unwrap();
+        assert_eq!(result.start_line, 2);
+        assert_eq!(result.end_line, 2);
+        assert_eq!(result.text, "    local b = 2\n");
+    }
+
+    #[test]
+    fn test_check_code_style() {
+        let code = "local a = 1\nlocal  b = 2\n";
+        let diagnostics = check_code_style("file:///test.lua", code, LuaLanguageLevel::Lua54);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].start_line, 1);
+        assert_eq!(diagnostics[0].start_col, 6);
+
+        assert!(
+            check_code_style("file:///test.lua", "local a = 1\n", LuaLanguageLevel::Lua54)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_format_at_language_level() {
+        let code = "local  count: number = 0\ncount  += 1\n";
+        assert_eq!(
+            reformat_code(code, "file:///test.luau", LuaLanguageLevel::Lua54),
+            code
+        );
+        assert_eq!(
+            reformat_code(code, "file:///test.luau", LuaLanguageLevel::Luau),
+            "local count: number = 0\ncount += 1\n"
+        );
+    }
 }
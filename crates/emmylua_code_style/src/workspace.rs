@@ -0,0 +1,255 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, RwLock},
+};
+
+use emmylua_parser::{
+    LineIndex, LuaAst, LuaAstNode, LuaBlock, LuaLanguageLevel, LuaParser, LuaSyntaxNode,
+    ParserConfig,
+};
+use rowan::{TextRange, TextSize};
+
+use crate::{
+    format::{LuaFormatter, print},
+    style_ruler::apply_styles,
+    styles::LuaCodeStyle,
+};
+
+// workspace directory => the style loaded from its `.editorconfig`
+static WORKSPACE_STYLES: LazyLock<RwLock<HashMap<String, LuaCodeStyle>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug)]
+pub struct RangeFormatResult {
+    pub start_line: i32,
+    pub start_col: i32,
+    pub end_line: i32,
+    pub end_col: i32,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub struct CodeStyleDiagnostic {
+    pub start_line: i32,
+    pub start_col: i32,
+    pub end_line: i32,
+    pub end_col: i32,
+    pub message: String,
+}
+
+/// Load the `.editorconfig` at `config_path` for the files under `workspace_uri`
+pub fn update_code_style(workspace_uri: &str, config_path: &str) {
+    let Ok(content) = std::fs::read_to_string(config_path) else {
+        return;
+    };
+
+    let style = LuaCodeStyle::from_editorconfig(&content);
+    if let Ok(mut styles) = WORKSPACE_STYLES.write() {
+        styles.insert(workspace_uri.trim_end_matches('/').to_string(), style);
+    }
+}
+
+pub fn remove_code_style(workspace_uri: &str) {
+    if let Ok(mut styles) = WORKSPACE_STYLES.write() {
+        styles.remove(workspace_uri.trim_end_matches('/'));
+    }
+}
+
+/// The style of the innermost workspace that contains the file
+pub fn get_code_style(uri: &str) -> LuaCodeStyle {
+    let Ok(styles) = WORKSPACE_STYLES.read() else {
+        return LuaCodeStyle::default();
+    };
+
+    styles
+        .iter()
+        .filter(|(dir, _)| uri.starts_with(dir.as_str()) && uri[dir.len()..].starts_with('/'))
+        .max_by_key(|(dir, _)| dir.len())
+        .map(|(_, style)| style.clone())
+        .unwrap_or_default()
+}
+
+/// Format the file parsed at the language level, the code is returned unchanged when it has
+/// syntax errors
+pub fn reformat_code(code: &str, uri: &str, level: LuaLanguageLevel) -> String {
+    let styles = get_code_style(uri);
+    let tree = LuaParser::parse(code, ParserConfig::new(level, None, HashMap::new()));
+    if !tree.get_errors().is_empty() {
+        return code.to_string();
+    }
+
+    let mut formatter = LuaFormatter::new(LuaAst::LuaChunk(tree.get_chunk_node()));
+    apply_styles(&mut formatter, &styles);
+    formatter.get_formatted_text(&styles)
+}
+
+/// Format the statements between the lines, the result replaces whole lines
+pub fn range_format_code(
+    code: &str,
+    uri: &str,
+    level: LuaLanguageLevel,
+    start_line: i32,
+    _start_col: i32,
+    end_line: i32,
+    _end_col: i32,
+) -> Option<RangeFormatResult> {
+    let styles = get_code_style(uri);
+    let tree = LuaParser::parse(code, ParserConfig::new(level, None, HashMap::new()));
+    if !tree.get_errors().is_empty() {
+        return None;
+    }
+
+    let line_index = LineIndex::parse(code);
+    let code_end = TextSize::from(code.len() as u32);
+    let range_start = line_index
+        .get_line_offset(start_line.max(0) as usize)
+        .unwrap_or(code_end);
+    let range_end = line_index
+        .get_line_offset(end_line.max(0) as usize)
+        .unwrap_or(code_end);
+    if range_start >= range_end {
+        return None;
+    }
+
+    let chunk = tree.get_chunk_node();
+    let (first, last) =
+        find_range_nodes(&chunk.get_block()?, TextRange::new(range_start, range_end))?;
+    let first_line = line_index.get_line(first.text_range().start())?;
+    let last_line = line_index.get_line(last.text_range().end() - TextSize::from(1))?;
+
+    let mut formatter = LuaFormatter::new(LuaAst::LuaChunk(chunk));
+    apply_styles(&mut formatter, &styles);
+    let result = print(&formatter, &styles);
+
+    // every element of the selected lines, formatting only adds line breaks so they are printed
+    // on their own lines
+    let lines_start = line_index.get_line_offset(first_line)?;
+    let lines_end = line_index
+        .get_line_offset(last_line + 1)
+        .unwrap_or(code_end);
+    let elements = formatter.get_elements();
+    let first_idx = elements.partition_point(|element| element.get_position() < lines_start);
+    let last_idx = elements
+        .partition_point(|element| element.get_position() < lines_end)
+        .checked_sub(1)?;
+    if first_idx > last_idx {
+        return None;
+    }
+
+    let eol = styles.end_of_line.get_eol_text(code);
+    let output_lines = result.text.split('\n').collect::<Vec<_>>();
+    let start = result.element_lines[first_idx].0;
+    let end = result.element_lines[last_idx].1;
+    let mut text = String::new();
+    for line in output_lines.get(start..=end)? {
+        text.push_str(line.trim_end_matches('\r'));
+        text.push_str(eol);
+    }
+
+    Some(RangeFormatResult {
+        start_line: first_line as i32,
+        start_col: 0,
+        end_line: last_line as i32,
+        end_col: 0,
+        text,
+    })
+}
+
+// the statements of the innermost block that overlap the range
+fn find_range_nodes(block: &LuaBlock, range: TextRange) -> Option<(LuaSyntaxNode, LuaSyntaxNode)> {
+    let overlapping = block
+        .syntax()
+        .children()
+        .filter(|node| {
+            let node_range = node.text_range();
+            node_range.start() < range.end() && range.start() < node_range.end()
+        })
+        .collect::<Vec<_>>();
+
+    if let [stat] = overlapping.as_slice()
+        && !range.contains_range(stat.text_range())
+    {
+        let inner_block = stat
+            .descendants()
+            .filter_map(LuaBlock::cast)
+            .filter(|block| block.get_range().contains_range(range))
+            .last();
+        if let Some(result) = inner_block.and_then(|block| find_range_nodes(&block, range)) {
+            return Some(result);
+        }
+    }
+
+    Some((overlapping.first()?.clone(), overlapping.last()?.clone()))
+}
+
+/// Report the lines which differ from the formatted code
+pub fn check_code_style(
+    uri: &str,
+    code: &str,
+    level: LuaLanguageLevel,
+) -> Vec<CodeStyleDiagnostic> {
+    let styles = get_code_style(uri);
+    let tree = LuaParser::parse(code, ParserConfig::new(level, None, HashMap::new()));
+    if !tree.get_errors().is_empty() {
+        return vec![];
+    }
+
+    let mut formatter = LuaFormatter::new(LuaAst::LuaChunk(tree.get_chunk_node()));
+    apply_styles(&mut formatter, &styles);
+    let formatted = formatter.get_formatted_text(&styles);
+    let original_lines = code.lines().collect::<Vec<_>>();
+    let formatted_lines = formatted.lines().collect::<Vec<_>>();
+
+    let prefix = original_lines
+        .iter()
+        .zip(formatted_lines.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    if prefix == original_lines.len() && prefix == formatted_lines.len() {
+        return vec![];
+    }
+
+    let max_suffix = original_lines.len().min(formatted_lines.len()) - prefix;
+    let suffix = original_lines
+        .iter()
+        .rev()
+        .zip(formatted_lines.iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut diagnostics = Vec::new();
+    if original_lines.len() == formatted_lines.len() {
+        for line in prefix..original_lines.len() - suffix {
+            let original = original_lines[line];
+            let expected = formatted_lines[line];
+            if original == expected {
+                continue;
+            }
+
+            let start_col = original
+                .chars()
+                .zip(expected.chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            diagnostics.push(CodeStyleDiagnostic {
+                start_line: line as i32,
+                start_col: start_col as i32,
+                end_line: line as i32,
+                end_col: original.chars().count() as i32,
+                message: format!("code style mismatch, expected `{}`", expected.trim()),
+            });
+        }
+    } else if original_lines.len() > prefix {
+        let end_line = original_lines.len() - suffix - 1;
+        diagnostics.push(CodeStyleDiagnostic {
+            start_line: prefix as i32,
+            start_col: 0,
+            end_line: end_line as i32,
+            end_col: original_lines[end_line].chars().count() as i32,
+            message: "code style mismatch".to_string(),
+        });
+    }
+
+    diagnostics
+}
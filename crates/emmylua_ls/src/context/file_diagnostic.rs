@@ -1,4 +1,11 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, Profile};
 use log::{debug, info};
@@ -13,6 +20,12 @@ pub struct FileDiagnostic {
     status_bar: Arc<StatusBar>,
     diagnostic_tokens: Arc<Mutex<HashMap<FileId, CancellationToken>>>,
     workspace_diagnostic_token: Arc<Mutex<Option<CancellationToken>>>,
+    // the client pulls diagnostics itself, so nothing is published
+    pull_mode: AtomicBool,
+    pull_refresh_support: AtomicBool,
+    // the result ids of the pulled diagnostics, they are dropped when the file or a file it
+    // depends on is updated
+    pull_result_ids: Arc<Mutex<HashMap<FileId, String>>>,
 }
 
 impl FileDiagnostic {
@@ -27,10 +40,51 @@ impl FileDiagnostic {
             diagnostic_tokens: Arc::new(Mutex::new(HashMap::new())),
             workspace_diagnostic_token: Arc::new(Mutex::new(None)),
             status_bar,
+            pull_mode: AtomicBool::new(false),
+            pull_refresh_support: AtomicBool::new(false),
+            pull_result_ids: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn enable_pull_mode(&self, refresh_support: bool) {
+        self.pull_mode.store(true, Ordering::SeqCst);
+        self.pull_refresh_support
+            .store(refresh_support, Ordering::SeqCst);
+    }
+
+    pub fn is_pull_mode(&self) -> bool {
+        self.pull_mode.load(Ordering::SeqCst)
+    }
+
+    pub async fn get_pull_result_id(&self, file_id: FileId) -> Option<String> {
+        self.pull_result_ids.lock().await.get(&file_id).cloned()
+    }
+
+    pub async fn set_pull_result_id(&self, file_id: FileId, result_id: String) {
+        self.pull_result_ids.lock().await.insert(file_id, result_id);
+    }
+
+    /// The next pull diagnoses the files again
+    pub async fn invalidate_pull_results(&self, file_ids: &[FileId]) {
+        let mut result_ids = self.pull_result_ids.lock().await;
+        for file_id in file_ids {
+            result_ids.remove(file_id);
+        }
+    }
+
+    /// Ask the client to pull the diagnostics again, nothing is published in pull mode
+    pub fn refresh_pull_diagnostics(&self) {
+        if self.is_pull_mode() && self.pull_refresh_support.load(Ordering::SeqCst) {
+            self.client.refresh_workspace_diagnostics();
         }
     }
 
     pub async fn add_diagnostic_task(&self, file_id: FileId, interval: u64) {
+        if self.is_pull_mode() {
+            self.invalidate_pull_results(&[file_id]).await;
+            return;
+        }
+
         let mut tokens = self.diagnostic_tokens.lock().await;
 
         if let Some(token) = tokens.get(&file_id) {
@@ -90,6 +144,13 @@ impl FileDiagnostic {
         interval: u64,
         silent: bool,
     ) {
+        if self.is_pull_mode() {
+            // let the client pull the diagnostics of the whole workspace again
+            self.pull_result_ids.lock().await.clear();
+            self.refresh_pull_diagnostics();
+            return;
+        }
+
         let mut token = self.workspace_diagnostic_token.lock().await;
         if let Some(token) = token.as_ref() {
             token.cancel();
@@ -2,12 +2,13 @@ use std::collections::HashSet;
 use std::{path::PathBuf, sync::Arc, time::Duration};
 
 use super::{ClientProxy, FileDiagnostic, StatusBar};
-use crate::handlers::{init_analysis, ClientConfig};
+use crate::handlers::{init_analysis, load_editorconfig, load_workspace_folders, ClientConfig};
 use dirs;
 use emmylua_code_analysis::update_code_style;
-use emmylua_code_analysis::{load_configs, EmmyLuaAnalysis, Emmyrc};
+use emmylua_code_analysis::{load_configs, EmmyLuaAnalysis, Emmyrc, FileId, WorkspaceId};
 use log::{debug, info};
-use lsp_types::Uri;
+use lsp_types::{PublishDiagnosticsParams, Uri};
+use notify::{RecursiveMode, Watcher};
 use tokio::sync::{Mutex, RwLock};
 use tokio_util::sync::CancellationToken;
 
@@ -16,6 +17,7 @@ pub struct WorkspaceManager {
     client: Arc<ClientProxy>,
     status_bar: Arc<StatusBar>,
     update_token: Arc<Mutex<Option<Arc<ReindexToken>>>>,
+    dependents_token: Arc<Mutex<Option<Arc<ReindexToken>>>>,
     file_diagnostic: Arc<FileDiagnostic>,
     pub client_config: ClientConfig,
     pub workspace_folders: Vec<PathBuf>,
@@ -37,6 +39,7 @@ impl WorkspaceManager {
             client_config: ClientConfig::default(),
             workspace_folders: Vec::new(),
             update_token: Arc::new(Mutex::new(None)),
+            dependents_token: Arc::new(Mutex::new(None)),
             file_diagnostic,
             watcher: None,
             current_open_files: HashSet::new(),
@@ -68,12 +71,16 @@ impl WorkspaceManager {
                 return;
             }
 
-            let emmyrc = load_emmy_config(Some(file_dir.clone()), client_config);
+            // the changed config may belong to any workspace folder, so all of them are reloaded
+            info!("reload config for the change in {:?}", file_dir);
+            let config_root = workspace_folders.first().cloned();
+            let emmyrc = load_emmy_config(config_root, client_config.clone());
+            let workspace_emmyrcs = load_workspace_emmyrcs(&workspace_folders, &client_config);
             init_analysis(
                 analysis,
                 client,
                 &status_bar,
-                workspace_folders,
+                workspace_emmyrcs,
                 emmyrc,
                 client_id,
                 file_diagnostic,
@@ -105,9 +112,10 @@ impl WorkspaceManager {
         };
 
         let emmyrc = load_emmy_config(config_root, self.client_config.clone());
+        let workspace_emmyrcs =
+            load_workspace_emmyrcs(&self.workspace_folders, &self.client_config);
         let analysis = self.analysis.clone();
         let client = self.client.clone();
-        let workspace_folders = self.workspace_folders.clone();
         let status_bar = self.status_bar.clone();
         let client_id = self.client_config.client_id;
         let file_diagnostic = self.file_diagnostic.clone();
@@ -115,7 +123,7 @@ impl WorkspaceManager {
             analysis,
             client,
             &status_bar,
-            workspace_folders,
+            workspace_emmyrcs,
             emmyrc,
             client_id,
             file_diagnostic,
@@ -125,6 +133,74 @@ impl WorkspaceManager {
         Some(())
     }
 
+    /// Add and remove workspace folders while the server runs, the files of a removed folder are
+    /// removed from the analysis and the added folders are loaded with their own configs
+    pub async fn update_workspace_folders(
+        &mut self,
+        added: Vec<PathBuf>,
+        removed: Vec<PathBuf>,
+    ) -> Option<()> {
+        for folder in &removed {
+            info!("remove workspace folder: {:?}", folder);
+            self.workspace_folders.retain(|it| it != folder);
+            if let Some(watcher) = &mut self.watcher {
+                let _ = watcher.unwatch(folder);
+            }
+
+            let removed_uris = self.analysis.write().await.remove_main_workspace(folder);
+            for uri in removed_uris {
+                self.client.publish_diagnostics(PublishDiagnosticsParams {
+                    uri,
+                    diagnostics: Vec::new(),
+                    version: None,
+                });
+            }
+        }
+
+        let added: Vec<PathBuf> = added
+            .into_iter()
+            .filter(|folder| !self.workspace_folders.contains(folder))
+            .collect();
+        if added.is_empty() {
+            return Some(());
+        }
+
+        for folder in &added {
+            info!("add workspace folder: {:?}", folder);
+            self.workspace_folders.push(folder.clone());
+            if let Some(watcher) = &mut self.watcher {
+                if let Err(e) = watcher.watch(folder, RecursiveMode::Recursive) {
+                    log::warn!("can not watch {:?}: {:?}", folder, e);
+                }
+            }
+        }
+
+        let workspace_emmyrcs = load_workspace_emmyrcs(&added, &self.client_config);
+        load_editorconfig(added);
+        let mut analysis = self.analysis.write().await;
+        let file_ids = load_workspace_folders(&mut analysis, &self.client, workspace_emmyrcs);
+        // the loaded files may define what the other files use
+        let dependent_files = analysis.update_dependents();
+        let module_index = analysis.compilation.get_db().get_module_index();
+        let file_ids: Vec<FileId> = file_ids
+            .into_iter()
+            .chain(dependent_files)
+            .filter(|file_id| module_index.get_workspace_id(*file_id) == Some(WorkspaceId::MAIN))
+            .collect();
+        let interval = analysis
+            .get_emmyrc()
+            .diagnostics
+            .diagnostic_interval
+            .unwrap_or(500);
+        drop(analysis);
+
+        self.file_diagnostic
+            .add_files_diagnostic_task(file_ids, interval)
+            .await;
+        self.file_diagnostic.refresh_pull_diagnostics();
+        Some(())
+    }
+
     pub async fn extend_reindex_delay(&self) -> Option<()> {
         let update_token = self.update_token.lock().await;
         if let Some(token) = update_token.as_ref() {
@@ -163,6 +239,54 @@ impl WorkspaceManager {
 
         Some(())
     }
+
+    /// Re-analyze the files which depend on the edited files once no edit arrives within the
+    /// delay, then refresh the diagnostics of the opened ones
+    pub async fn update_dependents(&self, delay: Duration) -> Option<()> {
+        let mut dependents_token = self.dependents_token.lock().await;
+        if let Some(token) = dependents_token.as_ref() {
+            token.cancel();
+        }
+
+        let cancel_token = Arc::new(ReindexToken::new(delay));
+        dependents_token.replace(cancel_token.clone());
+        drop(dependents_token);
+        let analysis = self.analysis.clone();
+        let file_diagnostic = self.file_diagnostic.clone();
+        let open_files: Vec<Uri> = self.current_open_files.iter().cloned().collect();
+
+        tokio::spawn(async move {
+            cancel_token.wait_for_reindex().await;
+            if cancel_token.is_cancelled() {
+                return;
+            }
+
+            let mut analysis = analysis.write().await;
+            let dependent_files = analysis.update_dependents();
+            file_diagnostic
+                .invalidate_pull_results(&dependent_files)
+                .await;
+            let file_ids = dependent_files
+                .into_iter()
+                .filter(|file_id| {
+                    analysis
+                        .get_uri(*file_id)
+                        .is_some_and(|uri| open_files.contains(&uri))
+                })
+                .collect();
+            let interval = analysis
+                .get_emmyrc()
+                .diagnostics
+                .diagnostic_interval
+                .unwrap_or(500);
+            drop(analysis);
+            file_diagnostic
+                .add_files_diagnostic_task(file_ids, interval)
+                .await;
+        });
+
+        Some(())
+    }
 }
 
 pub fn load_emmy_config(config_root: Option<PathBuf>, client_config: ClientConfig) -> Arc<Emmyrc> {
@@ -245,6 +369,21 @@ pub fn load_emmy_config(config_root: Option<PathBuf>, client_config: ClientConfi
     emmyrc.into()
 }
 
+/// Load the config of every workspace folder, a folder uses the global configs and its own local
+/// configs
+pub fn load_workspace_emmyrcs(
+    workspace_folders: &[PathBuf],
+    client_config: &ClientConfig,
+) -> Vec<(PathBuf, Arc<Emmyrc>)> {
+    workspace_folders
+        .iter()
+        .map(|folder| {
+            let emmyrc = load_emmy_config(Some(folder.clone()), client_config.clone());
+            (folder.clone(), emmyrc)
+        })
+        .collect()
+}
+
 fn merge_client_config(client_config: ClientConfig, emmyrc: &mut Emmyrc) -> Option<()> {
     emmyrc.runtime.extensions.extend(client_config.extensions);
     emmyrc.workspace.ignore_globs.extend(client_config.exclude);
@@ -1,35 +1,45 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
-use emmylua_code_analysis::{DiagnosticCode, FileId, SemanticModel};
+use emmylua_code_analysis::{
+    get_diagnostic_fix, DiagnosticCode, FileId, LuaCompilation, SemanticModel,
+};
+use emmylua_parser::LuaAstNode;
 use lsp_types::{
     CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionResponse, Diagnostic,
     NumberOrString, Range, WorkspaceEdit,
 };
 
-use crate::handlers::command::{make_disable_code_command, DisableAction};
+use crate::handlers::{
+    command::{make_disable_code_command, DisableAction},
+    rename::rename_references,
+};
 
 use super::actions::{build_disable_file_changes, build_disable_next_line_changes};
 
 pub fn build_actions(
+    compilation: &LuaCompilation,
     semantic_model: &SemanticModel,
     diagnostics: Vec<Diagnostic>,
 ) -> Option<CodeActionResponse> {
     let mut actions = Vec::new();
     let file_id = semantic_model.get_file_id();
     for diagnostic in diagnostics {
-        if diagnostic.source.is_none() {
-            continue;
-        }
-
-        let source = diagnostic.source.unwrap();
-        if source != "EmmyLua" {
+        if diagnostic.source.as_deref() != Some("EmmyLua") {
             continue;
         }
 
-        if let Some(code) = diagnostic.code {
+        if let Some(code) = &diagnostic.code {
             if let NumberOrString::String(action_string) = code {
-                if let Some(diagnostic_code) = DiagnosticCode::from_str(&action_string).ok() {
-                    add_fix_code_action(&mut actions, diagnostic_code, file_id, diagnostic.range);
+                if let Ok(diagnostic_code) = DiagnosticCode::from_str(action_string) {
+                    if diagnostic_code == DiagnosticCode::NameStyleCheck {
+                        add_rename_code_action(
+                            compilation,
+                            semantic_model,
+                            &mut actions,
+                            &diagnostic,
+                        );
+                    }
+                    add_fix_code_action(semantic_model, &mut actions, &diagnostic);
                     add_disable_code_action(
                         &semantic_model,
                         &mut actions,
@@ -49,13 +59,56 @@ pub fn build_actions(
     Some(actions)
 }
 
-#[allow(unused_variables)]
 fn add_fix_code_action(
+    semantic_model: &SemanticModel,
     actions: &mut Vec<CodeActionOrCommand>,
-    diagnostic_code: DiagnosticCode,
-    file_id: FileId,
-    range: Range,
+    diagnostic: &Diagnostic,
+) -> Option<()> {
+    let fix = get_diagnostic_fix(semantic_model, diagnostic)?;
+    let uri = semantic_model.get_document().get_uri();
+
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title: fix.title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri, fix.edits)])),
+            ..Default::default()
+        }),
+        is_preferred: Some(fix.is_preferred),
+        ..Default::default()
+    }));
+
+    Some(())
+}
+
+/// Renames the declaration and its references to the name suggested by the diagnostic
+fn add_rename_code_action(
+    compilation: &LuaCompilation,
+    semantic_model: &SemanticModel,
+    actions: &mut Vec<CodeActionOrCommand>,
+    diagnostic: &Diagnostic,
 ) -> Option<()> {
+    let new_name = diagnostic.data.as_ref()?.as_str()?.to_string();
+    let document = semantic_model.get_document();
+    let offset = document.get_offset(
+        diagnostic.range.start.line as usize,
+        diagnostic.range.start.character as usize,
+    )?;
+    let token = semantic_model
+        .get_root()
+        .syntax()
+        .token_at_offset(offset)
+        .right_biased()?;
+    let edit = rename_references(semantic_model, compilation, token, new_name.clone())?;
+
+    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+        title: t!("Rename to `%{name}`", name = new_name).to_string(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        edit: Some(edit),
+        is_preferred: Some(true),
+        ..Default::default()
+    }));
+
     Some(())
 }
 
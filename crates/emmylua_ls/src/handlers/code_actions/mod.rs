@@ -1,7 +1,8 @@
 mod actions;
 mod build_actions;
+mod test;
 
-use build_actions::build_actions;
+pub use build_actions::build_actions;
 use lsp_types::{
     ClientCapabilities, CodeActionParams, CodeActionProviderCapability, CodeActionResponse,
     ServerCapabilities,
@@ -22,9 +23,9 @@ pub async fn on_code_action_handler(
     let diagnostics = params.context.diagnostics;
     let analysis = context.analysis.read().await;
     let file_id = analysis.get_file_id(&uri)?;
-    let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
 
-    build_actions(&mut semantic_model, diagnostics)
+    build_actions(&analysis.compilation, &semantic_model, diagnostics)
 }
 
 pub struct CodeActionsCapabilities;
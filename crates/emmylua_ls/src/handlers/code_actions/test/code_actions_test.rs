@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::{DiagnosticCode, Emmyrc, EmmyrcNameStyleKind, EmmyrcNameStyleRule};
+
+    use crate::handlers::test_lib::ProviderVirtualWorkspace;
+
+    #[test]
+    fn test_add_doc_param() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::IncompleteSignatureDoc);
+        assert!(ws.check_code_action(
+            DiagnosticCode::IncompleteSignatureDoc,
+            r#"
+            ---@param a number
+            local function f(a, b)
+            end
+            "#,
+            r#"
+            ---@param a number
+            ---@param b any
+            local function f(a, b)
+            end
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_remove_doc_param() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_code_action(
+            DiagnosticCode::UndefinedDocParam,
+            r#"
+            ---@param a number
+            ---@param c string
+            local function f(a)
+            end
+            "#,
+            r#"
+            ---@param a number
+            local function f(a)
+            end
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Point
+            ---@field x number
+            ---@field y number
+            ---@field z? number
+
+            ---@class Label
+            ---@field text string
+            ---@field visible boolean
+            ---@field pos Point
+            ---@field on_click fun()
+            "#,
+        );
+        assert!(ws.check_code_action(
+            DiagnosticCode::MissingFields,
+            r#"
+            ---@type Point
+            local p = { x = 1 }
+            "#,
+            r#"
+            ---@type Point
+            local p = { x = 1, y = 0 }
+            "#,
+        ));
+        assert!(ws.check_code_action(
+            DiagnosticCode::MissingFields,
+            r#"
+            ---@type Point
+            local p = {
+                z = 1,
+            }
+            "#,
+            r#"
+            ---@type Point
+            local p = {
+                z = 1,
+                x = 0,
+                y = 0,
+            }
+            "#,
+        ));
+        assert!(ws.check_code_action(
+            DiagnosticCode::MissingFields,
+            r#"
+            ---@type Label
+            local label = {}
+            "#,
+            r#"
+            ---@type Label
+            local label = { on_click = function() end, pos = {}, text = "", visible = false }
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_undefined_global() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_code_action(
+            DiagnosticCode::UndefinedGlobal,
+            r#"
+            local function f()
+                return count + 1
+            end
+            "#,
+            r#"
+            local function f()
+                local count = 0
+                return count + 1
+            end
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_prefix_unused() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_code_action(
+            DiagnosticCode::Unused,
+            r#"
+            local value = 1
+            "#,
+            r#"
+            local _value = 1
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_rename_name_style() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.name_style.local_name =
+            vec![EmmyrcNameStyleRule::Style(EmmyrcNameStyleKind::SnakeCase)];
+        ws.analysis.update_config(emmyrc.into());
+        assert!(ws.check_code_action(
+            DiagnosticCode::NameStyleCheck,
+            r#"
+            local function f()
+                local maxSize = 1
+                return maxSize + 1
+            end
+            "#,
+            r#"
+            local function f()
+                local max_size = 1
+                return max_size + 1
+            end
+            "#,
+        ));
+    }
+}
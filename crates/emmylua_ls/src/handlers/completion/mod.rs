@@ -7,7 +7,7 @@ mod test;
 
 use add_completions::CompletionData;
 use completion_builder::CompletionBuilder;
-use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
+use emmylua_code_analysis::{complete_emmyrc_config, EmmyLuaAnalysis, FileId};
 use emmylua_parser::LuaAstNode;
 use log::error;
 use lsp_types::{
@@ -21,7 +21,10 @@ use tokio_util::sync::CancellationToken;
 
 use crate::context::{ClientId, ServerContextSnapshot};
 
-use super::RegisterCapabilities;
+use super::{
+    text_document::{get_config_document, get_config_file_kind, ConfigFileKind},
+    RegisterCapabilities,
+};
 
 pub async fn on_completion_handler(
     context: ServerContextSnapshot,
@@ -30,6 +33,11 @@ pub async fn on_completion_handler(
 ) -> Option<CompletionResponse> {
     let uri = params.text_document_position.text_document.uri;
     let position = params.text_document_position.position;
+    if get_config_file_kind(&uri) == Some(ConfigFileKind::Emmyrc) {
+        let items = complete_emmyrc_config(&get_config_document(&uri)?, position);
+        return Some(CompletionResponse::Array(items));
+    }
+
     let analysis = context.analysis.read().await;
     let file_id = analysis.get_file_id(&uri)?;
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
@@ -44,7 +44,9 @@ fn get_doc_completion_expected(trigger_token: &LuaSyntaxToken) -> Option<DocComp
         LuaTokenKind::TkName => {
             let parent_node = trigger_token.parent()?;
             match parent_node.kind().into() {
-                LuaSyntaxKind::DocTagParam => Some(DocCompletionExpected::ParamName),
+                LuaSyntaxKind::DocTagParam | LuaSyntaxKind::DocTagFormatString => {
+                    Some(DocCompletionExpected::ParamName)
+                }
                 LuaSyntaxKind::DocTagCast => Some(DocCompletionExpected::Cast),
                 LuaSyntaxKind::DocTagDiagnostic => Some(DocCompletionExpected::DiagnosticAction),
                 LuaSyntaxKind::DocDiagnosticCodeList => Some(DocCompletionExpected::DiagnosticCode),
@@ -54,7 +56,9 @@ fn get_doc_completion_expected(trigger_token: &LuaSyntaxToken) -> Option<DocComp
         LuaTokenKind::TkWhitespace => {
             let left_token = trigger_token.prev_token()?;
             match left_token.kind().into() {
-                LuaTokenKind::TkTagParam => Some(DocCompletionExpected::ParamName),
+                LuaTokenKind::TkTagParam | LuaTokenKind::TkTagFormatString => {
+                    Some(DocCompletionExpected::ParamName)
+                }
                 LuaTokenKind::TkTagCast => Some(DocCompletionExpected::Cast),
                 LuaTokenKind::TkTagDiagnostic => Some(DocCompletionExpected::DiagnosticAction),
                 LuaTokenKind::TkColon => {
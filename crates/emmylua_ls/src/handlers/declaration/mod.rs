@@ -0,0 +1,153 @@
+mod test;
+
+use emmylua_code_analysis::{
+    EmmyLuaAnalysis, FileId, LuaDeclId, LuaMemberId, LuaSemanticDeclId, SemanticDeclLevel,
+    SemanticModel,
+};
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaAstToken, LuaClosureExpr, LuaCommentOwner, LuaDocTagParam, LuaStat,
+    LuaTableField, LuaTokenKind,
+};
+use lsp_types::{
+    request::{GotoDeclarationParams, GotoDeclarationResponse},
+    ClientCapabilities, DeclarationCapability, Location, Position, ServerCapabilities,
+};
+use rowan::TokenAtOffset;
+use tokio_util::sync::CancellationToken;
+
+use crate::context::ServerContextSnapshot;
+
+use super::{definition::goto_def_definition, RegisterCapabilities};
+
+pub async fn on_goto_declaration_handler(
+    context: ServerContextSnapshot,
+    params: GotoDeclarationParams,
+    _: CancellationToken,
+) -> Option<GotoDeclarationResponse> {
+    let uri = params.text_document_position_params.text_document.uri;
+    let analysis = context.analysis.read().await;
+    let file_id = analysis.get_file_id(&uri)?;
+    let position = params.text_document_position_params.position;
+
+    declaration(&analysis, file_id, position)
+}
+
+/// Go to the `---@field` of a member or the `---@param` of a parameter, the other declarations
+/// are the same as their definitions
+pub fn declaration(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    position: Position,
+) -> Option<GotoDeclarationResponse> {
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let root = semantic_model.get_root();
+    let position_offset = {
+        let document = semantic_model.get_document();
+        document.get_offset(position.line as usize, position.character as usize)?
+    };
+
+    if position_offset > root.syntax().text_range().end() {
+        return None;
+    }
+
+    let token = match root.syntax().token_at_offset(position_offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, right) => {
+            if left.kind() == LuaTokenKind::TkName.into() {
+                left
+            } else {
+                right
+            }
+        }
+        TokenAtOffset::None => {
+            return None;
+        }
+    };
+
+    let semantic_decl = semantic_model.find_decl(token.into(), SemanticDeclLevel::default())?;
+    let locations = match &semantic_decl {
+        LuaSemanticDeclId::Member(member_id) => find_field_locations(&semantic_model, *member_id),
+        LuaSemanticDeclId::LuaDecl(decl_id) => find_doc_param_locations(&semantic_model, *decl_id),
+        _ => None,
+    };
+
+    match locations {
+        Some(locations) if !locations.is_empty() => Some(GotoDeclarationResponse::Array(locations)),
+        _ => goto_def_definition(&semantic_model, semantic_decl),
+    }
+}
+
+/// The `---@field` tags which declare the member
+fn find_field_locations(
+    semantic_model: &SemanticModel,
+    member_id: LuaMemberId,
+) -> Option<Vec<Location>> {
+    let member_index = semantic_model.get_db().get_member_index();
+    let member_item = member_index.get_member_item_by_member_id(member_id)?;
+    let mut locations = Vec::new();
+    for member_id in member_item.get_member_ids() {
+        let Some(member) = member_index.get_member(&member_id) else {
+            continue;
+        };
+        if !member.is_field() {
+            continue;
+        }
+
+        let document = semantic_model.get_document_by_file_id(member.get_file_id())?;
+        locations.push(document.to_lsp_location(member.get_range())?);
+    }
+
+    Some(locations)
+}
+
+/// The `---@param` tag of the parameter
+fn find_doc_param_locations(
+    semantic_model: &SemanticModel,
+    decl_id: LuaDeclId,
+) -> Option<Vec<Location>> {
+    let decl = semantic_model
+        .get_db()
+        .get_decl_index()
+        .get_decl(&decl_id)?;
+    if !decl.is_param() {
+        return None;
+    }
+
+    let name = decl.get_name();
+    let root = semantic_model.get_root_by_file_id(decl_id.file_id)?;
+    let param_node = LuaAst::cast(decl.get_syntax_id().to_node_from_root(root.syntax())?)?;
+    let closure_expr = param_node.ancestors::<LuaClosureExpr>().next()?;
+    let comments = if let Some(table_field) = closure_expr.get_parent::<LuaTableField>() {
+        table_field.get_comments()
+    } else if let Some(stat) = closure_expr.ancestors::<LuaStat>().next() {
+        stat.get_comments()
+    } else {
+        return None;
+    };
+
+    let document = semantic_model.get_document_by_file_id(decl_id.file_id)?;
+    let mut locations = Vec::new();
+    for comment in comments {
+        for tag_doc in comment.get_doc_tags() {
+            let Some(doc_param) = LuaDocTagParam::cast(tag_doc.syntax().clone()) else {
+                continue;
+            };
+            let Some(name_token) = doc_param.get_name_token() else {
+                continue;
+            };
+            if name_token.get_text() == name {
+                locations.push(document.to_lsp_location(name_token.get_range())?);
+            }
+        }
+    }
+
+    Some(locations)
+}
+
+pub struct DeclarationCapabilities;
+
+impl RegisterCapabilities for DeclarationCapabilities {
+    fn register_capabilities(server_capabilities: &mut ServerCapabilities, _: &ClientCapabilities) {
+        server_capabilities.declaration_provider = Some(DeclarationCapability::Simple(true));
+    }
+}
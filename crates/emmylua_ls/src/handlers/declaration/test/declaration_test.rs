@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::ProviderVirtualWorkspace;
+
+    #[test]
+    fn test_field_declaration() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_declaration(
+            r#"
+            ---@class Point
+            ---@field x number
+            local Point = {}
+
+            Point.x = 1
+
+            print(Point.<??>x)
+            "#,
+            vec![2],
+        ));
+    }
+
+    #[test]
+    fn test_param_declaration() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_declaration(
+            r#"
+            ---@param a number
+            ---@param b number
+            local function add(a, b)
+                return a + <??>b
+            end
+            "#,
+            vec![2],
+        ));
+    }
+
+    #[test]
+    fn test_local_declaration() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_declaration(
+            r#"
+            local a = 1
+            print(<??>a)
+            "#,
+            vec![1],
+        ));
+    }
+}
@@ -0,0 +1 @@
+mod declaration_test;
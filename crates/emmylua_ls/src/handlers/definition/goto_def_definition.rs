@@ -1,7 +1,9 @@
 use std::str::FromStr;
 
-use emmylua_code_analysis::{LuaSemanticDeclId, LuaType, LuaTypeDeclId, SemanticModel};
-use emmylua_parser::{LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr, LuaStringToken};
+use emmylua_code_analysis::{
+    find_goto_label, LuaSemanticDeclId, LuaType, LuaTypeDeclId, SemanticModel,
+};
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr, LuaGotoStat, LuaStringToken};
 use lsp_types::{GotoDefinitionResponse, Location, Position, Range, Uri};
 
 pub fn goto_def_definition(
@@ -142,3 +144,14 @@ pub fn goto_str_tpl_ref_definition(
 
     None
 }
+
+pub fn goto_label_definition(
+    semantic_model: &SemanticModel,
+    goto_stat: LuaGotoStat,
+) -> Option<GotoDefinitionResponse> {
+    let label_stat = find_goto_label(&goto_stat)?;
+    let name_token = label_stat.get_label_name_token()?;
+    let document = semantic_model.get_document();
+    let location = document.to_lsp_location(name_token.get_range())?;
+    Some(GotoDefinitionResponse::Scalar(location))
+}
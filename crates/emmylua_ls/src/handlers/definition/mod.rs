@@ -5,10 +5,11 @@ mod test;
 
 use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, SemanticDeclLevel};
 use emmylua_parser::{
-    LuaAstNode, LuaAstToken, LuaDocTagSee, LuaGeneralToken, LuaStringToken, LuaTokenKind,
+    LuaAstNode, LuaAstToken, LuaDocTagSee, LuaGeneralToken, LuaGotoStat, LuaStringToken,
+    LuaTokenKind,
 };
 pub use goto_def_definition::goto_def_definition;
-use goto_def_definition::goto_str_tpl_ref_definition;
+use goto_def_definition::{goto_label_definition, goto_str_tpl_ref_definition};
 pub use goto_doc_see::goto_doc_see;
 pub use goto_module_file::goto_module_file;
 use lsp_types::{
@@ -65,7 +66,11 @@ pub fn definition(
         }
     };
 
-    if let Some(semantic_decl) =
+    if let Some(goto_stat) = token.parent().and_then(LuaGotoStat::cast) {
+        if let Some(label_response) = goto_label_definition(&semantic_model, goto_stat) {
+            return Some(label_response);
+        }
+    } else if let Some(semantic_decl) =
         semantic_model.find_decl(token.clone().into(), SemanticDeclLevel::default())
     {
         return goto_def_definition(&semantic_model, semantic_decl);
@@ -20,4 +20,20 @@ mod tests {
             "#,
         );
     }
+
+    #[test]
+    fn test_goto_label_definition() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_definition_lines(
+            r#"
+                for i = 1, 10 do
+                    if i % 2 == 0 then
+                        goto <??>continue
+                    end
+                    ::continue::
+                end
+            "#,
+            vec![5],
+        ));
+    }
 }
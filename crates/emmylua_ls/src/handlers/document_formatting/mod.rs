@@ -37,7 +37,12 @@ pub async fn on_formatting_handler(
     let text = document.get_text();
     let file_path = document.get_file_path();
     let normalized_path = file_path.to_string_lossy().to_string().replace("\\", "/");
-    let mut formatted_text = reformat_code(text, &normalized_path);
+    let level = analysis
+        .compilation
+        .get_db()
+        .get_file_emmyrc(file_id)
+        .get_language_level();
+    let mut formatted_text = reformat_code(text, &normalized_path, level);
     if client_id.is_intellij() || client_id.is_other() {
         formatted_text = formatted_text.replace("\r\n", "\n");
     }
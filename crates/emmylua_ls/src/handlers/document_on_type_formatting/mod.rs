@@ -0,0 +1,183 @@
+mod test;
+
+use emmylua_code_analysis::{range_format_code, EmmyLuaAnalysis, FileId};
+use emmylua_parser::{LuaAstNode, LuaStat, LuaSyntaxToken, LuaTokenKind};
+use lsp_types::{
+    ClientCapabilities, DocumentOnTypeFormattingOptions, DocumentOnTypeFormattingParams, Position,
+    Range, ServerCapabilities, TextEdit,
+};
+use rowan::{TextRange, TokenAtOffset};
+use tokio_util::sync::CancellationToken;
+
+use crate::context::{ClientId, ServerContextSnapshot};
+
+use super::RegisterCapabilities;
+
+pub async fn on_type_formatting_handler(
+    context: ServerContextSnapshot,
+    params: DocumentOnTypeFormattingParams,
+    _: CancellationToken,
+) -> Option<Vec<TextEdit>> {
+    let uri = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let analysis = context.analysis.read().await;
+    let config_manager = context.workspace_manager.read().await;
+    let client_id = config_manager.client_config.client_id;
+    let file_id = analysis.get_file_id(&uri)?;
+
+    on_type_format(&analysis, file_id, position, &params.ch, client_id)
+}
+
+/// Reindent the block which was just finished, a newline formats the statement of the previous
+/// line and the `d` of an `end` formats the statement which the `end` closes
+pub fn on_type_format(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    position: Position,
+    ch: &str,
+    client_id: ClientId,
+) -> Option<Vec<TextEdit>> {
+    let vfs = analysis.compilation.get_db().get_vfs();
+    let syntax_tree = vfs.get_syntax_tree(&file_id)?;
+    let document = vfs.get_document(&file_id)?;
+    let root = syntax_tree.get_chunk_node();
+    let stat = match ch {
+        "\n" => {
+            let line = position.line.checked_sub(1)?;
+            let line_end = document.get_line_range(line as usize)?.end();
+            let token = find_last_token_before(root.syntax().token_at_offset(line_end))?;
+            token.parent_ancestors().find_map(LuaStat::cast)?
+        }
+        "d" => {
+            let offset =
+                document.get_offset(position.line as usize, position.character as usize)?;
+            let token = find_last_token_before(root.syntax().token_at_offset(offset))?;
+            if token.kind() != LuaTokenKind::TkEnd.into() {
+                return None;
+            }
+            token.parent_ancestors().find_map(LuaStat::cast)?
+        }
+        _ => return None,
+    };
+
+    // the rest of the file may be in the middle of being typed, so only the lines of the
+    // statement are formatted and they must parse on their own
+    let stat_range = stat.get_range();
+    if syntax_tree
+        .get_errors()
+        .iter()
+        .any(|error| error.range.intersect(stat_range).is_some())
+    {
+        return None;
+    }
+
+    let start_line = document.get_line(stat_range.start())?;
+    let end_line = document.get_line(stat_range.end())?;
+    let old_range = TextRange::new(
+        document.get_line_range(start_line)?.start(),
+        document.get_line_range(end_line)?.end(),
+    );
+    let old_text = document.get_text_slice(old_range);
+    // the statement is formatted on its own, so the indentation of its first line is put back
+    let indent: String = old_text
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect();
+    if !indent.is_empty() && has_multiline_token(&stat) {
+        return None;
+    }
+
+    let normalized_path = document
+        .get_file_path()
+        .to_string_lossy()
+        .to_string()
+        .replace("\\", "/");
+    let level = analysis
+        .compilation
+        .get_db()
+        .get_file_emmyrc(file_id)
+        .get_language_level();
+    let formatted_result = range_format_code(
+        old_text,
+        &normalized_path,
+        level,
+        0,
+        0,
+        (end_line - start_line) as i32 + 1,
+        0,
+    )?;
+
+    let mut formatted_text = String::new();
+    for line in formatted_result.text.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            formatted_text.push_str(&indent);
+        }
+        formatted_text.push_str(line);
+    }
+    if client_id.is_intellij() || client_id.is_other() {
+        formatted_text = formatted_text.replace("\r\n", "\n");
+    }
+
+    // the text is already formatted
+    let start_line = start_line + formatted_result.start_line as usize;
+    let end_line = start_line + (formatted_result.end_line - formatted_result.start_line) as usize;
+    let old_start = document.get_line_range(start_line)?;
+    let old_end = document.get_line_range(end_line)?;
+    let old_range = TextRange::new(old_start.start(), old_end.end());
+    if document.get_text_slice(old_range) == formatted_text {
+        return None;
+    }
+
+    Some(vec![TextEdit {
+        range: Range {
+            start: Position {
+                line: start_line as u32,
+                character: 0,
+            },
+            end: Position {
+                line: end_line as u32 + 1,
+                character: 0,
+            },
+        },
+        new_text: formatted_text,
+    }])
+}
+
+// a long string or comment spans lines, its content must not be indented
+fn has_multiline_token(stat: &LuaStat) -> bool {
+    stat.syntax()
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .any(|token| {
+            token.kind() != LuaTokenKind::TkEndOfLine.into() && token.text().contains('\n')
+        })
+}
+
+/// The token which ends at the offset, skipping the whitespace before the offset
+fn find_last_token_before(tokens: TokenAtOffset<LuaSyntaxToken>) -> Option<LuaSyntaxToken> {
+    let mut token = match tokens {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, _) => left,
+        TokenAtOffset::None => return None,
+    };
+    while matches!(
+        token.kind().into(),
+        LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine
+    ) {
+        token = token.prev_token()?;
+    }
+
+    Some(token)
+}
+
+pub struct DocumentOnTypeFormattingCapabilities;
+
+impl RegisterCapabilities for DocumentOnTypeFormattingCapabilities {
+    fn register_capabilities(server_capabilities: &mut ServerCapabilities, _: &ClientCapabilities) {
+        server_capabilities.document_on_type_formatting_provider =
+            Some(DocumentOnTypeFormattingOptions {
+                first_trigger_character: "\n".to_string(),
+                more_trigger_character: Some(vec!["d".to_string()]),
+            });
+    }
+}
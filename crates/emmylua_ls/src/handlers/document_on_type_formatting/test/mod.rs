@@ -0,0 +1 @@
+mod on_type_formatting_test;
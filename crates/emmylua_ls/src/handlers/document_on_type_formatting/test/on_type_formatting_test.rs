@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::Position;
+
+    use crate::{
+        context::ClientId,
+        handlers::{
+            document_on_type_formatting::on_type_format, test_lib::ProviderVirtualWorkspace,
+        },
+    };
+
+    #[test]
+    fn test_format_on_end() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def("local a = 1\nlocal function f()\nreturn a\n  end\n");
+        let edits = on_type_format(
+            &ws.analysis,
+            file_id,
+            Position::new(3, 5),
+            "d",
+            ClientId::Other,
+        )
+        .unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start.line, 1);
+        assert_eq!(edits[0].range.end.line, 4);
+        assert_eq!(edits[0].new_text, "local function f()\n    return a\nend\n");
+    }
+
+    #[test]
+    fn test_format_on_newline() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def("local   a =   1\n\nlocal b = 2\n");
+        let edits = on_type_format(
+            &ws.analysis,
+            file_id,
+            Position::new(1, 0),
+            "\n",
+            ClientId::Other,
+        )
+        .unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start.line, 0);
+        assert_eq!(edits[0].new_text, "local a = 1\n");
+
+        // the formatted code is left alone
+        assert!(on_type_format(
+            &ws.analysis,
+            file_id,
+            Position::new(3, 0),
+            "\n",
+            ClientId::Other
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_format_with_errors_elsewhere() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def("local function f()\n    if a then\nprint(a)\n    end\n\nlocal b =\n");
+        let edits = on_type_format(
+            &ws.analysis,
+            file_id,
+            Position::new(3, 7),
+            "d",
+            ClientId::Other,
+        )
+        .unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].range.start.line, 1);
+        assert_eq!(edits[0].range.end.line, 4);
+        assert_eq!(
+            edits[0].new_text,
+            "    if a then\n        print(a)\n    end\n"
+        );
+
+        // the statement itself is incomplete
+        assert!(on_type_format(
+            &ws.analysis,
+            file_id,
+            Position::new(6, 0),
+            "\n",
+            ClientId::Other
+        )
+        .is_none());
+    }
+}
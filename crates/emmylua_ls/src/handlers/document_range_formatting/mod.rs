@@ -38,9 +38,15 @@ pub async fn on_range_formatting_handler(
     let text = document.get_text();
     let file_path = document.get_file_path();
     let normalized_path = file_path.to_string_lossy().to_string().replace("\\", "/");
+    let level = analysis
+        .compilation
+        .get_db()
+        .get_file_emmyrc(file_id)
+        .get_language_level();
     let formatted_result = range_format_code(
         text,
         &normalized_path,
+        level,
         request_range.start.line as i32,
         0,
         request_range.end.line as i32 + 1,
@@ -0,0 +1,156 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use emmylua_code_analysis::{DbIndex, EmmyLuaAnalysis, FileId};
+use emmylua_parser::{LuaAstToken, LuaDocTagModule, LuaStringToken};
+use lsp_types::{TextEdit, WorkspaceEdit};
+
+use crate::handlers::document_link::is_require_path;
+
+/// Build the edits which rewrite the require paths and the `---@module` annotations that point
+/// to the renamed files, a renamed folder renames all the files in it
+pub fn build_file_rename_edits(
+    analysis: &EmmyLuaAnalysis,
+    renames: &[(PathBuf, PathBuf)],
+) -> Option<WorkspaceEdit> {
+    let db = analysis.compilation.get_db();
+    let new_module_paths = collect_new_module_paths(db, renames);
+    if new_module_paths.is_empty() {
+        return None;
+    }
+
+    let module_index = db.get_module_index();
+    let mut file_edits = Vec::new();
+    for file_id in db.get_vfs().get_all_file_ids() {
+        if module_index.is_std(&file_id) || module_index.is_library(&file_id) {
+            continue;
+        }
+
+        let edits = build_file_edits(db, file_id, &new_module_paths);
+        if edits.is_empty() {
+            continue;
+        }
+
+        if let Some(uri) = db.get_vfs().get_uri(&file_id) {
+            file_edits.push((uri, edits));
+        }
+    }
+
+    if file_edits.is_empty() {
+        return None;
+    }
+
+    Some(WorkspaceEdit {
+        changes: Some(file_edits.into_iter().collect()),
+        document_changes: None,
+        change_annotations: None,
+    })
+}
+
+/// The module path every renamed file has after the rename
+fn collect_new_module_paths(
+    db: &DbIndex,
+    renames: &[(PathBuf, PathBuf)],
+) -> HashMap<FileId, String> {
+    let module_index = db.get_module_index();
+    let vfs = db.get_vfs();
+    let mut new_module_paths = HashMap::new();
+    for file_id in vfs.get_all_file_ids() {
+        let Some(path) = vfs.get_file_path(&file_id) else {
+            continue;
+        };
+        let Some(new_path) = get_renamed_path(path, renames) else {
+            continue;
+        };
+        let Some(module_info) = module_index.get_module(file_id) else {
+            continue;
+        };
+        let Some((new_module_path, _)) = new_path
+            .to_str()
+            .and_then(|new_path| module_index.get_module_path_by_file_path(new_path))
+        else {
+            continue;
+        };
+
+        if new_module_path != module_info.full_module_name {
+            new_module_paths.insert(file_id, new_module_path);
+        }
+    }
+
+    new_module_paths
+}
+
+fn get_renamed_path(path: &Path, renames: &[(PathBuf, PathBuf)]) -> Option<PathBuf> {
+    renames.iter().find_map(|(old_path, new_path)| {
+        let relative_path = path.strip_prefix(old_path).ok()?;
+        if relative_path.as_os_str().is_empty() {
+            Some(new_path.clone())
+        } else {
+            Some(new_path.join(relative_path))
+        }
+    })
+}
+
+fn build_file_edits(
+    db: &DbIndex,
+    file_id: FileId,
+    new_module_paths: &HashMap<FileId, String>,
+) -> Vec<TextEdit> {
+    let mut edits = Vec::new();
+    let (Some(tree), Some(document)) = (
+        db.get_vfs().get_syntax_tree(&file_id),
+        db.get_vfs().get_document(&file_id),
+    ) else {
+        return edits;
+    };
+
+    let emmyrc = db.get_emmyrc();
+    let string_tokens = tree
+        .get_red_root()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter_map(LuaStringToken::cast);
+    for token in string_tokens {
+        let is_module_path = is_require_path(token.clone(), emmyrc).unwrap_or(false)
+            || token.get_parent::<LuaDocTagModule>().is_some();
+        if !is_module_path {
+            continue;
+        }
+
+        let Some(new_text) = get_new_module_text(db, &token, new_module_paths) else {
+            continue;
+        };
+        if let Some(range) = document.to_lsp_range(token.get_range()) {
+            edits.push(TextEdit { range, new_text });
+        }
+    }
+
+    edits
+}
+
+fn get_new_module_text(
+    db: &DbIndex,
+    token: &LuaStringToken,
+    new_module_paths: &HashMap<FileId, String>,
+) -> Option<String> {
+    let module_path = token.get_value();
+    let module_info = db.get_module_index().find_module(&module_path)?;
+    let new_module_path = new_module_paths.get(&module_info.file_id)?;
+
+    // long strings are left alone, the quotes of short strings are kept
+    let quote = token.get_text().chars().next()?;
+    if quote != '"' && quote != '\'' {
+        return None;
+    }
+
+    // keep the separator which the path already uses
+    let new_module_path = if module_path.contains('/') && !module_path.contains('.') {
+        new_module_path.replace('.', "/")
+    } else {
+        new_module_path.clone()
+    };
+
+    Some(format!("{}{}{}", quote, new_module_path, quote))
+}
@@ -0,0 +1,66 @@
+mod build_rename_edits;
+mod test;
+
+use build_rename_edits::build_file_rename_edits;
+use emmylua_code_analysis::uri_to_file_path;
+use lsp_types::{
+    ClientCapabilities, FileOperationFilter, FileOperationPattern, FileOperationPatternKind,
+    FileOperationRegistrationOptions, RenameFilesParams, ServerCapabilities,
+    WorkspaceFileOperationsServerCapabilities, WorkspaceServerCapabilities,
+};
+use lsp_types::{Uri, WorkspaceEdit};
+use tokio_util::sync::CancellationToken;
+
+use crate::context::ServerContextSnapshot;
+
+use super::RegisterCapabilities;
+
+pub async fn on_will_rename_files_handler(
+    context: ServerContextSnapshot,
+    params: RenameFilesParams,
+    _: CancellationToken,
+) -> Option<WorkspaceEdit> {
+    let renames = params
+        .files
+        .iter()
+        .filter_map(|file| {
+            let old_path = uri_to_file_path(&file.old_uri.parse::<Uri>().ok()?)?;
+            let new_path = uri_to_file_path(&file.new_uri.parse::<Uri>().ok()?)?;
+            Some((old_path, new_path))
+        })
+        .collect::<Vec<_>>();
+    if renames.is_empty() {
+        return None;
+    }
+
+    let analysis = context.analysis.read().await;
+    build_file_rename_edits(&analysis, &renames)
+}
+
+pub struct FileRenameCapabilities;
+
+impl RegisterCapabilities for FileRenameCapabilities {
+    fn register_capabilities(server_capabilities: &mut ServerCapabilities, _: &ClientCapabilities) {
+        let filter = |glob: &str, matches: FileOperationPatternKind| FileOperationFilter {
+            scheme: Some("file".to_string()),
+            pattern: FileOperationPattern {
+                glob: glob.to_string(),
+                matches: Some(matches),
+                options: None,
+            },
+        };
+
+        let workspace = server_capabilities
+            .workspace
+            .get_or_insert_with(WorkspaceServerCapabilities::default);
+        let file_operations = workspace
+            .file_operations
+            .get_or_insert_with(WorkspaceFileOperationsServerCapabilities::default);
+        file_operations.will_rename = Some(FileOperationRegistrationOptions {
+            filters: vec![
+                filter("**/*.lua", FileOperationPatternKind::File),
+                filter("**", FileOperationPatternKind::Folder),
+            ],
+        });
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::TextEdit;
+
+    use crate::handlers::{
+        file_rename::build_rename_edits::build_file_rename_edits,
+        test_lib::ProviderVirtualWorkspace,
+    };
+
+    fn get_edits(
+        ws: &ProviderVirtualWorkspace,
+        file_name: &str,
+        old: &str,
+        new: &str,
+    ) -> Vec<TextEdit> {
+        let gen = &ws.virtual_url_generator;
+        let renames = vec![(gen.new_path(old), gen.new_path(new))];
+        let edit = build_file_rename_edits(&ws.analysis, &renames).unwrap();
+        let uri = gen.new_uri(file_name);
+        let mut edits = edit.changes.unwrap().remove(&uri).unwrap_or_default();
+        edits.sort_by_key(|edit| edit.range.start);
+        edits
+    }
+
+    #[test]
+    fn test_rename_file() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file("foo/bar.lua", "return {}");
+        ws.def_file(
+            "main.lua",
+            r#"
+            local bar = require("foo.bar")
+            local bar2 = require 'foo/bar'
+            local other = require("foo.other")
+            "#,
+        );
+
+        let edits = get_edits(&ws, "main.lua", "foo/bar.lua", "foo/baz/bar.lua");
+        assert_eq!(edits.len(), 2);
+        assert_eq!(edits[0].new_text, "\"foo.baz.bar\"");
+        assert_eq!(edits[1].new_text, "'foo/baz/bar'");
+    }
+
+    #[test]
+    fn test_rename_folder() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file("foo/bar.lua", "return {}");
+        ws.def_file("foo/baz/init.lua", "return {}");
+        ws.def_file(
+            "main.lua",
+            r#"
+            ---@module "foo.bar"
+            local bar
+
+            local baz = require("foo.baz")
+            "#,
+        );
+
+        let edits = get_edits(&ws, "main.lua", "foo", "qux");
+        assert_eq!(edits.len(), 2);
+        assert_eq!(edits[0].new_text, "\"qux.bar\"");
+        assert_eq!(edits[1].new_text, "\"qux.baz\"");
+    }
+}
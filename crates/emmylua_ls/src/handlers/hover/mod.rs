@@ -8,7 +8,7 @@ mod test;
 pub use build_hover::build_hover_content_for_completion;
 use build_hover::build_semantic_info_hover;
 pub use build_hover::get_function_member_owner;
-use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
+use emmylua_code_analysis::{hover_emmyrc_config, EmmyLuaAnalysis, FileId};
 use emmylua_parser::LuaAstNode;
 pub use hover_builder::HoverBuilder;
 pub use hover_humanize::infer_prefix_global_name;
@@ -23,7 +23,10 @@ use tokio_util::sync::CancellationToken;
 
 use crate::context::ServerContextSnapshot;
 
-use super::RegisterCapabilities;
+use super::{
+    text_document::{get_config_document, get_config_file_kind, ConfigFileKind},
+    RegisterCapabilities,
+};
 
 pub async fn on_hover(
     context: ServerContextSnapshot,
@@ -32,6 +35,10 @@ pub async fn on_hover(
 ) -> Option<Hover> {
     let uri = params.text_document_position_params.text_document.uri;
     let position = params.text_document_position_params.position;
+    if get_config_file_kind(&uri) == Some(ConfigFileKind::Emmyrc) {
+        return hover_emmyrc_config(&get_config_document(&uri)?, position);
+    }
+
     let analysis = context.analysis.read().await;
     let file_id = analysis.get_file_id(&uri)?;
     hover(&analysis, file_id, position)
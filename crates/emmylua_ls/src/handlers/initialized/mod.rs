@@ -8,16 +8,22 @@ use std::{path::PathBuf, str::FromStr, sync::Arc};
 use crate::{
     cmd_args::CmdArgs,
     context::{
-        get_client_id, load_emmy_config, ClientId, ClientProxy, FileDiagnostic, ProgressTask,
-        ServerContextSnapshot, StatusBar,
+        get_client_id, load_emmy_config, load_workspace_emmyrcs, ClientId, ClientProxy,
+        FileDiagnostic, ProgressTask, ServerContextSnapshot, StatusBar,
+    },
+    handlers::{
+        pull_diagnostic::{is_pull_diagnostic_refresh_supported, is_pull_diagnostic_supported},
+        text_document::{publish_workspace_config_diagnostics, register_files_watch},
+        type_hierarchy::register_type_hierarchy,
     },
-    handlers::text_document::register_files_watch,
     logger::init_logger,
 };
 pub use client_config::{get_client_config, ClientConfig};
-use codestyle::load_editorconfig;
+pub use codestyle::load_editorconfig;
 use collect_files::collect_files;
-use emmylua_code_analysis::{uri_to_file_path, EmmyLuaAnalysis, Emmyrc};
+use emmylua_code_analysis::{
+    file_path_to_uri, get_index_cache_dir, uri_to_file_path, EmmyLuaAnalysis, Emmyrc, FileId,
+};
 use log::info;
 use lsp_types::InitializeParams;
 use tokio::sync::RwLock;
@@ -51,8 +57,15 @@ pub async fn initialized_handler(
     };
 
     let emmyrc = load_emmy_config(config_root, client_config.clone());
+    let workspace_emmyrcs = load_workspace_emmyrcs(&workspace_folders, &client_config);
     load_editorconfig(workspace_folders.clone());
 
+    if is_pull_diagnostic_supported(&params.capabilities) {
+        context
+            .file_diagnostic
+            .enable_pull_mode(is_pull_diagnostic_refresh_supported(&params.capabilities));
+    }
+
     // init std lib
     init_std_lib(context.analysis.clone(), &cmd_args, emmyrc.clone()).await;
 
@@ -66,7 +79,7 @@ pub async fn initialized_handler(
         context.analysis.clone(),
         context.client.clone(),
         &context.status_bar,
-        workspace_folders,
+        workspace_emmyrcs,
         emmyrc,
         client_id,
         context.file_diagnostic.clone(),
@@ -74,14 +87,15 @@ pub async fn initialized_handler(
     .await;
 
     register_files_watch(context.clone(), &params.capabilities).await;
+    register_type_hierarchy(&context.client, &params.capabilities);
     Some(())
 }
 
 pub async fn init_analysis(
     analysis: Arc<RwLock<EmmyLuaAnalysis>>,
-    _: Arc<ClientProxy>,
+    client: Arc<ClientProxy>,
     status_bar: &StatusBar,
-    workspace_folders: Vec<PathBuf>,
+    workspace_folders: Vec<(PathBuf, Arc<Emmyrc>)>,
     emmyrc: Arc<Emmyrc>,
     client_id: ClientId,
     file_diagnostic: Arc<FileDiagnostic>,
@@ -102,21 +116,32 @@ pub async fn init_analysis(
         Some("Loading workspace files".to_string()),
     );
 
-    let mut workspace_folders = workspace_folders;
-    for workspace_root in &workspace_folders {
+    for (workspace_root, workspace_emmyrc) in &workspace_folders {
         info!("add workspace root: {:?}", workspace_root);
         mut_analysis.add_main_workspace(workspace_root.clone());
+        mut_analysis.set_workspace_emmyrc(workspace_root.clone(), workspace_emmyrc.clone());
+        publish_workspace_config_diagnostics(&client, workspace_root);
     }
 
-    for workspace_root in &emmyrc.workspace.workspace_roots {
-        info!("add workspace root: {:?}", workspace_root);
-        mut_analysis.add_main_workspace(PathBuf::from_str(workspace_root).unwrap());
-    }
+    // the libraries of every config, each is collected with the config which adds it
+    let mut library_roots: Vec<(PathBuf, Arc<Emmyrc>)> = Vec::new();
+    let emmyrcs = std::iter::once(&emmyrc).chain(workspace_folders.iter().map(|(_, it)| it));
+    for config in emmyrcs {
+        for workspace_root in &config.workspace.workspace_roots {
+            info!("add workspace root: {:?}", workspace_root);
+            mut_analysis.add_main_workspace(PathBuf::from_str(workspace_root).unwrap());
+        }
+
+        for lib in &config.workspace.library {
+            let library_root = PathBuf::from_str(lib).unwrap();
+            if library_roots.iter().any(|(root, _)| *root == library_root) {
+                continue;
+            }
 
-    for lib in &emmyrc.workspace.library {
-        info!("add library: {:?}", lib);
-        mut_analysis.add_library_workspace(PathBuf::from_str(lib).unwrap());
-        workspace_folders.push(PathBuf::from_str(lib).unwrap());
+            info!("add library: {:?}", lib);
+            mut_analysis.add_library_workspace(library_root.clone());
+            library_roots.push((library_root, config.clone()));
+        }
     }
 
     status_bar.update_progress_task(
@@ -126,8 +151,11 @@ pub async fn init_analysis(
         Some(String::from("Collecting files")),
     );
 
-    // load files
-    let files = collect_files(&workspace_folders, &emmyrc);
+    // load files, every workspace folder is collected with its own config
+    let mut files = Vec::new();
+    for (root, config) in workspace_folders.iter().chain(library_roots.iter()) {
+        files.extend(collect_files(&vec![root.clone()], config));
+    }
     let files: Vec<(PathBuf, Option<String>)> =
         files.into_iter().map(|file| file.into_tuple()).collect();
 
@@ -140,6 +168,7 @@ pub async fn init_analysis(
     );
 
     mut_analysis.update_files_by_path(files);
+    mut_analysis.save_index_cache();
 
     status_bar.finish_progress_task(
         client_id,
@@ -154,6 +183,45 @@ pub async fn init_analysis(
         .await;
 }
 
+/// Load the workspace folders which are added while the server runs, each with its own config.
+/// Only the files of the folders and of the roots their configs add are analyzed, returns them
+pub fn load_workspace_folders(
+    analysis: &mut EmmyLuaAnalysis,
+    client: &ClientProxy,
+    workspace_folders: Vec<(PathBuf, Arc<Emmyrc>)>,
+) -> Vec<FileId> {
+    let mut files = Vec::new();
+    for (workspace_root, workspace_emmyrc) in workspace_folders {
+        info!("add workspace root: {:?}", workspace_root);
+        publish_workspace_config_diagnostics(client, &workspace_root);
+        let roots =
+            analysis.add_main_workspace_with_emmyrc(workspace_root, workspace_emmyrc.clone());
+        files.extend(collect_files(&roots, &workspace_emmyrc));
+    }
+
+    let files: Vec<(PathBuf, Option<String>)> = files
+        .into_iter()
+        .map(|file| {
+            let (path, text) = file.into_tuple();
+            // a loaded file keeps its text, the opened ones may have unsaved edits
+            let opened_text = file_path_to_uri(&path)
+                .and_then(|uri| analysis.get_file_id(&uri))
+                .and_then(|file_id| {
+                    analysis
+                        .compilation
+                        .get_db()
+                        .get_vfs()
+                        .get_file_content(&file_id)
+                        .cloned()
+                });
+            (path, opened_text.or(text))
+        })
+        .collect();
+    let file_ids = analysis.update_files_by_path(files);
+    analysis.save_index_cache();
+    file_ids
+}
+
 fn get_workspace_folders(params: &InitializeParams) -> Vec<PathBuf> {
     let mut workspace_folders = Vec::new();
     if let Some(workspaces) = &params.workspace_folders {
@@ -184,6 +252,7 @@ pub async fn init_std_lib(
     emmyrc: Arc<Emmyrc>,
 ) {
     let mut analysis = analysis.write().await;
+    analysis.set_index_cache_dir(Some(get_index_cache_dir()));
     if cmd_args.load_std_lib.0 {
         // double update config
         analysis.update_config(emmyrc);
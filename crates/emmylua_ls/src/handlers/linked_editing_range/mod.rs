@@ -0,0 +1,116 @@
+mod test;
+
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, LuaSemanticDeclId, SemanticDeclLevel};
+use emmylua_parser::{LuaAstNode, LuaTokenKind};
+use lsp_types::{
+    ClientCapabilities, LinkedEditingRangeParams, LinkedEditingRangeServerCapabilities,
+    LinkedEditingRanges, Position, ServerCapabilities,
+};
+use rowan::TokenAtOffset;
+use tokio_util::sync::CancellationToken;
+
+use crate::context::ServerContextSnapshot;
+
+use super::RegisterCapabilities;
+
+pub async fn on_linked_editing_range_handler(
+    context: ServerContextSnapshot,
+    params: LinkedEditingRangeParams,
+    _: CancellationToken,
+) -> Option<LinkedEditingRanges> {
+    let uri = params.text_document_position_params.text_document.uri;
+    let analysis = context.analysis.read().await;
+    let file_id = analysis.get_file_id(&uri)?;
+    let position = params.text_document_position_params.position;
+
+    linked_editing_range(&analysis, file_id, position)
+}
+
+// the names may have non-ASCII letters, the pattern excludes the ASCII punctuation instead of
+// using `\p{L}`, which the clients can not match without the unicode flag
+const WORD_PATTERN: &str = r"[^\s!-@\[-^`{-~][^\s!-/:-@\[-^`{-~]*";
+
+/// The name of a local variable and all its uses in the file, which are renamed together
+pub fn linked_editing_range(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    position: Position,
+) -> Option<LinkedEditingRanges> {
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let root = semantic_model.get_root();
+    let position_offset = {
+        let document = semantic_model.get_document();
+        document.get_offset(position.line as usize, position.character as usize)?
+    };
+
+    if position_offset > root.syntax().text_range().end() {
+        return None;
+    }
+
+    let token = match root.syntax().token_at_offset(position_offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, right) => {
+            if left.kind() == LuaTokenKind::TkName.into() {
+                left
+            } else {
+                right
+            }
+        }
+        TokenAtOffset::None => {
+            return None;
+        }
+    };
+    if token.kind() != LuaTokenKind::TkName.into() {
+        return None;
+    }
+
+    let LuaSemanticDeclId::LuaDecl(decl_id) =
+        semantic_model.find_decl(token.into(), SemanticDeclLevel::NoTrace)?
+    else {
+        return None;
+    };
+    if decl_id.file_id != file_id {
+        return None;
+    }
+
+    let db = semantic_model.get_db();
+    let decl_tree = db.get_decl_index().get_decl_tree(&file_id)?;
+    let decl = decl_tree.get_decl(&decl_id)?;
+    if !decl.is_local() {
+        return None;
+    }
+
+    let document = semantic_model.get_document();
+    let mut ranges = vec![document.to_lsp_range(decl.get_range())?];
+    if let Some(decl_refs) = db
+        .get_reference_index()
+        .get_decl_references(&file_id, &decl_id)
+    {
+        for decl_ref in decl_refs {
+            // a use outside the scope of the local, or where an inner local of the same name
+            // shadows it, is not renamed with it
+            let is_visible = decl_tree
+                .find_local_decl(decl.get_name(), decl_ref.range.start())
+                .is_some_and(|visible_decl| visible_decl.get_id() == decl_id);
+            if is_visible {
+                ranges.push(document.to_lsp_range(decl_ref.range)?);
+            }
+        }
+    }
+    ranges.sort_by_key(|range| range.start);
+    ranges.dedup();
+
+    Some(LinkedEditingRanges {
+        ranges,
+        word_pattern: Some(WORD_PATTERN.to_string()),
+    })
+}
+
+pub struct LinkedEditingRangeCapabilities;
+
+impl RegisterCapabilities for LinkedEditingRangeCapabilities {
+    fn register_capabilities(server_capabilities: &mut ServerCapabilities, _: &ClientCapabilities) {
+        server_capabilities.linked_editing_range_provider =
+            Some(LinkedEditingRangeServerCapabilities::Simple(true));
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::Position;
+
+    use crate::handlers::{
+        linked_editing_range::linked_editing_range, test_lib::ProviderVirtualWorkspace,
+    };
+
+    #[test]
+    fn test_local_linked_editing_range() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"local count = 1
+count = count + 1
+do
+    local count = 2
+    print(count)
+end
+print(count)
+"#,
+        );
+
+        let result = linked_editing_range(&ws.analysis, file_id, Position::new(1, 2)).unwrap();
+        let lines: Vec<u32> = result.ranges.iter().map(|range| range.start.line).collect();
+        assert_eq!(lines, vec![0, 1, 1, 6]);
+
+        let result = linked_editing_range(&ws.analysis, file_id, Position::new(3, 10)).unwrap();
+        let lines: Vec<u32> = result.ranges.iter().map(|range| range.start.line).collect();
+        assert_eq!(lines, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_linked_editing_range_with_shadowing_closure() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"local value = 1
+local function f()
+    print(value)
+    local value = 2
+    return function()
+        return value
+    end
+end
+print(value)
+"#,
+        );
+
+        let result = linked_editing_range(&ws.analysis, file_id, Position::new(0, 8)).unwrap();
+        let lines: Vec<u32> = result.ranges.iter().map(|range| range.start.line).collect();
+        assert_eq!(lines, vec![0, 2, 8]);
+
+        let result = linked_editing_range(&ws.analysis, file_id, Position::new(5, 16)).unwrap();
+        let lines: Vec<u32> = result.ranges.iter().map(|range| range.start.line).collect();
+        assert_eq!(lines, vec![3, 5]);
+    }
+
+    #[test]
+    fn test_unicode_linked_editing_range() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def("local 名字 = 1\nprint(名字)\n");
+
+        let result = linked_editing_range(&ws.analysis, file_id, Position::new(1, 7)).unwrap();
+        assert_eq!(result.ranges.len(), 2);
+    }
+
+    #[test]
+    fn test_global_has_no_linked_editing_range() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def("global_count = 1\nprint(global_count)\n");
+        assert!(linked_editing_range(&ws.analysis, file_id, Position::new(1, 8)).is_none());
+    }
+}
@@ -0,0 +1 @@
+mod linked_editing_range_test;
@@ -4,22 +4,27 @@ mod code_lens;
 mod command;
 mod completion;
 mod configuration;
+mod declaration;
 mod definition;
 mod document_color;
 mod document_formatting;
 mod document_highlight;
 mod document_link;
+mod document_on_type_formatting;
 mod document_range_formatting;
 mod document_selection_range;
 mod document_symbol;
 mod emmy_annotator;
+mod file_rename;
 mod fold_range;
 mod hover;
 mod implementation;
 mod initialized;
 mod inlay_hint;
 mod inline_values;
+mod linked_editing_range;
 mod notification_handler;
+mod pull_diagnostic;
 mod references;
 mod rename;
 mod request_handler;
@@ -28,10 +33,13 @@ mod semantic_token;
 mod signature_helper;
 mod test_lib;
 mod text_document;
+mod type_definition;
+mod type_hierarchy;
+mod workspace_folders;
 mod workspace_symbol;
 
 pub use initialized::initialized_handler;
-pub use initialized::{init_analysis, ClientConfig};
+pub use initialized::{init_analysis, load_editorconfig, load_workspace_folders, ClientConfig};
 use lsp_types::{ClientCapabilities, ServerCapabilities};
 pub use notification_handler::on_notification_handler;
 pub use request_handler::on_req_handler;
@@ -51,7 +59,7 @@ fn register<T: RegisterCapabilities>(
     T::register_capabilities(server_capabilities, client_capabilities);
 }
 
-pub fn server_capabilities(client_capabilities: &ClientCapabilities) -> ServerCapabilities {
+pub fn server_capabilities(client_capabilities: &ClientCapabilities) -> serde_json::Value {
     let mut server_capabilities = ServerCapabilities::default();
 
     register::<text_document::TextDocumentCapabilities>(
@@ -86,9 +94,22 @@ pub fn server_capabilities(client_capabilities: &ClientCapabilities) -> ServerCa
         &mut server_capabilities,
         client_capabilities,
     );
+    register::<document_on_type_formatting::DocumentOnTypeFormattingCapabilities>(
+        &mut server_capabilities,
+        client_capabilities,
+    );
+    register::<linked_editing_range::LinkedEditingRangeCapabilities>(
+        &mut server_capabilities,
+        client_capabilities,
+    );
     register::<completion::CompletionCapabilities>(&mut server_capabilities, client_capabilities);
     register::<inlay_hint::InlayHintCapabilities>(&mut server_capabilities, client_capabilities);
     register::<definition::DefinitionCapabilities>(&mut server_capabilities, client_capabilities);
+    register::<type_definition::TypeDefinitionCapabilities>(
+        &mut server_capabilities,
+        client_capabilities,
+    );
+    register::<declaration::DeclarationCapabilities>(&mut server_capabilities, client_capabilities);
     register::<implementation::ImplementationCapabilities>(
         &mut server_capabilities,
         client_capabilities,
@@ -127,10 +148,24 @@ pub fn server_capabilities(client_capabilities: &ClientCapabilities) -> ServerCa
         &mut server_capabilities,
         client_capabilities,
     );
+    register::<pull_diagnostic::PullDiagnosticCapabilities>(
+        &mut server_capabilities,
+        client_capabilities,
+    );
+    register::<file_rename::FileRenameCapabilities>(&mut server_capabilities, client_capabilities);
+    register::<workspace_folders::WorkspaceFoldersCapabilities>(
+        &mut server_capabilities,
+        client_capabilities,
+    );
     // register::<document_type_formatting::DocumentTypeFormatting>(
     //     &mut server_capabilities,
     //     client_capabilities,
     // );
 
+    let mut server_capabilities = serde_json::json!(server_capabilities);
+    type_hierarchy::register_type_hierarchy_capability(
+        &mut server_capabilities,
+        client_capabilities,
+    );
     server_capabilities
 }
@@ -0,0 +1,299 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
+use lsp_server::{ErrorCode, ResponseError};
+use lsp_types::{
+    ClientCapabilities, Diagnostic, DiagnosticOptions, DiagnosticServerCancellationData,
+    DiagnosticServerCapabilities, DocumentDiagnosticParams, DocumentDiagnosticReport,
+    DocumentDiagnosticReportResult, FullDocumentDiagnosticReport,
+    RelatedFullDocumentDiagnosticReport, RelatedUnchangedDocumentDiagnosticReport,
+    ServerCapabilities, UnchangedDocumentDiagnosticReport, Uri, WorkspaceDiagnosticParams,
+    WorkspaceDiagnosticReport, WorkspaceDiagnosticReportResult, WorkspaceDocumentDiagnosticReport,
+    WorkspaceFullDocumentDiagnosticReport, WorkspaceUnchangedDocumentDiagnosticReport,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::context::ServerContextSnapshot;
+
+use super::RegisterCapabilities;
+
+mod test;
+
+pub async fn on_pull_document_diagnostic(
+    context: ServerContextSnapshot,
+    params: DocumentDiagnosticParams,
+    cancel_token: CancellationToken,
+) -> Result<DocumentDiagnosticReportResult, ResponseError> {
+    let analysis = context.analysis.read().await;
+    let uri = params.text_document.uri;
+    let report = match analysis.get_file_id(&uri) {
+        Some(file_id) => pull_cached_file_diagnostic(
+            &context,
+            &analysis,
+            file_id,
+            params.previous_result_id.as_deref(),
+            cancel_token,
+        )
+        .await
+        .ok_or_else(make_cancelled_error)?,
+        None => PullDiagnosticReport::Full {
+            result_id: None,
+            items: vec![],
+        },
+    };
+
+    let report = match report {
+        PullDiagnosticReport::Full { result_id, items } => {
+            DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+                related_documents: None,
+                full_document_diagnostic_report: FullDocumentDiagnosticReport { result_id, items },
+            })
+        }
+        PullDiagnosticReport::Unchanged { result_id } => {
+            DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                related_documents: None,
+                unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                    result_id,
+                },
+            })
+        }
+    };
+
+    Ok(DocumentDiagnosticReportResult::Report(report))
+}
+
+pub async fn on_pull_workspace_diagnostic(
+    context: ServerContextSnapshot,
+    params: WorkspaceDiagnosticParams,
+    cancel_token: CancellationToken,
+) -> Result<WorkspaceDiagnosticReportResult, ResponseError> {
+    let previous_result_ids: HashMap<Uri, String> = params
+        .previous_result_ids
+        .into_iter()
+        .map(|previous| (previous.uri, previous.value))
+        .collect();
+
+    let main_workspace_file_ids = context
+        .analysis
+        .read()
+        .await
+        .compilation
+        .get_db()
+        .get_module_index()
+        .get_main_workspace_file_ids();
+    let mut items = Vec::new();
+    for file_id in main_workspace_file_ids {
+        if cancel_token.is_cancelled() {
+            return Err(make_cancelled_error());
+        }
+
+        // the lock is taken for each file, so edits are not blocked until the whole workspace
+        // is diagnosed
+        let analysis = context.analysis.read().await;
+        let Some(uri) = analysis.get_uri(file_id) else {
+            continue;
+        };
+        let previous_result_id = previous_result_ids.get(&uri).map(|id| id.as_str());
+        let report = pull_cached_file_diagnostic(
+            &context,
+            &analysis,
+            file_id,
+            previous_result_id,
+            cancel_token.clone(),
+        )
+        .await
+        .ok_or_else(make_cancelled_error)?;
+        drop(analysis);
+
+        let item = match report {
+            PullDiagnosticReport::Full { result_id, items } => {
+                WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+                    uri,
+                    version: None,
+                    full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                        result_id,
+                        items,
+                    },
+                })
+            }
+            PullDiagnosticReport::Unchanged { result_id } => {
+                WorkspaceDocumentDiagnosticReport::Unchanged(
+                    WorkspaceUnchangedDocumentDiagnosticReport {
+                        uri,
+                        version: None,
+                        unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                            result_id,
+                        },
+                    },
+                )
+            }
+        };
+        items.push(item);
+    }
+
+    Ok(WorkspaceDiagnosticReportResult::Report(
+        WorkspaceDiagnosticReport { items },
+    ))
+}
+
+#[derive(Debug)]
+enum PullDiagnosticReport {
+    Full {
+        result_id: Option<String>,
+        items: Vec<Diagnostic>,
+    },
+    Unchanged {
+        result_id: String,
+    },
+}
+
+impl PullDiagnosticReport {
+    fn get_result_id(&self) -> Option<&str> {
+        match self {
+            PullDiagnosticReport::Full { result_id, .. } => result_id.as_deref(),
+            PullDiagnosticReport::Unchanged { result_id } => Some(result_id),
+        }
+    }
+}
+
+// the id is saved while the analysis is still locked, so an update which comes later always
+// drops it
+async fn pull_cached_file_diagnostic(
+    context: &ServerContextSnapshot,
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    previous_result_id: Option<&str>,
+    cancel_token: CancellationToken,
+) -> Option<PullDiagnosticReport> {
+    let file_diagnostic = &context.file_diagnostic;
+    let cached_result_id = file_diagnostic.get_pull_result_id(file_id).await;
+    let report = pull_file_diagnostic(
+        analysis,
+        file_id,
+        previous_result_id,
+        cached_result_id.as_deref(),
+        cancel_token,
+    )?;
+    if let Some(result_id) = report.get_result_id() {
+        if cached_result_id.as_deref() != Some(result_id) {
+            file_diagnostic
+                .set_pull_result_id(file_id, result_id.to_string())
+                .await;
+        }
+    }
+
+    Some(report)
+}
+
+/// Returns `None` when the request is cancelled, the client then keeps its previous result.
+/// `cached_result_id` is the id of the last result, the file is not diagnosed again when the
+/// client still has it.
+fn pull_file_diagnostic(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    previous_result_id: Option<&str>,
+    cached_result_id: Option<&str>,
+    cancel_token: CancellationToken,
+) -> Option<PullDiagnosticReport> {
+    if let Some(result_id) = previous_result_id.filter(|id| Some(*id) == cached_result_id) {
+        return Some(PullDiagnosticReport::Unchanged {
+            result_id: result_id.to_string(),
+        });
+    }
+
+    let items = match analysis.diagnose_file(file_id, cancel_token.clone()) {
+        Some(items) => items,
+        // disabled diagnostics clear the previous result once, later pulls are unchanged
+        None if !analysis.is_diagnostic_enabled(file_id) => vec![],
+        None if cancel_token.is_cancelled() => return None,
+        // the file has not been analyzed, so there is nothing new to report
+        None => {
+            return Some(match previous_result_id {
+                Some(result_id) => PullDiagnosticReport::Unchanged {
+                    result_id: result_id.to_string(),
+                },
+                None => PullDiagnosticReport::Full {
+                    result_id: None,
+                    items: vec![],
+                },
+            });
+        }
+    };
+    // the diagnostics of a cancelled request may be incomplete
+    if cancel_token.is_cancelled() {
+        return None;
+    }
+
+    let result_id = make_result_id(&items);
+    if previous_result_id == Some(result_id.as_str()) {
+        return Some(PullDiagnosticReport::Unchanged { result_id });
+    }
+
+    Some(PullDiagnosticReport::Full {
+        result_id: Some(result_id),
+        items,
+    })
+}
+
+/// The client retriggers the pull, see `DiagnosticServerCancellationData`
+fn make_cancelled_error() -> ResponseError {
+    ResponseError {
+        code: ErrorCode::ServerCancelled as i32,
+        message: "diagnostic request cancelled".to_string(),
+        data: serde_json::to_value(DiagnosticServerCancellationData {
+            retrigger_request: true,
+        })
+        .ok(),
+    }
+}
+
+/// The diagnostics depend on the other files, so the result id is computed from the diagnostics
+/// instead of the version of the file
+fn make_result_id(diagnostics: &[Diagnostic]) -> String {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(diagnostics)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+pub fn is_pull_diagnostic_supported(client_capabilities: &ClientCapabilities) -> bool {
+    client_capabilities
+        .text_document
+        .as_ref()
+        .is_some_and(|text_document| text_document.diagnostic.is_some())
+}
+
+pub fn is_pull_diagnostic_refresh_supported(client_capabilities: &ClientCapabilities) -> bool {
+    client_capabilities
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.diagnostic.as_ref())
+        .and_then(|diagnostic| diagnostic.refresh_support)
+        .unwrap_or(false)
+}
+
+pub struct PullDiagnosticCapabilities;
+
+impl RegisterCapabilities for PullDiagnosticCapabilities {
+    fn register_capabilities(
+        server_capabilities: &mut ServerCapabilities,
+        client_capabilities: &ClientCapabilities,
+    ) {
+        // clients without pull diagnostics keep receiving `publishDiagnostics`
+        if !is_pull_diagnostic_supported(client_capabilities) {
+            return;
+        }
+
+        server_capabilities.diagnostic_provider =
+            Some(DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                identifier: Some("EmmyLua".to_string()),
+                inter_file_dependencies: true,
+                workspace_diagnostics: true,
+                ..Default::default()
+            }));
+    }
+}
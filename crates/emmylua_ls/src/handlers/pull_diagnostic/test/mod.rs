@@ -0,0 +1 @@
+mod pull_diagnostic_test;
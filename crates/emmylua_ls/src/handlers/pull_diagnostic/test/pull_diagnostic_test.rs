@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::{DiagnosticCode, Emmyrc};
+    use tokio_util::sync::CancellationToken;
+
+    use crate::handlers::{
+        pull_diagnostic::{pull_file_diagnostic, PullDiagnosticReport},
+        test_lib::ProviderVirtualWorkspace,
+    };
+
+    #[test]
+    fn test_pull_diagnostic_result_id() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::UndefinedGlobal);
+        let file_id = ws.def(
+            r#"
+            return count + 1
+            "#,
+        );
+
+        let report =
+            pull_file_diagnostic(&ws.analysis, file_id, None, None, CancellationToken::new());
+        let Some(PullDiagnosticReport::Full { result_id, items }) = report else {
+            panic!("expected a full report");
+        };
+        assert!(!items.is_empty());
+        let result_id = result_id.unwrap();
+
+        let report = pull_file_diagnostic(
+            &ws.analysis,
+            file_id,
+            Some(&result_id),
+            None,
+            CancellationToken::new(),
+        );
+        assert!(matches!(
+            report,
+            Some(PullDiagnosticReport::Unchanged { result_id: id }) if id == result_id
+        ));
+
+        let report = pull_file_diagnostic(
+            &ws.analysis,
+            file_id,
+            Some("outdated"),
+            None,
+            CancellationToken::new(),
+        );
+        assert!(matches!(report, Some(PullDiagnosticReport::Full { .. })));
+    }
+
+    #[test]
+    fn test_pull_diagnostic_cancelled() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::UndefinedGlobal);
+        let file_id = ws.def(
+            r#"
+            return count + 1
+            "#,
+        );
+
+        // a cancelled pull must not replace the previous result with an empty report
+        let cancel_token = CancellationToken::new();
+        cancel_token.cancel();
+        let report =
+            pull_file_diagnostic(&ws.analysis, file_id, Some("previous"), None, cancel_token);
+        assert!(report.is_none());
+    }
+
+    #[test]
+    fn test_pull_diagnostic_cached_result_id() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::UndefinedGlobal);
+        let file_id = ws.def(
+            r#"
+            return count + 1
+            "#,
+        );
+
+        // the file is not diagnosed again, so even a cancelled pull reports it unchanged
+        let cancel_token = CancellationToken::new();
+        cancel_token.cancel();
+        let report = pull_file_diagnostic(
+            &ws.analysis,
+            file_id,
+            Some("cached"),
+            Some("cached"),
+            cancel_token,
+        );
+        assert!(matches!(
+            report,
+            Some(PullDiagnosticReport::Unchanged { result_id }) if result_id == "cached"
+        ));
+
+        // the client has an older result
+        let report = pull_file_diagnostic(
+            &ws.analysis,
+            file_id,
+            Some("outdated"),
+            Some("cached"),
+            CancellationToken::new(),
+        );
+        assert!(matches!(report, Some(PullDiagnosticReport::Full { .. })));
+    }
+
+    #[test]
+    fn test_pull_diagnostic_disabled() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.diagnostics.enable = false;
+        ws.analysis.update_config(emmyrc.into());
+        let file_id = ws.def(
+            r#"
+            return count + 1
+            "#,
+        );
+
+        let report =
+            pull_file_diagnostic(&ws.analysis, file_id, None, None, CancellationToken::new());
+        let Some(PullDiagnosticReport::Full { result_id, items }) = report else {
+            panic!("expected a full report");
+        };
+        assert!(items.is_empty());
+        let result_id = result_id.unwrap();
+
+        let report = pull_file_diagnostic(
+            &ws.analysis,
+            file_id,
+            Some(&result_id),
+            None,
+            CancellationToken::new(),
+        );
+        assert!(matches!(
+            report,
+            Some(PullDiagnosticReport::Unchanged { result_id: id }) if id == result_id
+        ));
+    }
+}
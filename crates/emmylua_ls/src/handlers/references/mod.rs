@@ -1,8 +1,12 @@
 mod reference_seacher;
+mod test;
 
 use crate::context::ServerContextSnapshot;
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
 use emmylua_parser::{LuaAstNode, LuaTokenKind};
-use lsp_types::{ClientCapabilities, Location, OneOf, ReferenceParams, ServerCapabilities};
+use lsp_types::{
+    ClientCapabilities, Location, OneOf, Position, ReferenceParams, ServerCapabilities,
+};
 use reference_seacher::search_references;
 pub use reference_seacher::{search_decl_references, search_member_references};
 use rowan::TokenAtOffset;
@@ -19,7 +23,16 @@ pub async fn on_references_handler(
     let analysis = context.analysis.read().await;
     let file_id = analysis.get_file_id(&uri)?;
     let position = params.text_document_position.position;
-    let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+
+    references(&analysis, file_id, position)
+}
+
+pub fn references(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    position: Position,
+) -> Option<Vec<Location>> {
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
     if !semantic_model.get_emmyrc().references.enable {
         return None;
     }
@@ -48,7 +61,7 @@ pub async fn on_references_handler(
         }
     };
 
-    search_references(&mut semantic_model, &analysis.compilation, token)
+    search_references(&semantic_model, &analysis.compilation, token)
 }
 
 pub struct ReferencesCapabilities;
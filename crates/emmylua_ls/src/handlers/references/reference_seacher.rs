@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 
 use emmylua_code_analysis::{
-    LuaCompilation, LuaDeclId, LuaMemberId, LuaMemberKey, LuaSemanticDeclId, LuaTypeDeclId,
-    SemanticDeclLevel, SemanticModel,
+    find_goto_label, LuaCompilation, LuaDeclId, LuaMemberId, LuaMemberKey, LuaSemanticDeclId,
+    LuaTypeDeclId, SemanticDeclLevel, SemanticModel,
 };
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaAstToken, LuaNameToken, LuaStringToken, LuaSyntaxNode, LuaSyntaxToken,
+    LuaAst, LuaAstNode, LuaAstToken, LuaGotoStat, LuaLabelStat, LuaNameToken, LuaStringToken,
+    LuaSyntaxNode, LuaSyntaxToken,
 };
 use lsp_types::Location;
 
@@ -15,7 +16,9 @@ pub fn search_references(
     token: LuaSyntaxToken,
 ) -> Option<Vec<Location>> {
     let mut result = Vec::new();
-    if let Some(semantic_decl) =
+    if let Some(label_stat) = get_token_label(&token) {
+        search_label_references(semantic_model, label_stat, &mut result);
+    } else if let Some(semantic_decl) =
         semantic_model.find_decl(token.clone().into(), SemanticDeclLevel::default())
     {
         match semantic_decl {
@@ -179,6 +182,37 @@ fn search_string_references(
     Some(())
 }
 
+/// The label named by the token of a `::label::` or a `goto label`
+fn get_token_label(token: &LuaSyntaxToken) -> Option<LuaLabelStat> {
+    let parent = token.parent()?;
+    if let Some(label_stat) = LuaLabelStat::cast(parent.clone()) {
+        return Some(label_stat);
+    }
+
+    find_goto_label(&LuaGotoStat::cast(parent)?)
+}
+
+fn search_label_references(
+    semantic_model: &SemanticModel,
+    label_stat: LuaLabelStat,
+    result: &mut Vec<Location>,
+) -> Option<()> {
+    let document = semantic_model.get_document();
+    let name_token = label_stat.get_label_name_token()?;
+    result.push(document.to_lsp_location(name_token.get_range())?);
+    for goto_stat in semantic_model.get_root().descendants::<LuaGotoStat>() {
+        if find_goto_label(&goto_stat).as_ref() != Some(&label_stat) {
+            continue;
+        }
+
+        if let Some(name_token) = goto_stat.get_label_name_token() {
+            result.push(document.to_lsp_location(name_token.get_range())?);
+        }
+    }
+
+    Some(())
+}
+
 fn fuzzy_search_references(
     compilation: &LuaCompilation,
     token: LuaSyntaxToken,
@@ -0,0 +1 @@
+mod references_test;
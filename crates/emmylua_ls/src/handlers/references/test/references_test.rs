@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::ProviderVirtualWorkspace;
+
+    #[test]
+    fn test_label_references() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let block_str = r#"
+                for i = 1, 10 do
+                    if i % 2 == 0 then
+                        goto continue
+                    elseif i % 3 == 0 then
+                        goto continue
+                    end
+                    ::<??>continue::
+                end
+                for i = 1, 10 do
+                    goto continue
+                    ::continue::
+                end
+            "#;
+        assert!(ws.check_references(block_str, vec![3, 5, 7]));
+        assert!(ws.check_references(
+            &block_str
+                .replace("<??>", "")
+                .replacen("goto continue", "goto <??>continue", 1),
+            vec![3, 5, 7],
+        ));
+    }
+}
@@ -107,7 +107,7 @@ pub async fn on_prepare_rename_handler(
     }
 }
 
-fn rename_references(
+pub fn rename_references(
     semantic_model: &SemanticModel,
     compilation: &LuaCompilation,
     token: LuaSyntaxToken,
@@ -7,7 +7,7 @@ use emmylua_parser::{
     LuaVarExpr,
 };
 use lsp_types::{SemanticToken, SemanticTokenModifier, SemanticTokenType};
-use rowan::NodeOrToken;
+use rowan::{NodeOrToken, TextRange, WalkEvent};
 
 use crate::context::ClientId;
 
@@ -19,6 +19,7 @@ pub fn build_semantic_tokens(
     semantic_model: &mut SemanticModel,
     support_muliline_token: bool,
     client_id: ClientId,
+    range: Option<TextRange>,
 ) -> Option<Vec<SemanticToken>> {
     let root = semantic_model.get_root();
     let document = semantic_model.get_document();
@@ -29,7 +30,21 @@ pub fn build_semantic_tokens(
         SEMANTIC_TOKEN_MODIFIERS.to_vec(),
     );
 
-    for node_or_token in root.syntax().descendants_with_tokens() {
+    let mut preorder = root.syntax().preorder_with_tokens();
+    while let Some(event) = preorder.next() {
+        let WalkEvent::Enter(node_or_token) = event else {
+            continue;
+        };
+        // the nodes outside of the range are skipped with all their children
+        if let Some(range) = range {
+            if range.intersect(node_or_token.text_range()).is_none() {
+                if node_or_token.as_node().is_some() {
+                    preorder.skip_subtree();
+                }
+                continue;
+            }
+        }
+
         match node_or_token {
             NodeOrToken::Node(node) => {
                 build_node_semantic_token(semantic_model, &mut builder, node, client_id);
@@ -40,6 +55,10 @@ pub fn build_semantic_tokens(
         }
     }
 
+    if let Some(range) = range {
+        builder.retain_range(range);
+    }
+
     Some(builder.build())
 }
 
@@ -51,7 +70,7 @@ fn build_tokens_semantic_token(
     client_id: ClientId,
 ) {
     match token.kind().into() {
-        LuaTokenKind::TkLongString | LuaTokenKind::TkString => {
+        LuaTokenKind::TkLongString | LuaTokenKind::TkString | LuaTokenKind::TkInterpString => {
             builder.push(token, SemanticTokenType::STRING);
         }
         LuaTokenKind::TkAnd
@@ -71,7 +90,9 @@ fn build_tokens_semantic_token(
         | LuaTokenKind::TkReturn
         | LuaTokenKind::TkThen
         | LuaTokenKind::TkUntil
-        | LuaTokenKind::TkWhile => {
+        | LuaTokenKind::TkWhile
+        | LuaTokenKind::TkGlobal
+        | LuaTokenKind::TkContinue => {
             builder.push(token, SemanticTokenType::KEYWORD);
         }
         LuaTokenKind::TkLocal => {
@@ -103,7 +124,8 @@ fn build_tokens_semantic_token(
         | LuaTokenKind::TkLeftBrace
         | LuaTokenKind::TkRightBrace
         | LuaTokenKind::TkLeftBracket
-        | LuaTokenKind::TkRightBracket => {
+        | LuaTokenKind::TkRightBracket
+        | LuaTokenKind::TkArrow => {
             builder.push(token, SemanticTokenType::OPERATOR);
         }
         LuaTokenKind::TkComplex | LuaTokenKind::TkInt | LuaTokenKind::TkFloat => {
@@ -136,7 +158,8 @@ fn build_tokens_semantic_token(
         | LuaTokenKind::TkTagNamespace
         | LuaTokenKind::TkTagUsing
         | LuaTokenKind::TkTagSource
-        | LuaTokenKind::TkTagReturnCast => {
+        | LuaTokenKind::TkTagReturnCast
+        | LuaTokenKind::TkTagFormatString => {
             builder.push_with_modifier(
                 token,
                 SemanticTokenType::KEYWORD,
@@ -1,14 +1,23 @@
 mod build_semantic_tokens;
 mod semantic_token_builder;
+mod semantic_token_result;
+mod test;
 
 use crate::context::ServerContextSnapshot;
 use build_semantic_tokens::build_semantic_tokens;
 use lsp_types::{
-    ClientCapabilities, SemanticTokens, SemanticTokensFullOptions, SemanticTokensLegend,
-    SemanticTokensOptions, SemanticTokensParams, SemanticTokensResult,
-    SemanticTokensServerCapabilities, ServerCapabilities,
+    ClientCapabilities, SemanticToken, SemanticTokens, SemanticTokensDelta,
+    SemanticTokensDeltaParams, SemanticTokensFullDeltaResult, SemanticTokensFullOptions,
+    SemanticTokensLegend, SemanticTokensOptions, SemanticTokensParams, SemanticTokensRangeParams,
+    SemanticTokensRangeResult, SemanticTokensResult, SemanticTokensServerCapabilities,
+    ServerCapabilities, Uri,
 };
+use rowan::TextRange;
 pub use semantic_token_builder::{SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES};
+pub use semantic_token_result::remove_semantic_tokens;
+use semantic_token_result::{
+    build_semantic_tokens_edits, get_semantic_tokens, save_semantic_tokens,
+};
 use tokio_util::sync::CancellationToken;
 
 use super::RegisterCapabilities;
@@ -21,27 +30,87 @@ pub async fn on_semantic_token_handler(
     _: CancellationToken,
 ) -> Option<SemanticTokensResult> {
     let uri = params.text_document.uri;
+    let tokens = get_semantic_tokens_in_range(&context, &uri, None).await?;
+    let result_id = save_semantic_tokens(uri, tokens.clone());
+
+    Some(SemanticTokensResult::Tokens(SemanticTokens {
+        result_id: Some(result_id),
+        data: tokens,
+    }))
+}
+
+pub async fn on_semantic_token_range_handler(
+    context: ServerContextSnapshot,
+    params: SemanticTokensRangeParams,
+    _: CancellationToken,
+) -> Option<SemanticTokensRangeResult> {
+    let uri = params.text_document.uri;
+    let range = {
+        let analysis = context.analysis.read().await;
+        let file_id = analysis.get_file_id(&uri)?;
+        let document = analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_document(&file_id)?;
+        document.to_rowan_range(params.range)?
+    };
+    let tokens = get_semantic_tokens_in_range(&context, &uri, Some(range)).await?;
+
+    Some(SemanticTokensRangeResult::Tokens(SemanticTokens {
+        result_id: None,
+        data: tokens,
+    }))
+}
+
+pub async fn on_semantic_token_delta_handler(
+    context: ServerContextSnapshot,
+    params: SemanticTokensDeltaParams,
+    _: CancellationToken,
+) -> Option<SemanticTokensFullDeltaResult> {
+    let uri = params.text_document.uri;
+    let tokens = get_semantic_tokens_in_range(&context, &uri, None).await?;
+    let old_tokens = get_semantic_tokens(&uri, &params.previous_result_id);
+    let result_id = save_semantic_tokens(uri, tokens.clone());
+
+    // the client sends the last result id it got, when the server has forgotten it all the
+    // tokens are sent again
+    match old_tokens {
+        Some(old_tokens) => Some(SemanticTokensFullDeltaResult::TokensDelta(
+            SemanticTokensDelta {
+                result_id: Some(result_id),
+                edits: build_semantic_tokens_edits(&old_tokens, &tokens),
+            },
+        )),
+        None => Some(SemanticTokensFullDeltaResult::Tokens(SemanticTokens {
+            result_id: Some(result_id),
+            data: tokens,
+        })),
+    }
+}
+
+async fn get_semantic_tokens_in_range(
+    context: &ServerContextSnapshot,
+    uri: &Uri,
+    range: Option<TextRange>,
+) -> Option<Vec<SemanticToken>> {
     let analysis = context.analysis.read().await;
     let config_manager = context.workspace_manager.read().await;
     let client_id = config_manager.client_config.client_id;
     let _ = config_manager;
-    let file_id = analysis.get_file_id(&uri)?;
+    let file_id = analysis.get_file_id(uri)?;
     let mut semantic_model = analysis.compilation.get_semantic_model(file_id)?;
 
     if !semantic_model.get_emmyrc().semantic_tokens.enable {
         return None;
     }
 
-    let result = build_semantic_tokens(
+    build_semantic_tokens(
         &mut semantic_model,
         unsafe { SEMANTIC_MULTILINE_SUPPORT },
         client_id,
-    )?;
-
-    Some(SemanticTokensResult::Tokens(SemanticTokens {
-        result_id: None,
-        data: result,
-    }))
+        range,
+    )
 }
 
 pub struct SemanticTokenCapabilities;
@@ -57,7 +126,8 @@ impl RegisterCapabilities for SemanticTokenCapabilities {
                     token_modifiers: SEMANTIC_TOKEN_MODIFIERS.iter().cloned().collect(),
                     token_types: SEMANTIC_TOKEN_TYPES.iter().cloned().collect(),
                 },
-                full: Some(SemanticTokensFullOptions::Bool(true)),
+                full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+                range: Some(true),
                 ..Default::default()
             }),
         );
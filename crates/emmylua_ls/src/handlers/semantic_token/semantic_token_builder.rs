@@ -1,7 +1,7 @@
 use emmylua_code_analysis::LuaDocument;
 use emmylua_parser::LuaSyntaxToken;
 use lsp_types::{SemanticToken, SemanticTokenModifier, SemanticTokenType};
-use rowan::TextSize;
+use rowan::{TextRange, TextSize};
 use std::{collections::HashMap, vec::Vec};
 
 pub const SEMANTIC_TOKEN_TYPES: &[SemanticTokenType] = &[
@@ -209,6 +209,12 @@ impl<'a> SemanticBuilder<'a> {
         Some(())
     }
 
+    /// Remove the tokens which start outside of the range, a node in the range can push tokens
+    /// of its children which are not
+    pub fn retain_range(&mut self, range: TextRange) {
+        self.data.retain(|position, _| range.contains(*position));
+    }
+
     pub fn build(self) -> Vec<SemanticToken> {
         let mut data: Vec<BasicSemanticTokenData> = vec![];
         for (_, token_data) in self.data {
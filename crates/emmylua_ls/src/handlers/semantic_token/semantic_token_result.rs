@@ -0,0 +1,81 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        LazyLock, Mutex,
+    },
+};
+
+use lsp_types::{SemanticToken, SemanticTokensEdit, Uri};
+
+/// The result id and the tokens
+type SemanticTokensResult = (String, Vec<SemanticToken>);
+
+/// The last full tokens sent for every document, a delta request diffs against them
+static SEMANTIC_TOKEN_RESULTS: LazyLock<Mutex<HashMap<Uri, SemanticTokensResult>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static RESULT_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Save the tokens of the document and return their result id
+pub fn save_semantic_tokens(uri: Uri, tokens: Vec<SemanticToken>) -> String {
+    let result_id = RESULT_ID_COUNTER
+        .fetch_add(1, Ordering::Relaxed)
+        .to_string();
+    if let Ok(mut results) = SEMANTIC_TOKEN_RESULTS.lock() {
+        results.insert(uri, (result_id.clone(), tokens));
+    }
+
+    result_id
+}
+
+/// Get the tokens which were saved with the result id
+pub fn get_semantic_tokens(uri: &Uri, result_id: &str) -> Option<Vec<SemanticToken>> {
+    let results = SEMANTIC_TOKEN_RESULTS.lock().ok()?;
+    let (saved_id, tokens) = results.get(uri)?;
+    if saved_id != result_id {
+        return None;
+    }
+
+    Some(tokens.clone())
+}
+
+pub fn remove_semantic_tokens(uri: &Uri) {
+    if let Ok(mut results) = SEMANTIC_TOKEN_RESULTS.lock() {
+        results.remove(uri);
+    }
+}
+
+/// The edit which turns the old tokens into the new ones, the unchanged tokens at the start and
+/// at the end are kept. The positions of the edit count the integers of the encoded tokens, every
+/// token is encoded as 5 integers.
+pub fn build_semantic_tokens_edits(
+    old_tokens: &[SemanticToken],
+    new_tokens: &[SemanticToken],
+) -> Vec<SemanticTokensEdit> {
+    let prefix_len = old_tokens
+        .iter()
+        .zip(new_tokens)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let max_suffix_len = old_tokens.len().min(new_tokens.len()) - prefix_len;
+    let suffix_len = old_tokens
+        .iter()
+        .rev()
+        .zip(new_tokens.iter().rev())
+        .take(max_suffix_len)
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let delete_count = old_tokens.len() - prefix_len - suffix_len;
+    let inserted_tokens = &new_tokens[prefix_len..new_tokens.len() - suffix_len];
+    if delete_count == 0 && inserted_tokens.is_empty() {
+        return Vec::new();
+    }
+
+    vec![SemanticTokensEdit {
+        start: (prefix_len * 5) as u32,
+        delete_count: (delete_count * 5) as u32,
+        data: Some(inserted_tokens.to_vec()),
+    }]
+}
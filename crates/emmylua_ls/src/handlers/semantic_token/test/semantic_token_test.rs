@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::SemanticToken;
+    use rowan::TextRange;
+
+    use crate::{
+        context::ClientId,
+        handlers::{
+            semantic_token::{
+                build_semantic_tokens::build_semantic_tokens,
+                semantic_token_result::build_semantic_tokens_edits,
+            },
+            test_lib::ProviderVirtualWorkspace,
+        },
+    };
+
+    fn token(delta_line: u32, delta_start: u32, length: u32) -> SemanticToken {
+        SemanticToken {
+            delta_line,
+            delta_start,
+            length,
+            token_type: 0,
+            token_modifiers_bitset: 0,
+        }
+    }
+
+    fn encode(tokens: &[SemanticToken]) -> Vec<u32> {
+        tokens
+            .iter()
+            .flat_map(|token| {
+                [
+                    token.delta_line,
+                    token.delta_start,
+                    token.length,
+                    token.token_type,
+                    token.token_modifiers_bitset,
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_semantic_tokens_edits() {
+        let old_tokens = vec![token(0, 0, 5), token(1, 2, 3), token(1, 0, 4)];
+        assert!(build_semantic_tokens_edits(&old_tokens, &old_tokens).is_empty());
+
+        let new_tokens = vec![
+            token(0, 0, 5),
+            token(1, 2, 7),
+            token(0, 8, 1),
+            token(1, 0, 4),
+        ];
+        let edits = build_semantic_tokens_edits(&old_tokens, &new_tokens);
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].start, 5);
+        assert_eq!(edits[0].delete_count, 5);
+
+        let mut data = encode(&old_tokens);
+        let start = edits[0].start as usize;
+        let end = start + edits[0].delete_count as usize;
+        let inserted = encode(edits[0].data.as_deref().unwrap_or_default());
+        data.splice(start..end, inserted);
+        assert_eq!(data, encode(&new_tokens));
+
+        let edits = build_semantic_tokens_edits(&old_tokens, &old_tokens[..1]);
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].start, 5);
+        assert_eq!(edits[0].delete_count, 10);
+    }
+
+    #[test]
+    fn test_semantic_tokens_range() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"local a = 1
+local b = "hello"
+local function f(x)
+    return x
+end
+"#,
+        );
+        let mut semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let full_tokens =
+            build_semantic_tokens(&mut semantic_model, false, ClientId::Other, None).unwrap();
+
+        // the second line
+        let range = TextRange::new(12.into(), 29.into());
+        let range_tokens =
+            build_semantic_tokens(&mut semantic_model, false, ClientId::Other, Some(range))
+                .unwrap();
+        assert!(!range_tokens.is_empty());
+        assert!(range_tokens.len() < full_tokens.len());
+        assert_eq!(range_tokens[0].delta_line, 1);
+        assert!(range_tokens[1..].iter().all(|token| token.delta_line == 0));
+    }
+}
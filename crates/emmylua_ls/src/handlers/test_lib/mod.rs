@@ -1,7 +1,9 @@
-use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, VirtualUrlGenerator};
+use std::sync::Arc;
+
+use emmylua_code_analysis::{DiagnosticCode, EmmyLuaAnalysis, Emmyrc, FileId, VirtualUrlGenerator};
 use lsp_types::{
-    CompletionItemKind, CompletionResponse, CompletionTriggerKind, GotoDefinitionResponse, Hover,
-    HoverContents, MarkupContent, Position,
+    CodeActionOrCommand, CompletionItemKind, CompletionResponse, CompletionTriggerKind,
+    GotoDefinitionResponse, Hover, HoverContents, MarkupContent, NumberOrString, Position,
 };
 use tokio_util::sync::CancellationToken;
 
@@ -10,7 +12,7 @@ use crate::{
     handlers::completion::{completion, completion_resolve},
 };
 
-use super::{hover::hover, implementation::implementation};
+use super::{code_actions::build_actions, hover::hover, implementation::implementation};
 
 /// A virtual workspace for testing.
 #[allow(unused)]
@@ -245,4 +247,126 @@ impl ProviderVirtualWorkspace {
             GotoDefinitionResponse::Link(_) => true,
         }
     }
+
+    pub fn check_definition_lines(&mut self, block_str: &str, expect_lines: Vec<u32>) -> bool {
+        let content = Self::handle_file_content(block_str);
+        let Some((content, position)) = content else {
+            return false;
+        };
+        let file_id = self.def(&content);
+        let result = super::definition::definition(&self.analysis, file_id, position);
+        Self::check_location_lines(result, expect_lines)
+    }
+
+    pub fn check_references(&mut self, block_str: &str, expect_lines: Vec<u32>) -> bool {
+        let content = Self::handle_file_content(block_str);
+        let Some((content, position)) = content else {
+            return false;
+        };
+        let file_id = self.def(&content);
+        let result = super::references::references(&self.analysis, file_id, position);
+        Self::check_location_lines(result.map(GotoDefinitionResponse::Array), expect_lines)
+    }
+
+    pub fn check_type_definition(&mut self, block_str: &str, expect_lines: Vec<u32>) -> bool {
+        let content = Self::handle_file_content(block_str);
+        let Some((content, position)) = content else {
+            return false;
+        };
+        let file_id = self.def(&content);
+        let result = super::type_definition::type_definition(&self.analysis, file_id, position);
+        Self::check_location_lines(result, expect_lines)
+    }
+
+    pub fn check_declaration(&mut self, block_str: &str, expect_lines: Vec<u32>) -> bool {
+        let content = Self::handle_file_content(block_str);
+        let Some((content, position)) = content else {
+            return false;
+        };
+        let file_id = self.def(&content);
+        let result = super::declaration::declaration(&self.analysis, file_id, position);
+        Self::check_location_lines(result, expect_lines)
+    }
+
+    fn check_location_lines(
+        result: Option<GotoDefinitionResponse>,
+        expect_lines: Vec<u32>,
+    ) -> bool {
+        dbg!(&result);
+        let mut lines = match result {
+            Some(GotoDefinitionResponse::Scalar(location)) => vec![location.range.start.line],
+            Some(GotoDefinitionResponse::Array(locations)) => locations
+                .iter()
+                .map(|location| location.range.start.line)
+                .collect(),
+            _ => Vec::new(),
+        };
+        lines.sort();
+        lines == expect_lines
+    }
+
+    pub fn enable_check(&mut self, diagnostic_code: DiagnosticCode) {
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.diagnostics.enables.push(diagnostic_code);
+        self.analysis.diagnostic.update_config(Arc::new(emmyrc));
+    }
+
+    /// 应用 `diagnostic_code` 的第一个快速修复, 并检查修复后的文本
+    pub fn check_code_action(
+        &mut self,
+        diagnostic_code: DiagnosticCode,
+        block_str: &str,
+        expect: &str,
+    ) -> bool {
+        let file_id = self.def(block_str);
+        let Some(diagnostics) = self
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+        else {
+            return false;
+        };
+        let code_string = Some(NumberOrString::String(
+            diagnostic_code.get_name().to_string(),
+        ));
+        let diagnostics = diagnostics
+            .into_iter()
+            .filter(|diagnostic| diagnostic.code == code_string)
+            .take(1)
+            .collect::<Vec<_>>();
+        let Some(semantic_model) = self.analysis.compilation.get_semantic_model(file_id) else {
+            return false;
+        };
+        let Some(actions) = build_actions(&self.analysis.compilation, &semantic_model, diagnostics)
+        else {
+            return false;
+        };
+        let Some(CodeActionOrCommand::CodeAction(action)) = actions.into_iter().next() else {
+            return false;
+        };
+        let Some(mut edits) = action
+            .edit
+            .and_then(|edit| edit.changes)
+            .and_then(|changes| changes.into_values().next())
+        else {
+            return false;
+        };
+        let Some(document) = semantic_model.get_document_by_file_id(file_id) else {
+            return false;
+        };
+
+        // 从后往前应用, 避免前面的修改影响后面的偏移
+        edits.sort_by(|a, b| b.range.start.cmp(&a.range.start));
+        let mut text = block_str.to_string();
+        for edit in edits {
+            let Some(range) = document.to_rowan_range(edit.range) else {
+                return false;
+            };
+            text.replace_range(
+                usize::from(range.start())..usize::from(range.end()),
+                &edit.new_text,
+            );
+        }
+        dbg!(&text);
+        text == expect
+    }
 }
@@ -0,0 +1,126 @@
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{LazyLock, Mutex},
+};
+
+use emmylua_code_analysis::{
+    check_emmyrc_config, check_luarc_config, file_path_to_uri, read_file_with_encoding,
+    uri_to_file_path,
+};
+use emmylua_parser::LineIndex;
+use lsp_types::{PublishDiagnosticsParams, TextDocumentContentChangeEvent, Uri};
+
+use crate::context::ClientProxy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFileKind {
+    Emmyrc,
+    Luarc,
+}
+
+/// The text of the opened config files, they are json and are kept out of the lua vfs
+static CONFIG_DOCUMENTS: LazyLock<Mutex<HashMap<Uri, String>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+pub fn get_config_file_kind(uri: &Uri) -> Option<ConfigFileKind> {
+    let path = uri_to_file_path(uri)?;
+    match path.file_name()?.to_str()? {
+        ".emmyrc.json" => Some(ConfigFileKind::Emmyrc),
+        ".luarc.json" => Some(ConfigFileKind::Luarc),
+        _ => None,
+    }
+}
+
+pub fn get_config_document(uri: &Uri) -> Option<String> {
+    CONFIG_DOCUMENTS.lock().ok()?.get(uri).cloned()
+}
+
+pub fn set_config_document(uri: Uri, text: String) {
+    if let Ok(mut documents) = CONFIG_DOCUMENTS.lock() {
+        documents.insert(uri, text);
+    }
+}
+
+pub fn remove_config_document(uri: &Uri) {
+    if let Ok(mut documents) = CONFIG_DOCUMENTS.lock() {
+        documents.remove(uri);
+    }
+}
+
+/// Apply the changes to the opened config file and return the new text
+pub fn update_config_document(
+    uri: &Uri,
+    changes: Vec<TextDocumentContentChangeEvent>,
+) -> Option<String> {
+    let mut documents = CONFIG_DOCUMENTS.lock().ok()?;
+    let text = documents.get_mut(uri)?;
+    for change in changes {
+        match change.range {
+            Some(range) => {
+                let line_index = LineIndex::parse(text);
+                let start = line_index.get_offset_by_utf16_col(
+                    range.start.line as usize,
+                    range.start.character as usize,
+                    text,
+                )?;
+                let end = line_index.get_offset_by_utf16_col(
+                    range.end.line as usize,
+                    range.end.character as usize,
+                    text,
+                )?;
+                if start > end || usize::from(end) > text.len() {
+                    log::warn!("invalid change range {:?} for {}", range, uri.as_str());
+                    return None;
+                }
+                text.replace_range(usize::from(start)..usize::from(end), &change.text);
+            }
+            None => *text = change.text,
+        }
+    }
+
+    Some(text.clone())
+}
+
+pub fn check_config_document(kind: ConfigFileKind, text: &str) -> Vec<lsp_types::Diagnostic> {
+    match kind {
+        ConfigFileKind::Emmyrc => check_emmyrc_config(text),
+        ConfigFileKind::Luarc => check_luarc_config(text),
+    }
+}
+
+pub fn publish_config_diagnostics(
+    client: &ClientProxy,
+    uri: Uri,
+    kind: ConfigFileKind,
+    text: &str,
+) {
+    client.publish_diagnostics(PublishDiagnosticsParams {
+        uri,
+        diagnostics: check_config_document(kind, text),
+        version: None,
+    });
+}
+
+/// Check the config files in the workspace root, an opened config file is checked with the text
+/// of the editor
+pub fn publish_workspace_config_diagnostics(client: &ClientProxy, workspace_root: &Path) {
+    let config_files = [
+        (".emmyrc.json", ConfigFileKind::Emmyrc),
+        (".luarc.json", ConfigFileKind::Luarc),
+    ];
+    for (file_name, kind) in config_files {
+        let path = workspace_root.join(file_name);
+        if !path.exists() {
+            continue;
+        }
+        let Some(uri) = file_path_to_uri(&path) else {
+            continue;
+        };
+
+        let text = get_config_document(&uri).or_else(|| read_file_with_encoding(&path, "utf-8"));
+        if let Some(text) = text {
+            publish_config_diagnostics(client, uri, kind, &text);
+        }
+    }
+}
@@ -1,8 +1,12 @@
+mod config_document;
 mod register_file_watch;
 mod set_trace;
 mod text_document_handler;
 mod watched_file_handler;
 
+pub use config_document::{
+    get_config_document, get_config_file_kind, publish_workspace_config_diagnostics, ConfigFileKind,
+};
 use lsp_types::{
     ClientCapabilities, SaveOptions, ServerCapabilities, TextDocumentSyncCapability,
     TextDocumentSyncKind, TextDocumentSyncSaveOptions,
@@ -24,7 +28,7 @@ impl RegisterCapabilities for TextDocumentCapabilities {
         server_capabilities.text_document_sync = Some(TextDocumentSyncCapability::Options(
             lsp_types::TextDocumentSyncOptions {
                 open_close: Some(true),
-                change: Some(TextDocumentSyncKind::FULL),
+                change: Some(TextDocumentSyncKind::INCREMENTAL),
                 will_save: None,
                 will_save_wait_until: None,
                 save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions {
@@ -5,16 +5,28 @@ use lsp_types::{
     DidSaveTextDocumentParams,
 };
 
-use crate::context::ServerContextSnapshot;
+use super::config_document::{
+    get_config_file_kind, publish_config_diagnostics, remove_config_document, set_config_document,
+    update_config_document,
+};
+use crate::{context::ServerContextSnapshot, handlers::semantic_token::remove_semantic_tokens};
+
+// the files which depend on an edited file are re-analyzed once the typing pauses
+const UPDATE_DEPENDENTS_DELAY: Duration = Duration::from_secs(2);
 
 pub async fn on_did_open_text_document(
     context: ServerContextSnapshot,
     params: DidOpenTextDocumentParams,
 ) -> Option<()> {
-    let mut analysis = context.analysis.write().await;
     let uri = params.text_document.uri;
     let text = params.text_document.text;
+    if let Some(kind) = get_config_file_kind(&uri) {
+        publish_config_diagnostics(&context.client, uri.clone(), kind, &text);
+        set_config_document(uri, text);
+        return Some(());
+    }
 
+    let mut analysis = context.analysis.write().await;
     let file_id = analysis.update_file_by_uri(&uri, Some(text));
     let emmyrc = analysis.get_emmyrc();
     let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
@@ -34,10 +46,35 @@ pub async fn on_did_open_text_document(
 
 pub async fn on_did_save_text_document(
     context: ServerContextSnapshot,
-    _: DidSaveTextDocumentParams,
+    params: DidSaveTextDocumentParams,
 ) -> Option<()> {
+    // a saved config file is reloaded by the file watcher
+    if get_config_file_kind(&params.text_document.uri).is_some() {
+        return Some(());
+    }
+
+    // a file whose changes could not be applied is in sync again with the saved text
+    let mut analysis = context.analysis.write().await;
+    if let Some(file_id) = analysis.reload_out_of_sync_file(&params.text_document.uri) {
+        let interval = analysis
+            .get_emmyrc()
+            .diagnostics
+            .diagnostic_interval
+            .unwrap_or(500);
+        drop(analysis);
+        context
+            .file_diagnostic
+            .add_diagnostic_task(file_id, interval)
+            .await;
+    } else {
+        drop(analysis);
+    }
+
     let emmyrc = context.analysis.read().await.get_emmyrc();
     if !emmyrc.workspace.enable_reindex {
+        // the files which depend on the saved file are re-analyzed without waiting for idle
+        let workspace = context.workspace_manager.read().await;
+        workspace.update_dependents(Duration::ZERO).await;
         return Some(());
     }
 
@@ -57,19 +94,26 @@ pub async fn on_did_change_text_document(
     context: ServerContextSnapshot,
     params: DidChangeTextDocumentParams,
 ) -> Option<()> {
-    let mut analysis = context.analysis.write().await;
     let uri = params.text_document.uri;
-    let text = params.content_changes.first()?.text.clone();
-    let file_id = analysis.update_file_by_uri(&uri, Some(text));
+    if let Some(kind) = get_config_file_kind(&uri) {
+        let text = update_config_document(&uri, params.content_changes)?;
+        publish_config_diagnostics(&context.client, uri, kind, &text);
+        return Some(());
+    }
+
+    let mut analysis = context.analysis.write().await;
+    let file_id = analysis.update_file_by_changes(&uri, params.content_changes);
     let emmyrc = analysis.get_emmyrc();
     let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
     drop(analysis);
 
+    let workspace = context.workspace_manager.read().await;
     if emmyrc.workspace.enable_reindex {
-        let workspace = context.workspace_manager.read().await;
         workspace.extend_reindex_delay().await;
-        drop(workspace);
+    } else {
+        workspace.update_dependents(UPDATE_DEPENDENTS_DELAY).await;
     }
+    drop(workspace);
     if let Some(file_id) = file_id {
         context
             .file_diagnostic
@@ -89,5 +133,7 @@ pub async fn on_did_close_document(
         .current_open_files
         .remove(&params.text_document.uri);
     drop(workspace);
+    remove_semantic_tokens(&params.text_document.uri);
+    remove_config_document(&params.text_document.uri);
     Some(())
 }
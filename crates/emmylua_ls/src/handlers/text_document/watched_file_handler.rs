@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use emmylua_code_analysis::{read_file_with_encoding, uri_to_file_path};
 use lsp_types::{DidChangeWatchedFilesParams, FileChangeType, Uri};
 
@@ -13,13 +15,14 @@ pub async fn on_did_change_watched_files(
     let encoding = &emmyrc.workspace.encoding;
     let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
     let mut watched_lua_files: Vec<(Uri, Option<String>)> = Vec::new();
-    // let
+    let mut has_removed_files = false;
     for file_event in params.changes.into_iter() {
         let file_type = get_file_type(&file_event.uri);
         match file_type {
             Some(WatchedFileType::Lua) => {
                 if file_event.typ == FileChangeType::DELETED {
                     analysis.remove_file_by_uri(&file_event.uri);
+                    has_removed_files = true;
                     continue;
                 }
 
@@ -61,6 +64,10 @@ pub async fn on_did_change_watched_files(
     }
 
     let file_ids = analysis.update_files_by_uri(watched_lua_files);
+    drop(analysis);
+    if has_removed_files {
+        workspace.update_dependents(Duration::ZERO).await;
+    }
     context
         .file_diagnostic
         .add_files_diagnostic_task(file_ids, interval)
@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use emmylua_code_analysis::{LuaType, LuaTypeDeclId, VariadicType};
+
+/// The declared types which the type is made of, the types in unions, arrays, tuples and generic
+/// params are all collected in order
+pub fn collect_type_decl_ids(typ: &LuaType) -> Vec<LuaTypeDeclId> {
+    let mut type_decl_ids = Vec::new();
+    let mut visited = HashSet::new();
+    collect_type(typ, &mut type_decl_ids, &mut visited);
+    type_decl_ids
+}
+
+fn collect_type(
+    typ: &LuaType,
+    type_decl_ids: &mut Vec<LuaTypeDeclId>,
+    visited: &mut HashSet<LuaTypeDeclId>,
+) {
+    match typ {
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id)
+            if visited.insert(type_decl_id.clone()) =>
+        {
+            type_decl_ids.push(type_decl_id.clone());
+        }
+        LuaType::Generic(generic) => {
+            let base_id = generic.get_base_type_id();
+            if visited.insert(base_id.clone()) {
+                type_decl_ids.push(base_id);
+            }
+            for param in generic.get_params() {
+                collect_type(param, type_decl_ids, visited);
+            }
+        }
+        LuaType::Array(base) => collect_type(base, type_decl_ids, visited),
+        LuaType::Union(union) => {
+            for typ in union.get_types() {
+                collect_type(typ, type_decl_ids, visited);
+            }
+        }
+        LuaType::MultiLineUnion(union) => {
+            for (typ, _) in union.get_unions() {
+                collect_type(typ, type_decl_ids, visited);
+            }
+        }
+        LuaType::Intersection(intersection) => {
+            for typ in intersection.get_types() {
+                collect_type(typ, type_decl_ids, visited);
+            }
+        }
+        LuaType::Tuple(tuple) => {
+            for typ in tuple.get_types() {
+                collect_type(typ, type_decl_ids, visited);
+            }
+        }
+        LuaType::TableGeneric(params) => {
+            for typ in params.iter() {
+                collect_type(typ, type_decl_ids, visited);
+            }
+        }
+        LuaType::Variadic(variadic) => match variadic.as_ref() {
+            VariadicType::Base(base) => collect_type(base, type_decl_ids, visited),
+            VariadicType::Multi(types) => {
+                for typ in types {
+                    collect_type(typ, type_decl_ids, visited);
+                }
+            }
+        },
+        LuaType::Instance(instance) => collect_type(instance.get_base(), type_decl_ids, visited),
+        _ => {}
+    }
+}
@@ -0,0 +1,89 @@
+mod collect_type_decls;
+mod test;
+
+use collect_type_decls::collect_type_decl_ids;
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId};
+use emmylua_parser::{LuaAstNode, LuaTokenKind};
+use lsp_types::{
+    request::{GotoTypeDefinitionParams, GotoTypeDefinitionResponse},
+    ClientCapabilities, Position, ServerCapabilities, TypeDefinitionProviderCapability,
+};
+use rowan::TokenAtOffset;
+use tokio_util::sync::CancellationToken;
+
+use crate::context::ServerContextSnapshot;
+
+use super::RegisterCapabilities;
+
+pub async fn on_type_definition_handler(
+    context: ServerContextSnapshot,
+    params: GotoTypeDefinitionParams,
+    _: CancellationToken,
+) -> Option<GotoTypeDefinitionResponse> {
+    let uri = params.text_document_position_params.text_document.uri;
+    let analysis = context.analysis.read().await;
+    let file_id = analysis.get_file_id(&uri)?;
+    let position = params.text_document_position_params.position;
+
+    type_definition(&analysis, file_id, position)
+}
+
+/// Go to the declarations of the classes, aliases and enums which make up the type of the token
+pub fn type_definition(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    position: Position,
+) -> Option<GotoTypeDefinitionResponse> {
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let root = semantic_model.get_root();
+    let position_offset = {
+        let document = semantic_model.get_document();
+        document.get_offset(position.line as usize, position.character as usize)?
+    };
+
+    if position_offset > root.syntax().text_range().end() {
+        return None;
+    }
+
+    let token = match root.syntax().token_at_offset(position_offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, right) => {
+            if left.kind() == LuaTokenKind::TkName.into() {
+                left
+            } else {
+                right
+            }
+        }
+        TokenAtOffset::None => {
+            return None;
+        }
+    };
+
+    let semantic_info = semantic_model.get_semantic_info(token.into())?;
+    let db = semantic_model.get_db();
+    let mut locations = Vec::new();
+    for type_decl_id in collect_type_decl_ids(&semantic_info.typ) {
+        let Some(type_decl) = db.get_type_index().get_type_decl(&type_decl_id) else {
+            continue;
+        };
+        for lua_location in type_decl.get_locations() {
+            let document = semantic_model.get_document_by_file_id(lua_location.file_id)?;
+            locations.push(document.to_lsp_location(lua_location.range)?);
+        }
+    }
+
+    if locations.is_empty() {
+        return None;
+    }
+
+    Some(GotoTypeDefinitionResponse::Array(locations))
+}
+
+pub struct TypeDefinitionCapabilities;
+
+impl RegisterCapabilities for TypeDefinitionCapabilities {
+    fn register_capabilities(server_capabilities: &mut ServerCapabilities, _: &ClientCapabilities) {
+        server_capabilities.type_definition_provider =
+            Some(TypeDefinitionProviderCapability::Simple(true));
+    }
+}
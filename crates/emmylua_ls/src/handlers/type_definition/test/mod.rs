@@ -0,0 +1 @@
+mod type_definition_test;
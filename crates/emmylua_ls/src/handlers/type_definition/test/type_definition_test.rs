@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::ProviderVirtualWorkspace;
+
+    #[test]
+    fn test_type_definition() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_type_definition(
+            r#"
+            ---@class Foo
+            local Foo = {}
+
+            ---@type Foo
+            local foo
+
+            print(f<??>oo)
+            "#,
+            vec![1],
+        ));
+    }
+
+    #[test]
+    fn test_type_definition_union_and_array() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_type_definition(
+            r#"
+            ---@class Foo
+
+            ---@alias Bar string
+
+            ---@type (Foo|Bar)[]?
+            local foo
+
+            print(f<??>oo)
+            "#,
+            vec![1, 3],
+        ));
+    }
+
+    #[test]
+    fn test_type_definition_generic_and_inferred() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        assert!(ws.check_type_definition(
+            r#"
+            ---@class List<T>
+
+            ---@enum Color
+            local Color = { Red = 1 }
+
+            ---@return List<Color>
+            local function get_colors()
+            end
+
+            local colors = get_colors()
+            print(co<??>lors)
+            "#,
+            vec![1, 3],
+        ));
+    }
+}
@@ -0,0 +1,87 @@
+use emmylua_code_analysis::{DbIndex, LuaType, LuaTypeDeclId, SemanticModel};
+use lsp_types::{SymbolKind, TypeHierarchyItem};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TypeHierarchyItemData {
+    pub type_decl_id: LuaTypeDeclId,
+}
+
+pub fn build_type_hierarchy_item(
+    semantic_model: &SemanticModel,
+    type_decl_id: &LuaTypeDeclId,
+) -> Option<TypeHierarchyItem> {
+    let db = semantic_model.get_db();
+    let type_decl = db.get_type_index().get_type_decl(type_decl_id)?;
+    // 一个类型可能在多个文件中声明, 取第一个位置
+    let location = type_decl.get_locations().first()?;
+    let document = semantic_model.get_document_by_file_id(location.file_id)?;
+    let lsp_range = document.to_lsp_range(location.range)?;
+    let data = TypeHierarchyItemData {
+        type_decl_id: type_decl_id.clone(),
+    };
+
+    let kind = if type_decl.is_enum() {
+        SymbolKind::ENUM
+    } else if type_decl.is_alias() {
+        SymbolKind::TYPE_PARAMETER
+    } else {
+        SymbolKind::CLASS
+    };
+
+    Some(TypeHierarchyItem {
+        name: type_decl.get_name().to_string(),
+        kind,
+        tags: None,
+        detail: type_decl.get_namespace().map(|ns| ns.to_string()),
+        uri: document.get_uri(),
+        range: lsp_range,
+        selection_range: lsp_range,
+        data: Some(serde_json::to_value(data).ok()?),
+    })
+}
+
+pub fn build_supertypes(
+    semantic_model: &SemanticModel,
+    type_decl_id: &LuaTypeDeclId,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let db = semantic_model.get_db();
+    let super_types = db.get_type_index().get_super_types(type_decl_id)?;
+    let mut items = Vec::new();
+    for super_type in super_types {
+        let Some(super_type_id) = get_type_decl_id(db, &super_type) else {
+            continue;
+        };
+        if let Some(item) = build_type_hierarchy_item(semantic_model, &super_type_id) {
+            items.push(item);
+        }
+    }
+
+    Some(items)
+}
+
+pub fn build_subtypes(
+    semantic_model: &SemanticModel,
+    type_decl_id: &LuaTypeDeclId,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let db = semantic_model.get_db();
+    let sub_types = db.get_type_index().get_sub_types(type_decl_id)?;
+    let items = sub_types
+        .iter()
+        .filter_map(|sub_type_id| build_type_hierarchy_item(semantic_model, sub_type_id))
+        .collect();
+
+    Some(items)
+}
+
+fn get_type_decl_id(db: &DbIndex, typ: &LuaType) -> Option<LuaTypeDeclId> {
+    let type_decl_id = match typ {
+        LuaType::Ref(id) | LuaType::Def(id) => id.clone(),
+        LuaType::Generic(generic) => generic.get_base_type_id(),
+        _ => return None,
+    };
+
+    db.get_type_index()
+        .get_type_decl(&type_decl_id)
+        .map(|type_decl| type_decl.get_id())
+}
@@ -0,0 +1,160 @@
+mod build_type_hierarchy;
+mod test;
+
+use build_type_hierarchy::{
+    build_subtypes, build_supertypes, build_type_hierarchy_item, TypeHierarchyItemData,
+};
+use emmylua_code_analysis::{
+    EmmyLuaAnalysis, FileId, LuaSemanticDeclId, LuaType, LuaTypeDeclId, SemanticDeclLevel,
+};
+use emmylua_parser::{LuaAstNode, LuaTokenKind};
+use lsp_types::{
+    ClientCapabilities, Position, Registration, RegistrationParams, TypeHierarchyItem,
+    TypeHierarchyPrepareParams, TypeHierarchyRegistrationOptions, TypeHierarchySubtypesParams,
+    TypeHierarchySupertypesParams,
+};
+use rowan::TokenAtOffset;
+use tokio_util::sync::CancellationToken;
+
+use crate::context::{ClientProxy, ServerContextSnapshot};
+
+pub async fn on_prepare_type_hierarchy_handler(
+    context: ServerContextSnapshot,
+    params: TypeHierarchyPrepareParams,
+    _: CancellationToken,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let uri = params.text_document_position_params.text_document.uri;
+    let analysis = context.analysis.read().await;
+    let file_id = analysis.get_file_id(&uri)?;
+    let position = params.text_document_position_params.position;
+
+    prepare_type_hierarchy(&analysis, file_id, position)
+}
+
+pub fn prepare_type_hierarchy(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    position: Position,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+    let root = semantic_model.get_root();
+    let position_offset = {
+        let document = semantic_model.get_document();
+        document.get_offset(position.line as usize, position.character as usize)?
+    };
+
+    if position_offset > root.syntax().text_range().end() {
+        return None;
+    }
+
+    let token = match root.syntax().token_at_offset(position_offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, right) => {
+            if left.kind() == LuaTokenKind::TkName.into() {
+                left
+            } else {
+                right
+            }
+        }
+        TokenAtOffset::None => {
+            return None;
+        }
+    };
+
+    let semantic_decl = semantic_model.find_decl(token.into(), SemanticDeclLevel::default())?;
+    let type_decl_id = match semantic_decl {
+        LuaSemanticDeclId::TypeDecl(type_decl_id) => type_decl_id,
+        // `local A = {}` declared by `---@class A`
+        LuaSemanticDeclId::LuaDecl(decl_id) => {
+            let typ = semantic_model.get_type(decl_id.into());
+            match typ {
+                LuaType::Def(type_decl_id) | LuaType::Ref(type_decl_id) => type_decl_id,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    Some(vec![build_type_hierarchy_item(
+        &semantic_model,
+        &type_decl_id,
+    )?])
+}
+
+pub async fn on_type_hierarchy_supertypes_handler(
+    context: ServerContextSnapshot,
+    params: TypeHierarchySupertypesParams,
+    _: CancellationToken,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let analysis = context.analysis.read().await;
+    let (file_id, type_decl_id) = get_item_type_decl(&analysis, &params.item)?;
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+
+    build_supertypes(&semantic_model, &type_decl_id)
+}
+
+pub async fn on_type_hierarchy_subtypes_handler(
+    context: ServerContextSnapshot,
+    params: TypeHierarchySubtypesParams,
+    _: CancellationToken,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let analysis = context.analysis.read().await;
+    let (file_id, type_decl_id) = get_item_type_decl(&analysis, &params.item)?;
+    let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
+
+    build_subtypes(&semantic_model, &type_decl_id)
+}
+
+fn get_item_type_decl(
+    analysis: &EmmyLuaAnalysis,
+    item: &TypeHierarchyItem,
+) -> Option<(FileId, LuaTypeDeclId)> {
+    let data = item.data.as_ref()?;
+    let data = serde_json::from_value::<TypeHierarchyItemData>(data.clone()).ok()?;
+    let file_id = analysis.get_file_id(&item.uri)?;
+    Some((file_id, data.type_decl_id))
+}
+
+fn supports_dynamic_registration(client_capabilities: &ClientCapabilities) -> bool {
+    client_capabilities
+        .text_document
+        .as_ref()
+        .and_then(|text_document| text_document.type_hierarchy.as_ref())
+        .and_then(|type_hierarchy| type_hierarchy.dynamic_registration)
+        .unwrap_or(false)
+}
+
+/// `lsp-types` has no `typeHierarchyProvider` in `ServerCapabilities`, so it is set on the
+/// serialized capabilities. Clients that register it dynamically get it from
+/// `register_type_hierarchy` instead.
+pub fn register_type_hierarchy_capability(
+    server_capabilities: &mut serde_json::Value,
+    client_capabilities: &ClientCapabilities,
+) {
+    if supports_dynamic_registration(client_capabilities) {
+        return;
+    }
+
+    if let Some(server_capabilities) = server_capabilities.as_object_mut() {
+        server_capabilities.insert("typeHierarchyProvider".to_string(), true.into());
+    }
+}
+
+pub fn register_type_hierarchy(client: &ClientProxy, client_capabilities: &ClientCapabilities) {
+    if !supports_dynamic_registration(client_capabilities) {
+        return;
+    }
+
+    let Ok(register_options) = serde_json::to_value(TypeHierarchyRegistrationOptions::default())
+    else {
+        return;
+    };
+    let registration = Registration {
+        id: "emmylua_type_hierarchy".to_string(),
+        method: "textDocument/prepareTypeHierarchy".to_string(),
+        register_options: Some(register_options),
+    };
+    client.dynamic_register_capability(RegistrationParams {
+        registrations: vec![registration],
+    });
+}
@@ -0,0 +1 @@
+mod type_hierarchy_test;
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use emmylua_code_analysis::LuaTypeDeclId;
+    use lsp_types::{
+        ClientCapabilities, Position, TextDocumentClientCapabilities,
+        TypeHierarchyClientCapabilities,
+    };
+
+    use crate::handlers::{
+        server_capabilities,
+        test_lib::ProviderVirtualWorkspace,
+        type_hierarchy::{
+            build_type_hierarchy::{build_subtypes, build_supertypes},
+            prepare_type_hierarchy,
+        },
+    };
+
+    #[test]
+    fn test_type_hierarchy() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "base.lua",
+            r#"
+            ---@class Animal
+            ---@class Dog : Animal
+            ---@class Cat : Animal
+            "#,
+        );
+        let file_id = ws.def_file(
+            "puppy.lua",
+            r#"
+            ---@class Puppy : Dog
+            local Puppy = {}
+            "#,
+        );
+
+        let items = prepare_type_hierarchy(&ws.analysis, file_id, Position::new(2, 20)).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name, "Puppy");
+
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let supertypes = build_supertypes(&semantic_model, &LuaTypeDeclId::new("Puppy")).unwrap();
+        assert_eq!(supertypes.len(), 1);
+        assert_eq!(supertypes[0].name, "Dog");
+
+        let mut subtypes = build_subtypes(&semantic_model, &LuaTypeDeclId::new("Animal"))
+            .unwrap()
+            .into_iter()
+            .map(|item| item.name)
+            .collect::<Vec<_>>();
+        subtypes.sort();
+        assert_eq!(subtypes, vec!["Cat", "Dog"]);
+    }
+
+    #[test]
+    fn test_type_hierarchy_capability() {
+        let capabilities = server_capabilities(&ClientCapabilities::default());
+        assert_eq!(capabilities["typeHierarchyProvider"], true);
+
+        let client_capabilities = ClientCapabilities {
+            text_document: Some(TextDocumentClientCapabilities {
+                type_hierarchy: Some(TypeHierarchyClientCapabilities {
+                    dynamic_registration: Some(true),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let capabilities = server_capabilities(&client_capabilities);
+        assert!(capabilities.get("typeHierarchyProvider").is_none());
+    }
+}
@@ -0,0 +1,46 @@
+use emmylua_code_analysis::uri_to_file_path;
+use lsp_types::{
+    ClientCapabilities, DidChangeWorkspaceFoldersParams, OneOf, ServerCapabilities,
+    WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+};
+
+use crate::context::ServerContextSnapshot;
+
+use super::RegisterCapabilities;
+
+pub async fn on_did_change_workspace_folders(
+    context: ServerContextSnapshot,
+    params: DidChangeWorkspaceFoldersParams,
+) -> Option<()> {
+    let added = params
+        .event
+        .added
+        .iter()
+        .filter_map(|folder| uri_to_file_path(&folder.uri))
+        .collect();
+    let removed = params
+        .event
+        .removed
+        .iter()
+        .filter_map(|folder| uri_to_file_path(&folder.uri))
+        .collect();
+
+    let mut workspace_manager = context.workspace_manager.write().await;
+    workspace_manager
+        .update_workspace_folders(added, removed)
+        .await
+}
+
+pub struct WorkspaceFoldersCapabilities;
+
+impl RegisterCapabilities for WorkspaceFoldersCapabilities {
+    fn register_capabilities(server_capabilities: &mut ServerCapabilities, _: &ClientCapabilities) {
+        let workspace = server_capabilities
+            .workspace
+            .get_or_insert_with(WorkspaceServerCapabilities::default);
+        workspace.workspace_folders = Some(WorkspaceFoldersServerCapabilities {
+            supported: Some(true),
+            change_notifications: Some(OneOf::Left(true)),
+        });
+    }
+}
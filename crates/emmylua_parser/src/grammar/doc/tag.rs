@@ -40,6 +40,7 @@ fn parse_tag_detail(p: &mut LuaDocParser) -> ParseResult {
         LuaTokenKind::TkTagParam => parse_tag_param(p),
         LuaTokenKind::TkTagReturn => parse_tag_return(p),
         LuaTokenKind::TkTagReturnCast => parse_tag_return_cast(p),
+        LuaTokenKind::TkTagFormatString => parse_tag_format_string(p),
         // other tag
         LuaTokenKind::TkTagModule => parse_tag_module(p),
         LuaTokenKind::TkTagSee => parse_tag_see(p),
@@ -356,6 +357,18 @@ fn parse_tag_return_cast(p: &mut LuaDocParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+// ---@format_string <param name>
+fn parse_tag_format_string(p: &mut LuaDocParser) -> ParseResult {
+    p.set_state(LuaDocLexerState::Normal);
+    let m = p.mark(LuaSyntaxKind::DocTagFormatString);
+    p.bump();
+    expect_token(p, LuaTokenKind::TkName)?;
+
+    p.set_state(LuaDocLexerState::Description);
+    parse_description(p);
+    Ok(m.complete(p))
+}
+
 // ---@generic T
 // ---@generic T, R
 // ---@generic T, R : number
@@ -2548,4 +2548,79 @@ Syntax(Chunk)@0..40
 
         assert_ast_eq!(code, result);
     }
+
+    #[test]
+    fn test_mapped_and_index_access_type() {
+        let code = r#"
+        ---@alias A { [K in keyof T]?: T[K] }
+        "#;
+        let result = r#"
+Syntax(Chunk)@0..55
+  Syntax(Block)@0..55
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..46
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagAlias)@13..46
+        Token(TkTagAlias)@13..18 "alias"
+        Token(TkWhitespace)@18..19 " "
+        Token(TkName)@19..20 "A"
+        Token(TkWhitespace)@20..21 " "
+        Syntax(TypeMapped)@21..46
+          Token(TkLeftBrace)@21..22 "{"
+          Token(TkWhitespace)@22..23 " "
+          Syntax(DocMappedKeys)@23..38
+            Token(TkLeftBracket)@23..24 "["
+            Syntax(TypeName)@24..25
+              Token(TkName)@24..25 "K"
+            Token(TkWhitespace)@25..26 " "
+            Token(TkIn)@26..28 "in"
+            Token(TkWhitespace)@28..29 " "
+            Syntax(TypeUnary)@29..36
+              Token(TkDocKeyOf)@29..34 "keyof"
+              Token(TkWhitespace)@34..35 " "
+              Syntax(TypeName)@35..36
+                Token(TkName)@35..36 "T"
+            Token(TkRightBracket)@36..37 "]"
+            Token(TkDocQuestion)@37..38 "?"
+          Token(TkColon)@38..39 ":"
+          Token(TkWhitespace)@39..40 " "
+          Syntax(TypeIndexAccess)@40..44
+            Syntax(TypeName)@40..41
+              Token(TkName)@40..41 "T"
+            Token(TkLeftBracket)@41..42 "["
+            Syntax(TypeName)@42..43
+              Token(TkName)@42..43 "K"
+            Token(TkRightBracket)@43..44 "]"
+          Token(TkWhitespace)@44..45 " "
+          Token(TkRightBrace)@45..46 "}"
+    Token(TkEndOfLine)@46..47 "\n"
+    Token(TkWhitespace)@47..55 "        "
+        "#;
+
+        assert_ast_eq!(code, result);
+    }
+
+    #[test]
+    fn test_format_string() {
+        let code = r#"
+        ---@format_string fmt
+        "#;
+        let result = r#"
+Syntax(Chunk)@0..39
+  Syntax(Block)@0..39
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..30
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagFormatString)@13..30
+        Token(TkTagFormatString)@13..26 "format_string"
+        Token(TkWhitespace)@26..27 " "
+        Token(TkName)@27..30 "fmt"
+    Token(TkEndOfLine)@30..31 "\n"
+    Token(TkWhitespace)@31..39 "        "
+        "#;
+
+        assert_ast_eq!(code, result);
+    }
 }
@@ -142,12 +142,22 @@ fn parse_primary_type(p: &mut LuaDocParser) -> ParseResult {
 
 // { <name>: <type>, ... }
 // { <name> : <type>, ... }
+// { [<name> in <type>]: <type> }
 fn parse_object_or_mapped_type(p: &mut LuaDocParser) -> ParseResult {
-    let m = p.mark(LuaSyntaxKind::TypeObject);
+    let mut m = p.mark(LuaSyntaxKind::TypeObject);
     p.bump();
 
     if p.current_token() != LuaTokenKind::TkRightBrace {
-        parse_typed_field(p)?;
+        let cm = parse_typed_field(p)?;
+        if cm.kind == LuaSyntaxKind::DocMappedKeys {
+            m.set_kind(p, LuaSyntaxKind::TypeMapped);
+            expect_token(p, LuaTokenKind::TkColon)?;
+            parse_type(p)?;
+            if_token_bump(p, LuaTokenKind::TkComma);
+            expect_token(p, LuaTokenKind::TkRightBrace)?;
+            return Ok(m.complete(p));
+        }
+
         while p.current_token() == LuaTokenKind::TkComma {
             p.bump();
             if p.current_token() == LuaTokenKind::TkRightBrace {
@@ -167,8 +177,9 @@ fn parse_object_or_mapped_type(p: &mut LuaDocParser) -> ParseResult {
 // [<string>] : <type>
 // [<type>] : <type>
 // <name>? : <type>
+// [<name> in <type>]?
 fn parse_typed_field(p: &mut LuaDocParser) -> ParseResult {
-    let m = p.mark(LuaSyntaxKind::DocObjectField);
+    let mut m = p.mark(LuaSyntaxKind::DocObjectField);
     match p.current_token() {
         LuaTokenKind::TkName => {
             p.bump();
@@ -183,6 +194,16 @@ fn parse_typed_field(p: &mut LuaDocParser) -> ParseResult {
             } else {
                 parse_type(p)?;
             }
+
+            // the value type of a mapped type is parsed by the caller
+            if p.current_token() == LuaTokenKind::TkIn {
+                m.set_kind(p, LuaSyntaxKind::DocMappedKeys);
+                p.bump();
+                parse_type(p)?;
+                expect_token(p, LuaTokenKind::TkRightBracket)?;
+                if_token_bump(p, LuaTokenKind::TkDocQuestion);
+                return Ok(m.complete(p));
+            }
             expect_token(p, LuaTokenKind::TkRightBracket)?;
             if_token_bump(p, LuaTokenKind::TkDocQuestion);
         }
@@ -379,12 +400,10 @@ fn parse_suffixed_type(p: &mut LuaDocParser, cm: CompleteMarker) -> ParseResult
             LuaTokenKind::TkLeftBracket => {
                 let mut m = cm.precede(p, LuaSyntaxKind::TypeArray);
                 p.bump();
-                if matches!(
-                    p.current_token(),
-                    LuaTokenKind::TkString | LuaTokenKind::TkInt | LuaTokenKind::TkName
-                ) {
-                    m.set_kind(p, LuaSyntaxKind::IndexExpr);
-                    p.bump();
+                // <type>[<type>]
+                if p.current_token() != LuaTokenKind::TkRightBracket {
+                    m.set_kind(p, LuaSyntaxKind::TypeIndexAccess);
+                    parse_type(p)?;
                 }
                 expect_token(p, LuaTokenKind::TkRightBracket)?;
                 cm = m.complete(p);
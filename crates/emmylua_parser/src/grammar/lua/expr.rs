@@ -6,7 +6,11 @@ use crate::{
     SpecialFunction,
 };
 
-use super::{expect_token, if_token_bump, parse_block};
+use super::{
+    expect_token, if_token_bump, parse_block,
+    stat::{is_compound_assign, parse_optional_type_annotation},
+    types::{parse_generic_decl_list, parse_return_type_annotation},
+};
 
 pub fn parse_expr(p: &mut LuaParser) -> ParseResult {
     parse_sub_expr(p, 0)
@@ -34,8 +38,17 @@ fn parse_sub_expr(p: &mut LuaParser, limit: i32) -> ParseResult {
     };
 
     let mut bop = LuaOpKind::to_binary_operator(p.current_token());
-    while bop != BinaryOperator::OpNop && bop.get_priority().left > limit {
+    while bop != BinaryOperator::OpNop && bop.get_priority().left > limit && !is_compound_assign(p)
+    {
         let range = p.current_token_range();
+        if matches!(bop, BinaryOperator::OpBAnd | BinaryOperator::OpBOr)
+            && !p.parse_config.support_bitwise_operator()
+        {
+            p.push_error(LuaParseError::from_source_range(
+                &t!("bitwise operation is not supported"),
+                range,
+            ));
+        }
         let m = cm.precede(p, LuaSyntaxKind::BinaryExpr);
         p.bump();
         match parse_sub_expr(p, bop.get_priority().right) {
@@ -74,6 +87,7 @@ fn parse_simple_expr(p: &mut LuaParser) -> ParseResult {
         }
         LuaTokenKind::TkLeftBrace => parse_table_expr(p),
         LuaTokenKind::TkFunction => parse_closure_expr(p),
+        LuaTokenKind::TkInterpString => parse_interp_string_expr(p),
         _ => parse_suffixed_expr(p),
     }
 }
@@ -82,7 +96,13 @@ pub fn parse_closure_expr(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::ClosureExpr);
 
     if_token_bump(p, LuaTokenKind::TkFunction);
+    if p.parse_config.support_type_annotation() && p.current_token() == LuaTokenKind::TkLt {
+        parse_generic_decl_list(p)?;
+    }
     parse_param_list(p)?;
+    if p.parse_config.support_type_annotation() && p.current_token() == LuaTokenKind::TkColon {
+        parse_return_type_annotation(p)?;
+    }
 
     if p.current_token() != LuaTokenKind::TkEnd {
         parse_block(p)?;
@@ -119,6 +139,29 @@ fn parse_param_name(p: &mut LuaParser) -> ParseResult {
             p.current_token_range(),
         ));
     }
+    parse_optional_type_annotation(p)?;
+
+    Ok(m.complete(p))
+}
+
+// `text {expr} text`
+fn parse_interp_string_expr(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::InterpStringExpr);
+    loop {
+        let is_open = p.current_token_text().ends_with('{');
+        p.bump();
+        if !is_open {
+            break;
+        }
+
+        parse_expr(p)?;
+        if p.current_token() != LuaTokenKind::TkInterpString {
+            return Err(LuaParseError::from_source_range(
+                &t!("unfinished string"),
+                p.current_token_range(),
+            ));
+        }
+    }
 
     Ok(m.complete(p))
 }
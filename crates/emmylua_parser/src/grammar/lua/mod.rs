@@ -11,6 +11,7 @@ use super::ParseResult;
 mod expr;
 mod stat;
 mod test;
+mod types;
 
 pub fn parse_chunk(p: &mut LuaParser) {
     let m = p.mark(LuaSyntaxKind::Block);
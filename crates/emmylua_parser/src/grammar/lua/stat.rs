@@ -9,6 +9,7 @@ use super::{
     expect_token,
     expr::{parse_closure_expr, parse_expr},
     if_token_bump, parse_block,
+    types::{parse_generic_decl_list, parse_type, parse_type_annotation},
 };
 
 pub fn parse_stats(p: &mut LuaParser) {
@@ -54,6 +55,10 @@ fn parse_stat(p: &mut LuaParser) -> ParseResult {
         LuaTokenKind::TkGoto => parse_goto(p)?,
         LuaTokenKind::TkDbColon => parse_label_stat(p)?,
         LuaTokenKind::TkSemicolon => parse_empty_stat(p)?,
+        LuaTokenKind::TkName if is_global_stat(p) => parse_global(p)?,
+        LuaTokenKind::TkName if is_continue_stat(p) => parse_continue(p)?,
+        LuaTokenKind::TkName if is_unsupported_goto_stat(p) => parse_goto(p)?,
+        LuaTokenKind::TkName if is_type_alias_stat(p) => parse_type_alias(p)?,
         _ => parse_assign_or_expr_stat(p)?,
     };
 
@@ -125,6 +130,7 @@ fn parse_for(p: &mut LuaParser) -> ParseResult {
     let mut m = p.mark(LuaSyntaxKind::ForStat);
     p.bump();
     expect_token(p, LuaTokenKind::TkName)?;
+    parse_optional_type_annotation(p)?;
     match p.current_token() {
         LuaTokenKind::TkAssign => {
             p.bump();
@@ -141,6 +147,7 @@ fn parse_for(p: &mut LuaParser) -> ParseResult {
             while p.current_token() == LuaTokenKind::TkComma {
                 p.bump();
                 expect_token(p, LuaTokenKind::TkName)?;
+                parse_optional_type_annotation(p)?;
             }
 
             expect_token(p, LuaTokenKind::TkIn)?;
@@ -241,16 +248,96 @@ fn parse_local(p: &mut LuaParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+fn is_global_stat(p: &LuaParser) -> bool {
+    p.parse_config.support_global_decl()
+        && p.current_token_text() == "global"
+        && matches!(
+            p.peek_next_token(),
+            LuaTokenKind::TkName
+                | LuaTokenKind::TkFunction
+                | LuaTokenKind::TkLt
+                | LuaTokenKind::TkMul
+        )
+}
+
+// global function f() end
+// global <const> a, b = 1, 2
+// global <const> *
+fn parse_global(p: &mut LuaParser) -> ParseResult {
+    let mut m = p.mark(LuaSyntaxKind::GlobalStat);
+    p.remap_current_token(LuaTokenKind::TkGlobal);
+    p.bump();
+    if p.current_token() == LuaTokenKind::TkFunction {
+        p.bump();
+        m.set_kind(p, LuaSyntaxKind::FuncStat);
+        parse_func_name(p)?;
+        parse_closure_expr(p)?;
+        if_token_bump(p, LuaTokenKind::TkSemicolon);
+        return Ok(m.complete(p));
+    }
+
+    if p.current_token() == LuaTokenKind::TkLt {
+        parse_attrib(p)?;
+    }
+
+    if p.current_token() == LuaTokenKind::TkMul {
+        p.bump();
+        if_token_bump(p, LuaTokenKind::TkSemicolon);
+        return Ok(m.complete(p));
+    }
+
+    parse_global_name(p)?;
+    while p.current_token() == LuaTokenKind::TkComma {
+        p.bump();
+        parse_global_name(p)?;
+    }
+
+    if p.current_token() == LuaTokenKind::TkAssign {
+        p.bump();
+        parse_expr(p)?;
+        while p.current_token() == LuaTokenKind::TkComma {
+            p.bump();
+            parse_expr(p)?;
+        }
+    }
+
+    if_token_bump(p, LuaTokenKind::TkSemicolon);
+    Ok(m.complete(p))
+}
+
+fn parse_global_name(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::NameExpr);
+    expect_token(p, LuaTokenKind::TkName)?;
+    let cm = m.complete(p);
+    if p.current_token() == LuaTokenKind::TkLt {
+        parse_attrib(p)?;
+    }
+
+    Ok(cm)
+}
+
 fn parse_local_name(p: &mut LuaParser, support_attrib: bool) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::LocalName);
     expect_token(p, LuaTokenKind::TkName)?;
-    if support_attrib && p.current_token() == LuaTokenKind::TkLt {
-        parse_attrib(p)?;
+    if support_attrib {
+        parse_optional_type_annotation(p)?;
+        if p.current_token() == LuaTokenKind::TkLt {
+            parse_attrib(p)?;
+        }
     }
 
     Ok(m.complete(p))
 }
 
+// luau: <name>: <type>
+pub(super) fn parse_optional_type_annotation(p: &mut LuaParser) -> Result<(), LuaParseError> {
+    if p.parse_config.support_type_annotation() && p.current_token() == LuaTokenKind::TkColon {
+        parse_type_annotation(p)?;
+    }
+
+    Ok(())
+}
+
 fn parse_attrib(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::Attribute);
     let range: crate::text::SourceRange = p.current_token_range();
@@ -292,6 +379,73 @@ fn parse_break(p: &mut LuaParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+fn is_continue_stat(p: &LuaParser) -> bool {
+    // `continue` is still a valid name in luau, so it is only a statement when it can not be
+    // the start of an expression
+    p.parse_config.support_continue()
+        && p.current_token_text() == "continue"
+        && !matches!(
+            p.peek_next_token(),
+            LuaTokenKind::TkAssign
+                | LuaTokenKind::TkComma
+                | LuaTokenKind::TkDot
+                | LuaTokenKind::TkColon
+                | LuaTokenKind::TkLeftBracket
+                | LuaTokenKind::TkLeftParen
+                | LuaTokenKind::TkLeftBrace
+                | LuaTokenKind::TkString
+                | LuaTokenKind::TkLongString
+                | LuaTokenKind::TkPlus
+                | LuaTokenKind::TkMinus
+                | LuaTokenKind::TkMul
+                | LuaTokenKind::TkDiv
+                | LuaTokenKind::TkIDiv
+                | LuaTokenKind::TkMod
+                | LuaTokenKind::TkPow
+                | LuaTokenKind::TkConcat
+        )
+}
+
+fn parse_continue(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::ContinueStat);
+    p.remap_current_token(LuaTokenKind::TkContinue);
+    p.bump();
+    if_token_bump(p, LuaTokenKind::TkSemicolon);
+    Ok(m.complete(p))
+}
+
+fn is_type_alias_stat(p: &LuaParser) -> bool {
+    if !p.parse_config.support_type_annotation() || p.peek_next_token() != LuaTokenKind::TkName {
+        return false;
+    }
+
+    matches!(p.current_token_text(), "type" | "export")
+}
+
+// type Name<T> = <type>
+// export type Name = <type>
+fn parse_type_alias(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::TypeAliasStat);
+    if p.current_token_text() == "export" {
+        p.bump();
+        if p.current_token_text() != "type" {
+            return Err(LuaParseError::from_source_range(
+                &t!("expected type after export"),
+                p.current_token_range(),
+            ));
+        }
+    }
+    p.bump();
+    expect_token(p, LuaTokenKind::TkName)?;
+    if p.current_token() == LuaTokenKind::TkLt {
+        parse_generic_decl_list(p)?;
+    }
+    expect_token(p, LuaTokenKind::TkAssign)?;
+    parse_type(p)?;
+    if_token_bump(p, LuaTokenKind::TkSemicolon);
+    Ok(m.complete(p))
+}
+
 fn parse_repeat(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::RepeatStat);
     p.bump();
@@ -302,8 +456,26 @@ fn parse_repeat(p: &mut LuaParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+fn is_unsupported_goto_stat(p: &LuaParser) -> bool {
+    // `goto` is a name before lua 5.2, parse `goto label` as a goto statement to report a clear
+    // error instead of an unexpected name
+    !p.parse_config.lexer_config().support_goto()
+        && p.current_token_text() == "goto"
+        && p.peek_next_token() == LuaTokenKind::TkName
+}
+
 fn parse_goto(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::GotoStat);
+    if p.current_token() == LuaTokenKind::TkName {
+        p.errors.push(LuaParseError::from_source_range(
+            &t!(
+                "goto is not supported for current version: %{level}",
+                level = p.parse_config.level
+            ),
+            p.current_token_range(),
+        ));
+        p.remap_current_token(LuaTokenKind::TkGoto);
+    }
     p.bump();
     expect_token(p, LuaTokenKind::TkName)?;
     if_token_bump(p, LuaTokenKind::TkSemicolon);
@@ -341,6 +513,15 @@ fn parse_assign_or_expr_stat(p: &mut LuaParser) -> ParseResult {
         ));
     }
 
+    // luau: a += 1
+    if is_compound_assign(p) {
+        p.bump();
+        p.bump();
+        parse_expr(p)?;
+        if_token_bump(p, LuaTokenKind::TkSemicolon);
+        return Ok(m.complete(p));
+    }
+
     while p.current_token() == LuaTokenKind::TkComma {
         p.bump();
         cm = parse_expr(p)?;
@@ -370,6 +551,22 @@ fn parse_assign_or_expr_stat(p: &mut LuaParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+pub(super) fn is_compound_assign(p: &LuaParser) -> bool {
+    p.parse_config.support_compound_assign()
+        && matches!(
+            p.current_token(),
+            LuaTokenKind::TkPlus
+                | LuaTokenKind::TkMinus
+                | LuaTokenKind::TkMul
+                | LuaTokenKind::TkDiv
+                | LuaTokenKind::TkIDiv
+                | LuaTokenKind::TkMod
+                | LuaTokenKind::TkPow
+                | LuaTokenKind::TkConcat
+        )
+        && p.peek_next_token() == LuaTokenKind::TkAssign
+}
+
 fn parse_label_stat(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::LabelStat);
     p.bump();
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::{parser::ParserConfig, LuaParser};
+    use std::collections::HashMap;
+
+    use crate::{parser::ParserConfig, LuaLanguageLevel, LuaParser, LuaSyntaxKind};
 
     macro_rules! assert_ast_eq {
         ($lua_code:expr, $expected:expr) => {
@@ -1115,4 +1117,165 @@ Syntax(Chunk)@0..4
 
         assert_ast_eq!(code, result);
     }
+
+    #[test]
+    fn test_global_stat() {
+        let code = "global <const> a, b = 1\nglobal *\nglobal function f() end\nglobal = 1";
+        let result = r#"
+Syntax(Chunk)@0..67
+  Syntax(Block)@0..67
+    Syntax(GlobalStat)@0..23
+      Token(TkGlobal)@0..6 "global"
+      Token(TkWhitespace)@6..7 " "
+      Syntax(Attribute)@7..14
+        Token(TkLt)@7..8 "<"
+        Token(TkName)@8..13 "const"
+        Token(TkGt)@13..14 ">"
+      Token(TkWhitespace)@14..15 " "
+      Syntax(NameExpr)@15..16
+        Token(TkName)@15..16 "a"
+      Token(TkComma)@16..17 ","
+      Token(TkWhitespace)@17..18 " "
+      Syntax(NameExpr)@18..19
+        Token(TkName)@18..19 "b"
+      Token(TkWhitespace)@19..20 " "
+      Token(TkAssign)@20..21 "="
+      Token(TkWhitespace)@21..22 " "
+      Syntax(LiteralExpr)@22..23
+        Token(TkInt)@22..23 "1"
+    Token(TkEndOfLine)@23..24 "\n"
+    Syntax(GlobalStat)@24..32
+      Token(TkGlobal)@24..30 "global"
+      Token(TkWhitespace)@30..31 " "
+      Token(TkMul)@31..32 "*"
+    Token(TkEndOfLine)@32..33 "\n"
+    Syntax(FuncStat)@33..56
+      Token(TkGlobal)@33..39 "global"
+      Token(TkWhitespace)@39..40 " "
+      Token(TkFunction)@40..48 "function"
+      Token(TkWhitespace)@48..49 " "
+      Syntax(NameExpr)@49..50
+        Token(TkName)@49..50 "f"
+      Syntax(ClosureExpr)@50..56
+        Syntax(ParamList)@50..52
+          Token(TkLeftParen)@50..51 "("
+          Token(TkRightParen)@51..52 ")"
+        Token(TkWhitespace)@52..53 " "
+        Token(TkEnd)@53..56 "end"
+    Token(TkEndOfLine)@56..57 "\n"
+    Syntax(AssignStat)@57..67
+      Syntax(NameExpr)@57..63
+        Token(TkName)@57..63 "global"
+      Token(TkWhitespace)@63..64 " "
+      Token(TkAssign)@64..65 "="
+      Token(TkWhitespace)@65..66 " "
+      Syntax(LiteralExpr)@66..67
+        Token(TkInt)@66..67 "1"
+        "#;
+
+        let config = ParserConfig::new(LuaLanguageLevel::Lua55, None, HashMap::new());
+        let tree = LuaParser::parse(code, config);
+        assert!(tree.get_errors().is_empty());
+        let ast = format!("{:#?}", tree.get_red_root());
+        assert_eq!(ast.trim(), result.trim());
+    }
+
+    #[test]
+    fn test_luau_syntax() {
+        let code = r#"
+export type Point<T = number> = { x: T, y: T }
+type Callback = (name: string, ...any) -> (boolean, string?)
+type Mode = | "a" | "b"
+
+local count: number = 0
+count += 1
+count //= 2
+local name = `count is {count + 1}!`
+
+local function map<T, U>(list: {T}, f: (T) -> U): {U}
+    local result: {U} = {}
+    for i: number, v in ipairs(list) do
+        if v == nil then
+            continue
+        end
+        result[i] = f(v)
+    end
+    return result
+end
+
+local continue = 1
+continue = continue + 1
+type = 1
+"#;
+
+        let config = ParserConfig::new(LuaLanguageLevel::Luau, None, HashMap::new());
+        let tree = LuaParser::parse(code, config);
+        assert!(tree.get_errors().is_empty(), "{:?}", tree.get_errors());
+        let kinds: Vec<LuaSyntaxKind> = tree
+            .get_red_root()
+            .descendants()
+            .map(|node| node.kind().into())
+            .collect();
+        assert_eq!(
+            kinds
+                .iter()
+                .filter(|kind| **kind == LuaSyntaxKind::TypeAliasStat)
+                .count(),
+            3
+        );
+        assert_eq!(
+            kinds
+                .iter()
+                .filter(|kind| **kind == LuaSyntaxKind::ContinueStat)
+                .count(),
+            1
+        );
+        assert!(kinds.contains(&LuaSyntaxKind::InterpStringExpr));
+        assert!(kinds.contains(&LuaSyntaxKind::TypeFun));
+        assert!(kinds.contains(&LuaSyntaxKind::DocGenericDeclareList));
+
+        let tree = LuaParser::parse("local x: number = 1", ParserConfig::default());
+        assert!(!tree.get_errors().is_empty());
+    }
+
+    #[test]
+    fn test_luau_no_bitwise_operator() {
+        for code in ["a &= 1", "a |= 1", "local x = a & b", "local x = a | b"] {
+            let config = ParserConfig::new(LuaLanguageLevel::Luau, None, HashMap::new());
+            let tree = LuaParser::parse(code, config);
+            assert!(!tree.get_errors().is_empty(), "{}", code);
+        }
+
+        let config = ParserConfig::new(LuaLanguageLevel::Lua54, None, HashMap::new());
+        let tree = LuaParser::parse("local x = a & b | c", config);
+        assert!(tree.get_errors().is_empty());
+    }
+
+    #[test]
+    fn test_goto_before_lua52() {
+        let code = r#"
+for i = 1, 10 do
+    goto continue
+    ::continue::
+end
+local goto = 1
+goto = goto + 1
+"#;
+
+        let config = ParserConfig::new(LuaLanguageLevel::Lua51, None, HashMap::new());
+        let tree = LuaParser::parse(code, config);
+        assert_eq!(tree.get_errors().len(), 1);
+        let kinds: Vec<LuaSyntaxKind> = tree
+            .get_red_root()
+            .descendants()
+            .map(|node| node.kind().into())
+            .collect();
+        assert_eq!(
+            kinds
+                .iter()
+                .filter(|kind| **kind == LuaSyntaxKind::GotoStat)
+                .count(),
+            1
+        );
+    }
 }
@@ -0,0 +1,304 @@
+use crate::{
+    grammar::ParseResult,
+    kind::{LuaSyntaxKind, LuaTokenKind},
+    parser::{LuaParser, MarkerEventContainer},
+    parser_error::LuaParseError,
+};
+
+use super::{expect_token, if_token_bump};
+
+// luau type annotations are parsed into the same nodes as the doc types, so the analyzer
+// can infer them like a `---@type`
+
+// : <type>
+pub fn parse_type_annotation(p: &mut LuaParser) -> Result<(), LuaParseError> {
+    expect_token(p, LuaTokenKind::TkColon)?;
+    parse_type(p)?;
+    Ok(())
+}
+
+// : <type>
+// : (<type>, <type>)
+// : ...<type>
+pub fn parse_return_type_annotation(p: &mut LuaParser) -> ParseResult {
+    expect_token(p, LuaTokenKind::TkColon)?;
+    parse_return_type_list(p)
+}
+
+// < T, U... >
+pub fn parse_generic_decl_list(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocGenericDeclareList);
+    expect_token(p, LuaTokenKind::TkLt)?;
+    parse_generic_param(p)?;
+    while p.current_token() == LuaTokenKind::TkComma {
+        p.bump();
+        parse_generic_param(p)?;
+    }
+    expect_token(p, LuaTokenKind::TkGt)?;
+    Ok(m.complete(p))
+}
+
+// T
+// T...
+// T = <type>
+fn parse_generic_param(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocGenericParameter);
+    expect_token(p, LuaTokenKind::TkName)?;
+    if_token_bump(p, LuaTokenKind::TkDots);
+    if p.current_token() == LuaTokenKind::TkAssign {
+        p.bump();
+        parse_type(p)?;
+    }
+    Ok(m.complete(p))
+}
+
+// <type> | <type>
+pub fn parse_type(p: &mut LuaParser) -> ParseResult {
+    // leading operator of a multi line union: `| "a" | "b"`
+    if p.current_token() == LuaTokenKind::TkBitOr {
+        p.remap_current_token(LuaTokenKind::TkDocOr);
+        p.bump();
+    }
+
+    let mut cm = parse_intersection_type(p)?;
+    while p.current_token() == LuaTokenKind::TkBitOr {
+        let m = cm.precede(p, LuaSyntaxKind::TypeBinary);
+        p.remap_current_token(LuaTokenKind::TkDocOr);
+        p.bump();
+        parse_intersection_type(p)?;
+        cm = m.complete(p);
+    }
+
+    Ok(cm)
+}
+
+// <type> & <type>
+fn parse_intersection_type(p: &mut LuaParser) -> ParseResult {
+    if p.current_token() == LuaTokenKind::TkBitAnd {
+        p.remap_current_token(LuaTokenKind::TkDocAnd);
+        p.bump();
+    }
+
+    let mut cm = parse_nullable_type(p)?;
+    while p.current_token() == LuaTokenKind::TkBitAnd {
+        let m = cm.precede(p, LuaSyntaxKind::TypeBinary);
+        p.remap_current_token(LuaTokenKind::TkDocAnd);
+        p.bump();
+        parse_nullable_type(p)?;
+        cm = m.complete(p);
+    }
+
+    Ok(cm)
+}
+
+// <type>?
+fn parse_nullable_type(p: &mut LuaParser) -> ParseResult {
+    let mut cm = parse_primary_type(p)?;
+    while is_question(p) {
+        let m = cm.precede(p, LuaSyntaxKind::TypeNullable);
+        p.remap_current_token(LuaTokenKind::TkDocQuestion);
+        p.bump();
+        cm = m.complete(p);
+    }
+
+    Ok(cm)
+}
+
+fn is_question(p: &LuaParser) -> bool {
+    p.current_token() == LuaTokenKind::TkUnknown && p.current_token_text() == "?"
+}
+
+fn parse_primary_type(p: &mut LuaParser) -> ParseResult {
+    match p.current_token() {
+        LuaTokenKind::TkName => parse_name_or_generic_type(p),
+        LuaTokenKind::TkNil
+        | LuaTokenKind::TkTrue
+        | LuaTokenKind::TkFalse
+        | LuaTokenKind::TkString => {
+            let m = p.mark(LuaSyntaxKind::TypeLiteral);
+            p.bump();
+            Ok(m.complete(p))
+        }
+        LuaTokenKind::TkLeftBrace => parse_table_type(p),
+        LuaTokenKind::TkLeftParen => {
+            if p.peek_token_after_paren_group() == LuaTokenKind::TkArrow {
+                parse_func_type(p)
+            } else {
+                p.bump();
+                let cm = parse_type(p)?;
+                expect_token(p, LuaTokenKind::TkRightParen)?;
+                Ok(cm)
+            }
+        }
+        LuaTokenKind::TkDots => {
+            let m = p.mark(LuaSyntaxKind::TypeVariadic);
+            p.bump();
+            parse_nullable_type(p)?;
+            Ok(m.complete(p))
+        }
+        _ => Err(LuaParseError::from_source_range(
+            &t!("expect type"),
+            p.current_token_range(),
+        )),
+    }
+}
+
+// <name>
+// <module>.<name>
+// <name><<type>, ...>
+fn parse_name_or_generic_type(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::TypeName);
+    p.bump();
+    while p.current_token() == LuaTokenKind::TkDot {
+        p.bump();
+        expect_token(p, LuaTokenKind::TkName)?;
+    }
+    let cm = m.complete(p);
+    if p.current_token() != LuaTokenKind::TkLt {
+        return Ok(cm);
+    }
+
+    let m = cm.precede(p, LuaSyntaxKind::TypeGeneric);
+    p.bump();
+    let list = p.mark(LuaSyntaxKind::DocTypeList);
+    parse_type(p)?;
+    while p.current_token() == LuaTokenKind::TkComma {
+        p.bump();
+        parse_type(p)?;
+    }
+    list.complete(p);
+    expect_token(p, LuaTokenKind::TkGt)?;
+    Ok(m.complete(p))
+}
+
+// { <type> }
+// { <name>: <type>, [<type>]: <type> }
+fn parse_table_type(p: &mut LuaParser) -> ParseResult {
+    let mut m = p.mark(LuaSyntaxKind::TypeObject);
+    p.bump();
+    if p.current_token() == LuaTokenKind::TkRightBrace {
+        p.bump();
+        return Ok(m.complete(p));
+    }
+
+    let is_field = p.current_token() == LuaTokenKind::TkLeftBracket
+        || (p.current_token() == LuaTokenKind::TkName
+            && p.peek_next_token() == LuaTokenKind::TkColon);
+    if !is_field {
+        m.set_kind(p, LuaSyntaxKind::TypeArray);
+        parse_type(p)?;
+        expect_token(p, LuaTokenKind::TkRightBrace)?;
+        return Ok(m.complete(p));
+    }
+
+    parse_table_type_field(p)?;
+    while matches!(
+        p.current_token(),
+        LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon
+    ) {
+        p.bump();
+        if p.current_token() == LuaTokenKind::TkRightBrace {
+            break;
+        }
+        parse_table_type_field(p)?;
+    }
+
+    expect_token(p, LuaTokenKind::TkRightBrace)?;
+    Ok(m.complete(p))
+}
+
+// <name>: <type>
+// [<type>]: <type>
+fn parse_table_type_field(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocObjectField);
+    match p.current_token() {
+        LuaTokenKind::TkName => p.bump(),
+        LuaTokenKind::TkLeftBracket => {
+            p.bump();
+            if p.current_token() == LuaTokenKind::TkString {
+                p.bump();
+            } else {
+                parse_type(p)?;
+            }
+            expect_token(p, LuaTokenKind::TkRightBracket)?;
+        }
+        _ => {
+            return Err(LuaParseError::from_source_range(
+                &t!("expect name or [<type>]"),
+                p.current_token_range(),
+            ));
+        }
+    }
+
+    parse_type_annotation(p)?;
+    Ok(m.complete(p))
+}
+
+// (<name>: <type>, <type>, ...<type>) -> <type>
+fn parse_func_type(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::TypeFun);
+    expect_token(p, LuaTokenKind::TkLeftParen)?;
+    if p.current_token() != LuaTokenKind::TkRightParen {
+        parse_func_type_param(p)?;
+        while p.current_token() == LuaTokenKind::TkComma {
+            p.bump();
+            parse_func_type_param(p)?;
+        }
+    }
+    expect_token(p, LuaTokenKind::TkRightParen)?;
+    expect_token(p, LuaTokenKind::TkArrow)?;
+    parse_return_type_list(p)?;
+    Ok(m.complete(p))
+}
+
+fn parse_func_type_param(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocTypedParameter);
+    match p.current_token() {
+        LuaTokenKind::TkName if p.peek_next_token() == LuaTokenKind::TkColon => {
+            p.bump();
+            parse_type_annotation(p)?;
+        }
+        LuaTokenKind::TkDots => {
+            p.bump();
+            if p.current_token() == LuaTokenKind::TkColon {
+                parse_type_annotation(p)?;
+            } else {
+                parse_nullable_type(p)?;
+            }
+        }
+        _ => {
+            parse_type(p)?;
+        }
+    }
+
+    Ok(m.complete(p))
+}
+
+// <type>
+// (<type>, <type>)
+fn parse_return_type_list(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocTypeList);
+    if p.current_token() == LuaTokenKind::TkLeftParen
+        && p.peek_token_after_paren_group() != LuaTokenKind::TkArrow
+    {
+        p.bump();
+        if p.current_token() != LuaTokenKind::TkRightParen {
+            parse_return_type(p)?;
+            while p.current_token() == LuaTokenKind::TkComma {
+                p.bump();
+                parse_return_type(p)?;
+            }
+        }
+        expect_token(p, LuaTokenKind::TkRightParen)?;
+    } else {
+        parse_return_type(p)?;
+    }
+
+    Ok(m.complete(p))
+}
+
+fn parse_return_type(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocNamedReturnType);
+    parse_type(p)?;
+    Ok(m.complete(p))
+}
@@ -6,7 +6,9 @@ pub enum LuaLanguageLevel {
     Lua52,
     Lua53,
     Lua54,
+    Lua55,
     LuaJIT,
+    Luau,
 }
 
 impl fmt::Display for LuaLanguageLevel {
@@ -16,7 +18,9 @@ impl fmt::Display for LuaLanguageLevel {
             LuaLanguageLevel::Lua52 => write!(f, "Lua 5.2"),
             LuaLanguageLevel::Lua53 => write!(f, "Lua 5.3"),
             LuaLanguageLevel::Lua54 => write!(f, "Lua 5.4"),
+            LuaLanguageLevel::Lua55 => write!(f, "Lua 5.5"),
             LuaLanguageLevel::LuaJIT => write!(f, "LuaJIT"),
+            LuaLanguageLevel::Luau => write!(f, "Luau"),
         }
     }
 }
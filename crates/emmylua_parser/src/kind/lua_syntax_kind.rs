@@ -23,10 +23,13 @@ pub enum LuaSyntaxKind {
     FuncStat,
     LabelStat,
     BreakStat,
+    ContinueStat,
     ReturnStat,
     GotoStat,
     CallExprStat,
     AssignStat,
+    GlobalStat,
+    TypeAliasStat,
     UnknownStat,
 
     // expressions
@@ -46,6 +49,7 @@ pub enum LuaSyntaxKind {
     SetmetatableCallExpr, // setmetatable(a, b)
     IndexExpr,
     NameExpr,
+    InterpStringExpr, // `a {b} c`
 
     // other
     LocalName,
@@ -89,6 +93,7 @@ pub enum LuaSyntaxKind {
     DocTagSource,
     DocTagReadonly,
     DocTagReturnCast,
+    DocTagFormatString,
 
     // doc Type
     TypeArray,          // baseType []
@@ -108,8 +113,9 @@ pub enum LuaSyntaxKind {
 
     // follow donot support now
     TypeMatch,
+
     TypeIndexAccess, // type[keyType]
-    TypeMapped,      // { [p in KeyType]+? : ValueType }
+    TypeMapped,      // { [p in KeyType]? : ValueType }
 
     // doc other
     DocObjectField,
@@ -133,4 +139,9 @@ pub enum LuaSyntaxKind {
 
     // [<|>] [<framework>] <version>, <version> can be '5.1', '5.2', '5.3', '5.4', 'JIT', <framework> can be 'openresty'
     DocVersion,
+    // update `LuaSyntaxKind::LAST` when adding a variant here
+}
+
+impl LuaSyntaxKind {
+    pub(crate) const LAST: LuaSyntaxKind = LuaSyntaxKind::DocVersion;
 }
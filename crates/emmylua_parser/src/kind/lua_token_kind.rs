@@ -27,6 +27,8 @@ pub enum LuaTokenKind {
     TkTrue,
     TkUntil,
     TkWhile,
+    TkGlobal,   // only a keyword at the start of a `global` declaration in Lua 5.5
+    TkContinue, // only a keyword as a statement in Luau
 
     TkWhitespace,   // whitespace
     TkEndOfLine,    // end of line
@@ -63,6 +65,7 @@ pub enum LuaTokenKind {
     TkRightParen,   // )
     TkLeftBrace,    // {
     TkRightBrace,   // }
+    TkArrow,        // ->
     TkComplex,      // complex
     TkInt,          // int
     TkFloat,        // float
@@ -70,6 +73,7 @@ pub enum LuaTokenKind {
     TkName,         // name
     TkString,       // string
     TkLongString,   // long string
+    TkInterpString, // part of a luau interpolated string
     TkShortComment, // short comment
     TkLongComment,  // long comment
     TkShebang,      // shebang
@@ -93,30 +97,31 @@ pub enum LuaTokenKind {
     TkTagAlias,     // alias
     TkTagModule,    // module
 
-    TkTagField,      // field
-    TkTagType,       // type
-    TkTagParam,      // param
-    TkTagReturn,     // return
-    TkTagOverload,   // overload
-    TkTagGeneric,    // generic
-    TkTagSee,        // see
-    TkTagDeprecated, // deprecated
-    TkTagAsync,      // async
-    TkTagCast,       // cast
-    TkTagOther,      // other
-    TkTagVisibility, // public private protected package
-    TkTagReadonly,   // readonly
-    TkTagDiagnostic, // diagnostic
-    TkTagMeta,       // meta
-    TkTagVersion,    // version
-    TkTagAs,         // as
-    TkTagNodiscard,  // nodiscard
-    TkTagOperator,   // operator
-    TkTagMapping,    // mapping
-    TkTagNamespace,  // namespace
-    TkTagUsing,      // using
-    TkTagSource,     // source
-    TkTagReturnCast, // return cast
+    TkTagField,        // field
+    TkTagType,         // type
+    TkTagParam,        // param
+    TkTagReturn,       // return
+    TkTagOverload,     // overload
+    TkTagGeneric,      // generic
+    TkTagSee,          // see
+    TkTagDeprecated,   // deprecated
+    TkTagAsync,        // async
+    TkTagCast,         // cast
+    TkTagOther,        // other
+    TkTagVisibility,   // public private protected package
+    TkTagReadonly,     // readonly
+    TkTagDiagnostic,   // diagnostic
+    TkTagMeta,         // meta
+    TkTagVersion,      // version
+    TkTagAs,           // as
+    TkTagNodiscard,    // nodiscard
+    TkTagOperator,     // operator
+    TkTagMapping,      // mapping
+    TkTagNamespace,    // namespace
+    TkTagUsing,        // using
+    TkTagSource,       // source
+    TkTagReturnCast,   // return cast
+    TkTagFormatString, // format_string
 
     TkDocOr,              // |
     TkDocAnd,             // &
@@ -139,6 +144,11 @@ pub enum LuaTokenKind {
     TkDocRegion,          // region
     TkDocEndRegion,       // endregion
     TkDocSeeContent,      // see content
+                          // update `LuaTokenKind::LAST` when adding a variant here
+}
+
+impl LuaTokenKind {
+    pub(crate) const LAST: LuaTokenKind = LuaTokenKind::TkDocSeeContent;
 }
 
 impl fmt::Display for LuaTokenKind {
@@ -1,6 +1,8 @@
 use std::fmt;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct LuaVersionNumber {
     pub major: u32,
     pub minor: u32,
@@ -66,7 +68,7 @@ impl fmt::Display for LuaVersionNumber {
 }
 
 #[allow(unused)]
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LuaVersionCondition {
     Eq(LuaVersionNumber),
     Gte(LuaVersionNumber),
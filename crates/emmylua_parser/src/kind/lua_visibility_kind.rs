@@ -1,4 +1,6 @@
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum VisibilityKind {
     None,
     Public,
@@ -15,6 +15,9 @@ pub use lua_type_operator_kind::{
 };
 pub use lua_version::{LuaVersionCondition, LuaVersionNumber};
 pub use lua_visibility_kind::VisibilityKind;
+use std::{collections::HashMap, sync::OnceLock};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u16)]
@@ -69,6 +72,25 @@ impl LuaKind {
         }
     }
 
+    /// The name of the kind, it doesn't change when variants are added to the kind enums
+    pub fn get_name(self) -> String {
+        format!("{:?}", self)
+    }
+
+    pub fn from_name(name: &str) -> Option<LuaKind> {
+        static KINDS: OnceLock<HashMap<String, LuaKind>> = OnceLock::new();
+        let kinds = KINDS.get_or_init(|| {
+            let syntax_kinds =
+                (0..=LuaSyntaxKind::LAST as u16).map(|raw| LuaKind::from_raw(raw | 0x8000));
+            let token_kinds = (0..=LuaTokenKind::LAST as u16).map(LuaKind::from_raw);
+            syntax_kinds
+                .chain(token_kinds)
+                .map(|kind| (kind.get_name(), kind))
+                .collect()
+        });
+        kinds.get(name).copied()
+    }
+
     pub fn from_raw(raw: u16) -> LuaKind {
         if raw & 0x8000 != 0 {
             LuaKind::Syntax(unsafe { std::mem::transmute(raw & 0x7FFF) })
@@ -78,6 +100,28 @@ impl LuaKind {
     }
 }
 
+// the kinds are saved by name, since a variant added in the middle of an enum shifts the raw
+// values of the variants after it
+impl Serialize for LuaKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.get_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for LuaKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        LuaKind::from_name(&name)
+            .ok_or_else(|| de::Error::custom(format!("unknown kind: {}", name)))
+    }
+}
+
 #[derive(Debug)]
 pub struct PriorityTable {
     pub left: i32,
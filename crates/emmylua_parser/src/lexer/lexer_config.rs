@@ -12,6 +12,7 @@ impl LexerConfig {
             LuaLanguageLevel::Lua52
                 | LuaLanguageLevel::Lua53
                 | LuaLanguageLevel::Lua54
+                | LuaLanguageLevel::Lua55
                 | LuaLanguageLevel::LuaJIT
         )
     }
@@ -31,19 +32,33 @@ impl LexerConfig {
     pub fn support_integer_operation(&self) -> bool {
         matches!(
             self.language_level,
-            LuaLanguageLevel::Lua53 | LuaLanguageLevel::Lua54
+            LuaLanguageLevel::Lua53 | LuaLanguageLevel::Lua54 | LuaLanguageLevel::Lua55
         )
     }
 
+    pub fn support_integer_division(&self) -> bool {
+        self.support_integer_operation() || self.language_level == LuaLanguageLevel::Luau
+    }
+
     pub fn support_pow_operator(&self) -> bool {
         matches!(
             self.language_level,
             LuaLanguageLevel::Lua52
                 | LuaLanguageLevel::Lua53
                 | LuaLanguageLevel::Lua54
+                | LuaLanguageLevel::Lua55
                 | LuaLanguageLevel::LuaJIT
+                | LuaLanguageLevel::Luau
         )
     }
+
+    pub fn support_type_annotation(&self) -> bool {
+        self.language_level == LuaLanguageLevel::Luau
+    }
+
+    pub fn support_interpolated_string(&self) -> bool {
+        self.language_level == LuaLanguageLevel::Luau
+    }
 }
 
 impl Default for LexerConfig {
@@ -550,6 +550,7 @@ fn to_tag(text: &str) -> LuaTokenKind {
         "param" => LuaTokenKind::TkTagParam,
         "return" => LuaTokenKind::TkTagReturn,
         "return_cast" => LuaTokenKind::TkTagReturnCast,
+        "format_string" => LuaTokenKind::TkTagFormatString,
         "generic" => LuaTokenKind::TkTagGeneric,
         "see" => LuaTokenKind::TkTagSee,
         "overload" => LuaTokenKind::TkTagOverload,
@@ -588,6 +589,7 @@ fn to_token_or_name(text: &str) -> LuaTokenKind {
         "false" => LuaTokenKind::TkFalse,
         "keyof" => LuaTokenKind::TkDocKeyOf,
         "extends" => LuaTokenKind::TkDocExtends,
+        "in" => LuaTokenKind::TkIn,
         "as" => LuaTokenKind::TkDocAs,
         "and" => LuaTokenKind::TkAnd,
         "or" => LuaTokenKind::TkOr,
@@ -6,6 +6,8 @@ pub struct LuaLexer<'a> {
     reader: Reader<'a>,
     lexer_config: LexerConfig,
     errors: &'a mut Vec<LuaParseError>,
+    // brace depth of every open interpolation in a luau interpolated string
+    interp_brace_depths: Vec<usize>,
 }
 
 impl LuaLexer<'_> {
@@ -18,6 +20,7 @@ impl LuaLexer<'_> {
             reader: Reader::new(text),
             lexer_config,
             errors,
+            interp_brace_depths: Vec::new(),
         }
     }
 
@@ -78,6 +81,11 @@ impl LuaLexer<'_> {
             ' ' | '\t' => self.lex_white_space(),
             '-' => {
                 self.reader.bump();
+                if self.reader.current_char() == '>' && self.lexer_config.support_type_annotation()
+                {
+                    self.reader.bump();
+                    return LuaTokenKind::TkArrow;
+                }
                 if self.reader.current_char() != '-' {
                     return LuaTokenKind::TkMinus;
                 }
@@ -149,7 +157,8 @@ impl LuaLexer<'_> {
                         self.reader.bump();
                         LuaTokenKind::TkGe
                     }
-                    '>' => {
+                    // luau has no shift operators, `>>` closes nested generic types
+                    '>' if !self.lexer_config.support_type_annotation() => {
                         if !self.lexer_config.support_integer_operation() {
                             self.errors.push(LuaParseError::from_source_range(
                                 &t!("bitwise operation is not supported"),
@@ -248,7 +257,7 @@ impl LuaLexer<'_> {
                 if self.reader.current_char() != '/' {
                     return LuaTokenKind::TkDiv;
                 }
-                if !self.lexer_config.support_integer_operation() {
+                if !self.lexer_config.support_integer_division() {
                     self.errors.push(LuaParseError::from_source_range(
                         &t!("integer division is not supported"),
                         self.reader.saved_range(),
@@ -290,7 +299,10 @@ impl LuaLexer<'_> {
                 LuaTokenKind::TkShebang
             }
             '&' => {
-                if !self.lexer_config.support_integer_operation() {
+                // luau uses `&` for intersection types
+                if !self.lexer_config.support_integer_operation()
+                    && !self.lexer_config.support_type_annotation()
+                {
                     self.errors.push(LuaParseError::from_source_range(
                         &t!("bitwise operation is not supported"),
                         self.reader.saved_range(),
@@ -301,7 +313,10 @@ impl LuaLexer<'_> {
                 LuaTokenKind::TkBitAnd
             }
             '|' => {
-                if !self.lexer_config.support_integer_operation() {
+                // luau uses `|` for union types
+                if !self.lexer_config.support_integer_operation()
+                    && !self.lexer_config.support_type_annotation()
+                {
                     self.errors.push(LuaParseError::from_source_range(
                         &t!("bitwise operation is not supported"),
                         self.reader.saved_range(),
@@ -321,11 +336,25 @@ impl LuaLexer<'_> {
             }
             '{' => {
                 self.reader.bump();
+                if let Some(depth) = self.interp_brace_depths.last_mut() {
+                    *depth += 1;
+                }
                 LuaTokenKind::TkLeftBrace
             }
             '}' => {
                 self.reader.bump();
-                LuaTokenKind::TkRightBrace
+                match self.interp_brace_depths.last_mut() {
+                    Some(0) => self.lex_interp_string(false),
+                    Some(depth) => {
+                        *depth -= 1;
+                        LuaTokenKind::TkRightBrace
+                    }
+                    None => LuaTokenKind::TkRightBrace,
+                }
+            }
+            '`' if self.lexer_config.support_interpolated_string() => {
+                self.reader.bump();
+                self.lex_interp_string(true)
             }
             ']' => {
                 self.reader.bump();
@@ -417,6 +446,48 @@ impl LuaLexer<'_> {
         LuaTokenKind::TkLongString
     }
 
+    // `text {expr} text`
+    // every part of the string up to an opening `{` or the closing backtick is one token
+    fn lex_interp_string(&mut self, is_begin: bool) -> LuaTokenKind {
+        while !self.reader.is_eof() {
+            match self.reader.current_char() {
+                '`' => {
+                    self.reader.bump();
+                    if !is_begin {
+                        self.interp_brace_depths.pop();
+                    }
+                    return LuaTokenKind::TkInterpString;
+                }
+                '{' => {
+                    self.reader.bump();
+                    if is_begin {
+                        self.interp_brace_depths.push(0);
+                    }
+                    return LuaTokenKind::TkInterpString;
+                }
+                '\\' => {
+                    self.reader.bump();
+                    if !self.reader.is_eof() {
+                        self.reader.bump();
+                    }
+                }
+                '\n' | '\r' => break,
+                _ => {
+                    self.reader.bump();
+                }
+            }
+        }
+
+        self.errors.push(LuaParseError::from_source_range(
+            &t!("unfinished string"),
+            self.reader.saved_range(),
+        ));
+        if !is_begin {
+            self.interp_brace_depths.pop();
+        }
+        LuaTokenKind::TkInterpString
+    }
+
     fn lex_number(&mut self) -> LuaTokenKind {
         enum NumberState {
             Int,
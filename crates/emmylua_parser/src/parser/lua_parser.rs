@@ -119,6 +119,14 @@ impl<'a> LuaParser<'a> {
         &self.text[range.start_offset..range.end_offset()]
     }
 
+    /// Changes the kind of the current token, used for contextual keywords like `global`.
+    pub fn remap_current_token(&mut self, kind: LuaTokenKind) {
+        if self.token_index < self.tokens.len() {
+            self.tokens[self.token_index].kind = kind;
+            self.current_token = kind;
+        }
+    }
+
     pub fn bump(&mut self) {
         if !is_invalid_kind(self.current_token) && self.token_index < self.tokens.len() {
             let token = &self.tokens[self.token_index];
@@ -152,6 +160,33 @@ impl<'a> LuaParser<'a> {
         }
     }
 
+    /// Returns the first token after the parenthesized group which starts at the current `(`.
+    pub fn peek_token_after_paren_group(&self) -> LuaTokenKind {
+        let mut depth = 0;
+        let mut index = self.token_index;
+        while index < self.tokens.len() {
+            match self.tokens[index].kind {
+                LuaTokenKind::TkLeftParen => depth += 1,
+                LuaTokenKind::TkRightParen => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+
+        let mut next_index = index + 1;
+        self.skip_trivia(&mut next_index);
+        if next_index >= self.tokens.len() {
+            LuaTokenKind::None
+        } else {
+            self.tokens[next_index].kind
+        }
+    }
+
     fn skip_trivia(&self, index: &mut usize) {
         if index >= &mut self.tokens.len() {
             return;
@@ -0,0 +1,226 @@
+use rowan::TextRange;
+
+use crate::{
+    kind::{LuaSyntaxKind, LuaTokenKind},
+    parser_error::LuaParseError,
+    LuaSyntaxNode, LuaSyntaxTree,
+};
+
+use super::{LuaParser, ParserConfig};
+
+impl<'a> LuaParser<'a> {
+    /// Reparses `text` after `edit_range` of the old text was replaced by `edit_text`.
+    ///
+    /// Only the smallest statement closed by `end` that contains the edit is parsed again, the
+    /// green nodes of the rest of `old_tree` are reused. When no such statement can be reparsed
+    /// on its own the whole text is parsed again.
+    pub fn reparse(
+        text: &'a str,
+        old_tree: &LuaSyntaxTree,
+        edit_range: TextRange,
+        edit_text: &str,
+        mut config: ParserConfig,
+    ) -> LuaSyntaxTree {
+        if let Some(tree) = reparse_block_stat(text, old_tree, edit_range, edit_text, &mut config) {
+            return tree;
+        }
+
+        LuaParser::parse(text, config)
+    }
+}
+
+fn reparse_block_stat(
+    text: &str,
+    old_tree: &LuaSyntaxTree,
+    edit_range: TextRange,
+    edit_text: &str,
+    config: &mut ParserConfig,
+) -> Option<LuaSyntaxTree> {
+    let root = old_tree.get_red_root();
+    if edit_range.end() > root.text_range().end() {
+        return None;
+    }
+
+    let old_node = find_block_stat(&root, edit_range)?;
+    let old_range = old_node.text_range();
+    let new_len = u32::from(old_range.len()) + edit_text.len() as u32 - u32::from(edit_range.len());
+    let new_range = TextRange::at(old_range.start(), new_len.into());
+    let node_text = text.get(usize::from(new_range.start())..usize::from(new_range.end()))?;
+
+    let node_tree = LuaParser::parse(node_text, config.reborrow());
+    if !node_tree.get_errors().is_empty() {
+        return None;
+    }
+
+    // the new text must still be exactly one statement of the same kind, otherwise the edit
+    // changed how the surrounding code is parsed
+    let chunk = node_tree.get_red_root();
+    let block = chunk.first_child()?;
+    let mut elements = block.children_with_tokens();
+    let new_node = elements.next()?.into_node()?;
+    if elements.next().is_some()
+        || new_node.kind() != old_node.kind()
+        || new_node.text_range() != chunk.text_range()
+        || !is_closed_by_end(&new_node)
+    {
+        return None;
+    }
+
+    let errors = shift_errors(old_tree.get_errors(), old_range, new_range)?;
+    let new_root = old_node.replace_with(new_node.green().into_owned());
+    Some(LuaSyntaxTree::new(new_root, errors))
+}
+
+/// Finds the smallest statement which strictly contains `edit_range` and ends with `end`.
+///
+/// Such a statement never depends on the tokens after it, so it can be parsed on its own.
+fn find_block_stat(root: &LuaSyntaxNode, edit_range: TextRange) -> Option<LuaSyntaxNode> {
+    let element = root.covering_element(edit_range);
+    let node = match element {
+        rowan::NodeOrToken::Node(node) => node,
+        rowan::NodeOrToken::Token(token) => token.parent()?,
+    };
+
+    node.ancestors().find(|node| {
+        let range = node.text_range();
+        is_block_stat_kind(node.kind().into())
+            && range.start() < edit_range.start()
+            && edit_range.end() < range.end()
+            && is_closed_by_end(node)
+    })
+}
+
+fn is_block_stat_kind(kind: LuaSyntaxKind) -> bool {
+    matches!(
+        kind,
+        LuaSyntaxKind::DoStat
+            | LuaSyntaxKind::WhileStat
+            | LuaSyntaxKind::ForStat
+            | LuaSyntaxKind::ForRangeStat
+            | LuaSyntaxKind::IfStat
+            | LuaSyntaxKind::FuncStat
+            | LuaSyntaxKind::LocalFuncStat
+    )
+}
+
+fn is_closed_by_end(node: &LuaSyntaxNode) -> bool {
+    node.last_token()
+        .is_some_and(|token| token.kind() == LuaTokenKind::TkEnd.into())
+}
+
+/// Drops the errors of the replaced node and moves the errors after it by the edit delta.
+fn shift_errors(
+    errors: &[LuaParseError],
+    old_range: TextRange,
+    new_range: TextRange,
+) -> Option<Vec<LuaParseError>> {
+    let mut result = Vec::new();
+    for error in errors {
+        if error.range.end() <= old_range.start() {
+            result.push(error.clone());
+        } else if error.range.start() >= old_range.end() {
+            let start = error.range.start() - old_range.end() + new_range.end();
+            result.push(LuaParseError {
+                message: error.message.clone(),
+                range: TextRange::at(start, error.range.len()),
+            });
+        } else if !old_range.contains_range(error.range) {
+            // the error depends on the text on both sides of the node
+            return None;
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use rowan::{TextRange, TextSize};
+
+    use crate::{LuaParser, ParserConfig};
+
+    fn apply_edit(text: &str, start: usize, end: usize, edit_text: &str) -> String {
+        format!("{}{}{}", &text[..start], edit_text, &text[end..])
+    }
+
+    /// Replaces `old` with `new` and checks that the reparsed tree is the tree of a full parse
+    fn check_reparse(text: &str, old: &str, new: &str) {
+        let old_tree = LuaParser::parse(text, ParserConfig::default());
+        let start = text.find(old).unwrap();
+        let end = start + old.len();
+        let new_text = apply_edit(text, start, end, new);
+        let edit_range = TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32));
+
+        let reparsed = LuaParser::reparse(
+            &new_text,
+            &old_tree,
+            edit_range,
+            new,
+            ParserConfig::default(),
+        );
+        let full = LuaParser::parse(&new_text, ParserConfig::default());
+        let reparsed_dump = format!("{:#?}", reparsed.get_red_root());
+        let full_dump = format!("{:#?}", full.get_red_root());
+        assert_eq!(
+            reparsed_dump, full_dump,
+            "the reparsed tree differs from the full parse after replacing {:?} with {:?}",
+            old, new
+        );
+        assert_eq!(reparsed.get_errors(), full.get_errors());
+    }
+
+    #[test]
+    fn test_reparse_function_body() {
+        let code = r#"
+            local a = 1
+
+            function foo(x)
+                return x + a
+            end
+
+            local b = foo(2)
+        "#;
+        check_reparse(code, "x + a", "x * a + 1");
+        check_reparse(code, "return x + a", "");
+        check_reparse(code, "x + a", "x, 'a\nb'");
+    }
+
+    #[test]
+    fn test_reparse_nested_block() {
+        let code = r#"
+            local function foo(list)
+                for i = 1, #list do
+                    if list[i] then
+                        print(i)
+                    end
+                end
+            end
+        "#;
+        check_reparse(code, "print(i)", "print(i, list[i])");
+        check_reparse(code, "print(i)", "print(i) end");
+        check_reparse(code, "print(i)", "--[[ print(i)");
+    }
+
+    #[test]
+    fn test_reparse_keep_errors() {
+        let code = r#"
+            local a = = 1
+
+            do
+                local b = 1
+            end
+
+            local c = = 2
+        "#;
+        check_reparse(code, "local b = 1", "local bb = 11");
+    }
+
+    #[test]
+    fn test_reparse_top_level() {
+        let code = r#"
+            local a = 1
+            local b = 2
+        "#;
+        check_reparse(code, "b = 2", "b = a + 2");
+    }
+}
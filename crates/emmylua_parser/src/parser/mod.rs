@@ -1,5 +1,6 @@
 mod lua_doc_parser;
 mod lua_parser;
+mod lua_reparser;
 mod marker;
 mod parser_config;
 
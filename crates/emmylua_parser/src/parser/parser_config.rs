@@ -32,13 +32,48 @@ impl<'cache> ParserConfig<'cache> {
     }
 
     pub fn support_local_attrib(&self) -> bool {
-        self.level == LuaLanguageLevel::Lua54
+        matches!(
+            self.level,
+            LuaLanguageLevel::Lua54 | LuaLanguageLevel::Lua55
+        )
+    }
+
+    pub fn support_global_decl(&self) -> bool {
+        self.level == LuaLanguageLevel::Lua55
+    }
+
+    pub fn support_compound_assign(&self) -> bool {
+        self.level == LuaLanguageLevel::Luau
+    }
+
+    /// The lexer reports the bitwise operators of the older levels, luau lexes `&` and `|` for
+    /// its intersection and union types but has no bitwise operators.
+    pub fn support_bitwise_operator(&self) -> bool {
+        self.level != LuaLanguageLevel::Luau
+    }
+
+    pub fn support_continue(&self) -> bool {
+        self.level == LuaLanguageLevel::Luau
+    }
+
+    pub fn support_type_annotation(&self) -> bool {
+        self.lexer_config.support_type_annotation()
     }
 
     pub fn node_cache(&mut self) -> Option<&mut NodeCache> {
         self.node_cache.as_deref_mut()
     }
 
+    /// Creates a config with the same settings which shares this config's node cache.
+    pub fn reborrow(&mut self) -> ParserConfig<'_> {
+        ParserConfig {
+            level: self.level,
+            lexer_config: self.lexer_config,
+            node_cache: self.node_cache.as_deref_mut(),
+            special_like: self.special_like.clone(),
+        }
+    }
+
     pub fn get_special_function(&self, name: &str) -> SpecialFunction {
         match name {
             "require" => SpecialFunction::Require,
@@ -171,11 +171,10 @@ impl Serialize for LuaSyntaxId {
     where
         S: Serializer,
     {
-        let kind_raw = self.kind.get_raw();
         let start = u32::from(self.range.start());
         let end = u32::from(self.range.end());
         let range_combined = ((start as u64) << 32) | (end as u64);
-        let value = format!("{:x}:{:x}", kind_raw, range_combined);
+        let value = format!("{}:{:x}", self.kind.get_name(), range_combined);
         serializer.serialize_str(&value)
     }
 }
@@ -203,8 +202,8 @@ impl<'de> Deserialize<'de> for LuaSyntaxId {
                     return Err(E::custom("expected format 'kind:range'"));
                 }
 
-                let kind_raw = u16::from_str_radix(parts[0], 16)
-                    .map_err(|e| E::custom(format!("invalid kind: {}", e)))?;
+                let kind = LuaKind::from_name(parts[0])
+                    .ok_or_else(|| E::custom(format!("invalid kind: {}", parts[0])))?;
                 let range_combined = u64::from_str_radix(parts[1], 16)
                     .map_err(|e| E::custom(format!("invalid range: {}", e)))?;
 
@@ -212,7 +211,7 @@ impl<'de> Deserialize<'de> for LuaSyntaxId {
                 let end = TextSize::new((range_combined & 0xFFFFFFFF) as u32);
 
                 Ok(LuaSyntaxId {
-                    kind: LuaKind::from_raw(kind_raw),
+                    kind,
                     range: TextRange::new(start, end),
                 })
             }
@@ -359,6 +359,54 @@ impl LuaDocObjectField {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocMappedKeys {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocMappedKeys {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::DocMappedKeys
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocMappedKeys {
+    /// The name of the key parameter, `K` in `[K in keyof T]`
+    pub fn get_param_name_type(&self) -> Option<LuaDocNameType> {
+        match self.children::<LuaDocType>().next()? {
+            LuaDocType::Name(name_type) => Some(name_type),
+            _ => None,
+        }
+    }
+
+    /// The keys to map, `keyof T` in `[K in keyof T]`
+    pub fn get_constraint_type(&self) -> Option<LuaDocType> {
+        self.children().nth(1)
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.token_by_kind(LuaTokenKind::TkDocQuestion).is_some()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LuaDocObjectFieldKey {
     Name(LuaNameToken),
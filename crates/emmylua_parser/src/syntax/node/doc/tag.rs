@@ -39,6 +39,7 @@ pub enum LuaDocTag {
     As(LuaDocTagAs),
     Visibility(LuaDocTagVisibility),
     ReturnCast(LuaDocTagReturnCast),
+    FormatString(LuaDocTagFormatString),
 }
 
 impl LuaAstNode for LuaDocTag {
@@ -71,6 +72,7 @@ impl LuaAstNode for LuaDocTag {
             LuaDocTag::As(it) => it.syntax(),
             LuaDocTag::Visibility(it) => it.syntax(),
             LuaDocTag::ReturnCast(it) => it.syntax(),
+            LuaDocTag::FormatString(it) => it.syntax(),
         }
     }
 
@@ -105,6 +107,7 @@ impl LuaAstNode for LuaDocTag {
             || kind == LuaSyntaxKind::DocTagAs
             || kind == LuaSyntaxKind::DocTagVisibility
             || kind == LuaSyntaxKind::DocTagReturnCast
+            || kind == LuaSyntaxKind::DocTagFormatString
     }
 
     fn cast(syntax: LuaSyntaxNode) -> Option<Self>
@@ -189,6 +192,9 @@ impl LuaAstNode for LuaDocTag {
             LuaSyntaxKind::DocTagReturnCast => Some(LuaDocTag::ReturnCast(
                 LuaDocTagReturnCast::cast(syntax).unwrap(),
             )),
+            LuaSyntaxKind::DocTagFormatString => Some(LuaDocTag::FormatString(
+                LuaDocTagFormatString::cast(syntax).unwrap(),
+            )),
             _ => None,
         }
     }
@@ -1452,3 +1458,37 @@ impl LuaDocTagReturnCast {
         self.token()
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocTagFormatString {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocTagFormatString {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool {
+        kind == LuaSyntaxKind::DocTagFormatString
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocDescriptionOwner for LuaDocTagFormatString {}
+
+impl LuaDocTagFormatString {
+    pub fn get_name_token(&self) -> Option<LuaNameToken> {
+        self.token()
+    }
+}
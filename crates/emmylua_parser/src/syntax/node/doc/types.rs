@@ -3,7 +3,7 @@ use crate::{
     LuaLiteralToken, LuaNameToken, LuaSyntaxKind, LuaSyntaxNode, LuaTokenKind,
 };
 
-use super::{LuaDocDescription, LuaDocObjectField, LuaDocTypeList};
+use super::{LuaDocDescription, LuaDocMappedKeys, LuaDocObjectField, LuaDocTypeList};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LuaDocType {
@@ -21,6 +21,8 @@ pub enum LuaDocType {
     Generic(LuaDocGenericType),
     StrTpl(LuaDocStrTplType),
     MultiLineUnion(LuaDocMultiLineUnionType),
+    IndexAccess(LuaDocIndexAccessType),
+    Mapped(LuaDocMappedType),
 }
 
 impl LuaAstNode for LuaDocType {
@@ -40,6 +42,8 @@ impl LuaAstNode for LuaDocType {
             LuaDocType::Generic(it) => it.syntax(),
             LuaDocType::StrTpl(it) => it.syntax(),
             LuaDocType::MultiLineUnion(it) => it.syntax(),
+            LuaDocType::IndexAccess(it) => it.syntax(),
+            LuaDocType::Mapped(it) => it.syntax(),
         }
     }
 
@@ -62,6 +66,8 @@ impl LuaAstNode for LuaDocType {
             LuaSyntaxKind::TypeGeneric => true,
             LuaSyntaxKind::TypeStringTemplate => true,
             LuaSyntaxKind::TypeMultiLineUnion => true,
+            LuaSyntaxKind::TypeIndexAccess => true,
+            LuaSyntaxKind::TypeMapped => true,
             _ => false,
         }
     }
@@ -99,6 +105,10 @@ impl LuaAstNode for LuaDocType {
             LuaSyntaxKind::TypeMultiLineUnion => Some(LuaDocType::MultiLineUnion(
                 LuaDocMultiLineUnionType::cast(syntax)?,
             )),
+            LuaSyntaxKind::TypeIndexAccess => Some(LuaDocType::IndexAccess(
+                LuaDocIndexAccessType::cast(syntax)?,
+            )),
+            LuaSyntaxKind::TypeMapped => Some(LuaDocType::Mapped(LuaDocMappedType::cast(syntax)?)),
             _ => None,
         }
     }
@@ -134,8 +144,9 @@ impl LuaAstNode for LuaDocNameType {
 }
 
 impl LuaDocNameType {
+    /// For a qualified luau type like `Module.Type` this is the last name.
     pub fn get_name_token(&self) -> Option<LuaNameToken> {
-        self.token()
+        self.tokens().last()
     }
 
     pub fn get_name_text(&self) -> Option<String> {
@@ -427,6 +438,83 @@ impl LuaDocConditionalType {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocIndexAccessType {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocIndexAccessType {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::TypeIndexAccess
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocIndexAccessType {
+    pub fn get_types(&self) -> Option<(LuaDocType, LuaDocType)> {
+        let mut children = self.children();
+        let base_type = children.next()?;
+        let key_type = children.next()?;
+        Some((base_type, key_type))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocMappedType {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocMappedType {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::TypeMapped
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocMappedType {
+    pub fn get_mapped_keys(&self) -> Option<LuaDocMappedKeys> {
+        self.child()
+    }
+
+    pub fn get_value_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LuaDocTupleType {
     syntax: LuaSyntaxNode,
@@ -5,7 +5,8 @@ use crate::{
         node::{LuaBinaryOpToken, LuaNameToken, LuaUnaryOpToken},
         traits::{LuaAstChildren, LuaAstNode},
     },
-    LuaAstToken, LuaIndexToken, LuaLiteralToken, LuaSyntaxNode, LuaSyntaxToken, LuaTokenKind,
+    LuaAstToken, LuaDocGenericDeclList, LuaDocTypeList, LuaIndexToken, LuaLiteralToken,
+    LuaSyntaxNode, LuaSyntaxToken, LuaTokenKind,
 };
 
 use super::{
@@ -23,6 +24,7 @@ pub enum LuaExpr {
     ParenExpr(LuaParenExpr),
     NameExpr(LuaNameExpr),
     IndexExpr(LuaIndexExpr),
+    InterpStringExpr(LuaInterpStringExpr),
 }
 
 impl LuaAstNode for LuaExpr {
@@ -37,6 +39,7 @@ impl LuaAstNode for LuaExpr {
             LuaExpr::ParenExpr(node) => node.syntax(),
             LuaExpr::NameExpr(node) => node.syntax(),
             LuaExpr::IndexExpr(node) => node.syntax(),
+            LuaExpr::InterpStringExpr(node) => node.syntax(),
         }
     }
 
@@ -61,6 +64,7 @@ impl LuaAstNode for LuaExpr {
             LuaSyntaxKind::ParenExpr => true,
             LuaSyntaxKind::NameExpr => true,
             LuaSyntaxKind::IndexExpr => true,
+            LuaSyntaxKind::InterpStringExpr => true,
             _ => false,
         }
     }
@@ -88,6 +92,9 @@ impl LuaAstNode for LuaExpr {
             LuaSyntaxKind::ParenExpr => LuaParenExpr::cast(syntax).map(LuaExpr::ParenExpr),
             LuaSyntaxKind::NameExpr => LuaNameExpr::cast(syntax).map(LuaExpr::NameExpr),
             LuaSyntaxKind::IndexExpr => LuaIndexExpr::cast(syntax).map(LuaExpr::IndexExpr),
+            LuaSyntaxKind::InterpStringExpr => {
+                LuaInterpStringExpr::cast(syntax).map(LuaExpr::InterpStringExpr)
+            }
             _ => None,
         }
     }
@@ -532,6 +539,48 @@ impl LuaLiteralExpr {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaInterpStringExpr {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaInterpStringExpr {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::InterpStringExpr
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaInterpStringExpr {
+    /// Returns the expressions interpolated between the braces.
+    pub fn get_exprs(&self) -> LuaAstChildren<LuaExpr> {
+        self.children()
+    }
+}
+
+impl From<LuaInterpStringExpr> for LuaExpr {
+    fn from(expr: LuaInterpStringExpr) -> Self {
+        LuaExpr::InterpStringExpr(expr)
+    }
+}
+
 impl From<LuaLiteralExpr> for LuaSingleArgExpr {
     fn from(expr: LuaLiteralExpr) -> Self {
         LuaSingleArgExpr::LiteralExpr(expr)
@@ -674,6 +723,16 @@ impl LuaClosureExpr {
     pub fn get_params_list(&self) -> Option<LuaParamList> {
         self.child()
     }
+
+    /// Returns the generic parameters of a luau function, `function f<T>(a: T) end`.
+    pub fn get_generic_decl_list(&self) -> Option<LuaDocGenericDeclList> {
+        self.child()
+    }
+
+    /// Returns the luau return type annotation, `function f(): number end`.
+    pub fn get_return_type_list(&self) -> Option<LuaDocTypeList> {
+        self.child()
+    }
 }
 
 impl From<LuaClosureExpr> for LuaExpr {
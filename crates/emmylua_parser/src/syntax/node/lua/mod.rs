@@ -6,7 +6,7 @@ mod test;
 use crate::{
     kind::{LuaSyntaxKind, LuaTokenKind},
     syntax::traits::{LuaAstChildren, LuaAstNode, LuaAstToken},
-    LuaCommentOwner, LuaSyntaxNode,
+    LuaCommentOwner, LuaDocType, LuaSyntaxNode,
 };
 
 pub use expr::*;
@@ -123,6 +123,11 @@ impl LuaLocalName {
     pub fn get_attrib(&self) -> Option<LuaLocalAttribute> {
         self.child()
     }
+
+    /// Returns the luau type annotation, `local a: number`.
+    pub fn get_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -439,6 +444,11 @@ impl LuaParamName {
     pub fn is_dots(&self) -> bool {
         self.token_by_kind(LuaTokenKind::TkDots).is_some()
     }
+
+    /// Returns the luau type annotation, `function f(a: number) end`.
+    pub fn get_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
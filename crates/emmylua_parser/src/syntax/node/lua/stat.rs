@@ -2,21 +2,22 @@ use crate::{
     kind::{LuaSyntaxKind, LuaTokenKind},
     syntax::{
         comment_trait::LuaCommentOwner,
-        node::LuaNameToken,
+        node::{LuaBinaryOpToken, LuaNameToken},
         traits::{LuaAstChildren, LuaAstNode, LuaAstTokenChildren},
     },
-    LuaSyntaxNode,
+    LuaDocGenericDeclList, LuaDocType, LuaSyntaxNode,
 };
 
 use super::{
-    expr::{LuaCallExpr, LuaClosureExpr, LuaExpr, LuaVarExpr},
-    LuaBlock, LuaLocalName,
+    expr::{LuaCallExpr, LuaClosureExpr, LuaExpr, LuaNameExpr, LuaVarExpr},
+    LuaBlock, LuaLocalAttribute, LuaLocalName,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LuaStat {
     LocalStat(LuaLocalStat),
     AssignStat(LuaAssignStat),
+    GlobalStat(LuaGlobalStat),
     CallExprStat(LuaCallExprStat),
     FuncStat(LuaFuncStat),
     LocalFuncStat(LuaLocalFuncStat),
@@ -27,10 +28,12 @@ pub enum LuaStat {
     ForRangeStat(LuaForRangeStat),
     RepeatStat(LuaRepeatStat),
     BreakStat(LuaBreakStat),
+    ContinueStat(LuaContinueStat),
     ReturnStat(LuaReturnStat),
     GotoStat(LuaGotoStat),
     LabelStat(LuaLabelStat),
     EmptyStat(LuaEmptyStat),
+    TypeAliasStat(LuaTypeAliasStat),
 }
 
 impl LuaAstNode for LuaStat {
@@ -38,6 +41,7 @@ impl LuaAstNode for LuaStat {
         match self {
             LuaStat::LocalStat(node) => node.syntax(),
             LuaStat::AssignStat(node) => node.syntax(),
+            LuaStat::GlobalStat(node) => node.syntax(),
             LuaStat::CallExprStat(node) => node.syntax(),
             LuaStat::FuncStat(node) => node.syntax(),
             LuaStat::LocalFuncStat(node) => node.syntax(),
@@ -48,10 +52,12 @@ impl LuaAstNode for LuaStat {
             LuaStat::ForRangeStat(node) => node.syntax(),
             LuaStat::RepeatStat(node) => node.syntax(),
             LuaStat::BreakStat(node) => node.syntax(),
+            LuaStat::ContinueStat(node) => node.syntax(),
             LuaStat::ReturnStat(node) => node.syntax(),
             LuaStat::GotoStat(node) => node.syntax(),
             LuaStat::LabelStat(node) => node.syntax(),
             LuaStat::EmptyStat(node) => node.syntax(),
+            LuaStat::TypeAliasStat(node) => node.syntax(),
         }
     }
 
@@ -62,6 +68,7 @@ impl LuaAstNode for LuaStat {
         match kind {
             LuaSyntaxKind::LocalStat => true,
             LuaSyntaxKind::AssignStat => true,
+            LuaSyntaxKind::GlobalStat => true,
             LuaSyntaxKind::CallExprStat => true,
             LuaSyntaxKind::FuncStat => true,
             LuaSyntaxKind::LocalFuncStat => true,
@@ -72,10 +79,12 @@ impl LuaAstNode for LuaStat {
             LuaSyntaxKind::ForRangeStat => true,
             LuaSyntaxKind::RepeatStat => true,
             LuaSyntaxKind::BreakStat => true,
+            LuaSyntaxKind::ContinueStat => true,
             LuaSyntaxKind::ReturnStat => true,
             LuaSyntaxKind::GotoStat => true,
             LuaSyntaxKind::LabelStat => true,
             LuaSyntaxKind::EmptyStat => true,
+            LuaSyntaxKind::TypeAliasStat => true,
             _ => false,
         }
     }
@@ -87,6 +96,7 @@ impl LuaAstNode for LuaStat {
         match syntax.kind().into() {
             LuaSyntaxKind::LocalStat => Some(LuaStat::LocalStat(LuaLocalStat::cast(syntax)?)),
             LuaSyntaxKind::AssignStat => Some(LuaStat::AssignStat(LuaAssignStat::cast(syntax)?)),
+            LuaSyntaxKind::GlobalStat => Some(LuaStat::GlobalStat(LuaGlobalStat::cast(syntax)?)),
             LuaSyntaxKind::CallExprStat => {
                 Some(LuaStat::CallExprStat(LuaCallExprStat::cast(syntax)?))
             }
@@ -103,10 +113,16 @@ impl LuaAstNode for LuaStat {
             }
             LuaSyntaxKind::RepeatStat => Some(LuaStat::RepeatStat(LuaRepeatStat::cast(syntax)?)),
             LuaSyntaxKind::BreakStat => Some(LuaStat::BreakStat(LuaBreakStat::cast(syntax)?)),
+            LuaSyntaxKind::ContinueStat => {
+                Some(LuaStat::ContinueStat(LuaContinueStat::cast(syntax)?))
+            }
             LuaSyntaxKind::ReturnStat => Some(LuaStat::ReturnStat(LuaReturnStat::cast(syntax)?)),
             LuaSyntaxKind::GotoStat => Some(LuaStat::GotoStat(LuaGotoStat::cast(syntax)?)),
             LuaSyntaxKind::LabelStat => Some(LuaStat::LabelStat(LuaLabelStat::cast(syntax)?)),
             LuaSyntaxKind::EmptyStat => Some(LuaStat::EmptyStat(LuaEmptyStat::cast(syntax)?)),
+            LuaSyntaxKind::TypeAliasStat => {
+                Some(LuaStat::TypeAliasStat(LuaTypeAliasStat::cast(syntax)?))
+            }
             _ => None,
         }
     }
@@ -284,6 +300,93 @@ impl LuaAssignStat {
 
         (vars, exprs)
     }
+
+    /// Returns the operator of a luau compound assignment like `a += 1`.
+    pub fn get_compound_op_token(&self) -> Option<LuaBinaryOpToken> {
+        self.token()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaGlobalStat {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaGlobalStat {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::GlobalStat
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if syntax.kind() == LuaSyntaxKind::GlobalStat.into() {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaCommentOwner for LuaGlobalStat {}
+
+impl LuaGlobalStat {
+    pub fn get_var_and_expr_list(&self) -> (Vec<LuaNameExpr>, Vec<LuaExpr>) {
+        let mut vars = Vec::new();
+        let mut exprs = Vec::new();
+        let mut meet_assign = false;
+        for child in self.syntax.children_with_tokens() {
+            if child.kind() == LuaTokenKind::TkAssign.into() {
+                meet_assign = true;
+            }
+
+            if let Some(node) = child.into_node() {
+                if meet_assign {
+                    if let Some(expr) = LuaExpr::cast(node) {
+                        exprs.push(expr);
+                    }
+                } else if let Some(var) = LuaNameExpr::cast(node) {
+                    vars.push(var);
+                }
+            }
+        }
+
+        (vars, exprs)
+    }
+
+    /// The attribute written right after `global`, it applies to every declared name.
+    pub fn get_attrib(&self) -> Option<LuaLocalAttribute> {
+        self.syntax
+            .children()
+            .take_while(|node| node.kind() != LuaSyntaxKind::NameExpr.into())
+            .find_map(LuaLocalAttribute::cast)
+    }
+
+    /// `global *` declares all the names which are not declared explicitly.
+    pub fn is_collective(&self) -> bool {
+        self.token_by_kind(LuaTokenKind::TkMul).is_some()
+    }
+
+    /// The attribute of a declared name, either written after the name or after `global`.
+    pub fn get_var_attrib(&self, var: &LuaNameExpr) -> Option<LuaLocalAttribute> {
+        if let Some(attrib) = var
+            .syntax()
+            .next_sibling()
+            .and_then(LuaLocalAttribute::cast)
+        {
+            return Some(attrib);
+        }
+
+        self.get_attrib()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -362,6 +465,11 @@ impl LuaFuncStat {
     pub fn get_closure(&self) -> Option<LuaClosureExpr> {
         self.child()
     }
+
+    /// `global function f() end` also declares `f` as a global.
+    pub fn is_global_decl(&self) -> bool {
+        self.token_by_kind(LuaTokenKind::TkGlobal).is_some()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -833,6 +941,37 @@ impl LuaAstNode for LuaBreakStat {
 
 impl LuaCommentOwner for LuaBreakStat {}
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaContinueStat {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaContinueStat {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::ContinueStat
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if syntax.kind() == LuaSyntaxKind::ContinueStat.into() {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaCommentOwner for LuaContinueStat {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LuaReturnStat {
     syntax: LuaSyntaxNode,
@@ -974,3 +1113,54 @@ impl LuaAstNode for LuaEmptyStat {
 }
 
 impl LuaCommentOwner for LuaEmptyStat {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaTypeAliasStat {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaTypeAliasStat {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::TypeAliasStat
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if syntax.kind() == LuaSyntaxKind::TypeAliasStat.into() {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaCommentOwner for LuaTypeAliasStat {}
+
+impl LuaTypeAliasStat {
+    pub fn is_export(&self) -> bool {
+        self.token::<LuaNameToken>()
+            .is_some_and(|token| token.get_name_text() == "export")
+    }
+
+    /// The alias name is the last name token, after the `export` and `type` keywords.
+    pub fn get_name_token(&self) -> Option<LuaNameToken> {
+        self.tokens::<LuaNameToken>().last()
+    }
+
+    pub fn get_generic_decl_list(&self) -> Option<LuaDocGenericDeclList> {
+        self.child()
+    }
+
+    pub fn get_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
+}
@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod tests {
     use crate::{
-        LuaAstNode, LuaCallExpr, LuaIndexExpr, LuaNameExpr, LuaParser, LuaSyntaxTree, ParserConfig,
-        PathTrait,
+        LuaAstNode, LuaCallExpr, LuaIndexExpr, LuaKind, LuaNameExpr, LuaParser, LuaSyntaxKind,
+        LuaSyntaxTree, LuaTokenKind, ParserConfig, PathTrait,
     };
 
     fn get_tree(code: &str) -> LuaSyntaxTree {
@@ -55,4 +55,20 @@ mod tests {
         let index_expr = root.descendants::<LuaIndexExpr>().next().unwrap();
         assert_eq!(index_expr.get_access_path().unwrap(), "name.[okok.yes]");
     }
+
+    #[test]
+    fn test_kind_name() {
+        let kinds: [LuaKind; 6] = [
+            LuaSyntaxKind::None.into(),
+            LuaSyntaxKind::Block.into(),
+            LuaSyntaxKind::LAST.into(),
+            LuaTokenKind::None.into(),
+            LuaTokenKind::TkName.into(),
+            LuaTokenKind::LAST.into(),
+        ];
+        for kind in kinds {
+            assert_eq!(LuaKind::from_name(&kind.get_name()), Some(kind));
+        }
+        assert_eq!(LuaKind::from_name("Syntax(Unknown)"), None);
+    }
 }
@@ -21,10 +21,13 @@ pub enum LuaAst {
     LuaBlock(LuaBlock),
     // stats
     LuaAssignStat(LuaAssignStat),
+    LuaGlobalStat(LuaGlobalStat),
     LuaLocalStat(LuaLocalStat),
     LuaCallExprStat(LuaCallExprStat),
     LuaLabelStat(LuaLabelStat),
     LuaBreakStat(LuaBreakStat),
+    LuaContinueStat(LuaContinueStat),
+    LuaTypeAliasStat(LuaTypeAliasStat),
     LuaGotoStat(LuaGotoStat),
     LuaDoStat(LuaDoStat),
     LuaWhileStat(LuaWhileStat),
@@ -45,6 +48,7 @@ pub enum LuaAst {
     LuaParenExpr(LuaParenExpr),
     LuaCallExpr(LuaCallExpr),
     LuaLiteralExpr(LuaLiteralExpr),
+    LuaInterpStringExpr(LuaInterpStringExpr),
     LuaClosureExpr(LuaClosureExpr),
 
     // other lua struct
@@ -86,6 +90,7 @@ pub enum LuaAst {
     LuaDocTagAsync(LuaDocTagAsync),
     LuaDocTagAs(LuaDocTagAs),
     LuaDocTagReturnCast(LuaDocTagReturnCast),
+    LuaDocTagFormatString(LuaDocTagFormatString),
 
     // doc type
     LuaDocNameType(LuaDocNameType),
@@ -95,6 +100,8 @@ pub enum LuaAst {
     LuaDocBinaryType(LuaDocBinaryType),
     LuaDocUnaryType(LuaDocUnaryType),
     LuaDocConditionalType(LuaDocConditionalType),
+    LuaDocIndexAccessType(LuaDocIndexAccessType),
+    LuaDocMappedType(LuaDocMappedType),
     LuaDocTupleType(LuaDocTupleType),
     LuaDocLiteralType(LuaDocLiteralType),
     LuaDocVariadicType(LuaDocVariadicType),
@@ -111,10 +118,13 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaChunk(node) => node.syntax(),
             LuaAst::LuaBlock(node) => node.syntax(),
             LuaAst::LuaAssignStat(node) => node.syntax(),
+            LuaAst::LuaGlobalStat(node) => node.syntax(),
             LuaAst::LuaLocalStat(node) => node.syntax(),
             LuaAst::LuaCallExprStat(node) => node.syntax(),
             LuaAst::LuaLabelStat(node) => node.syntax(),
             LuaAst::LuaBreakStat(node) => node.syntax(),
+            LuaAst::LuaContinueStat(node) => node.syntax(),
+            LuaAst::LuaTypeAliasStat(node) => node.syntax(),
             LuaAst::LuaGotoStat(node) => node.syntax(),
             LuaAst::LuaDoStat(node) => node.syntax(),
             LuaAst::LuaWhileStat(node) => node.syntax(),
@@ -133,6 +143,7 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaParenExpr(node) => node.syntax(),
             LuaAst::LuaCallExpr(node) => node.syntax(),
             LuaAst::LuaLiteralExpr(node) => node.syntax(),
+            LuaAst::LuaInterpStringExpr(node) => node.syntax(),
             LuaAst::LuaClosureExpr(node) => node.syntax(),
             LuaAst::LuaComment(node) => node.syntax(),
             LuaAst::LuaTableField(node) => node.syntax(),
@@ -169,6 +180,7 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaDocTagAsync(node) => node.syntax(),
             LuaAst::LuaDocTagAs(node) => node.syntax(),
             LuaAst::LuaDocTagReturnCast(node) => node.syntax(),
+            LuaAst::LuaDocTagFormatString(node) => node.syntax(),
             LuaAst::LuaDocNameType(node) => node.syntax(),
             LuaAst::LuaDocArrayType(node) => node.syntax(),
             LuaAst::LuaDocFuncType(node) => node.syntax(),
@@ -176,6 +188,8 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaDocBinaryType(node) => node.syntax(),
             LuaAst::LuaDocUnaryType(node) => node.syntax(),
             LuaAst::LuaDocConditionalType(node) => node.syntax(),
+            LuaAst::LuaDocIndexAccessType(node) => node.syntax(),
+            LuaAst::LuaDocMappedType(node) => node.syntax(),
             LuaAst::LuaDocTupleType(node) => node.syntax(),
             LuaAst::LuaDocLiteralType(node) => node.syntax(),
             LuaAst::LuaDocVariadicType(node) => node.syntax(),
@@ -194,10 +208,13 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::Chunk => true,
             LuaSyntaxKind::Block => true,
             LuaSyntaxKind::AssignStat => true,
+            LuaSyntaxKind::GlobalStat => true,
             LuaSyntaxKind::LocalStat => true,
             LuaSyntaxKind::CallExprStat => true,
             LuaSyntaxKind::LabelStat => true,
             LuaSyntaxKind::BreakStat => true,
+            LuaSyntaxKind::ContinueStat => true,
+            LuaSyntaxKind::TypeAliasStat => true,
             LuaSyntaxKind::GotoStat => true,
             LuaSyntaxKind::DoStat => true,
             LuaSyntaxKind::WhileStat => true,
@@ -223,6 +240,7 @@ impl LuaAstNode for LuaAst {
             | LuaSyntaxKind::TypeCallExpr
             | LuaSyntaxKind::SetmetatableCallExpr => true,
             LuaSyntaxKind::LiteralExpr => true,
+            LuaSyntaxKind::InterpStringExpr => true,
             LuaSyntaxKind::ClosureExpr => true,
             LuaSyntaxKind::ParamList => true,
             LuaSyntaxKind::CallArgList => true,
@@ -259,6 +277,7 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::DocTagAsync => true,
             LuaSyntaxKind::DocTagAs => true,
             LuaSyntaxKind::DocTagReturnCast => true,
+            LuaSyntaxKind::DocTagFormatString => true,
             LuaSyntaxKind::TypeName => true,
             LuaSyntaxKind::TypeArray => true,
             LuaSyntaxKind::TypeFun => true,
@@ -266,6 +285,8 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::TypeBinary => true,
             LuaSyntaxKind::TypeUnary => true,
             LuaSyntaxKind::TypeConditional => true,
+            LuaSyntaxKind::TypeIndexAccess => true,
+            LuaSyntaxKind::TypeMapped => true,
             LuaSyntaxKind::TypeTuple => true,
             LuaSyntaxKind::TypeLiteral => true,
             LuaSyntaxKind::TypeVariadic => true,
@@ -285,12 +306,19 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::Chunk => LuaChunk::cast(syntax).map(LuaAst::LuaChunk),
             LuaSyntaxKind::Block => LuaBlock::cast(syntax).map(LuaAst::LuaBlock),
             LuaSyntaxKind::AssignStat => LuaAssignStat::cast(syntax).map(LuaAst::LuaAssignStat),
+            LuaSyntaxKind::GlobalStat => LuaGlobalStat::cast(syntax).map(LuaAst::LuaGlobalStat),
             LuaSyntaxKind::LocalStat => LuaLocalStat::cast(syntax).map(LuaAst::LuaLocalStat),
             LuaSyntaxKind::CallExprStat => {
                 LuaCallExprStat::cast(syntax).map(LuaAst::LuaCallExprStat)
             }
             LuaSyntaxKind::LabelStat => LuaLabelStat::cast(syntax).map(LuaAst::LuaLabelStat),
             LuaSyntaxKind::BreakStat => LuaBreakStat::cast(syntax).map(LuaAst::LuaBreakStat),
+            LuaSyntaxKind::ContinueStat => {
+                LuaContinueStat::cast(syntax).map(LuaAst::LuaContinueStat)
+            }
+            LuaSyntaxKind::TypeAliasStat => {
+                LuaTypeAliasStat::cast(syntax).map(LuaAst::LuaTypeAliasStat)
+            }
             LuaSyntaxKind::GotoStat => LuaGotoStat::cast(syntax).map(LuaAst::LuaGotoStat),
             LuaSyntaxKind::DoStat => LuaDoStat::cast(syntax).map(LuaAst::LuaDoStat),
             LuaSyntaxKind::WhileStat => LuaWhileStat::cast(syntax).map(LuaAst::LuaWhileStat),
@@ -324,6 +352,9 @@ impl LuaAstNode for LuaAst {
                 LuaCallExpr::cast(syntax).map(LuaAst::LuaCallExpr)
             }
             LuaSyntaxKind::LiteralExpr => LuaLiteralExpr::cast(syntax).map(LuaAst::LuaLiteralExpr),
+            LuaSyntaxKind::InterpStringExpr => {
+                LuaInterpStringExpr::cast(syntax).map(LuaAst::LuaInterpStringExpr)
+            }
             LuaSyntaxKind::ClosureExpr => LuaClosureExpr::cast(syntax).map(LuaAst::LuaClosureExpr),
             LuaSyntaxKind::Comment => LuaComment::cast(syntax).map(LuaAst::LuaComment),
             LuaSyntaxKind::TableFieldAssign | LuaSyntaxKind::TableFieldValue => {
@@ -394,6 +425,9 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::DocTagReturnCast => {
                 LuaDocTagReturnCast::cast(syntax).map(LuaAst::LuaDocTagReturnCast)
             }
+            LuaSyntaxKind::DocTagFormatString => {
+                LuaDocTagFormatString::cast(syntax).map(LuaAst::LuaDocTagFormatString)
+            }
             LuaSyntaxKind::TypeName => LuaDocNameType::cast(syntax).map(LuaAst::LuaDocNameType),
             LuaSyntaxKind::TypeArray => LuaDocArrayType::cast(syntax).map(LuaAst::LuaDocArrayType),
             LuaSyntaxKind::TypeFun => LuaDocFuncType::cast(syntax).map(LuaAst::LuaDocFuncType),
@@ -407,6 +441,12 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::TypeConditional => {
                 LuaDocConditionalType::cast(syntax).map(LuaAst::LuaDocConditionalType)
             }
+            LuaSyntaxKind::TypeIndexAccess => {
+                LuaDocIndexAccessType::cast(syntax).map(LuaAst::LuaDocIndexAccessType)
+            }
+            LuaSyntaxKind::TypeMapped => {
+                LuaDocMappedType::cast(syntax).map(LuaAst::LuaDocMappedType)
+            }
             LuaSyntaxKind::TypeTuple => LuaDocTupleType::cast(syntax).map(LuaAst::LuaDocTupleType),
             LuaSyntaxKind::TypeLiteral => {
                 LuaDocLiteralType::cast(syntax).map(LuaAst::LuaDocLiteralType)
@@ -261,7 +261,9 @@ impl LuaAstToken for LuaKeywordToken {
             | LuaTokenKind::TkThen
             | LuaTokenKind::TkTrue
             | LuaTokenKind::TkUntil
-            | LuaTokenKind::TkWhile => true,
+            | LuaTokenKind::TkWhile
+            | LuaTokenKind::TkGlobal
+            | LuaTokenKind::TkContinue => true,
             _ => false,
         }
     }
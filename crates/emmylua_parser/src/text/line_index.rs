@@ -112,7 +112,8 @@ impl LineIndex {
         }
 
         if self.is_line_only_ascii(line.try_into().unwrap()) {
-            let col = col.min(source_text.len());
+            let line_len = self.get_line_text(line, source_text)?.len();
+            let col = col.min(line_len);
             Some(start_offset + TextSize::from(col as u32))
         } else {
             let mut offset = 0;
@@ -128,4 +129,51 @@ impl LineIndex {
             Some(start_offset + TextSize::from(offset as u32))
         }
     }
+
+    /// get offset by line and col, the col counts UTF-16 code units like the positions of the
+    /// language server protocol, and a col past the end of the line stops at the end of the line
+    pub fn get_offset_by_utf16_col(
+        &self,
+        line: usize,
+        col: usize,
+        source_text: &str,
+    ) -> Option<TextSize> {
+        let start_offset = self.get_line_offset(line)?;
+        let line_text = self.get_line_text(line, source_text)?;
+        let mut offset = 0;
+        let mut utf16_col = 0;
+        for c in line_text.chars() {
+            if utf16_col >= col {
+                break;
+            }
+
+            utf16_col += c.len_utf16();
+            offset += c.len_utf8();
+        }
+        Some(start_offset + TextSize::from(offset as u32))
+    }
+
+    /// get line and col base 0, the col counts UTF-16 code units like the positions of the
+    /// language server protocol
+    pub fn get_line_utf16_col(
+        &self,
+        offset: TextSize,
+        source_text: &str,
+    ) -> Option<(usize, usize)> {
+        let (line, start_offset) = self.get_line_with_start_offset(offset)?;
+        let text = source_text.get(usize::from(start_offset)..usize::from(offset))?;
+        Some((line, text.encode_utf16().count()))
+    }
+
+    // the text of the line without the line break
+    fn get_line_text<'a>(&self, line: usize, source_text: &'a str) -> Option<&'a str> {
+        let start = usize::from(self.get_line_offset(line)?);
+        let end = match self.get_line_offset(line + 1) {
+            Some(next_line_offset) => usize::from(next_line_offset),
+            None => source_text.len(),
+        };
+        let line_text = source_text.get(start..end)?;
+        let line_text = line_text.strip_suffix('\n').unwrap_or(line_text);
+        Some(line_text.strip_suffix('\r').unwrap_or(line_text))
+    }
 }
@@ -25,4 +25,24 @@ mod tests {
         let line_col_3 = tree.get_line_col(offset_3, code).unwrap();
         assert_eq!(line_col_3, (3, 0));
     }
+
+    #[test]
+    fn test_utf16_col() {
+        let code = "local a = 1\nlocal s = \"😀你\" -- x\r\nend";
+        let tree = LineIndex::parse(code);
+        // the emoji takes two UTF-16 code units and four bytes
+        let offset = tree.get_offset_by_utf16_col(1, 13, code).unwrap();
+        assert_eq!(&code[usize::from(offset)..usize::from(offset) + 3], "你");
+        let offset = tree.get_offset_by_utf16_col(1, 14, code).unwrap();
+        assert_eq!(&code[usize::from(offset)..usize::from(offset) + 1], "\"");
+        // a col past the end of the line stops before the line break
+        let offset = tree.get_offset_by_utf16_col(1, 100, code).unwrap();
+        assert_eq!(&code[usize::from(offset)..usize::from(offset) + 2], "\r\n");
+        let offset = tree.get_offset(0, 100, code).unwrap();
+        assert_eq!(offset, 11.into());
+
+        let offset = tree.get_offset_by_utf16_col(1, 13, code).unwrap();
+        assert_eq!(tree.get_line_utf16_col(offset, code), Some((1, 13)));
+        assert_eq!(tree.get_line_col(offset, code), Some((1, 12)));
+    }
 }